@@ -6,6 +6,7 @@ fn main() {
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=build/windows/sempal.rc");
     println!("cargo:rerun-if-changed=assets/logo3.ico");
+    println!("cargo:rerun-if-env-changed=SEMPAL_GIT_SHA");
 
     if compiling_for_windows_target()
         && let Err(error) = compile_windows_resources()
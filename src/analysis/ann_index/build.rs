@@ -5,7 +5,7 @@ use super::state::{
 use super::storage::{
     default_index_path, hnsw_dump_paths, legacy_id_map_path_for, load_legacy_id_map, read_meta,
 };
-use crate::analysis::decode_f32_le_blob;
+use crate::analysis::vector::decode_embedding_blob;
 use hnsw_rs::hnswio::HnswIo;
 use hnsw_rs::prelude::*;
 use rusqlite::{Connection, params};
@@ -91,7 +91,7 @@ fn insert_embeddings(
 ) -> Result<(), String> {
     let mut stmt = conn
         .prepare(
-            "SELECT sample_id, vec
+            "SELECT sample_id, dtype, vec
              FROM embeddings
              WHERE model_id = ?1
              ORDER BY sample_id ASC",
@@ -102,8 +102,15 @@ fn insert_embeddings(
         .map_err(|err| format!("Failed to iterate embeddings: {err}"))?;
     while let Some(row) = rows.next().map_err(|err| err.to_string())? {
         let sample_id: String = row.get(0).map_err(|err| err.to_string())?;
-        let blob: Vec<u8> = row.get(1).map_err(|err| err.to_string())?;
-        let embedding = decode_f32_le_blob(&blob)?;
+        let dtype: String = row.get(1).map_err(|err| err.to_string())?;
+        let blob: Vec<u8> = row.get(2).map_err(|err| err.to_string())?;
+        let embedding = match decode_embedding_blob(&blob, &dtype) {
+            Ok(embedding) => embedding,
+            Err(err) => {
+                tracing::warn!("Skipping corrupt embedding for {sample_id} in ANN build: {err}");
+                continue;
+            }
+        };
         if embedding.len() != params.dim {
             continue;
         }
@@ -16,7 +16,8 @@ pub(crate) mod update;
 #[cfg(not(test))]
 mod update;
 
-use crate::analysis::{decode_f32_le_blob, similarity};
+use crate::analysis::similarity;
+use crate::analysis::vector::decode_embedding_blob;
 use rusqlite::Connection;
 use std::collections::HashMap;
 use std::sync::{Arc, LazyLock, RwLock};
@@ -224,7 +225,7 @@ fn fallback_neighbors(
 ) -> Result<Vec<SimilarNeighbor>, String> {
     let mut stmt = conn
         .prepare(
-            "SELECT sample_id, vec
+            "SELECT sample_id, dtype, vec
              FROM embeddings
              WHERE model_id = ?1",
         )
@@ -238,8 +239,15 @@ fn fallback_neighbors(
         if skip_id == Some(sample_id.as_str()) {
             continue;
         }
-        let blob: Vec<u8> = row.get(1).map_err(|err| err.to_string())?;
-        let candidate = decode_f32_le_blob(&blob)?;
+        let dtype: String = row.get(1).map_err(|err| err.to_string())?;
+        let blob: Vec<u8> = row.get(2).map_err(|err| err.to_string())?;
+        let candidate = match decode_embedding_blob(&blob, &dtype) {
+            Ok(candidate) => candidate,
+            Err(err) => {
+                tracing::warn!("Skipping corrupt embedding for {sample_id}: {err}");
+                continue;
+            }
+        };
         if candidate.len() != embedding.len() {
             continue;
         }
@@ -281,12 +289,13 @@ pub fn rebuild_index(conn: &Connection) -> Result<(), String> {
 }
 
 fn load_embedding(conn: &Connection, sample_id: &str) -> Result<Vec<f32>, String> {
-    let blob: Vec<u8> = conn
+    let (dtype, blob): (String, Vec<u8>) = conn
         .query_row(
-            "SELECT vec FROM embeddings WHERE sample_id = ?1 AND model_id = ?2",
+            "SELECT dtype, vec FROM embeddings WHERE sample_id = ?1 AND model_id = ?2",
             rusqlite::params![sample_id, similarity::SIMILARITY_MODEL_ID],
-            |row| row.get(0),
+            |row| Ok((row.get(0)?, row.get(1)?)),
         )
         .map_err(|err| format!("Failed to load embedding for {sample_id}: {err}"))?;
-    decode_f32_le_blob(&blob)
+    decode_embedding_blob(&blob, &dtype)
+        .map_err(|err| format!("Failed to decode embedding for {sample_id}: {err}"))
 }
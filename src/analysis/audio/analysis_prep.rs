@@ -1,5 +1,6 @@
 use super::normalize::{normalize_peak_in_place, rms};
 use super::silence::trim_silence_with_hysteresis;
+use crate::analysis::time_domain::is_silent;
 use super::{
     AnalysisAudio, MAX_ANALYSIS_SECONDS, MIN_ANALYSIS_SECONDS, WINDOW_HOP_SECONDS, WINDOW_SECONDS,
 };
@@ -12,6 +13,7 @@ pub(crate) fn prepare_mono_for_analysis_from_slice(
     samples: &[f32],
     sample_rate: u32,
 ) -> AnalysisAudio {
+    let silent = is_silent(samples);
     let mut processed = trim_silence_with_hysteresis(samples, sample_rate);
     processed = apply_energy_windowing(&processed, sample_rate);
     pad_to_min_duration(&mut processed, sample_rate);
@@ -21,6 +23,8 @@ pub(crate) fn prepare_mono_for_analysis_from_slice(
         mono: processed,
         duration_seconds,
         sample_rate_used: sample_rate,
+        sample_rate_mismatch: false,
+        silent,
     }
 }
 
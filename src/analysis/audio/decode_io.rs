@@ -8,8 +8,10 @@ use symphonia::core::io::MediaSourceStream;
 use std::time::Duration;
 
 use super::analysis_prep::{downmix_to_mono_into, prepare_mono_for_analysis_from_slice};
+use super::rate_check::likely_sample_rate_mismatch;
 use super::resample::resample_linear_into;
 use super::{ANALYSIS_SAMPLE_RATE, AnalysisAudio, MAX_ANALYSIS_SECONDS, WINDOW_SECONDS};
+use crate::analysis::time_domain::is_silent;
 
 pub(crate) fn decode_for_analysis(path: &Path) -> Result<AnalysisAudio, String> {
     decode_for_analysis_with_rate(path, ANALYSIS_SAMPLE_RATE)
@@ -17,9 +19,7 @@ pub(crate) fn decode_for_analysis(path: &Path) -> Result<AnalysisAudio, String>
 
 pub(crate) struct AudioProbe {
     pub(crate) duration_seconds: Option<f32>,
-    #[allow(dead_code)]
     pub(crate) sample_rate: Option<u32>,
-    #[allow(dead_code)]
     pub(crate) channels: Option<u16>,
 }
 
@@ -50,11 +50,8 @@ pub(crate) fn probe_metadata(path: &Path) -> Result<AudioProbe, String> {
         .and_then(|ext| ext.to_str())
         .map(str::to_ascii_lowercase);
     let mss = MediaSourceStream::new(Box::new(file), Default::default());
-    let mut decoder = SymphoniaDecoder::new(mss)
+    let decoder = SymphoniaDecoder::new_with_hint(mss, hint.as_deref().unwrap_or("wav"))
         .map_err(|err| format!("Audio metadata probe failed for {}: {err}", path.display()))?;
-    if let Some(hint) = hint.as_deref() {
-        decoder.set_hint(hint);
-    }
     Ok(AudioProbe {
         duration_seconds: decoder.total_duration().map(|dur: Duration| dur.as_secs_f32()),
         sample_rate: Some(decoder.sample_rate().max(1)),
@@ -80,6 +77,8 @@ pub(crate) fn decode_for_analysis_with_rate_limit(
         .map(|limit| default_max.min(limit + WINDOW_SECONDS))
         .unwrap_or(default_max);
     let decoded = crate::analysis::audio_decode::decode_audio(path, Some(max_decode_seconds))?;
+    // Judge silence on every channel of the whole file; a capped decode can miss a late onset.
+    let silent = !decoded.truncated && is_silent(&decoded.samples);
     DECODE_SCRATCH.with(|scratch| {
         let mut scratch = scratch.borrow_mut();
         downmix_to_mono_into(&mut scratch.mono, &decoded.samples, decoded.channels);
@@ -91,10 +90,11 @@ pub(crate) fn decode_for_analysis_with_rate_limit(
             decoded.sample_rate,
             sample_rate,
         );
-        Ok(prepare_mono_for_analysis_from_slice(
-            &resampled,
-            sample_rate,
-        ))
+        let mut audio = prepare_mono_for_analysis_from_slice(&resampled, sample_rate);
+        audio.sample_rate_mismatch =
+            likely_sample_rate_mismatch(&scratch.mono[..mono_len], decoded.sample_rate);
+        audio.silent = silent;
+        Ok(audio)
     })
 }
 
@@ -225,4 +225,55 @@ mod tests {
             .fold(0.0, f32::max);
         assert!(peak > 0.5);
     }
+
+    #[test]
+    fn late_onset_past_the_decode_cap_is_not_reported_silent() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("late.wav");
+        let sample_rate = 8_000;
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        };
+        let mut writer = WavWriter::create(&path, spec).unwrap();
+        for _ in 0..(sample_rate * 10) {
+            writer.write_sample::<i16>(0).unwrap();
+        }
+        for _ in 0..(sample_rate / 2) {
+            writer.write_sample::<i16>(i16::MAX / 2).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let decoded = decode_for_analysis(&path).unwrap();
+        assert!(!decoded.silent);
+    }
+
+    #[test]
+    fn silence_is_judged_before_downmix() {
+        let dir = TempDir::new().unwrap();
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 8_000,
+            bits_per_sample: 32,
+            sample_format: SampleFormat::Float,
+        };
+        let silent_path = dir.path().join("silent.wav");
+        let mut writer = WavWriter::create(&silent_path, spec).unwrap();
+        for _ in 0..(8_000 * 2) {
+            writer.write_sample::<f32>(0.0).unwrap();
+        }
+        writer.finalize().unwrap();
+        let opposed_path = dir.path().join("opposed.wav");
+        let mut writer = WavWriter::create(&opposed_path, spec).unwrap();
+        for _ in 0..8_000 {
+            writer.write_sample::<f32>(0.5).unwrap();
+            writer.write_sample::<f32>(-0.5).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        assert!(decode_for_analysis(&silent_path).unwrap().silent);
+        assert!(!decode_for_analysis(&opposed_path).unwrap().silent);
+    }
 }
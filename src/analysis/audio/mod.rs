@@ -2,6 +2,7 @@ mod analysis_prep;
 mod decode;
 mod decode_io;
 mod normalize;
+mod rate_check;
 mod resample;
 mod silence;
 
@@ -32,6 +33,10 @@ pub(crate) struct AnalysisAudio {
     pub(crate) mono: Vec<f32>,
     pub(crate) duration_seconds: f32,
     pub(crate) sample_rate_used: u32,
+    /// Set when the source audio looks like its header declares the wrong sample rate.
+    pub(crate) sample_rate_mismatch: bool,
+    /// Set when the source audio peaks below the silence threshold before normalization.
+    pub(crate) silent: bool,
 }
 
 pub(crate) fn preprocess_mono_for_embedding(samples: &[f32], sample_rate: u32) -> Vec<f32> {
@@ -44,3 +49,14 @@ pub(crate) fn preprocess_mono_for_embedding(samples: &[f32], sample_rate: u32) -
 pub(crate) fn prepare_mono_for_analysis(samples: Vec<f32>, sample_rate: u32) -> AnalysisAudio {
     decode::prepare_mono_for_analysis(samples, sample_rate)
 }
+
+/// Resample in-memory mono audio to `sample_rate`, then apply the post-decode preparation.
+pub(crate) fn prepare_mono_for_analysis_with_rate(
+    samples: &[f32],
+    input_rate: u32,
+    sample_rate: u32,
+) -> AnalysisAudio {
+    let mut resampled = Vec::new();
+    resample::resample_linear_into(&mut resampled, samples, input_rate, sample_rate);
+    analysis_prep::prepare_mono_for_analysis_from_slice(&resampled, sample_rate)
+}
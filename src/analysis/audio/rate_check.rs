@@ -0,0 +1,81 @@
+//! Heuristic check for audio whose header declares the wrong sample rate.
+//!
+//! A header that under-reports the rate squeezes the whole spectrum towards Nyquist.
+//! Recordings made at low rates rarely keep most of their energy that close to the
+//! band edge, so a high centroid relative to a low declared Nyquist is a strong hint.
+
+use crate::analysis::fft::{hann_window, rfft};
+
+/// Declared rates at or below this put Nyquist inside the audible band.
+const MAX_SUSPECT_RATE: u32 = 32_000;
+/// Spectral centroid, as a fraction of Nyquist, above which the declared rate is suspect.
+const CENTROID_NYQUIST_RATIO: f32 = 0.6;
+const FFT_LEN: usize = 2048;
+const MAX_FRAMES: usize = 32;
+const MIN_FRAME_POWER: f32 = 1e-8;
+
+/// Return true when `mono`, decoded at `declared_rate`, looks like it was recorded at a higher rate.
+pub(crate) fn likely_sample_rate_mismatch(mono: &[f32], declared_rate: u32) -> bool {
+    if declared_rate == 0 || declared_rate > MAX_SUSPECT_RATE || mono.len() < FFT_LEN {
+        return false;
+    }
+    let Some(centroid_ratio) = mean_centroid_ratio(mono) else {
+        return false;
+    };
+    centroid_ratio > CENTROID_NYQUIST_RATIO
+}
+
+/// Power-weighted spectral centroid across up to `MAX_FRAMES` frames, as a fraction of Nyquist.
+fn mean_centroid_ratio(mono: &[f32]) -> Option<f32> {
+    let window = hann_window(FFT_LEN);
+    let frame_count = mono.len() / FFT_LEN;
+    let step = (frame_count / MAX_FRAMES).max(1);
+    let mut frame = vec![0.0_f32; FFT_LEN];
+    let mut total_power = 0.0_f64;
+    let mut weighted_bins = 0.0_f64;
+    for index in (0..frame_count).step_by(step).take(MAX_FRAMES) {
+        let start = index * FFT_LEN;
+        for (slot, (sample, weight)) in frame
+            .iter_mut()
+            .zip(mono[start..start + FFT_LEN].iter().zip(&window))
+        {
+            *slot = sample * weight;
+        }
+        for (bin, value) in rfft(&frame).iter().enumerate() {
+            let power = (value.re * value.re + value.im * value.im) as f64;
+            total_power += power;
+            weighted_bins += power * bin as f64;
+        }
+    }
+    if total_power <= MIN_FRAME_POWER as f64 {
+        return None;
+    }
+    let nyquist_bin = (FFT_LEN / 2) as f64;
+    Some((weighted_bins / total_power / nyquist_bin) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::TAU;
+
+    fn tone(frequency: f32, sample_rate: u32, seconds: f32) -> Vec<f32> {
+        let len = (sample_rate as f32 * seconds) as usize;
+        (0..len)
+            .map(|n| (TAU * frequency * n as f32 / sample_rate as f32).sin() * 0.5)
+            .collect()
+    }
+
+    #[test]
+    fn tone_declared_at_half_its_rate_is_flagged() {
+        let samples = tone(16_000.0, 44_100, 0.5);
+        assert!(likely_sample_rate_mismatch(&samples, 22_050));
+        assert!(!likely_sample_rate_mismatch(&samples, 44_100));
+    }
+
+    #[test]
+    fn ordinary_low_rate_tone_is_not_flagged() {
+        let samples = tone(1_000.0, 22_050, 0.5);
+        assert!(!likely_sample_rate_mismatch(&samples, 22_050));
+    }
+}
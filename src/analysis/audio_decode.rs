@@ -11,15 +11,18 @@ pub(crate) struct DecodedAudio {
     pub(crate) samples: Vec<f32>,
     pub(crate) sample_rate: u32,
     pub(crate) channels: u16,
+    /// Set when decoding stopped at `max_seconds` before the end of the file.
+    pub(crate) truncated: bool,
 }
 
 /// Decode audio into interleaved `f32` samples with sample rate and channel count.
 pub(crate) fn decode_audio(path: &Path, max_seconds: Option<f32>) -> Result<DecodedAudio, String> {
     match decode_with_symphonia(path, max_seconds) {
-        Ok((samples, sample_rate, channels)) => Ok(DecodedAudio {
+        Ok((samples, sample_rate, channels, truncated)) => Ok(DecodedAudio {
             samples,
             sample_rate: sample_rate.max(1),
             channels: channels.max(1),
+            truncated,
         }),
         Err(err) => Err(format!(
             "Audio decode failed for {}: {err}",
@@ -31,7 +34,7 @@ pub(crate) fn decode_audio(path: &Path, max_seconds: Option<f32>) -> Result<Deco
 fn decode_with_symphonia(
     path: &Path,
     max_seconds: Option<f32>,
-) -> Result<(Vec<f32>, u32, u16), String> {
+) -> Result<(Vec<f32>, u32, u16, bool), String> {
     let file = File::open(path).map_err(|err| format!("Open {}: {err}", path.display()))?;
     let mss = MediaSourceStream::new(Box::new(file), Default::default());
     let mut hint = Hint::new();
@@ -69,8 +72,11 @@ fn decode_with_symphonia(
         .map_err(|err| format!("Symphonia decoder failed for {}: {err}", path.display()))?;
 
     let mut samples = Vec::new();
+    let mut skipped_packets = 0usize;
+    let mut truncated = false;
     loop {
         if max_samples.is_some_and(|limit| samples.len() >= limit) {
+            truncated = true;
             break;
         }
         let packet = match format.next_packet() {
@@ -85,7 +91,10 @@ fn decode_with_symphonia(
         };
         let audio_buf = match decoder.decode(&packet) {
             Ok(audio_buf) => audio_buf,
-            Err(Error::DecodeError(_)) => continue,
+            Err(Error::DecodeError(_)) => {
+                skipped_packets += 1;
+                continue;
+            }
             Err(err) => {
                 return Err(format!(
                     "Symphonia decode failed for {}: {err}",
@@ -100,17 +109,20 @@ fn decode_with_symphonia(
         if let Some(limit) = max_samples {
             if samples.len() >= limit {
                 samples.truncate(limit);
+                truncated = true;
                 break;
             }
         }
     }
 
-    if samples.is_empty() {
+    // A file with no frames at all is valid and analyzes as silence; only fail when every
+    // packet we saw was undecodable.
+    if samples.is_empty() && skipped_packets > 0 {
         return Err(format!(
             "Symphonia decoded 0 samples for {}",
             path.display()
         ));
     }
 
-    Ok((samples, sample_rate, channels))
+    Ok((samples, sample_rate, channels, truncated))
 }
@@ -0,0 +1,247 @@
+//! Nearest-class-centroid classifier over similarity embeddings.
+//!
+//! A zero-training baseline: each class is represented by the mean of its labeled
+//! embeddings and new embeddings are assigned to the closest mean.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::similarity::SIMILARITY_DIM;
+
+/// Prediction interface shared by embedding classifiers.
+pub trait EmbeddingClassifier {
+    /// Class labels in index order.
+    fn classes(&self) -> &[String];
+    /// Return the index of the predicted class, or `None` when the embedding can't be scored.
+    fn predict_class_index(&self, embedding: &[f32]) -> Option<usize>;
+}
+
+/// Per-class mean embeddings used for nearest-centroid classification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NearestCentroidModel {
+    /// Expected embedding length.
+    pub embedding_dim: usize,
+    /// Class labels, sorted; indices match `centroids`.
+    pub classes: Vec<String>,
+    /// Mean embedding for each class.
+    pub centroids: Vec<Vec<f32>>,
+}
+
+impl NearestCentroidModel {
+    /// Build per-class centroids from `(label, embedding)` pairs.
+    pub fn fit<'a>(
+        labeled: impl IntoIterator<Item = (&'a str, &'a [f32])>,
+    ) -> Result<Self, String> {
+        let mut sums: BTreeMap<&str, (Vec<f64>, usize)> = BTreeMap::new();
+        let mut embedding_dim = None;
+        for (label, embedding) in labeled {
+            let dim = *embedding_dim.get_or_insert(embedding.len());
+            if embedding.len() != dim {
+                return Err(format!(
+                    "Embedding length mismatch for '{label}': expected {dim}, got {}",
+                    embedding.len()
+                ));
+            }
+            if embedding.iter().any(|value| !value.is_finite()) {
+                return Err(format!(
+                    "Embedding for '{label}' contains non-finite values"
+                ));
+            }
+            let (sum, count) = sums.entry(label).or_insert_with(|| (vec![0.0; dim], 0));
+            for (acc, value) in sum.iter_mut().zip(embedding) {
+                *acc += f64::from(*value);
+            }
+            *count += 1;
+        }
+        let Some(embedding_dim) = embedding_dim.filter(|dim| *dim > 0) else {
+            return Err("No labeled embeddings to build centroids from".to_string());
+        };
+        let mut classes = Vec::with_capacity(sums.len());
+        let mut centroids = Vec::with_capacity(sums.len());
+        for (label, (sum, count)) in sums {
+            classes.push(label.to_string());
+            centroids.push(sum.into_iter().map(|v| (v / count as f64) as f32).collect());
+        }
+        Ok(Self {
+            embedding_dim,
+            classes,
+            centroids,
+        })
+    }
+
+    /// Check that class labels and centroids are consistent with `embedding_dim`.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.embedding_dim == 0 {
+            return Err("embedding_dim must be greater than 0".to_string());
+        }
+        if self.classes.is_empty() {
+            return Err("Model has no classes".to_string());
+        }
+        if self.classes.len() != self.centroids.len() {
+            return Err(format!(
+                "Class count mismatch: {} classes, {} centroids",
+                self.classes.len(),
+                self.centroids.len()
+            ));
+        }
+        for (label, centroid) in self.classes.iter().zip(&self.centroids) {
+            if centroid.len() != self.embedding_dim {
+                return Err(format!(
+                    "Centroid length mismatch for '{label}': expected {}, got {}",
+                    self.embedding_dim,
+                    centroid.len()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Predict a class index with a confidence in `(0, 1]`.
+    ///
+    /// Confidence is a softmax over negative squared centroid distances, so it approaches 1
+    /// when the embedding sits much closer to one centroid than to the others.
+    pub fn predict_with_confidence(&self, embedding: &[f32]) -> Option<(usize, f32)> {
+        if embedding.len() != self.embedding_dim {
+            return None;
+        }
+        let distances: Vec<f32> = self
+            .centroids
+            .iter()
+            .map(|centroid| {
+                centroid
+                    .iter()
+                    .zip(embedding)
+                    .map(|(c, e)| (c - e) * (c - e))
+                    .sum()
+            })
+            .collect();
+        let (best, best_distance) = distances
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, distance)| distance.is_finite())
+            .min_by(|a, b| a.1.total_cmp(&b.1))?;
+        let total: f32 = distances
+            .iter()
+            .filter(|distance| distance.is_finite())
+            .map(|distance| (best_distance - distance).exp())
+            .sum();
+        Some((best, 1.0 / total))
+    }
+}
+
+/// Read a model JSON file and check it can score this build's similarity embeddings.
+///
+/// Errors name the failing step (read, parse, validation) so a bad import can be fixed.
+pub fn load_model_validated(path: &Path) -> Result<NearestCentroidModel, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read model {}: {err}", path.display()))?;
+    let model: NearestCentroidModel = serde_json::from_str(&text)
+        .map_err(|err| format!("Model {} is not valid JSON: {err}", path.display()))?;
+    model
+        .validate()
+        .map_err(|err| format!("Invalid model {}: {err}", path.display()))?;
+    if model.embedding_dim != SIMILARITY_DIM {
+        return Err(format!(
+            "Invalid model {}: embedding_dim is {}, similarity embeddings have {SIMILARITY_DIM}",
+            path.display(),
+            model.embedding_dim
+        ));
+    }
+    Ok(model)
+}
+
+impl EmbeddingClassifier for NearestCentroidModel {
+    fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    fn predict_class_index(&self, embedding: &[f32]) -> Option<usize> {
+        if embedding.len() != self.embedding_dim {
+            return None;
+        }
+        let mut best: Option<(usize, f32)> = None;
+        for (index, centroid) in self.centroids.iter().enumerate() {
+            let distance: f32 = centroid
+                .iter()
+                .zip(embedding)
+                .map(|(c, e)| (c - e) * (c - e))
+                .sum();
+            if !distance.is_finite() {
+                continue;
+            }
+            if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+                best = Some((index, distance));
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn well_separated_clusters_classify_to_nearest_centroid() {
+        let kicks: [[f32; 2]; 3] = [[0.9, 0.1], [1.1, -0.1], [1.0, 0.0]];
+        let snares: [[f32; 2]; 3] = [[-1.0, 5.0], [-0.8, 5.2], [-1.2, 4.8]];
+        let labeled = kicks
+            .iter()
+            .map(|e| ("kick", e.as_slice()))
+            .chain(snares.iter().map(|e| ("snare", e.as_slice())));
+
+        let model = NearestCentroidModel::fit(labeled).unwrap();
+
+        assert_eq!(model.classes, vec!["kick".to_string(), "snare".to_string()]);
+        assert!(model.validate().is_ok());
+        assert!((model.centroids[0][0] - 1.0).abs() < 1e-6);
+        assert!((model.centroids[1][1] - 5.0).abs() < 1e-6);
+        assert_eq!(model.predict_class_index(&[0.7, 0.6]), Some(0));
+        assert_eq!(model.predict_class_index(&[-0.5, 3.9]), Some(1));
+        assert_eq!(model.predict_class_index(&[1.0]), None);
+        let (index, confidence) = model.predict_with_confidence(&[1.0, 0.0]).unwrap();
+        assert_eq!(index, 0);
+        assert!(confidence > 0.99 && confidence <= 1.0);
+    }
+
+    #[test]
+    fn load_model_validated_reports_embedding_dim_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("model.json");
+        std::fs::write(
+            &path,
+            r#"{"embedding_dim": 3, "classes": ["kick"], "centroids": [[0.1, 0.2]]}"#,
+        )
+        .unwrap();
+
+        let err = load_model_validated(&path).unwrap_err();
+
+        assert!(
+            err.ends_with("Centroid length mismatch for 'kick': expected 3, got 2"),
+            "{err}"
+        );
+
+        std::fs::write(
+            &path,
+            r#"{"embedding_dim": 2, "classes": ["kick"], "centroids": [[0.1, 0.2]]}"#,
+        )
+        .unwrap();
+        let err = load_model_validated(&path).unwrap_err();
+        assert!(
+            err.ends_with(&format!(
+                "embedding_dim is 2, similarity embeddings have {SIMILARITY_DIM}"
+            )),
+            "{err}"
+        );
+
+        let model = NearestCentroidModel {
+            embedding_dim: SIMILARITY_DIM,
+            classes: vec!["kick".to_string()],
+            centroids: vec![vec![0.0; SIMILARITY_DIM]],
+        };
+        std::fs::write(&path, serde_json::to_string(&model).unwrap()).unwrap();
+        assert_eq!(load_model_validated(&path).unwrap(), model);
+    }
+}
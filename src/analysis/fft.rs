@@ -1,10 +1,11 @@
 use std::f32::consts::PI;
 use std::sync::Arc;
 
-pub(crate) use rustfft::num_complex::Complex32;
+pub use rustfft::num_complex::Complex32;
 use rustfft::{Fft, FftPlanner};
 
-pub(crate) fn hann_window(length: usize) -> Vec<f32> {
+/// Symmetric Hann window of `length` taps, zero at both edges.
+pub fn hann_window(length: usize) -> Vec<f32> {
     if length <= 1 {
         return vec![1.0_f32; length.max(1)];
     }
@@ -14,6 +15,56 @@ pub(crate) fn hann_window(length: usize) -> Vec<f32> {
         .collect()
 }
 
+/// Forward FFT of a real signal, returning the `len / 2 + 1` non-negative frequency bins.
+///
+/// No window is applied and the output is unnormalized. When transforming a frame cut
+/// from a longer signal, multiply it by [`hann_window`] first to limit spectral leakage;
+/// the analyzer's own STFT frames are windowed that way. Any length is accepted, though
+/// power-of-two lengths are fastest.
+pub fn rfft(samples: &[f32]) -> Vec<Complex32> {
+    if samples.is_empty() {
+        return Vec::new();
+    }
+    let mut buffer: Vec<Complex32> = samples
+        .iter()
+        .map(|sample| Complex32::new(*sample, 0.0))
+        .collect();
+    FftPlanner::<f32>::new()
+        .plan_fft_forward(buffer.len())
+        .process(&mut buffer);
+    buffer.truncate(samples.len() / 2 + 1);
+    buffer
+}
+
+/// Inverse of [`rfft`]: rebuild `len` real samples from a `len / 2 + 1` bin half spectrum.
+///
+/// The output is scaled by `1 / len`, so `irfft(&rfft(x), x.len())` returns `x`. Any
+/// window applied before the forward transform is not undone.
+pub fn irfft(spectrum: &[Complex32], len: usize) -> Result<Vec<f32>, String> {
+    if len == 0 {
+        return Ok(Vec::new());
+    }
+    let bins = len / 2 + 1;
+    if spectrum.len() != bins {
+        return Err(format!(
+            "Inverse FFT expects {bins} bins for length {len}, got {}",
+            spectrum.len()
+        ));
+    }
+    let mut buffer = vec![Complex32::new(0.0, 0.0); len];
+    for (bin, value) in spectrum.iter().enumerate() {
+        buffer[bin] = *value;
+        if bin > 0 && bin < len - bin {
+            buffer[len - bin] = value.conj();
+        }
+    }
+    FftPlanner::<f32>::new()
+        .plan_fft_inverse(len)
+        .process(&mut buffer);
+    let scale = 1.0 / len as f32;
+    Ok(buffer.into_iter().map(|value| value.re * scale).collect())
+}
+
 #[cfg(test)]
 pub(crate) fn fft_radix2_inplace(buffer: &mut [Complex32]) -> Result<(), String> {
     let plan = FftPlan::new(buffer.len())?;
@@ -76,6 +127,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn unit_impulse_has_flat_magnitude_spectrum() {
+        let mut impulse = vec![0.0_f32; 32];
+        impulse[0] = 1.0;
+        let spectrum = rfft(&impulse);
+        assert_eq!(spectrum.len(), 17);
+        for bin in &spectrum {
+            assert!((bin.norm() - 1.0).abs() < 1e-5, "magnitude {}", bin.norm());
+        }
+    }
+
+    #[test]
+    fn irfft_round_trips_odd_and_even_lengths() {
+        for len in [15, 16] {
+            let signal: Vec<f32> = (0..len).map(|i| (i as f32 * 0.7).sin()).collect();
+            let restored = irfft(&rfft(&signal), len).unwrap();
+            for (a, b) in signal.iter().zip(&restored) {
+                assert!((a - b).abs() < 1e-4, "{a} vs {b}");
+            }
+        }
+        assert!(irfft(&[Complex32::new(1.0, 0.0)], 8).is_err());
+    }
+
     #[test]
     fn fft_plan_matches_plain_fft() {
         let mut buf = vec![Complex32::new(0.0, 0.0); 16];
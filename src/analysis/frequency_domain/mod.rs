@@ -78,6 +78,9 @@ pub(crate) fn extract_frequency_domain_features(
     samples: &[f32],
     sample_rate: u32,
 ) -> Result<FrequencyDomainFeatures, String> {
+    if sample_rate == 0 {
+        return Err("Sample rate must be greater than 0".to_string());
+    }
     let mel = MelBank::new(sample_rate, STFT_FRAME_SIZE, 40, 20, 20.0, 16_000.0);
     let frames =
         stft::compute_frames(samples, sample_rate, STFT_FRAME_SIZE, STFT_HOP_SIZE, &mel)?;
@@ -120,4 +123,16 @@ mod tests {
         assert_eq!(a.mfcc20.mean, b.mfcc20.mean);
         assert_eq!(a.mfcc20.std, b.mfcc20.std);
     }
+
+    #[test]
+    fn sub_frame_and_empty_inputs_produce_finite_features() {
+        let sr = ANALYSIS_SAMPLE_RATE;
+        for samples in [vec![], vec![0.5_f32; 10]] {
+            let feats = extract_frequency_domain_features(&samples, sr).unwrap();
+            assert_eq!(feats.mfcc20.mean.len(), 20);
+            assert!(feats.spectral.centroid_hz.mean.is_finite());
+            assert!(feats.mfcc20.std_late.iter().all(|v| v.is_finite()));
+        }
+        assert!(extract_frequency_domain_features(&[0.5; 10], 0).is_err());
+    }
 }
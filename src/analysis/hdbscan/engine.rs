@@ -55,7 +55,7 @@ fn load_embeddings(
 ) -> Result<(Vec<String>, Vec<Vec<f32>>), String> {
     let (sql, params) = if let Some(prefix) = sample_id_prefix {
         (
-            "SELECT sample_id, dim, vec
+            "SELECT sample_id, dim, dtype, vec
              FROM embeddings
              WHERE model_id = ?1 AND sample_id LIKE ?2
              ORDER BY sample_id ASC",
@@ -66,7 +66,7 @@ fn load_embeddings(
         )
     } else {
         (
-            "SELECT sample_id, dim, vec
+            "SELECT sample_id, dim, dtype, vec
              FROM embeddings
              WHERE model_id = ?1
              ORDER BY sample_id ASC",
@@ -80,8 +80,9 @@ fn load_embeddings(
         .query_map(params_from_iter(params), |row| {
             let sample_id: String = row.get(0)?;
             let dim: i64 = row.get(1)?;
-            let blob: Vec<u8> = row.get(2)?;
-            Ok((sample_id, dim as usize, blob))
+            let dtype: String = row.get(2)?;
+            let blob: Vec<u8> = row.get(3)?;
+            Ok((sample_id, dim as usize, dtype, blob))
         })
         .map_err(|err| format!("Query embeddings failed: {err}"))?;
     decode_embedding_rows(rows)
@@ -89,15 +90,15 @@ fn load_embeddings(
 
 fn decode_embedding_rows<I>(rows: I) -> Result<(Vec<String>, Vec<Vec<f32>>), String>
 where
-    I: Iterator<Item = Result<(String, usize, Vec<u8>), rusqlite::Error>>,
+    I: Iterator<Item = Result<(String, usize, String, Vec<u8>), rusqlite::Error>>,
 {
     let mut sample_ids = Vec::new();
     let mut data = Vec::new();
     let mut expected_dim: Option<usize> = None;
     for row in rows {
-        let (sample_id, dim, blob) =
+        let (sample_id, dim, dtype, blob) =
             row.map_err(|err| format!("Read embedding row failed: {err}"))?;
-        let vec = crate::analysis::decode_f32_le_blob(&blob)?;
+        let vec = crate::analysis::vector::decode_embedding_blob(&blob, &dtype)?;
         validate_embedding_dim(&sample_id, dim, vec.len(), expected_dim)?;
         expected_dim = Some(dim);
         sample_ids.push(sample_id);
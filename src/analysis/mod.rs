@@ -4,8 +4,11 @@
 pub mod ann_index;
 pub(crate) mod audio;
 pub(crate) mod audio_decode;
+/// Nearest-class-centroid classifier over similarity embeddings.
+pub mod centroid_classifier;
 pub(crate) mod features;
-pub(crate) mod fft;
+/// Real FFT helpers reusable by external feature extractors.
+pub mod fft;
 pub(crate) mod frequency_domain;
 pub mod hdbscan;
 pub mod similarity;
@@ -25,14 +28,47 @@ use std::path::Path;
 /// Lightweight DSP vector length (time-domain features only).
 pub const LIGHT_DSP_VECTOR_LEN: usize = 9;
 
+/// Features and similarity embedding computed for an in-memory buffer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampleAnalysis {
+    /// V1 feature vector with `FEATURE_VECTOR_LEN_V1` values.
+    pub features: Vec<f32>,
+    /// Normalized similarity embedding derived from `features`.
+    pub embedding: Vec<f32>,
+}
+
 /// Decode an audio file and compute the V1 feature vector used by the analyzer.
 pub fn compute_feature_vector_v1_for_path(path: &Path) -> Result<Vec<f32>, String> {
     let decoded = audio::decode_for_analysis(path)?;
+    feature_vector_v1_for_prepared(&decoded)
+}
+
+/// Analyze mono samples held in memory, such as a live or recorded buffer.
+///
+/// Runs the same resample, trim and feature pipeline as decoded files, so results match
+/// analyzing the same audio written to disk.
+pub fn analyze_samples(samples: &[f32], sample_rate: u32) -> Result<SampleAnalysis, String> {
+    if sample_rate == 0 {
+        return Err("Sample rate must be greater than 0".to_string());
+    }
+    let mut mono = samples.to_vec();
+    audio::sanitize_samples_in_place(&mut mono);
+    let prepared =
+        audio::prepare_mono_for_analysis_with_rate(&mono, sample_rate, audio::ANALYSIS_SAMPLE_RATE);
+    let features = feature_vector_v1_for_prepared(&prepared)?;
+    let embedding = similarity::embedding_from_features(&features)?;
+    Ok(SampleAnalysis {
+        features,
+        embedding,
+    })
+}
+
+fn feature_vector_v1_for_prepared(prepared: &audio::AnalysisAudio) -> Result<Vec<f32>, String> {
     let time_domain =
-        time_domain::extract_time_domain_features(&decoded.mono, decoded.sample_rate_used);
+        time_domain::extract_time_domain_features(&prepared.mono, prepared.sample_rate_used);
     let frequency_domain = frequency_domain::extract_frequency_domain_features(
-        &decoded.mono,
-        decoded.sample_rate_used,
+        &prepared.mono,
+        prepared.sample_rate_used,
     )?;
     let features = features::AnalysisFeaturesV1::new(time_domain, frequency_domain);
     Ok(vector::to_f32_vector_v1(&features))
@@ -58,14 +94,7 @@ pub fn compute_feature_vector_v1_for_mono_samples(
     let mut mono = samples.to_vec();
     audio::sanitize_samples_in_place(&mut mono);
     let prepared = audio::prepare_mono_for_analysis(mono, sample_rate);
-    let time_domain =
-        time_domain::extract_time_domain_features(&prepared.mono, prepared.sample_rate_used);
-    let frequency_domain = frequency_domain::extract_frequency_domain_features(
-        &prepared.mono,
-        prepared.sample_rate_used,
-    )?;
-    let features = features::AnalysisFeaturesV1::new(time_domain, frequency_domain);
-    Ok(vector::to_f32_vector_v1(&features))
+    feature_vector_v1_for_prepared(&prepared)
 }
 
 /// Compute the similarity embedding from mono samples using V1 DSP features.
@@ -136,4 +165,80 @@ mod tests {
         let vec = compute_feature_vector_v1_for_path(&path).unwrap();
         assert_eq!(vec.len(), FEATURE_VECTOR_LEN_V1);
     }
+
+    #[test]
+    fn ten_sample_wav_produces_full_feature_vector() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("blip.wav");
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 44_100,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        };
+        let mut writer = WavWriter::create(&path, spec).unwrap();
+        for i in 0..10 {
+            let sample = if i % 2 == 0 { i16::MAX / 2 } else { -i16::MAX / 2 };
+            writer.write_sample(sample).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let vec = compute_feature_vector_v1_for_path(&path).unwrap();
+        assert_eq!(vec.len(), FEATURE_VECTOR_LEN_V1);
+        assert!(vec.iter().all(|value| value.is_finite()));
+    }
+
+    #[test]
+    fn zero_frame_wav_decodes_as_empty_and_analyzes() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("empty.wav");
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 44_100,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        };
+        WavWriter::create(&path, spec).unwrap().finalize().unwrap();
+
+        let decoded = audio_decode::decode_audio(&path, None).unwrap();
+        assert!(decoded.samples.is_empty());
+        assert_eq!(decoded.channels, 2);
+        let vec = compute_feature_vector_v1_for_path(&path).unwrap();
+        assert_eq!(vec.len(), FEATURE_VECTOR_LEN_V1);
+        assert!(vec.iter().all(|value| value.is_finite()));
+    }
+
+    #[test]
+    fn in_memory_analysis_matches_wav_round_trip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("tone.wav");
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 44_100,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        };
+        let mut writer = WavWriter::create(&path, spec).unwrap();
+        let mut samples = Vec::with_capacity(22_050);
+        for i in 0..22_050 {
+            let t = i as f32 / 44_100.0;
+            let envelope = 1.0 - i as f32 / 22_050.0;
+            let value = (t * 330.0 * std::f32::consts::TAU).sin() * 0.6 * envelope;
+            let value_i16 = (value * i16::MAX as f32) as i16;
+            writer.write_sample(value_i16).unwrap();
+            samples.push(value_i16 as f32 / 32_768.0);
+        }
+        writer.finalize().unwrap();
+
+        let in_memory = analyze_samples(&samples, 44_100).unwrap();
+        let from_file = compute_feature_vector_v1_for_path(&path).unwrap();
+
+        assert_eq!(in_memory.features.len(), FEATURE_VECTOR_LEN_V1);
+        for (a, b) in in_memory.features.iter().zip(&from_file) {
+            assert!((a - b).abs() <= 1e-3 * (1.0 + b.abs()), "{a} vs {b}");
+        }
+        let expected = similarity::embedding_from_features(&from_file).unwrap();
+        let cosine: f32 = in_memory.embedding.iter().zip(&expected).map(|(a, b)| a * b).sum();
+        assert!(cosine > 0.9999);
+    }
 }
@@ -8,6 +8,8 @@ pub const SIMILARITY_MODEL_ID: &str = "features_v1__len183__l2";
 pub const SIMILARITY_DIM: usize = FEATURE_VECTOR_LEN_V1;
 /// Data type label for stored similarity embeddings.
 pub const SIMILARITY_DTYPE_F32: &str = "f32";
+/// Data type label for similarity embeddings stored with [`super::vector::encode_int8`].
+pub const SIMILARITY_DTYPE_I8: &str = "i8";
 /// Default analysis batch size for similarity feature extraction.
 pub const SIMILARITY_BATCH_MAX: usize = 8;
 
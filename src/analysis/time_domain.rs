@@ -41,14 +41,14 @@ pub(crate) fn extract_time_domain_features(
     }
 }
 
-fn duration_seconds(sample_count: usize, sample_rate: u32) -> f32 {
+pub(crate) fn duration_seconds(sample_count: usize, sample_rate: u32) -> f32 {
     if sample_rate == 0 {
         return 0.0;
     }
     sample_count as f32 / sample_rate as f32
 }
 
-fn peak(samples: &[f32]) -> f32 {
+pub(crate) fn peak(samples: &[f32]) -> f32 {
     if samples.is_empty() {
         return 0.0;
     }
@@ -72,7 +72,15 @@ fn peak(samples: &[f32]) -> f32 {
         .clamp(0.0, 1.0)
 }
 
-fn rms(samples: &[f32]) -> f32 {
+/// Peak level (about -80 dBFS) below which a sample is treated as silent.
+pub(crate) const SILENT_PEAK_THRESHOLD: f32 = 1.0e-4;
+
+/// Return true when the loudest sample stays below [`SILENT_PEAK_THRESHOLD`].
+pub(crate) fn is_silent(samples: &[f32]) -> bool {
+    peak(samples) < SILENT_PEAK_THRESHOLD
+}
+
+pub(crate) fn rms(samples: &[f32]) -> f32 {
     if samples.is_empty() {
         return 0.0;
     }
@@ -283,6 +291,90 @@ fn db_to_linear(db: f32) -> f32 {
     10.0_f32.powf(db / 20.0)
 }
 
+/// Quietest loudness reported; matches the BS.1770 absolute gate.
+pub(crate) const LOUDNESS_FLOOR_LUFS: f32 = -70.0;
+
+/// Ungated BS.1770 loudness of interleaved audio, in LUFS.
+///
+/// Each channel is K-weighted and its mean square summed with unit weight, which is
+/// enough for short samples where gating blocks would cover only a few windows.
+pub(crate) fn integrated_loudness_lufs(samples: &[f32], channels: usize, sample_rate: u32) -> f32 {
+    let channels = channels.max(1);
+    let frames = samples.len() / channels;
+    if frames == 0 || sample_rate == 0 {
+        return LOUDNESS_FLOOR_LUFS;
+    }
+    let mut power = 0.0_f64;
+    for channel in 0..channels {
+        let mut shelf = Biquad::k_weighting_shelf(sample_rate);
+        let mut high_pass = Biquad::k_weighting_high_pass(sample_rate);
+        let mut sum = 0.0_f64;
+        for frame in 0..frames {
+            let sample = sanitize_sample(samples[frame * channels + channel]) as f64;
+            let weighted = high_pass.process(shelf.process(sample));
+            sum += weighted * weighted;
+        }
+        power += sum / frames as f64;
+    }
+    if power <= 0.0 {
+        return LOUDNESS_FLOOR_LUFS;
+    }
+    ((-0.691 + 10.0 * power.log10()) as f32).max(LOUDNESS_FLOOR_LUFS)
+}
+
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    x: [f64; 2],
+    y: [f64; 2],
+}
+
+impl Biquad {
+    fn k_weighting_shelf(sample_rate: u32) -> Self {
+        let k = (std::f64::consts::PI * 1681.974_450_955_533 / sample_rate as f64).tan();
+        let q = 0.707_175_236_955_419_6;
+        let vh = 10.0_f64.powf(3.999_843_853_973_347 / 20.0);
+        let vb = vh.powf(0.499_666_774_154_541_6);
+        let a0 = 1.0 + k / q + k * k;
+        Self::new(
+            [
+                (vh + vb * k / q + k * k) / a0,
+                2.0 * (k * k - vh) / a0,
+                (vh - vb * k / q + k * k) / a0,
+            ],
+            [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        )
+    }
+
+    fn k_weighting_high_pass(sample_rate: u32) -> Self {
+        let k = (std::f64::consts::PI * 38.135_470_876_024_44 / sample_rate as f64).tan();
+        let q = 0.500_327_037_323_877_3;
+        let a0 = 1.0 + k / q + k * k;
+        Self::new(
+            [1.0, -2.0, 1.0],
+            [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        )
+    }
+
+    fn new(b: [f64; 3], a: [f64; 2]) -> Self {
+        Self {
+            b,
+            a,
+            x: [0.0; 2],
+            y: [0.0; 2],
+        }
+    }
+
+    fn process(&mut self, input: f64) -> f64 {
+        let output = self.b[0] * input + self.b[1] * self.x[0] + self.b[2] * self.x[1]
+            - self.a[0] * self.y[0]
+            - self.a[1] * self.y[1];
+        self.x = [input, self.x[0]];
+        self.y = [output, self.y[0]];
+        output
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,6 +392,17 @@ mod tests {
         assert!(feats.onset_count >= 1);
     }
 
+    #[test]
+    fn all_zero_buffer_is_silent_but_tone_is_not() {
+        let sr = ANALYSIS_SAMPLE_RATE;
+        let zeros = vec![0.0_f32; sr as usize / 10];
+        assert!(is_silent(&zeros));
+        let tone: Vec<f32> = (0..sr as usize / 10)
+            .map(|n| (n as f32 * 0.1).sin() * 0.5)
+            .collect();
+        assert!(!is_silent(&tone));
+    }
+
     #[test]
     fn alternating_signal_has_high_zero_crossing_rate() {
         let sr = ANALYSIS_SAMPLE_RATE;
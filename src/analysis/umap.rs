@@ -1,13 +1,11 @@
-use crate::analysis::decode_f32_le_blob;
-use linfa::dataset::DatasetBase;
-use linfa::traits::{Fit, Transformer};
-use linfa_reduction::Pca;
+use crate::analysis::vector::{PcaProjection, decode_embedding_blob};
+use linfa::traits::Transformer;
 use linfa_tsne::TSneParams;
 use ndarray::Array2;
 use rand_08::SeedableRng;
 use rand_08::rngs::SmallRng;
-use rusqlite::{Connection, params};
-use serde::Serialize;
+use rusqlite::{Connection, OptionalExtension, params};
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -15,7 +13,9 @@ const DEFAULT_PERPLEXITY: f64 = 35.0;
 const DEFAULT_APPROX_THRESHOLD: f64 = 0.5;
 const DEFAULT_MAX_ITER: usize = 1500;
 const DEFAULT_N_COMPONENTS: usize = 2;
-const DEFAULT_PCA_COMPONENTS: usize = 50;
+/// Default number of principal components kept before running t-SNE.
+pub const DEFAULT_PCA_COMPONENTS: usize = 50;
+const PCA_CACHE_KEY_PREFIX: &str = "pca_projection_v2";
 
 /// Report summarizing the UMAP/t-SNE layout coverage and bounds.
 #[derive(Debug, Serialize)]
@@ -45,11 +45,36 @@ pub fn build_umap_layout(
     umap_version: &str,
     seed: u64,
     min_coverage: f32,
+) -> Result<UmapReport, String> {
+    build_umap_layout_with_pca(
+        conn,
+        model_id,
+        umap_version,
+        seed,
+        min_coverage,
+        DEFAULT_PCA_COMPONENTS,
+    )
+}
+
+/// Build and persist a 2D layout, first reducing embeddings to `pca_components` dimensions.
+///
+/// The fitted PCA projection is cached in the database and reused until the embeddings change,
+/// so rebuilding an unchanged library's map skips the refit.
+pub fn build_umap_layout_with_pca(
+    conn: &mut Connection,
+    model_id: &str,
+    umap_version: &str,
+    seed: u64,
+    min_coverage: f32,
+    pca_components: usize,
 ) -> Result<UmapReport, String> {
     let (sample_ids, vectors, dim) = load_embeddings(conn, model_id)?;
     if vectors.is_empty() {
         return Err(format!("No embeddings found for model_id {model_id}"));
     }
+    let fingerprint = embeddings_fingerprint(conn, model_id, &sample_ids)?;
+    let (vectors, dim) =
+        reduce_with_cached_pca(conn, model_id, &fingerprint, vectors, dim, pca_components)?;
     let layout = compute_tsne(vectors, dim, seed)?;
     if layout.len() != sample_ids.len() {
         return Err("t-SNE output length mismatch".to_string());
@@ -89,7 +114,7 @@ fn load_embeddings(
 
     let mut stmt = conn
         .prepare(
-            "SELECT sample_id, dim, vec
+            "SELECT sample_id, dim, dtype, vec
              FROM embeddings
              WHERE model_id = ?1
              ORDER BY sample_id ASC",
@@ -99,17 +124,18 @@ fn load_embeddings(
         .query_map(params![model_id], |row| {
             let sample_id: String = row.get(0)?;
             let dim: i64 = row.get(1)?;
-            let blob: Vec<u8> = row.get(2)?;
-            Ok((sample_id, dim as usize, blob))
+            let dtype: String = row.get(2)?;
+            let blob: Vec<u8> = row.get(3)?;
+            Ok((sample_id, dim as usize, dtype, blob))
         })
         .map_err(|err| format!("Query embeddings failed: {err}"))?;
     let mut sample_ids = Vec::with_capacity(count);
     let mut vectors = Vec::new();
     let mut expected_dim: Option<usize> = None;
     for row in rows {
-        let (sample_id, dim, blob) =
+        let (sample_id, dim, dtype, blob) =
             row.map_err(|err| format!("Read embedding row failed: {err}"))?;
-        let vec = decode_f32_le_blob(&blob)?;
+        let vec = decode_embedding_blob(&blob, &dtype)?;
         if vec.len() != dim {
             return Err(format!(
                 "Embedding dim mismatch for {sample_id}: expected {dim}, got {}",
@@ -138,6 +164,35 @@ fn load_embeddings(
     Ok((sample_ids, vectors, dim))
 }
 
+/// Identify the embedding set so a cached projection is dropped after re-analysis or a model
+/// bump, where every vector changes but the count stays the same.
+fn embeddings_fingerprint(
+    conn: &Connection,
+    model_id: &str,
+    sample_ids: &[String],
+) -> Result<String, String> {
+    let newest: Option<i64> = conn
+        .query_row(
+            "SELECT MAX(created_at) FROM embeddings WHERE model_id = ?1",
+            params![model_id],
+            |row| row.get(0),
+        )
+        .map_err(|err| format!("Read embedding timestamps failed: {err}"))?;
+    // FNV-1a, so the fingerprint stays stable across builds and Rust versions.
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    for sample_id in sample_ids {
+        for byte in sample_id.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    Ok(format!(
+        "{}:{}:{hash:016x}",
+        sample_ids.len(),
+        newest.unwrap_or(0)
+    ))
+}
+
 fn compute_tsne(vectors: Vec<f64>, dim: usize, seed: u64) -> Result<Vec<[f32; 2]>, String> {
     let n_samples = vectors.len() / dim;
     if n_samples < 2 {
@@ -147,24 +202,9 @@ fn compute_tsne(vectors: Vec<f64>, dim: usize, seed: u64) -> Result<Vec<[f32; 2]
     let perplexity = DEFAULT_PERPLEXITY.min(max_perplexity).max(1.0);
 
     // Create matrix from owned vector to avoid additional copying.
-    let mut matrix = Array2::from_shape_vec((n_samples, dim), vectors)
+    let matrix = Array2::from_shape_vec((n_samples, dim), vectors)
         .map_err(|err| format!("Build embedding matrix failed: {err}"))?;
 
-    if dim > DEFAULT_PCA_COMPONENTS {
-        let pca_components = DEFAULT_PCA_COMPONENTS
-            .min(dim)
-            .min(n_samples.saturating_sub(1).max(1));
-        if pca_components < 2 {
-            return Err("Need at least 2 samples for PCA reduction".to_string());
-        }
-        let dataset = DatasetBase::from(matrix);
-        let pca = Pca::params(pca_components)
-            .fit(&dataset)
-            .map_err(|err| format!("PCA fit failed: {err}"))?;
-        let reduced = pca.transform(dataset);
-        matrix = reduced.records;
-    }
-
     let rng = SmallRng::seed_from_u64(seed);
     let embedding = TSneParams::embedding_size_with_rng(DEFAULT_N_COMPONENTS, rng)
         .perplexity(perplexity)
@@ -180,6 +220,90 @@ fn compute_tsne(vectors: Vec<f64>, dim: usize, seed: u64) -> Result<Vec<[f32; 2]
     Ok(out)
 }
 
+/// Project embeddings onto at most `pca_components` principal components, reusing a cached fit.
+fn reduce_with_cached_pca(
+    conn: &Connection,
+    model_id: &str,
+    fingerprint: &str,
+    vectors: Vec<f64>,
+    dim: usize,
+    pca_components: usize,
+) -> Result<(Vec<f64>, usize), String> {
+    let n_samples = vectors.len() / dim;
+    let components = pca_components
+        .min(dim)
+        .min(n_samples.saturating_sub(1).max(1));
+    if dim <= pca_components || components < 2 {
+        return Ok((vectors, dim));
+    }
+    let cache_key = format!("{PCA_CACHE_KEY_PREFIX}::{model_id}");
+    let cached = load_cached_projection(conn, &cache_key)
+        .filter(|cached| projection_still_fits(cached, fingerprint, dim, components, n_samples));
+    let projection = match cached {
+        Some(cached) => cached.projection,
+        None => {
+            let fitted = CachedPca {
+                fingerprint: fingerprint.to_string(),
+                projection: PcaProjection::fit(&vectors, dim, components)?,
+            };
+            if let Err(err) = store_cached_projection(conn, &cache_key, &fitted) {
+                tracing::warn!("Failed to cache PCA projection: {err}");
+            }
+            fitted.projection
+        }
+    };
+    Ok((projection.project_rows(&vectors), components))
+}
+
+/// PCA projection persisted alongside the fingerprint of the embeddings it was fitted on.
+#[derive(Serialize, Deserialize)]
+struct CachedPca {
+    fingerprint: String,
+    projection: PcaProjection,
+}
+
+fn projection_still_fits(
+    cached: &CachedPca,
+    fingerprint: &str,
+    dim: usize,
+    components: usize,
+    n_samples: usize,
+) -> bool {
+    let projection = &cached.projection;
+    cached.fingerprint == fingerprint
+        && projection.fitted_rows == n_samples
+        && projection.input_dim == dim
+        && projection.output_dim == components
+}
+
+fn load_cached_projection(conn: &Connection, key: &str) -> Option<CachedPca> {
+    let payload: Option<String> = conn
+        .query_row("SELECT value FROM metadata WHERE key = ?1", [key], |row| {
+            row.get(0)
+        })
+        .optional()
+        .ok()
+        .flatten();
+    serde_json::from_str(&payload?).ok()
+}
+
+fn store_cached_projection(
+    conn: &Connection,
+    key: &str,
+    cached: &CachedPca,
+) -> Result<(), String> {
+    let payload = serde_json::to_string(cached)
+        .map_err(|err| format!("Encode PCA projection failed: {err}"))?;
+    conn.execute(
+        "INSERT INTO metadata (key, value)
+         VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        params![key, payload],
+    )
+    .map_err(|err| format!("Store PCA projection failed: {err}"))?;
+    Ok(())
+}
+
 fn validate_layout(layout: &[[f32; 2]], min_coverage: f32) -> Result<UmapReport, String> {
     let total = layout.len();
     let mut valid = 0usize;
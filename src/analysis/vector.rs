@@ -1,4 +1,9 @@
 use super::features::AnalysisFeaturesV1;
+use linfa::DatasetBase;
+use linfa::traits::Fit;
+use linfa_reduction::Pca;
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
 
 /// Current persisted feature vector version.
 pub const FEATURE_VERSION_V1: i64 = 1;
@@ -60,6 +65,45 @@ pub(crate) fn to_f32_vector_v1(features: &AnalysisFeaturesV1) -> Vec<f32> {
     out
 }
 
+/// Column names for each value of a `FEATURE_VERSION_V1` vector, in storage order.
+pub(crate) fn feature_names_v1() -> Vec<String> {
+    let mut names: Vec<String> = [
+        "duration_seconds",
+        "peak",
+        "rms",
+        "crest_factor",
+        "zero_crossing_rate",
+        "attack_seconds",
+        "decay_20db_seconds",
+        "decay_40db_seconds",
+        "onset_count",
+    ]
+    .iter()
+    .map(|name| name.to_string())
+    .collect();
+    for segment in ["", "_early", "_late"] {
+        for stat in ["centroid_hz", "rolloff_hz", "flatness", "bandwidth_hz"] {
+            names.push(format!("spectral_{stat}{segment}_mean"));
+            names.push(format!("spectral_{stat}{segment}_std"));
+        }
+    }
+    for segment in ["", "_early", "_late"] {
+        for band in ["sub", "low", "mid", "high", "air"] {
+            names.push(format!("band_{band}{segment}_mean"));
+            names.push(format!("band_{band}{segment}_std"));
+        }
+    }
+    for segment in ["", "_early", "_late"] {
+        for stat in ["mean", "std"] {
+            for coeff in 0..20 {
+                names.push(format!("mfcc{segment}_{stat}_{coeff}"));
+            }
+        }
+    }
+    debug_assert_eq!(names.len(), FEATURE_VECTOR_LEN_V1);
+    names
+}
+
 /// Encode a `f32` slice into a little-endian byte buffer for storage.
 pub fn encode_f32_le_blob(values: &[f32]) -> Vec<u8> {
     let mut out = Vec::with_capacity(values.len().saturating_mul(4));
@@ -70,9 +114,16 @@ pub fn encode_f32_le_blob(values: &[f32]) -> Vec<u8> {
 }
 
 /// Decode a little-endian `f32` blob (as stored in SQLite) into a `Vec<f32>`.
+///
+/// Blobs whose length is not a multiple of 4 are rejected rather than truncated, so a
+/// corrupted row surfaces as an error instead of a silently shortened vector.
 pub fn decode_f32_le_blob(blob: &[u8]) -> Result<Vec<f32>, String> {
     if blob.len() % 4 != 0 {
-        return Err("Feature blob length is not a multiple of 4 bytes".to_string());
+        return Err(format!(
+            "Corrupt f32 blob: {} bytes is not a multiple of 4 ({} trailing bytes)",
+            blob.len(),
+            blob.len() % 4
+        ));
     }
     let mut out = Vec::with_capacity(blob.len() / 4);
     for chunk in blob.chunks_exact(4) {
@@ -83,6 +134,128 @@ pub fn decode_f32_le_blob(blob: &[u8]) -> Result<Vec<f32>, String> {
     Ok(out)
 }
 
+/// Quantize a `f32` slice to int8 with a single symmetric scale.
+///
+/// The blob stores the scale as a little-endian `f32` followed by one signed byte per value,
+/// so a value is reconstructed as `byte * scale`.
+pub fn encode_int8(values: &[f32]) -> Vec<u8> {
+    let max_abs = values
+        .iter()
+        .filter(|value| value.is_finite())
+        .fold(0.0_f32, |acc, value| acc.max(value.abs()));
+    let scale = if max_abs > 0.0 { max_abs / 127.0 } else { 1.0 };
+    let mut out = Vec::with_capacity(4 + values.len());
+    out.extend_from_slice(&scale.to_le_bytes());
+    for &value in values {
+        let quantized = if value.is_finite() {
+            (value / scale).round().clamp(-127.0, 127.0) as i8
+        } else {
+            0
+        };
+        out.push(quantized as u8);
+    }
+    out
+}
+
+/// Reconstruct approximate `f32` values from a blob written by [`encode_int8`].
+pub fn decode_int8(blob: &[u8]) -> Result<Vec<f32>, String> {
+    let Some((scale, values)) = blob.split_first_chunk::<4>() else {
+        return Err("Int8 blob is missing its scale header".to_string());
+    };
+    let scale = f32::from_le_bytes(*scale);
+    if !scale.is_finite() {
+        return Err("Int8 blob has a non-finite scale".to_string());
+    }
+    Ok(values
+        .iter()
+        .map(|byte| *byte as i8 as f32 * scale)
+        .collect())
+}
+
+/// Decode a stored embedding blob according to its `dtype` column.
+pub fn decode_embedding_blob(blob: &[u8], dtype: &str) -> Result<Vec<f32>, String> {
+    match dtype {
+        super::similarity::SIMILARITY_DTYPE_I8 => decode_int8(blob),
+        _ => decode_f32_le_blob(blob),
+    }
+}
+
+/// Linear projection onto the leading principal components of a set of vectors.
+///
+/// Used to shrink embeddings before t-SNE so map builds scale to large libraries.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PcaProjection {
+    /// Dimension of the input vectors.
+    pub input_dim: usize,
+    /// Number of principal components kept.
+    pub output_dim: usize,
+    /// Number of vectors the projection was fitted on.
+    pub fitted_rows: usize,
+    mean: Vec<f64>,
+    /// Row-major `output_dim x input_dim` matrix of unit components.
+    components: Vec<f64>,
+}
+
+impl PcaProjection {
+    /// Fit the top `output_dim` components of row-major `data` with `dim` columns.
+    pub fn fit(data: &[f64], dim: usize, output_dim: usize) -> Result<Self, String> {
+        if dim == 0 || !data.len().is_multiple_of(dim) {
+            return Err("PCA input is not a whole number of rows".to_string());
+        }
+        let rows = data.len() / dim;
+        if rows < 2 {
+            return Err("Need at least 2 vectors to fit PCA".to_string());
+        }
+        if output_dim == 0 || output_dim > dim {
+            return Err(format!(
+                "PCA output dimension must be in 1..={dim}, got {output_dim}"
+            ));
+        }
+        let records = Array2::from_shape_vec((rows, dim), data.to_vec())
+            .map_err(|err| format!("PCA input shape failed: {err}"))?;
+        let pca = Pca::params(output_dim)
+            .fit(&DatasetBase::from(records))
+            .map_err(|err| format!("PCA fit failed: {err}"))?;
+        let mean = pca.mean().to_vec();
+        let components = pca
+            .components()
+            .outer_iter()
+            .flat_map(|component| component.to_vec())
+            .collect();
+        Ok(Self {
+            input_dim: dim,
+            output_dim,
+            fitted_rows: rows,
+            mean,
+            components,
+        })
+    }
+
+    /// Project one vector onto the fitted components.
+    pub fn project(&self, values: &[f64]) -> Vec<f64> {
+        self.components
+            .chunks_exact(self.input_dim)
+            .map(|component| {
+                component
+                    .iter()
+                    .zip(values)
+                    .zip(&self.mean)
+                    .map(|((weight, value), mean)| weight * (value - mean))
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Project every row of row-major `data`, returning `output_dim` values per row.
+    pub fn project_rows(&self, data: &[f64]) -> Vec<f64> {
+        let mut out = Vec::with_capacity(data.len() / self.input_dim.max(1) * self.output_dim);
+        for row in data.chunks_exact(self.input_dim) {
+            out.extend(self.project(row));
+        }
+        out
+    }
+}
+
 fn push_stats(out: &mut Vec<f32>, stats: &super::frequency_domain::Stats) {
     out.push(stats.mean);
     out.push(stats.std);
@@ -165,6 +338,14 @@ mod tests {
         assert_eq!(vec.len(), FEATURE_VECTOR_LEN_V1);
     }
 
+    #[test]
+    fn feature_names_cover_every_value_once() {
+        let names = feature_names_v1();
+        assert_eq!(names.len(), FEATURE_VECTOR_LEN_V1);
+        let unique: std::collections::HashSet<&String> = names.iter().collect();
+        assert_eq!(unique.len(), names.len());
+    }
+
     #[test]
     fn encode_blob_is_little_endian() {
         let values = [1.0_f32, -2.5_f32];
@@ -187,4 +368,81 @@ mod tests {
         let err = decode_f32_le_blob(&[1, 2, 3]).unwrap_err();
         assert!(err.to_ascii_lowercase().contains("multiple of 4"));
     }
+
+    #[test]
+    fn decode_blob_rejects_five_bytes_and_decodes_eight() {
+        let err = decode_f32_le_blob(&[0, 0, 128, 63, 7]).unwrap_err();
+        assert!(err.contains("5 bytes"), "{err}");
+        let mut blob = 1.0_f32.to_le_bytes().to_vec();
+        blob.extend_from_slice(&(-0.5_f32).to_le_bytes());
+        assert_eq!(decode_f32_le_blob(&blob).unwrap(), vec![1.0, -0.5]);
+    }
+
+    #[test]
+    fn int8_round_trip_stays_close_in_cosine_distance() {
+        let mut values: Vec<f32> = (0..FEATURE_VECTOR_LEN_V1)
+            .map(|i| ((i as f32 * 0.37).sin() + 0.2 * (i as f32 * 1.3).cos()) * 0.1)
+            .collect();
+        assert!(crate::analysis::similarity::normalize_l2_in_place(
+            &mut values
+        ));
+
+        let blob = encode_int8(&values);
+        assert_eq!(blob.len(), 4 + values.len());
+        let restored =
+            decode_embedding_blob(&blob, crate::analysis::similarity::SIMILARITY_DTYPE_I8).unwrap();
+
+        let dot: f32 = values.iter().zip(&restored).map(|(a, b)| a * b).sum();
+        let norm: f32 = restored.iter().map(|v| v * v).sum::<f32>().sqrt();
+        let cosine_distance = 1.0 - dot / norm;
+        assert!(cosine_distance < 1e-3, "cosine distance {cosine_distance}");
+        assert!(decode_int8(&[0, 0]).is_err());
+    }
+
+    #[test]
+    fn pca_projection_keeps_nearest_neighbours_for_low_rank_data() {
+        let dim = 12;
+        let mut seed = 0x2545_f491_u64;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) as f64 / (1u64 << 31) as f64) - 0.5
+        };
+        // Three strong latent axes spread over all dimensions, plus faint noise.
+        let axes: Vec<Vec<f64>> = (0..3).map(|_| (0..dim).map(|_| next()).collect()).collect();
+        let mut data = Vec::new();
+        for _ in 0..40 {
+            let weights = [next() * 10.0, next() * 6.0, next() * 3.0];
+            for d in 0..dim {
+                let signal: f64 = axes.iter().zip(weights).map(|(axis, w)| axis[d] * w).sum();
+                data.push(signal + next() * 0.01);
+            }
+        }
+
+        let projection = PcaProjection::fit(&data, dim, 3).unwrap();
+        let reduced = projection.project_rows(&data);
+        assert_eq!(reduced.len(), 40 * 3);
+
+        let nearest = |rows: &[f64], width: usize, query: usize| {
+            let row = |i: usize| &rows[i * width..(i + 1) * width];
+            (0..rows.len() / width)
+                .filter(|i| *i != query)
+                .min_by(|a, b| {
+                    let dist = |i: usize| -> f64 {
+                        row(i)
+                            .iter()
+                            .zip(row(query))
+                            .map(|(x, y)| (x - y).powi(2))
+                            .sum()
+                    };
+                    dist(*a).total_cmp(&dist(*b))
+                })
+                .unwrap()
+        };
+        for query in 0..40 {
+            assert_eq!(nearest(&data, dim, query), nearest(&reduced, 3, query));
+        }
+        assert!(PcaProjection::fit(&data[..dim], dim, 2).is_err());
+    }
 }
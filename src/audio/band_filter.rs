@@ -0,0 +1,252 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::Duration;
+
+use crate::audio::Source;
+
+const LOW_BAND_EDGE_HZ: f32 = 250.0;
+const HIGH_BAND_EDGE_HZ: f32 = 4_000.0;
+/// Section Q values for a 4th-order Butterworth built from two biquads.
+const BUTTERWORTH_Q: [f32; 2] = [0.541_196_1, 1.306_563];
+/// Most biquad sections any band chains (the mid band is a high-pass plus a low-pass).
+const MAX_BAND_SECTIONS: usize = 2 * BUTTERWORTH_Q.len();
+
+/// Frequency band isolated by the solo-band audition filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoloBand {
+    /// Lows below ~250 Hz.
+    Low,
+    /// Mids between ~250 Hz and ~4 kHz.
+    Mid,
+    /// Highs above ~4 kHz.
+    High,
+}
+
+impl SoloBand {
+    /// All bands in display order.
+    pub const ALL: [SoloBand; 3] = [SoloBand::Low, SoloBand::Mid, SoloBand::High];
+
+    /// Short label for UI controls.
+    pub fn label(self) -> &'static str {
+        match self {
+            SoloBand::Low => "Low",
+            SoloBand::Mid => "Mid",
+            SoloBand::High => "High",
+        }
+    }
+
+    fn to_raw(band: Option<SoloBand>) -> u8 {
+        match band {
+            None => 0,
+            Some(SoloBand::Low) => 1,
+            Some(SoloBand::Mid) => 2,
+            Some(SoloBand::High) => 3,
+        }
+    }
+
+    fn from_raw(raw: u8) -> Option<SoloBand> {
+        match raw {
+            1 => Some(SoloBand::Low),
+            2 => Some(SoloBand::Mid),
+            3 => Some(SoloBand::High),
+            _ => None,
+        }
+    }
+}
+
+/// Shared switch that lets the UI change the soloed band of playing sources.
+#[derive(Clone, Default)]
+pub(crate) struct SoloBandHandle {
+    band: Arc<AtomicU8>,
+}
+
+impl SoloBandHandle {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn set(&self, band: Option<SoloBand>) {
+        self.band.store(SoloBand::to_raw(band), Ordering::Release);
+    }
+
+    pub(crate) fn get(&self) -> Option<SoloBand> {
+        SoloBand::from_raw(self.band.load(Ordering::Acquire))
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Biquad {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+}
+
+impl Biquad {
+    fn lowpass(sample_rate: u32, cutoff_hz: f32, q: f32) -> Self {
+        let (cos, alpha) = Self::prewarp(sample_rate, cutoff_hz, q);
+        let b1 = 1.0 - cos;
+        Self::normalized(b1 * 0.5, b1, b1 * 0.5, cos, alpha)
+    }
+
+    fn highpass(sample_rate: u32, cutoff_hz: f32, q: f32) -> Self {
+        let (cos, alpha) = Self::prewarp(sample_rate, cutoff_hz, q);
+        let b1 = -(1.0 + cos);
+        Self::normalized(-b1 * 0.5, b1, -b1 * 0.5, cos, alpha)
+    }
+
+    fn prewarp(sample_rate: u32, cutoff_hz: f32, q: f32) -> (f32, f32) {
+        let rate = sample_rate.max(1) as f32;
+        let cutoff = cutoff_hz.clamp(1.0, rate * 0.45);
+        let w0 = 2.0 * PI * cutoff / rate;
+        (w0.cos(), w0.sin() / (2.0 * q))
+    }
+
+    fn normalized(b0: f32, b1: f32, b2: f32, cos: f32, alpha: f32) -> Self {
+        let a0 = 1.0 + alpha;
+        Self {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: -2.0 * cos / a0,
+            a2: (1.0 - alpha) / a0,
+        }
+    }
+}
+
+/// Transposed direct form II state for one biquad on one channel.
+#[derive(Clone, Copy, Default)]
+struct BiquadState {
+    z1: f32,
+    z2: f32,
+}
+
+impl BiquadState {
+    fn process(&mut self, coeffs: &Biquad, input: f32) -> f32 {
+        let output = coeffs.b0 * input + self.z1;
+        self.z1 = coeffs.b1 * input - coeffs.a1 * output + self.z2;
+        self.z2 = coeffs.b2 * input - coeffs.a2 * output;
+        output
+    }
+}
+
+fn band_sections(band: SoloBand, sample_rate: u32) -> Vec<Biquad> {
+    let lowpass = |cutoff| BUTTERWORTH_Q.map(|q| Biquad::lowpass(sample_rate, cutoff, q));
+    let highpass = |cutoff| BUTTERWORTH_Q.map(|q| Biquad::highpass(sample_rate, cutoff, q));
+    match band {
+        SoloBand::Low => lowpass(LOW_BAND_EDGE_HZ).to_vec(),
+        SoloBand::Mid => {
+            let mut sections = highpass(LOW_BAND_EDGE_HZ).to_vec();
+            sections.extend(lowpass(HIGH_BAND_EDGE_HZ));
+            sections
+        }
+        SoloBand::High => highpass(HIGH_BAND_EDGE_HZ).to_vec(),
+    }
+}
+
+/// Output-only filter that isolates the band selected on its [`SoloBandHandle`].
+///
+/// Passes samples through untouched while no band is soloed; filter state resets whenever
+/// the band changes so switching mid-playback never rings from stale history. Coefficients
+/// and state for every band are allocated up front, so switching never allocates on the
+/// audio thread.
+pub(crate) struct BandFilter<S> {
+    inner: S,
+    handle: SoloBandHandle,
+    channels: u16,
+    samples_emitted: u64,
+    active: Option<SoloBand>,
+    /// Sections for each band, indexed in [`SoloBand::ALL`] order.
+    band_sections: [Vec<Biquad>; SoloBand::ALL.len()],
+    states: Vec<BiquadState>,
+}
+
+impl<S> BandFilter<S>
+where
+    S: Source,
+{
+    pub(crate) fn new(inner: S, handle: SoloBandHandle) -> Self {
+        let sample_rate = inner.sample_rate();
+        let channels = inner.channels();
+        let state_count = MAX_BAND_SECTIONS * channels.max(1) as usize;
+        let mut filter = Self {
+            inner,
+            handle,
+            channels,
+            samples_emitted: 0,
+            active: None,
+            band_sections: SoloBand::ALL.map(|band| band_sections(band, sample_rate)),
+            states: vec![BiquadState::default(); state_count],
+        };
+        filter.sync_band();
+        filter
+    }
+
+    fn sync_band(&mut self) {
+        let band = self.handle.get();
+        if band == self.active {
+            return;
+        }
+        self.active = band;
+        self.states.fill(BiquadState::default());
+    }
+}
+
+impl<S> Iterator for BandFilter<S>
+where
+    S: Source,
+{
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = self.inner.next()?;
+        let channels = self.channels.max(1) as u64;
+        let channel = (self.samples_emitted % channels) as usize;
+        if channel == 0 {
+            self.sync_band();
+        }
+        self.samples_emitted = self.samples_emitted.saturating_add(1);
+        let Some(band) = self.active else {
+            return Some(sample);
+        };
+        let sections = &self.band_sections[band as usize];
+        let offset = channel * sections.len();
+        let states = &mut self.states[offset..offset + sections.len()];
+        let filtered = sections
+            .iter()
+            .zip(states.iter_mut())
+            .fold(sample, |value, (coeffs, state)| state.process(coeffs, value));
+        Some(filtered)
+    }
+}
+
+impl<S> Source for BandFilter<S>
+where
+    S: Source,
+{
+    #[inline]
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    #[inline]
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+
+    fn last_error(&self) -> Option<String> {
+        self.inner.last_error()
+    }
+}
@@ -0,0 +1,199 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use crate::audio::Source;
+
+const CLICK_FREQUENCY_HZ: f32 = 1_500.0;
+const CLICK_SECONDS: f32 = 0.02;
+const CLICK_GAIN: f32 = 0.3;
+/// Seconds between beats at `bpm`, or `None` for a tempo that cannot click.
+pub(crate) fn beat_seconds(bpm: f32) -> Option<f64> {
+    (bpm.is_finite() && bpm > 0.0).then(|| 60.0 / bpm as f64)
+}
+
+/// Index of the first beat at or after `seconds` from the start of the sample.
+///
+/// Beats sit on a grid anchored at zero, so the same BPM always clicks at the same positions
+/// regardless of where playback started.
+pub(crate) fn first_beat_index(beat_seconds: f64, seconds: f64) -> u64 {
+    (seconds.max(0.0) / beat_seconds).ceil() as u64
+}
+
+/// Shared switch that lets the UI enable the metronome and change its tempo mid-playback.
+#[derive(Clone, Default)]
+pub(crate) struct MetronomeHandle {
+    bpm_bits: Arc<AtomicU32>,
+}
+
+impl MetronomeHandle {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Click at `bpm`, or stay silent when `None`.
+    pub(crate) fn set(&self, bpm: Option<f32>) {
+        let bits = bpm
+            .filter(|bpm| bpm.is_finite() && *bpm > 0.0)
+            .map_or(0, f32::to_bits);
+        self.bpm_bits.store(bits, Ordering::Release);
+    }
+
+    pub(crate) fn get(&self) -> Option<f32> {
+        let bits = self.bpm_bits.load(Ordering::Acquire);
+        (bits != 0).then(|| f32::from_bits(bits))
+    }
+}
+
+/// Where in the sample a playback source starts, and the span it repeats when looping.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ClickTimeline {
+    pub(crate) start_seconds: f32,
+    pub(crate) loop_span: Option<(f32, f32)>,
+}
+
+/// Output-only mixer that adds a short click on every beat while the metronome is enabled.
+///
+/// The source audio is passed through unchanged; clicks follow the sample's beat grid and
+/// restart at the loop start each time a looped span wraps.
+pub(crate) struct MetronomeClicks<S> {
+    inner: S,
+    handle: MetronomeHandle,
+    sample_rate: u32,
+    channels: u16,
+    samples_emitted: u64,
+    loop_span: Option<(f32, f32)>,
+    segment_start_seconds: f64,
+    segment_start_frame: u64,
+    frame: u64,
+    /// Tempo the next beat was computed for, and that beat's index on the grid.
+    next_beat: Option<(f32, u64)>,
+    click_frame: Option<u32>,
+    click_level: f32,
+}
+
+impl<S> MetronomeClicks<S>
+where
+    S: Source,
+{
+    pub(crate) fn new(inner: S, handle: MetronomeHandle, timeline: ClickTimeline) -> Self {
+        let sample_rate = inner.sample_rate().max(1);
+        let channels = inner.channels();
+        let start = timeline.start_seconds.max(0.0);
+        Self {
+            inner,
+            handle,
+            sample_rate,
+            channels,
+            samples_emitted: 0,
+            loop_span: timeline.loop_span.filter(|(start, end)| end > start),
+            segment_start_seconds: start as f64,
+            segment_start_frame: 0,
+            frame: 0,
+            next_beat: None,
+            click_frame: None,
+            click_level: 0.0,
+        }
+    }
+
+    fn position_seconds(&self) -> f32 {
+        let frames = self.frame - self.segment_start_frame;
+        (self.segment_start_seconds + frames as f64 / self.sample_rate as f64) as f32
+    }
+
+    fn advance_frame(&mut self) {
+        let mut position = self.position_seconds();
+        if let Some((loop_start, loop_end)) = self.loop_span
+            && position >= loop_end
+        {
+            self.segment_start_seconds = loop_start as f64 + (position - loop_end) as f64;
+            self.segment_start_frame = self.frame;
+            position = self.position_seconds();
+            self.next_beat = None;
+        }
+        self.frame += 1;
+        self.click_level = self.next_click_level();
+        let Some((bpm, spacing)) = self
+            .handle
+            .get()
+            .and_then(|bpm| beat_seconds(bpm).map(|spacing| (bpm, spacing)))
+        else {
+            self.next_beat = None;
+            return;
+        };
+        let mut beat = match self.next_beat {
+            Some((scheduled_bpm, beat)) if scheduled_bpm == bpm => beat,
+            _ => first_beat_index(spacing, position as f64),
+        };
+        let loop_end = self.loop_span.map(|(_, end)| end as f64);
+        let due = position as f64 + 0.5 / self.sample_rate as f64;
+        let beat_time = beat as f64 * spacing;
+        if beat_time <= due && loop_end.is_none_or(|end| beat_time < end) {
+            beat = first_beat_index(spacing, due).max(beat + 1);
+            self.click_frame = Some(0);
+            self.click_level = self.next_click_level();
+        }
+        self.next_beat = Some((bpm, beat));
+    }
+
+    fn next_click_level(&mut self) -> f32 {
+        let Some(frame) = self.click_frame else {
+            return 0.0;
+        };
+        let elapsed = frame as f32 / self.sample_rate as f32;
+        if elapsed >= CLICK_SECONDS {
+            self.click_frame = None;
+            return 0.0;
+        }
+        self.click_frame = Some(frame + 1);
+        let envelope = 1.0 - elapsed / CLICK_SECONDS;
+        (2.0 * PI * CLICK_FREQUENCY_HZ * elapsed).sin() * envelope * envelope * CLICK_GAIN
+    }
+}
+
+impl<S> Iterator for MetronomeClicks<S>
+where
+    S: Source,
+{
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = self.inner.next()?;
+        let channels = self.channels.max(1) as u64;
+        if self.samples_emitted.is_multiple_of(channels) {
+            self.advance_frame();
+        }
+        self.samples_emitted = self.samples_emitted.saturating_add(1);
+        Some(sample + self.click_level)
+    }
+}
+
+impl<S> Source for MetronomeClicks<S>
+where
+    S: Source,
+{
+    #[inline]
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    #[inline]
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+
+    fn last_error(&self) -> Option<String> {
+        self.inner.last_error()
+    }
+}
@@ -8,10 +8,13 @@ pub mod output;
 /// Recording and input monitoring helpers.
 pub mod recording;
 
+mod band_filter;
 mod fade;
 mod loop_diagnostic;
+mod metronome;
 mod mixer;
 mod player;
+mod resample;
 mod source;
 mod async_decode;
 /// Low-level decoder wrapper for Symphonia.
@@ -28,13 +31,21 @@ pub use output::{
     AudioDeviceSummary, AudioHostSummary, AudioOutputConfig, AudioOutputError, ResolvedOutput,
     available_devices, available_hosts, open_output_stream, supported_sample_rates,
 };
+pub use band_filter::SoloBand;
 pub use player::AudioPlayer;
+pub use resample::ResampleQuality;
 pub(crate) use time_stretch::Wsola;
 pub use recording::{AudioRecorder, InputMonitor, RecordingOutcome};
 
 pub(crate) use async_decode::AsyncSource;
 pub use source::{Source, SamplesBuffer};
 #[cfg(test)]
+pub(crate) use band_filter::{BandFilter, SoloBandHandle};
+#[cfg(test)]
+pub(crate) use metronome::{
+    ClickTimeline, MetronomeClicks, MetronomeHandle, beat_seconds, first_beat_index,
+};
+#[cfg(test)]
 pub(crate) use fade::{EdgeFade, FadeOutHandle, FadeOutOnRequest, fade_duration};
 #[cfg(test)]
 pub(crate) use routing::normalized_progress;
@@ -5,6 +5,7 @@ use thiserror::Error;
 use tracing::{info, warn};
 
 use super::device::{device_label, host_label};
+use super::resample::{ResampleQuality, Resampled};
 /// Errors that can occur while enumerating or opening audio outputs.
 #[derive(Debug, Error)]
 pub enum AudioOutputError {
@@ -68,6 +69,9 @@ pub struct AudioOutputConfig {
     /// Preferred buffer size in frames.
     #[serde(default)]
     pub buffer_size: Option<u32>,
+    /// Interpolation used when a sample's rate differs from the device rate.
+    #[serde(default)]
+    pub resample_quality: ResampleQuality,
 }
 
 /// Available audio host (backend) presented to the user.
@@ -187,6 +191,8 @@ impl CallbackState {
 /// Custom container for cpal output stream.
 pub struct CpalAudioStream {
     _stream: cpal::Stream,
+    sample_rate: u32,
+    resample_quality: ResampleQuality,
     command_sender: SyncSender<StreamCommand>,
     active_sources: Arc<AtomicUsize>,
     volume_bits: Arc<AtomicU32>,
@@ -198,6 +204,8 @@ impl CpalAudioStream {
     /// Wrap a cpal stream with shared playback state.
     fn new(
         stream: cpal::Stream,
+        sample_rate: u32,
+        resample_quality: ResampleQuality,
         command_sender: SyncSender<StreamCommand>,
         active_sources: Arc<AtomicUsize>,
         volume_bits: Arc<AtomicU32>,
@@ -206,6 +214,8 @@ impl CpalAudioStream {
     ) -> Self {
         Self {
             _stream: stream,
+            sample_rate,
+            resample_quality,
             command_sender,
             active_sources,
             volume_bits,
@@ -223,11 +233,14 @@ impl CpalAudioStream {
         source: S,
         volume: f32,
     ) -> Result<(), String> {
+        let source: Box<dyn crate::audio::Source + Send> =
+            if source.sample_rate() != self.sample_rate && self.sample_rate > 0 {
+                Box::new(Resampled::new(source, self.sample_rate, self.resample_quality))
+            } else {
+                Box::new(source)
+            };
         self.command_sender
-            .try_send(StreamCommand::Append {
-                source: Box::new(source),
-                volume,
-            })
+            .try_send(StreamCommand::Append { source, volume })
             .map_err(|err| match err {
                 TrySendError::Full(_) => {
                     "Audio command queue full; dropping source".to_string()
@@ -503,6 +516,8 @@ pub fn open_output_stream(
     Ok(OpenStreamOutcome {
         stream: CpalAudioStream::new(
             stream,
+            resolved.sample_rate,
+            config.resample_quality,
             command_sender,
             active_sources,
             volume_bits,
@@ -558,24 +573,53 @@ fn resolve_device(
         .default_output_device()
         .ok_or(AudioOutputError::NoOutputDevices)?;
     let default_name = device_label(&default_device).unwrap_or_else(|| "Default device".into());
-    let requested_name = name.unwrap_or(&default_name);
-    let devices = host
+    let devices: Vec<(cpal::Device, String)> = host
         .output_devices()
-        .map_err(|source| AudioOutputError::ListOutputDevices { source })?;
-    let mut chosen = None;
-    for device in devices {
-        if device_label(&device)
-            .as_ref()
-            .is_some_and(|name| name == requested_name)
-        {
-            chosen = Some(device);
-            break;
-        }
+        .map_err(|source| AudioOutputError::ListOutputDevices { source })?
+        .filter_map(|device| device_label(&device).map(|label| (device, label)))
+        .collect();
+    let names: Vec<String> = devices.iter().map(|(_, label)| label.clone()).collect();
+    let choice = choose_output_device(&names, &default_name, name);
+    let resolved = devices
+        .into_iter()
+        .find(|(_, label)| *label == choice.name)
+        .map(|(device, _)| device)
+        .unwrap_or(default_device);
+    Ok((resolved, choice.name, choice.used_fallback))
+}
+
+/// Output device picked for a stream after matching the user preference.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct OutputDeviceChoice {
+    /// Name of the device that will be opened.
+    pub(crate) name: String,
+    /// Whether the preferred device was missing and the default was used instead.
+    pub(crate) used_fallback: bool,
+}
+
+/// Match a preferred output device name against the devices currently available.
+///
+/// Without a preference the host default is used; a preference that is no longer
+/// present falls back to the default and is flagged so the UI can warn about it.
+pub(crate) fn choose_output_device(
+    available: &[String],
+    default_name: &str,
+    requested: Option<&str>,
+) -> OutputDeviceChoice {
+    match requested {
+        Some(requested) if available.iter().any(|name| name == requested) => OutputDeviceChoice {
+            name: requested.to_string(),
+            used_fallback: false,
+        },
+        Some(_) => OutputDeviceChoice {
+            name: default_name.to_string(),
+            used_fallback: true,
+        },
+        None => OutputDeviceChoice {
+            name: default_name.to_string(),
+            used_fallback: false,
+        },
     }
-    let resolved = chosen.unwrap_or(default_device);
-    let resolved_name = device_label(&resolved).unwrap_or_else(|| default_name.clone());
-    let used_fallback = resolved_name != requested_name;
-    Ok((resolved, resolved_name, used_fallback))
 }
 
 fn process_audio_callback(state: &mut CallbackState, data: &mut [f32]) {
@@ -851,6 +895,23 @@ mod tests {
         let _ = callback_thread.join();
     }
 
+    #[test]
+    fn missing_output_device_falls_back_to_default() {
+        let available = vec!["Speakers".to_string(), "Headphones".to_string()];
+
+        let missing = choose_output_device(&available, "Speakers", Some("USB Interface"));
+        assert_eq!(missing.name, "Speakers");
+        assert!(missing.used_fallback);
+
+        let present = choose_output_device(&available, "Speakers", Some("Headphones"));
+        assert_eq!(present.name, "Headphones");
+        assert!(!present.used_fallback);
+
+        let unset = choose_output_device(&available, "Speakers", None);
+        assert_eq!(unset.name, "Speakers");
+        assert!(!unset.used_fallback);
+    }
+
     #[test]
     fn resolved_output_uses_fallback_stream_config() {
         let fallback_config = cpal::StreamConfig {
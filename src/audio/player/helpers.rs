@@ -8,6 +8,8 @@ use tracing::warn;
 use super::super::DEFAULT_ANTI_CLIP_FADE;
 #[cfg(test)]
 use super::super::fade::{EdgeFade, fade_duration};
+use super::super::band_filter::BandFilter;
+use super::super::metronome::{ClickTimeline, MetronomeClicks};
 use super::super::fade::{FadeOutHandle, FadeOutOnRequest, fade_frames_for_duration};
 #[cfg(test)]
 use crate::audio::mixer::{decoder_from_bytes, map_seek_error};
@@ -37,14 +39,18 @@ impl AudioPlayer {
     pub(super) fn build_sink_with_fade<S: Source + Send + 'static>(
         &mut self,
         source: S,
+        timeline: ClickTimeline,
     ) -> (FadeOutHandle, (u32, u16)) {
         let _volume = self.effective_volume();
         let format = (source.sample_rate(), source.channels());
         let handle = FadeOutHandle::new();
+        let fade_in = self.seek_crossfade.take().unwrap_or_default();
+        let filtered = BandFilter::new(source.fade_in(fade_in), self.solo_band.clone());
+        let clicked = MetronomeClicks::new(filtered, self.metronome.clone(), timeline);
         
         if self
             .stream
-            .append_source(FadeOutOnRequest::new(source, handle.clone()), 1.0)
+            .append_source(FadeOutOnRequest::new(clicked, handle.clone()), 1.0)
             .is_ok()
         {
             self.active_sources = self.active_sources.saturating_add(1);
@@ -83,6 +89,15 @@ impl AudioPlayer {
         }
     }
 
+    /// Fade out the playing sink before a restart, stretching to a queued seek crossfade.
+    pub(super) fn fade_out_for_restart(&mut self) {
+        let anti_clip = self.anti_clip_fade();
+        let fade = self
+            .seek_crossfade
+            .map_or(anti_clip, |crossfade| crossfade.max(anti_clip));
+        self.fade_out_current_sink(fade);
+    }
+
     pub(super) fn normalized_span(&self, start: f32, end: f32) -> Result<(f32, f32, f32), String> {
         let duration = self
             .track_duration
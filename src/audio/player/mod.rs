@@ -1,7 +1,9 @@
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use super::band_filter::SoloBandHandle;
 use super::fade::FadeOutHandle;
+use super::metronome::MetronomeHandle;
 use super::output::{CpalAudioStream, ResolvedOutput};
 
 mod helpers;
@@ -29,6 +31,9 @@ pub struct AudioPlayer {
     playback_gain: f32,
     anti_clip_enabled: bool,
     anti_clip_fade: Duration,
+    seek_crossfade: Option<Duration>,
+    solo_band: SoloBandHandle,
+    metronome: MetronomeHandle,
     min_span_seconds: Option<f32>,
     output: ResolvedOutput,
     #[cfg(test)]
@@ -4,6 +4,7 @@ use crate::audio::{AsyncSource, Source};
 use crate::audio::SamplesBuffer;
 
 use super::super::fade::{EdgeFade, fade_duration};
+use super::super::metronome::ClickTimeline;
 use super::super::mixer::{decoder_from_bytes, map_seek_error};
 use super::{AudioPlayer, EditFadeSource};
 
@@ -49,7 +50,7 @@ impl AudioPlayer {
             return Err("Load a .wav file first".into());
         }
 
-        self.fade_out_current_sink(self.anti_clip_fade());
+        self.fade_out_for_restart();
 
         let source = decoder_from_bytes(bytes)?;
         let sample_rate = source.sample_rate();
@@ -73,7 +74,11 @@ impl AudioPlayer {
             .repeat_infinite()
             .skip_duration(offset_dur);
 
-        let (handle, format) = self.build_sink_with_fade(repeated);
+        let timeline = ClickTimeline {
+            start_seconds: offset,
+            loop_span: Some((0.0, aligned_duration.as_secs_f32())),
+        };
+        let (handle, format) = self.build_sink_with_fade(repeated, timeline);
         self.started_at = Some(std::time::Instant::now());
         self.play_span = Some((0.0, aligned_duration.as_secs_f32()));
         self.looping = true;
@@ -106,7 +111,7 @@ impl AudioPlayer {
         }
         let span_length = (bounded_end - bounded_start).max(0.001);
 
-        self.fade_out_current_sink(self.anti_clip_fade());
+        self.fade_out_for_restart();
 
         let mut source = decoder_from_bytes(bytes)?;
         let aligned_span_dur = if looped {
@@ -206,7 +211,11 @@ impl AudioPlayer {
             let faded = EdgeFade::new(editable, fade);
             Box::new(faded)
         };
-        let (handle, format) = self.build_sink_with_fade(final_source);
+        let timeline = ClickTimeline {
+            start_seconds: bounded_start,
+            loop_span: looped.then_some((bounded_start, bounded_start + aligned_span_sec)),
+        };
+        let (handle, format) = self.build_sink_with_fade(final_source, timeline);
         self.started_at = Some(std::time::Instant::now());
         self.play_span = Some((bounded_start, bounded_start + aligned_span_sec));
         self.looping = looped;
@@ -241,7 +250,7 @@ impl AudioPlayer {
             offset_seconds = self.frame_align(offset_seconds, rate);
         }
         let span_length = (end_seconds - start_seconds).max(0.001);
-        self.fade_out_current_sink(self.anti_clip_fade());
+        self.fade_out_for_restart();
         let mut source = decoder_from_bytes(bytes)?;
         let seek_dur = Self::aligned_seek_duration(start_seconds, source.sample_rate());
         let sample_rate = source.sample_rate();
@@ -295,7 +304,11 @@ impl AudioPlayer {
             Box::new(diagnostic)
         };
         
-        let (handle, format) = self.build_sink_with_fade(final_source);
+        let timeline = ClickTimeline {
+            start_seconds: start_seconds + offset_seconds,
+            loop_span: Some((start_seconds, start_seconds + aligned_span_sec)),
+        };
+        let (handle, format) = self.build_sink_with_fade(final_source, timeline);
         self.started_at = Some(std::time::Instant::now());
         self.play_span = Some((start_seconds, end_seconds));
         self.looping = true;
@@ -54,6 +54,28 @@ impl AudioPlayer {
         self.track_duration
     }
 
+    /// Full loop cycles completed since looped playback started.
+    pub fn completed_loops(&self) -> Option<u32> {
+        if !self.looping {
+            return None;
+        }
+        let started_at = self.started_at?;
+        let (start, end) = self.play_span?;
+        let span_length = duration_from_secs_f32((end - start).max(f32::EPSILON));
+        if span_length.is_zero() {
+            return None;
+        }
+        let elapsed = self.elapsed_since(started_at);
+        let base_offset = duration_from_secs_f32(self.loop_offset.unwrap_or(0.0));
+        let cycles = base_offset.saturating_add(elapsed).as_nanos() / span_length.as_nanos();
+        Some(u32::try_from(cycles).unwrap_or(u32::MAX))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn set_elapsed_for_tests(&mut self, elapsed: Option<Duration>) {
+        self.elapsed_override = elapsed;
+    }
+
     /// Remaining wall-clock time until the current loop iteration finishes.
     pub fn remaining_loop_duration(&self) -> Option<Duration> {
         if !self.looping {
@@ -6,6 +6,10 @@ use std::time::{Duration, Instant};
 use crate::audio::Source;
  
 use super::super::DEFAULT_ANTI_CLIP_FADE;
+use super::super::band_filter::{SoloBand, SoloBandHandle};
+#[cfg(test)]
+use super::super::metronome::ClickTimeline;
+use super::super::metronome::MetronomeHandle;
 use super::super::output::{AudioOutputConfig, ResolvedOutput, open_output_stream};
 use super::super::routing::duration_from_secs_f32;
 
@@ -38,6 +42,9 @@ impl AudioPlayer {
             playback_gain: 1.0,
             anti_clip_enabled: true,
             anti_clip_fade: DEFAULT_ANTI_CLIP_FADE,
+            seek_crossfade: None,
+            solo_band: SoloBandHandle::new(),
+            metronome: MetronomeHandle::new(),
             min_span_seconds: None,
             output: outcome.resolved,
             #[cfg(test)]
@@ -91,8 +98,24 @@ impl AudioPlayer {
         self.anti_clip_fade = duration_from_secs_f32(fade_ms / 1000.0);
     }
 
+    /// Crossfade the next restart over `crossfade` instead of cutting; `None` keeps the hard jump.
+    pub fn set_seek_crossfade(&mut self, crossfade: Option<Duration>) {
+        self.seek_crossfade = crossfade.filter(|window| !window.is_zero());
+    }
+
+    /// Solo a frequency band on current and future playback; `None` restores the full range.
+    pub fn set_solo_band(&self, band: Option<SoloBand>) {
+        self.solo_band.set(band);
+    }
+
+    /// Mix a click on every beat at `bpm` into current and future playback; `None` disables it.
+    pub fn set_metronome_bpm(&self, bpm: Option<f32>) {
+        self.metronome.set(bpm);
+    }
+
     /// Stop any active playback.
     pub fn stop(&mut self) {
+        self.seek_crossfade = None;
         self.fade_out_current_sink(self.anti_clip_fade());
         self.reset_playback_state();
     }
@@ -138,6 +161,9 @@ impl AudioPlayer {
             playback_gain: 1.0,
             anti_clip_enabled: true,
             anti_clip_fade: DEFAULT_ANTI_CLIP_FADE,
+            seek_crossfade: None,
+            solo_band: SoloBandHandle::new(),
+            metronome: MetronomeHandle::new(),
             min_span_seconds: None,
             output: ResolvedOutput::default(),
             elapsed_override,
@@ -168,7 +194,7 @@ impl AudioPlayer {
 
         let mut player = AudioPlayer::new().ok()?;
         let source = SineWave { pos: 0.0, step: 220.0 * 2.0 * std::f32::consts::PI / 44100.0 };
-        player.build_sink_with_fade(source);
+        player.build_sink_with_fade(source, ClickTimeline::default());
         player.started_at = Some(Instant::now());
         Some(player)
     }
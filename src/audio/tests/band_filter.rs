@@ -0,0 +1,36 @@
+use super::super::{BandFilter, SamplesBuffer, SoloBand, SoloBandHandle};
+use std::f32::consts::PI;
+
+fn sine(frequency: f32, sample_rate: u32, seconds: f32) -> Vec<f32> {
+    let frames = (sample_rate as f32 * seconds) as usize;
+    (0..frames)
+        .map(|i| (2.0 * PI * frequency * i as f32 / sample_rate as f32).sin())
+        .collect()
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt()
+}
+
+#[test]
+fn high_band_strongly_attenuates_low_tone() {
+    let sample_rate = 48_000;
+    let tone = sine(100.0, sample_rate, 0.5);
+    let handle = SoloBandHandle::new();
+    handle.set(Some(SoloBand::High));
+    let source = SamplesBuffer::new(1, sample_rate, tone.clone());
+    let filtered: Vec<f32> = BandFilter::new(source, handle).collect();
+
+    assert_eq!(filtered.len(), tone.len());
+    // Skip the filter's settling time before comparing steady-state levels.
+    let settled = sample_rate as usize / 10;
+    assert!(rms(&filtered[settled..]) < rms(&tone[settled..]) * 0.01);
+}
+
+#[test]
+fn band_filter_passes_audio_through_when_no_band_is_soloed() {
+    let tone = sine(100.0, 48_000, 0.05);
+    let source = SamplesBuffer::new(1, 48_000, tone.clone());
+    let filtered: Vec<f32> = BandFilter::new(source, SoloBandHandle::new()).collect();
+    assert_eq!(filtered, tone);
+}
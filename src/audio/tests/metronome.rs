@@ -0,0 +1,60 @@
+use super::super::{
+    ClickTimeline, MetronomeClicks, MetronomeHandle, SamplesBuffer, beat_seconds, first_beat_index,
+};
+
+#[test]
+fn beats_fall_every_half_second_at_120_bpm() {
+    let beat = beat_seconds(120.0).unwrap();
+    assert!((beat - 0.5).abs() < 1e-9);
+    assert_eq!(first_beat_index(beat, 0.2), 1);
+    assert_eq!(first_beat_index(beat, 0.5), 1);
+    assert_eq!(first_beat_index(beat, 2.2), 5);
+}
+
+#[test]
+fn beat_seconds_is_none_without_a_valid_tempo() {
+    assert!(beat_seconds(0.0).is_none());
+    assert!(beat_seconds(f32::NAN).is_none());
+}
+
+#[test]
+fn metronome_leaves_audio_untouched_when_disabled() {
+    let silence = vec![0.0_f32; 4_800];
+    let source = SamplesBuffer::new(1, 48_000, silence.clone());
+    let mixed: Vec<f32> =
+        MetronomeClicks::new(source, MetronomeHandle::new(), ClickTimeline::default()).collect();
+    assert_eq!(mixed, silence);
+}
+
+#[test]
+fn metronome_mixes_clicks_only_near_beats() {
+    let sample_rate = 48_000;
+    let handle = MetronomeHandle::new();
+    handle.set(Some(120.0));
+    let source = SamplesBuffer::new(1, sample_rate, vec![0.0_f32; sample_rate as usize]);
+    let mixed: Vec<f32> = MetronomeClicks::new(source, handle, ClickTimeline::default()).collect();
+
+    let beat_frame = sample_rate as usize / 2;
+    let click_frames = sample_rate as usize / 50;
+    let energy = |range: std::ops::Range<usize>| mixed[range].iter().map(|s| s.abs()).sum::<f32>();
+    assert!(energy(beat_frame..beat_frame + click_frames) > 1.0);
+    assert!(energy(click_frames + 1..beat_frame) < 1e-6);
+}
+
+#[test]
+fn metronome_keeps_clicking_past_the_first_few_seconds() {
+    let sample_rate = 8_000;
+    let handle = MetronomeHandle::new();
+    handle.set(Some(120.0));
+    let source = SamplesBuffer::new(1, sample_rate, vec![0.0_f32; sample_rate as usize * 5]);
+    let mixed: Vec<f32> = MetronomeClicks::new(source, handle, ClickTimeline::default()).collect();
+
+    let click_frames = sample_rate as usize / 50;
+    let energy = |range: std::ops::Range<usize>| mixed[range].iter().map(|s| s.abs()).sum::<f32>();
+    for beat in 0..10 {
+        let beat_frame = beat * sample_rate as usize / 2;
+        assert!(energy(beat_frame..beat_frame + click_frames) > 0.1, "beat {beat} missing");
+        let gap = beat_frame + click_frames + 1..beat_frame + sample_rate as usize / 2;
+        assert!(energy(gap) < 1e-6, "stray click after beat {beat}");
+    }
+}
@@ -1,5 +1,7 @@
+mod band_filter;
 mod decode;
 mod fade;
+mod metronome;
 mod player;
 mod playhead_edges;
 mod routing;
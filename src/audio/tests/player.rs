@@ -127,6 +127,28 @@ fn remaining_loop_duration_uses_elapsed_override() {
     assert!((remaining.as_secs_f32() - 1.6).abs() < 0.01);
 }
 
+#[test]
+fn completed_loops_counts_span_wraps_from_offset() {
+    let Ok(outcome) = open_output_stream(&AudioOutputConfig::default()) else {
+        return;
+    };
+    let mut player = test_player(
+        outcome.stream,
+        Some(8.0),
+        Some(Instant::now()),
+        Some((1.0, 3.0)),
+        true,
+        Some(1.5),
+        Some(Duration::from_secs_f32(0.4)),
+    );
+
+    assert_eq!(player.completed_loops(), Some(0));
+    player.set_elapsed_for_tests(Some(Duration::from_secs_f32(0.6)));
+    assert_eq!(player.completed_loops(), Some(1));
+    player.set_elapsed_for_tests(Some(Duration::from_secs_f32(4.6)));
+    assert_eq!(player.completed_loops(), Some(3));
+}
+
 #[test]
 fn play_range_at_track_end_expands_backwards() {
     let Ok(mut player) = AudioPlayer::new() else {
@@ -237,7 +237,7 @@ fn load_embeddings(
 ) -> Result<(Vec<String>, Vec<Vec<f32>>), String> {
     let mut stmt = conn
         .prepare(
-            "SELECT sample_id, dim, vec
+            "SELECT sample_id, dim, dtype, vec
              FROM embeddings
              WHERE model_id = ?1
              ORDER BY sample_id ASC",
@@ -247,17 +247,18 @@ fn load_embeddings(
         .query_map(params![model_id], |row| {
             let sample_id: String = row.get(0)?;
             let dim: i64 = row.get(1)?;
-            let blob: Vec<u8> = row.get(2)?;
-            Ok((sample_id, dim as usize, blob))
+            let dtype: String = row.get(2)?;
+            let blob: Vec<u8> = row.get(3)?;
+            Ok((sample_id, dim as usize, dtype, blob))
         })
         .map_err(|err| format!("Query embeddings failed: {err}"))?;
     let mut sample_ids = Vec::new();
     let mut data = Vec::new();
     let mut expected_dim: Option<usize> = None;
     for row in rows {
-        let (sample_id, dim, blob) =
+        let (sample_id, dim, dtype, blob) =
             row.map_err(|err| format!("Read embedding row failed: {err}"))?;
-        let vec = sempal::analysis::decode_f32_le_blob(&blob)?;
+        let vec = sempal::analysis::vector::decode_embedding_blob(&blob, &dtype)?;
         if vec.len() != dim {
             return Err(format!(
                 "Embedding dim mismatch for {sample_id}: expected {dim}, got {}",
@@ -1,39 +1,25 @@
 use std::{
-    collections::HashSet,
     fs,
     path::{Path, PathBuf},
 };
 
 use ::sempal::app_dirs;
+use ::sempal::updater::{collect_bundle_entries, plan_install};
 
 use crate::{download, paths, registry, shortcuts, ui};
 
-pub(crate) struct InstallPlan {
-    pub(crate) actions: Vec<PlanAction>,
-}
-
-pub(crate) enum PlanAction {
-    CreateDir {
-        #[allow(dead_code)]
-        path: PathBuf,
-    },
-    Copy {
-        #[allow(dead_code)]
-        source: PathBuf,
-        #[allow(dead_code)]
-        target: PathBuf,
-    },
-}
-
 pub(crate) fn run_install(
     bundle_dir: &Path,
     install_dir: &Path,
+    shortcut_options: shortcuts::ShortcutOptions,
     sender: ui::InstallerSender,
 ) -> Result<(), String> {
     send_log(&sender, "Starting installer")?;
     download::ensure_downloads(&sender)?;
     send_log(&sender, "Collecting bundle entries")?;
     let entries = collect_bundle_entries(bundle_dir)?;
+    send_log(&sender, "Checking free disk space")?;
+    ensure_free_space(install_dir, estimate_bundle_size(&entries)?)?;
     ui::send_started(&sender, entries.len())?;
 
     send_log(&sender, "Creating install directory")?;
@@ -56,14 +42,16 @@ pub(crate) fn run_install(
     ensure_app_data_models(bundle_dir)?;
     send_log(&sender, "Registering uninstall entry")?;
     registry::register_uninstall_entry(install_dir)?;
-    send_log(&sender, "Creating Start Menu shortcut")?;
-    shortcuts::create_start_menu_shortcut(install_dir)?;
+    for action in shortcuts::plan_shortcuts(shortcut_options) {
+        send_log(&sender, &format!("Creating {}", action.label()))?;
+        shortcuts::create_shortcut(action, install_dir)?;
+    }
     send_log(&sender, "Finishing install")?;
     ui::send_finished(&sender)?;
     Ok(())
 }
 
-pub(crate) fn run_dry_run() -> Result<(), String> {
+pub(crate) fn run_dry_run(shortcut_options: shortcuts::ShortcutOptions) -> Result<(), String> {
     let bundle_dir = paths::default_bundle_dir();
     let install_dir = paths::default_install_dir();
     let plan = plan_install(&bundle_dir, &install_dir)?;
@@ -72,34 +60,51 @@ pub(crate) fn run_dry_run() -> Result<(), String> {
         plan.actions.len(),
         install_dir.display()
     );
+    for action in shortcuts::plan_shortcuts(shortcut_options) {
+        println!("Would create {}", action.label());
+    }
     Ok(())
 }
 
-pub(crate) fn plan_install(bundle_dir: &Path, install_dir: &Path) -> Result<InstallPlan, String> {
-    let entries = collect_bundle_entries(bundle_dir)?;
-    let mut actions = Vec::new();
-    let mut seen_dirs = HashSet::new();
+/// Total size in bytes of the files that will be copied out of the bundle.
+pub(crate) fn estimate_bundle_size(entries: &[(PathBuf, PathBuf)]) -> Result<u64, String> {
+    entries.iter().try_fold(0u64, |total, (source, _)| {
+        let len = fs::metadata(source)
+            .map_err(|err| format!("Failed to read size of {}: {err}", source.display()))?
+            .len();
+        Ok(total.saturating_add(len))
+    })
+}
 
-    for (source, relative) in entries {
-        let target = install_dir.join(relative);
-        if let Some(parent) = target.parent() {
-            add_dir_action(&mut actions, &mut seen_dirs, parent.to_path_buf());
-        }
-        actions.push(PlanAction::Copy { source, target });
+/// Abort before copying anything when the target volume cannot hold the bundle.
+fn ensure_free_space(install_dir: &Path, required: u64) -> Result<(), String> {
+    let Some(available) = available_space_for(install_dir) else {
+        return Ok(());
+    };
+    if available >= required {
+        return Ok(());
     }
+    Err(format!(
+        "Not enough disk space to install to {}: need {}, only {} free",
+        install_dir.display(),
+        format_megabytes(required),
+        format_megabytes(available)
+    ))
+}
 
-    let models_dir = app_dirs::app_root_dir()
-        .map_err(|err| err.to_string())?
-        .join("models");
-    add_dir_action(&mut actions, &mut seen_dirs, models_dir);
-
-    Ok(InstallPlan { actions })
+/// Free space on the volume whose mount point is the longest prefix of `path`.
+fn available_space_for(path: &Path) -> Option<u64> {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| disk.available_space())
 }
 
-fn add_dir_action(actions: &mut Vec<PlanAction>, seen_dirs: &mut HashSet<PathBuf>, path: PathBuf) {
-    if seen_dirs.insert(path.clone()) {
-        actions.push(PlanAction::CreateDir { path });
-    }
+fn format_megabytes(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
 }
 
 fn ensure_parent_dir(target: &Path) -> Result<(), String> {
@@ -116,39 +121,6 @@ fn send_log(sender: &ui::InstallerSender, message: &str) -> Result<(), String> {
         .map_err(|err| format!("Failed to send log update: {err}"))
 }
 
-pub(crate) fn collect_bundle_entries(bundle_dir: &Path) -> Result<Vec<(PathBuf, PathBuf)>, String> {
-    if !bundle_dir.exists() {
-        return Err(format!(
-            "Bundle directory not found at {}",
-            bundle_dir.display()
-        ));
-    }
-    let mut files = Vec::new();
-    visit_bundle(bundle_dir, bundle_dir, &mut files)?;
-    Ok(files)
-}
-
-fn visit_bundle(
-    root: &Path,
-    dir: &Path,
-    files: &mut Vec<(PathBuf, PathBuf)>,
-) -> Result<(), String> {
-    for entry in fs::read_dir(dir).map_err(|err| format!("Failed to read bundle: {err}"))? {
-        let entry = entry.map_err(|err| format!("Failed to read bundle entry: {err}"))?;
-        let path = entry.path();
-        if path.is_dir() {
-            visit_bundle(root, &path, files)?;
-        } else {
-            let relative = path
-                .strip_prefix(root)
-                .map_err(|err| format!("Failed to build relative path: {err}"))?
-                .to_path_buf();
-            files.push((path, relative));
-        }
-    }
-    Ok(())
-}
-
 fn ensure_app_data_models(bundle_dir: &Path) -> Result<(), String> {
     let app_root = app_dirs::app_root_dir().map_err(|err| err.to_string())?;
     let models_dir = app_root.join("models");
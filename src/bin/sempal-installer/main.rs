@@ -27,19 +27,20 @@ fn main() -> eframe::Result<()> {
         return Ok(());
     }
 
+    let shortcut_options = shortcuts::ShortcutOptions::from_args(env::args());
     if env::args().any(|arg| arg == "--dry-run") {
-        if let Err(err) = install::run_dry_run() {
+        if let Err(err) = install::run_dry_run(shortcut_options) {
             eprintln!("Dry run failed: {err}");
         }
         return Ok(());
     }
 
-    ui::run_installer_app()
+    ui::run_installer_app(shortcut_options)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::install::{PlanAction, plan_install};
+    use sempal::updater::{PlanAction, plan_install};
     use std::fs;
 
     #[test]
@@ -58,4 +59,17 @@ mod tests {
             .count();
         assert_eq!(copies, 1);
     }
+
+    #[test]
+    fn bundle_size_sums_every_entry() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let bundle = temp.path().join("bundle");
+        fs::create_dir_all(bundle.join("models")).expect("bundle dir");
+        fs::write(bundle.join("sempal.exe"), vec![0u8; 1200]).expect("exe");
+        fs::write(bundle.join("models").join("model.onnx"), vec![0u8; 300]).expect("model");
+        fs::write(bundle.join("LICENSE"), "mit").expect("license");
+
+        let entries = sempal::updater::collect_bundle_entries(&bundle).expect("entries");
+        assert_eq!(super::install::estimate_bundle_size(&entries).expect("size"), 1503);
+    }
 }
@@ -33,3 +33,11 @@ pub(crate) fn start_menu_dir() -> Option<PathBuf> {
             .join(APP_NAME)
     })
 }
+
+/// Return the current user's desktop folder (Windows only).
+#[cfg(target_os = "windows")]
+pub(crate) fn desktop_dir() -> Option<PathBuf> {
+    env::var("USERPROFILE")
+        .ok()
+        .map(|root| PathBuf::from(root).join("Desktop"))
+}
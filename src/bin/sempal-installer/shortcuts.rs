@@ -17,16 +17,82 @@ use windows::{
     core::{HSTRING, Interface, PCWSTR},
 };
 
-pub(crate) fn create_start_menu_shortcut(install_dir: &Path) -> Result<(), String> {
+/// Which shortcuts the installer creates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ShortcutOptions {
+    pub(crate) start_menu: bool,
+    pub(crate) desktop: bool,
+}
+
+impl Default for ShortcutOptions {
+    fn default() -> Self {
+        Self {
+            start_menu: true,
+            desktop: false,
+        }
+    }
+}
+
+impl ShortcutOptions {
+    /// Apply `--desktop-shortcut` and `--no-start-menu` command-line flags to the defaults.
+    pub(crate) fn from_args(args: impl IntoIterator<Item = String>) -> Self {
+        let mut options = Self::default();
+        for arg in args {
+            match arg.as_str() {
+                "--desktop-shortcut" => options.desktop = true,
+                "--no-start-menu" => options.start_menu = false,
+                _ => {}
+            }
+        }
+        options
+    }
+}
+
+/// Single shortcut the installer will write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ShortcutAction {
+    StartMenu,
+    Desktop,
+}
+
+impl ShortcutAction {
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::StartMenu => "Start Menu shortcut",
+            Self::Desktop => "desktop shortcut",
+        }
+    }
+}
+
+/// Build the ordered list of shortcuts to create for the chosen options.
+pub(crate) fn plan_shortcuts(options: ShortcutOptions) -> Vec<ShortcutAction> {
+    let mut actions = Vec::new();
+    if options.start_menu {
+        actions.push(ShortcutAction::StartMenu);
+    }
+    if options.desktop {
+        actions.push(ShortcutAction::Desktop);
+    }
+    actions
+}
+
+pub(crate) fn create_shortcut(action: ShortcutAction, install_dir: &Path) -> Result<(), String> {
     #[cfg(not(target_os = "windows"))]
-    let _ = install_dir;
+    let _ = (action, install_dir);
     #[cfg(target_os = "windows")]
     {
-        let start_menu = paths::start_menu_dir().ok_or_else(|| "APPDATA not set".to_string())?;
-        fs::create_dir_all(&start_menu)
-            .map_err(|err| format!("Failed to create Start Menu folder: {err}"))?;
+        let folder = match action {
+            ShortcutAction::StartMenu => {
+                paths::start_menu_dir().ok_or_else(|| "APPDATA not set".to_string())?
+            }
+            ShortcutAction::Desktop => {
+                paths::desktop_dir().ok_or_else(|| "USERPROFILE not set".to_string())?
+            }
+        };
+        fs::create_dir_all(&folder)
+            .map_err(|err| format!("Failed to create {} folder: {err}", action.label()))?;
 
-        let shortcut_path = start_menu.join("SemPal.lnk");
+        let shortcut_path = folder.join("SemPal.lnk");
         let target_path = install_dir.join("sempal.exe");
         let icon_path = install_dir.join("sempal.ico");
 
@@ -63,5 +129,39 @@ pub(crate) fn create_start_menu_shortcut(install_dir: &Path) -> Result<(), Strin
         return Ok(());
     }
     #[allow(unreachable_code)]
-    Err("Start Menu shortcut is only supported on Windows.".to_string())
+    Err(format!("Creating a {} is only supported on Windows.", action.label()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn desktop_option_toggles_only_the_desktop_action() {
+        let without = plan_shortcuts(ShortcutOptions {
+            start_menu: true,
+            desktop: false,
+        });
+        let with = plan_shortcuts(ShortcutOptions {
+            start_menu: true,
+            desktop: true,
+        });
+        assert_eq!(without, vec![ShortcutAction::StartMenu]);
+        assert_eq!(with, vec![ShortcutAction::StartMenu, ShortcutAction::Desktop]);
+
+        let desktop_only = plan_shortcuts(ShortcutOptions {
+            start_menu: false,
+            desktop: true,
+        });
+        assert_eq!(desktop_only, vec![ShortcutAction::Desktop]);
+    }
+
+    #[test]
+    fn flags_override_default_shortcuts() {
+        let args = ["--desktop-shortcut", "--no-start-menu"].map(String::from);
+        let options = ShortcutOptions::from_args(args);
+        assert!(options.desktop);
+        assert!(!options.start_menu);
+        assert_eq!(ShortcutOptions::from_args(Vec::new()), ShortcutOptions::default());
+    }
 }
@@ -3,9 +3,10 @@ use std::{path::PathBuf, sync::mpsc, thread};
 
 use sempal::egui_app::ui::style;
 
+use crate::shortcuts::ShortcutOptions;
 use crate::{APP_NAME, install, paths};
 
-pub(crate) fn run_installer_app() -> eframe::Result<()> {
+pub(crate) fn run_installer_app(shortcut_options: ShortcutOptions) -> eframe::Result<()> {
     let mut viewport = egui::ViewportBuilder::default().with_inner_size([600.0, 300.0]);
     if let Some(icon) = load_installer_icon() {
         viewport = viewport.with_icon(icon);
@@ -17,7 +18,7 @@ pub(crate) fn run_installer_app() -> eframe::Result<()> {
     eframe::run_native(
         "SemPal Installer",
         native_options,
-        Box::new(|cc| Ok(Box::new(InstallerApp::new(cc)))),
+        Box::new(move |cc| Ok(Box::new(InstallerApp::new(cc, shortcut_options)))),
     )
 }
 
@@ -79,6 +80,7 @@ struct InstallerApp {
     step: InstallStep,
     install_dir: PathBuf,
     bundle_dir: PathBuf,
+    shortcut_options: ShortcutOptions,
     license_text: String,
     progress: InstallProgress,
     receiver: Option<mpsc::Receiver<InstallerEvent>>,
@@ -91,7 +93,7 @@ struct InstallerApp {
 }
 
 impl InstallerApp {
-    fn new(cc: &eframe::CreationContext<'_>) -> Self {
+    fn new(cc: &eframe::CreationContext<'_>, shortcut_options: ShortcutOptions) -> Self {
         let mut visuals = cc.egui_ctx.style().visuals.clone();
         style::apply_visuals(&mut visuals);
         cc.egui_ctx.set_visuals(visuals);
@@ -100,6 +102,7 @@ impl InstallerApp {
             step: InstallStep::Welcome,
             install_dir: paths::default_install_dir(),
             bundle_dir: paths::default_bundle_dir(),
+            shortcut_options,
             license_text: include_str!("../../../LICENSE").to_string(),
             progress: InstallProgress::default(),
             receiver: None,
@@ -115,6 +118,7 @@ impl InstallerApp {
     fn start_install(&mut self) {
         let bundle_dir = self.bundle_dir.clone();
         let install_dir = self.install_dir.clone();
+        let shortcut_options = self.shortcut_options;
         let (tx, rx) = mpsc::channel();
         self.receiver = Some(rx);
         self.progress = InstallProgress::default();
@@ -123,7 +127,9 @@ impl InstallerApp {
         self.finish_errors.clear();
         self.logs.clear();
         thread::spawn(move || {
-            if let Err(err) = install::run_install(&bundle_dir, &install_dir, tx.clone()) {
+            if let Err(err) =
+                install::run_install(&bundle_dir, &install_dir, shortcut_options, tx.clone())
+            {
                 let _ = tx.send(InstallerEvent::Failed(err));
             }
         });
@@ -208,6 +214,11 @@ impl eframe::App for InstallerApp {
                             }
                         }
                     });
+                    ui.checkbox(
+                        &mut self.shortcut_options.start_menu,
+                        "Create Start Menu entry",
+                    );
+                    ui.checkbox(&mut self.shortcut_options.desktop, "Create desktop shortcut");
                     ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                         if ui.button("Install").clicked() {
                             self.start_install();
@@ -0,0 +1,60 @@
+use crate::analysis::similarity::SIMILARITY_MODEL_ID;
+use crate::analysis::version::analysis_version;
+
+/// Identifies the exact build for bug reports and the About dialog.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// Crate version from `Cargo.toml`.
+    pub version: &'static str,
+    /// Git commit the binary was built from, when `SEMPAL_GIT_SHA` was set at build time.
+    pub git_sha: Option<&'static str>,
+    /// Analysis pipeline version hash used to key cached features.
+    pub analysis_version: &'static str,
+    /// Identifier of the similarity embedding model.
+    pub embedding_model_id: &'static str,
+}
+
+impl BuildInfo {
+    /// Short git hash for display, or "unknown" when the build did not record one.
+    pub fn short_sha(&self) -> &'static str {
+        match self.git_sha {
+            Some(sha) => &sha[..sha.len().min(10)],
+            None => "unknown",
+        }
+    }
+
+    /// Multi-line summary suitable for pasting into an issue.
+    pub fn summary_lines(&self) -> Vec<String> {
+        vec![
+            format!("App version: {}", self.version),
+            format!("Git commit: {}", self.short_sha()),
+            format!("Analysis version: {}", self.analysis_version),
+            format!("Embedding model: {}", self.embedding_model_id),
+        ]
+    }
+}
+
+/// Return version and build metadata for the running binary.
+pub fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        git_sha: option_env!("SEMPAL_GIT_SHA")
+            .map(str::trim)
+            .filter(|sha| !sha.is_empty()),
+        analysis_version: analysis_version(),
+        embedding_model_id: SIMILARITY_MODEL_ID,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_info_reports_crate_version_and_model() {
+        let info = build_info();
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.embedding_model_id, "features_v1__len183__l2");
+        assert!(info.analysis_version.starts_with("analysis_v1_"));
+    }
+}
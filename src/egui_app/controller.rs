@@ -28,11 +28,15 @@ use crate::{
 };
 pub(in crate::egui_app::controller) use library::analysis_jobs::AnalysisJobMessage;
 use library::analysis_jobs::AnalysisWorkerPool;
-use playback::audio_loader::{AudioLoadError, AudioLoadJob, AudioLoadOutcome};
+use playback::audio_loader::{AudioLoadError, AudioLoadJob, AudioLoadOutcome, LoadedTransients};
 pub(crate) use controller_state::*;
+pub(crate) use library::level_match::LevelMatchMode;
+pub(crate) use playback::SelectionStatistics;
+pub use playback::PlaybackPosition;
 use egui::Color32;
 use open;
 use rfd::FileDialog;
+pub(crate) use ui::feedback_issue::gpu_summary_line;
 pub(crate) use ui::hotkeys;
 pub(crate) use ui::status_message::StatusMessage;
 use std::{
@@ -51,6 +55,8 @@ pub(crate) const RANDOM_HISTORY_LIMIT: usize = 20;
 pub(crate) const FOCUS_HISTORY_LIMIT: usize = 100;
 pub(crate) const UNDO_LIMIT: usize = 20;
 pub(crate) const STATUS_LOG_LIMIT: usize = 200;
+/// Delay before analysis claiming resumes after a drag or edit gesture ends.
+const INTERACTION_RESUME_DEBOUNCE: Duration = Duration::from_millis(400);
 
 /// Maintains app state and bridges core logic to the egui UI.
 pub struct EguiController {
@@ -156,7 +162,9 @@ impl EguiController {
             .analysis
             .as_ref()
             .is_some_and(|snapshot| snapshot.pending > 0 || snapshot.running > 0);
-        let pause_claiming = (self.is_playing() || recent_input) && !analysis_active;
+        let interaction_hold = self.interaction_pause_held(now);
+        let pause_claiming =
+            interaction_hold || ((self.is_playing() || recent_input) && !analysis_active);
         let last_activity_at = match (
             self.runtime.performance.last_user_activity_at,
             self.runtime.performance.last_slow_frame_at,
@@ -191,6 +199,49 @@ impl EguiController {
         }
     }
 
+    /// Pause analysis claiming for the duration of a drag or edit gesture.
+    pub(crate) fn begin_interaction_pause(&mut self) {
+        if !self.settings.analysis.pause_analysis_during_interaction {
+            return;
+        }
+        self.runtime.performance.interaction_active = true;
+        self.runtime.performance.interaction_resume_at = None;
+        self.runtime.analysis.pause_claiming();
+    }
+
+    /// Schedule claiming to resume once the gesture has been idle for a short debounce.
+    pub(crate) fn end_interaction_pause(&mut self) {
+        if !self.runtime.performance.interaction_active {
+            return;
+        }
+        self.runtime.performance.interaction_active = false;
+        self.runtime.performance.interaction_resume_at =
+            Some(Instant::now() + INTERACTION_RESUME_DEBOUNCE);
+    }
+
+    fn interaction_pause_held(&mut self, now: Instant) -> bool {
+        // Gestures can end without a finish call (cleared selection, lost pointer), so release
+        // the hold once no selection drag is live.
+        if self.runtime.performance.interaction_active
+            && !self.is_selection_dragging()
+            && !self.is_edit_selection_dragging()
+        {
+            self.end_interaction_pause();
+        }
+        let performance = &mut self.runtime.performance;
+        if performance.interaction_active {
+            return true;
+        }
+        match performance.interaction_resume_at {
+            Some(resume_at) if now < resume_at => true,
+            Some(_) => {
+                performance.interaction_resume_at = None;
+                false
+            }
+            None => false,
+        }
+    }
+
     #[cfg(target_os = "windows")]
     /// Store the HWND used for initiating external drag-and-drop operations on Windows.
     /// This is populated from the egui frame when available.
@@ -204,6 +255,7 @@ impl EguiController {
         self.ui.status.text = text.clone();
         self.ui.status.badge_label = label;
         self.ui.status.badge_color = color;
+        self.ui.status.action = None;
         let entry = format!("[{}] {}", self.ui.status.badge_label, text);
         if self.ui.status.log.last().is_some_and(|last| last == &entry) {
             return;
@@ -216,6 +268,39 @@ impl EguiController {
         log_status_entry(tone, self.ui.status.log.last().expect("just pushed"));
     }
 
+    /// Set a status message that offers to undo the most recently pushed undo entry.
+    pub(crate) fn set_status_with_undo(&mut self, text: impl Into<String>, tone: StatusTone) {
+        self.set_status(text, tone);
+        self.ui.status.action = self
+            .history
+            .undo_stack
+            .last_undo()
+            .map(|(entry_id, label)| crate::egui_app::state::StatusAction::Undo {
+                entry_id,
+                label: label.to_string(),
+            });
+    }
+
+    /// Label of the status bar's undo action while its entry is still the next one to undo.
+    pub(crate) fn status_undo_label(&self) -> Option<&str> {
+        let Some(crate::egui_app::state::StatusAction::Undo { entry_id, label }) =
+            &self.ui.status.action
+        else {
+            return None;
+        };
+        let (top_id, _) = self.history.undo_stack.last_undo()?;
+        (top_id == *entry_id).then_some(label.as_str())
+    }
+
+    /// Run the status bar's undo action, refusing if other edits were pushed since.
+    pub(crate) fn undo_status_action(&mut self) {
+        if self.status_undo_label().is_some() {
+            self.undo();
+        } else {
+            self.set_status("That edit is no longer the latest change", StatusTone::Warning);
+        }
+    }
+
     pub(crate) fn set_status_message(&mut self, message: StatusMessage) {
         let (text, tone) = message.into_text_and_tone();
         self.set_status(text, tone);
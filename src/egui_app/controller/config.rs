@@ -5,7 +5,23 @@ impl EguiController {
     /// Load persisted configuration and populate initial UI state.
     pub fn load_configuration(&mut self) -> Result<(), crate::sample_sources::config::ConfigError> {
         let cfg = crate::sample_sources::config::load_or_default()?;
-        self.apply_configuration(cfg)
+        self.apply_configuration(cfg)?;
+        self.library.read_only = matches!(
+            crate::sample_sources::library::access_mode(),
+            Ok(crate::sample_sources::library::LibraryAccess::ReadOnly)
+        );
+        if self.library.read_only {
+            self.set_status(
+                "Library database is read-only; browsing and playback only",
+                StatusTone::Warning,
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns true when the library database is read-only and source edits are disabled.
+    pub(crate) fn library_read_only(&self) -> bool {
+        self.library.read_only
     }
 
     /// Apply a preloaded configuration snapshot to the controller state.
@@ -27,7 +43,9 @@ impl EguiController {
         self.settings.job_message_queue_capacity = cfg.core.job_message_queue_capacity;
         self.settings.app_data_dir = cfg.core.app_data_dir.clone();
         self.settings.trash_folder = cfg.core.trash_folder.clone();
+        self.settings.trash_strategy = cfg.core.trash_strategy;
         self.settings.drop_targets = cfg.core.drop_targets.clone();
+        self.apply_window_layout(cfg.core.layout.clone());
         self.settings.audio_output = cfg.core.audio_output.clone();
         self.ui.audio.selected = self.settings.audio_output.clone();
         self.settings.audio_input = cfg.core.audio_input.clone();
@@ -43,6 +61,18 @@ impl EguiController {
             super::ui::interaction_options::clamp_anti_clip_fade_ms(
                 self.settings.controls.anti_clip_fade_ms,
             );
+        self.settings.controls.seek_crossfade_ms =
+            super::ui::interaction_options::clamp_seek_crossfade_ms(
+                self.settings.controls.seek_crossfade_ms,
+            );
+        self.settings.controls.playhead_trail_seconds =
+            super::ui::interaction_options::clamp_playhead_trail_seconds(
+                self.settings.controls.playhead_trail_seconds,
+            );
+        self.settings.controls.playhead_trail_fade_seconds =
+            super::ui::interaction_options::clamp_playhead_trail_fade_seconds(
+                self.settings.controls.playhead_trail_fade_seconds,
+            );
         self.ui.controls = crate::egui_app::state::InteractionOptionsState {
             invert_waveform_scroll: self.settings.controls.invert_waveform_scroll,
             waveform_scroll_speed: self.settings.controls.waveform_scroll_speed,
@@ -50,22 +80,33 @@ impl EguiController {
             keyboard_zoom_factor: self.settings.controls.keyboard_zoom_factor,
             anti_clip_fade_enabled: self.settings.controls.anti_clip_fade_enabled,
             anti_clip_fade_ms: self.settings.controls.anti_clip_fade_ms,
+            seek_crossfade_enabled: self.settings.controls.seek_crossfade_enabled,
+            seek_crossfade_ms: self.settings.controls.seek_crossfade_ms,
             auto_edge_fades_on_selection_exports: self
                 .settings
                 .controls
                 .auto_edge_fades_on_selection_exports,
+            markers_on_selection_exports: self.settings.controls.markers_on_selection_exports,
             destructive_yolo_mode: self.settings.controls.destructive_yolo_mode,
+            destructive_confirmations: self.settings.controls.destructive_confirmations,
             waveform_channel_view: self.settings.controls.waveform_channel_view,
             input_monitoring_enabled: self.settings.controls.input_monitoring_enabled,
             advance_after_rating: self.settings.controls.advance_after_rating,
             tooltip_mode: self.settings.controls.tooltip_mode,
+            playhead_trail_seconds: self.settings.controls.playhead_trail_seconds,
+            playhead_trail_fade_seconds: self.settings.controls.playhead_trail_fade_seconds,
+            load_selection_mode: self.settings.controls.load_selection_mode,
+            high_contrast_theme: self.settings.controls.high_contrast_theme,
         };
         self.ui.waveform.channel_view = self.settings.controls.waveform_channel_view;
         self.ui.waveform.bpm_snap_enabled = self.settings.controls.bpm_snap_enabled;
         self.ui.waveform.bpm_lock_enabled = self.settings.controls.bpm_lock_enabled;
         self.ui.waveform.bpm_stretch_enabled = self.settings.controls.bpm_stretch_enabled;
         self.ui.waveform.bpm_value = normalize_bpm_value(self.settings.controls.bpm_value);
+        self.ui.waveform.metronome_enabled = self.settings.controls.metronome_enabled;
         self.ui.waveform.loop_lock_enabled = self.settings.controls.loop_lock_enabled;
+        self.ui.waveform.loop_cycle_limit =
+            self.settings.controls.loop_cycle_limit.filter(|limit| *limit > 0);
         self.ui.waveform.transient_markers_enabled =
             self.settings.controls.transient_markers_enabled;
         self.ui.waveform.transient_snap_enabled = self.settings.controls.transient_snap_enabled
@@ -118,6 +159,9 @@ impl EguiController {
             .filter(|id| self.library.sources.iter().any(|s| &s.id == id));
         self.selection_state.ctx.last_selected_browsable_source =
             self.selection_state.ctx.selected_source.clone();
+        self.runtime
+            .analysis
+            .set_priority_source(self.selection_state.ctx.selected_source.clone());
         self.refresh_sources_ui();
         if self.selection_state.ctx.selected_source.is_some() {
             let _ = self.refresh_wavs();
@@ -129,12 +173,27 @@ impl EguiController {
         self.runtime
             .analysis
             .set_worker_count(self.settings.analysis.analysis_worker_count);
+        self.runtime
+            .analysis
+            .set_claim_batch_size(self.settings.analysis.analysis_claim_batch);
         self.runtime
             .analysis
             .start(self.runtime.jobs.message_sender());
         Ok(())
     }
 
+    /// Restore persisted panel split ratios and map window state.
+    pub(super) fn apply_window_layout(
+        &mut self,
+        layout: crate::sample_sources::config::WindowLayout,
+    ) {
+        self.ui.sources.sources_split_ratio = layout.sources_split;
+        self.ui.sources.drop_targets.split_ratio = layout.drop_targets_split;
+        self.ui.map.open = layout.map_open && !self.map_disabled();
+        self.ui.map.window_size = layout.map_size;
+        self.settings.layout = layout;
+    }
+
     pub(super) fn persist_config(&mut self, error_prefix: &str) -> Result<(), String> {
         self.save_full_config()
             .map_err(|err| format!("{error_prefix}: {err}"))
@@ -152,6 +211,7 @@ impl EguiController {
                 job_message_queue_capacity: self.settings.job_message_queue_capacity,
                 app_data_dir: self.settings.app_data_dir.clone(),
                 trash_folder: self.settings.trash_folder.clone(),
+                trash_strategy: self.settings.trash_strategy,
                 drop_targets: self.settings.drop_targets.clone(),
                 last_selected_source: self
                     .selection_state
@@ -169,6 +229,7 @@ impl EguiController {
                 audio_input: self.settings.audio_input.clone(),
                 volume: self.ui.volume,
                 controls: self.settings.controls.clone(),
+                layout: self.settings.layout.clone(),
             },
         })
     }
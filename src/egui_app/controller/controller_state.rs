@@ -7,7 +7,7 @@ pub(super) use super::state::audio::{
 pub(crate) use super::state::cache::{AnalysisJobStatus, FeatureCache, FeatureStatus};
 pub(super) use super::state::cache::{ControllerUiCacheState, LibraryCacheState, WavEntriesState};
 pub(super) use super::state::history::{
-    ControllerHistoryState, FocusHistoryEntry, RandomHistoryEntry,
+    ControllerHistoryState, EditBackupRef, FocusHistoryEntry, RandomHistoryEntry,
 };
 pub(super) use super::state::library::{LibraryState, MissingState, RowFlags};
 pub(crate) use super::state::runtime::{
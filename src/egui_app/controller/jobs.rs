@@ -40,9 +40,13 @@ pub(crate) enum JobMessage {
     FileOps(FileOpMessage),
     Analysis(AnalysisJobMessage),
     AnalysisFailuresLoaded(AnalysisFailuresResult),
+    DecodeOutcomeCountsLoaded(DecodeOutcomeCountsResult),
     UmapBuilt(UmapBuildResult),
     UmapClustersBuilt(UmapClusterBuildResult),
     SimilarityPrepared(SimilarityPrepResult),
+    OutlierScanFinished(OutlierScanResult),
+    SourceSummaryLoaded(SourceSummaryResult),
+    WaveformDiffLoaded(WaveformDiffResult),
     UpdateChecked(UpdateCheckResult),
     IssueGatewayCreated(IssueGatewayCreateResult),
     IssueGatewayAuthed(IssueGatewayAuthResult),
@@ -109,6 +113,8 @@ pub(crate) struct SearchJob {
     pub(super) folder_selection: Option<BTreeSet<PathBuf>>,
     pub(super) folder_negated: Option<BTreeSet<PathBuf>>,
     pub(super) root_mode: crate::egui_app::state::RootFolderFilterMode,
+    /// Paths flagged silent by analysis when the silent-only filter is active.
+    pub(super) silent_paths: Option<std::collections::HashSet<PathBuf>>,
 }
 
 #[derive(Debug)]
@@ -290,10 +296,56 @@ pub(crate) struct SimilarityPrepResult {
     pub(crate) result: Result<SimilarityPrepOutcome, String>,
 }
 
+/// Request to rank a source's samples by how atypical their embeddings are.
+#[derive(Debug, Clone)]
+pub(crate) struct OutlierScanJob {
+    pub(crate) source_id: SourceId,
+    pub(crate) source_root: PathBuf,
+}
+
+/// Ranked outlier candidates for a source.
+#[derive(Debug)]
+pub(crate) struct OutlierScanResult {
+    pub(crate) source_id: SourceId,
+    /// Sample ids with their mean neighbour distance, most atypical first.
+    pub(crate) result: Result<Vec<(String, f32)>, String>,
+}
+
+/// Analysis coverage and completion time read for one source row.
+#[derive(Debug)]
+pub(crate) struct SourceSummaryResult {
+    pub(crate) source_id: SourceId,
+    pub(crate) coverage: Option<crate::sample_sources::AnalysisCoverage>,
+    pub(crate) last_analyzed_at: Option<i64>,
+}
+
+/// Request to decode the pre-edit audio and diff it against the loaded waveform.
+pub(crate) struct WaveformDiffJob {
+    pub(crate) renderer: crate::waveform::WaveformRenderer,
+    pub(crate) before_path: PathBuf,
+    pub(crate) current: crate::waveform::DecodedWaveform,
+}
+
+/// A-B difference waveform computed for a loaded waveform.
+#[derive(Debug)]
+pub(crate) struct WaveformDiffResult {
+    /// Cache token of the waveform the difference was computed against.
+    pub(crate) current_token: u64,
+    pub(crate) result: Result<crate::waveform::DecodedWaveform, String>,
+}
+
 #[derive(Debug)]
 pub(crate) struct AnalysisFailuresResult {
     pub(crate) source_id: SourceId,
     pub(crate) result: Result<std::collections::HashMap<PathBuf, String>, String>,
+    pub(crate) warnings: std::collections::HashMap<PathBuf, String>,
+    pub(crate) silent: std::collections::HashSet<PathBuf>,
+}
+
+#[derive(Debug)]
+pub(crate) struct DecodeOutcomeCountsResult {
+    pub(crate) source_id: SourceId,
+    pub(crate) result: Result<super::library::analysis_jobs::DecodeOutcomeCounts, String>,
 }
 
 #[derive(Debug)]
@@ -337,6 +389,19 @@ pub(crate) enum FileOpResult {
     FolderMove(FolderMoveResult),
     /// Undo/redo filesystem results.
     UndoFile(UndoFileOpResult),
+    /// Feature vector CSV export results.
+    FeatureExport(FeatureExportResult),
+}
+
+/// Result of exporting browser feature vectors to a CSV file.
+#[derive(Debug)]
+pub(crate) struct FeatureExportResult {
+    /// Destination CSV path.
+    pub(crate) out: PathBuf,
+    /// Number of rows written, or the first failure.
+    pub(crate) result: Result<usize, String>,
+    /// Whether the export stopped because the user cancelled it.
+    pub(crate) cancelled: bool,
 }
 
 /// Successful paste into a source folder with metadata for follow-up updates.
@@ -422,6 +487,8 @@ pub(crate) struct SourceMoveSuccess {
     pub(crate) looped: bool,
     /// Last played timestamp, if any.
     pub(crate) last_played_at: Option<i64>,
+    /// Star rating, if any.
+    pub(crate) stars: Option<u8>,
 }
 
 /// Request payload for a background in-source folder sample move.
@@ -450,6 +517,8 @@ pub(crate) struct FolderEntryMove {
     pub(crate) looped: bool,
     /// Last played timestamp, if any.
     pub(crate) last_played_at: Option<i64>,
+    /// Star rating, if any.
+    pub(crate) stars: Option<u8>,
 }
 
 /// Result of a background in-source folder sample move operation.
@@ -539,6 +608,18 @@ pub(crate) enum UndoFileJob {
         /// Tag to apply after restoration.
         tag: crate::sample_sources::Rating,
     },
+    /// Several file jobs applied in order as one undo step.
+    Batch(Vec<UndoFileJob>),
+}
+
+impl UndoFileJob {
+    /// Number of files the job touches, used for progress reporting.
+    pub(crate) fn file_count(&self) -> usize {
+        match self {
+            UndoFileJob::Batch(jobs) => jobs.iter().map(UndoFileJob::file_count).sum(),
+            _ => 1,
+        }
+    }
 }
 
 /// Result of a background undo/redo filesystem operation.
@@ -569,6 +650,8 @@ pub(crate) enum UndoFileOutcome {
         looped: bool,
         /// Last played timestamp, if any.
         last_played_at: Option<i64>,
+        /// Star rating, if any.
+        stars: Option<u8>,
     },
     /// File removal completed.
     Removed {
@@ -593,7 +676,11 @@ pub(crate) enum UndoFileOutcome {
         looped: bool,
         /// Last played timestamp, if any.
         last_played_at: Option<i64>,
+        /// Star rating, if any.
+        stars: Option<u8>,
     },
+    /// Outcomes of a grouped job, in the order they ran.
+    Batch(Vec<UndoFileOutcome>),
 }
 
 /// Coordinator for controller job channels, worker handles, and job state.
@@ -628,6 +715,8 @@ pub(crate) struct ControllerJobs {
     pub(super) file_ops_cancel: Option<Arc<std::sync::atomic::AtomicBool>>,
     pub(super) umap_build_in_progress: bool,
     pub(super) umap_cluster_build_in_progress: bool,
+    pub(super) outlier_scan_in_progress: bool,
+    pub(super) pending_waveform_diff: Option<u64>,
     pub(super) update_check_in_progress: bool,
     pub(super) issue_gateway_in_progress: bool,
     pub(super) issue_gateway_auth_in_progress: bool,
@@ -780,6 +869,8 @@ impl ControllerJobs {
             file_ops_cancel: None,
             umap_build_in_progress: false,
             umap_cluster_build_in_progress: false,
+            outlier_scan_in_progress: false,
+            pending_waveform_diff: None,
             update_check_in_progress: false,
             issue_gateway_in_progress: false,
             issue_gateway_auth_in_progress: false,
@@ -1141,6 +1232,104 @@ impl ControllerJobs {
         self.umap_build_in_progress = false;
     }
 
+    pub(crate) fn outlier_scan_in_progress(&self) -> bool {
+        self.outlier_scan_in_progress
+    }
+
+    pub(crate) fn begin_outlier_scan(&mut self, job: OutlierScanJob) {
+        if self.outlier_scan_in_progress {
+            return;
+        }
+        self.outlier_scan_in_progress = true;
+        let tx = self.message_tx.clone();
+        let signal = self.repaint_signal.clone();
+        thread::spawn(move || {
+            let result = super::library::wavs::rank_source_outliers(
+                &job.source_id,
+                &job.source_root,
+            );
+            let _ = tx.send(JobMessage::OutlierScanFinished(OutlierScanResult {
+                source_id: job.source_id,
+                result,
+            }));
+            if let Ok(lock) = signal.lock() {
+                if let Some(ctx) = lock.as_ref() {
+                    ctx.request_repaint();
+                }
+            }
+        });
+    }
+
+    pub(crate) fn clear_outlier_scan(&mut self) {
+        self.outlier_scan_in_progress = false;
+    }
+
+    /// Decode the pre-edit audio for an A-B difference view on a background thread.
+    pub(crate) fn begin_waveform_diff(&mut self, job: WaveformDiffJob) {
+        let current_token = job.current.cache_token;
+        self.pending_waveform_diff = Some(current_token);
+        let tx = self.message_tx.clone();
+        let signal = self.repaint_signal.clone();
+        thread::spawn(move || {
+            let result = super::library::wavs::load_waveform_ab_diff(
+                &job.renderer,
+                &job.before_path,
+                &job.current,
+            );
+            let _ = tx.send(JobMessage::WaveformDiffLoaded(WaveformDiffResult {
+                current_token,
+                result,
+            }));
+            if let Ok(lock) = signal.lock() {
+                if let Some(ctx) = lock.as_ref() {
+                    ctx.request_repaint();
+                }
+            }
+        });
+    }
+
+    /// Drop a pending A-B difference load; returns whether one was pending.
+    pub(crate) fn cancel_waveform_diff(&mut self) -> bool {
+        self.pending_waveform_diff.take().is_some()
+    }
+
+    /// Clear the pending A-B difference load if `current_token` matches it.
+    pub(crate) fn finish_waveform_diff(&mut self, current_token: u64) -> bool {
+        if self.pending_waveform_diff != Some(current_token) {
+            return false;
+        }
+        self.pending_waveform_diff = None;
+        true
+    }
+
+    /// Read sidebar analysis summaries for `sources` on a background thread.
+    pub(crate) fn load_source_summaries(&self, sources: Vec<crate::sample_sources::SampleSource>) {
+        if sources.is_empty() {
+            return;
+        }
+        let tx = self.message_tx.clone();
+        let signal = self.repaint_signal.clone();
+        thread::spawn(move || {
+            for source in sources {
+                let (coverage, last_analyzed_at) =
+                    super::library::sources::source_analysis_summary(&source);
+                let message = JobMessage::SourceSummaryLoaded(SourceSummaryResult {
+                    source_id: source.id,
+                    coverage,
+                    last_analyzed_at,
+                });
+                if tx.send(message).is_err() {
+                    return;
+                }
+            }
+            if let Ok(lock) = signal.lock() {
+                if let Some(ctx) = lock.as_ref() {
+                    ctx.request_repaint();
+                }
+            }
+        });
+    }
+
     pub(super) fn begin_umap_cluster_build(&mut self, job: UmapClusterBuildJob) {
         if self.umap_cluster_build_in_progress {
             return;
@@ -100,6 +100,86 @@ impl EguiController {
         });
     }
 
+    /// Queue embedding-only analysis for a source, skipping the full feature analysis.
+    ///
+    /// Enough for similarity search and the map; BPM, transients and stored feature
+    /// vectors are left for a later full analysis.
+    pub fn analyze_embeddings_only_for_source(&mut self, index: usize) {
+        let Some(source) = self.library.sources.get(index).cloned() else {
+            return;
+        };
+        self.set_status(
+            format!("Analyzing embeddings for {}", source.root.display()),
+            StatusTone::Busy,
+        );
+        let tx = self.runtime.jobs.message_sender();
+        std::thread::spawn(move || {
+            let result = analysis_jobs::enqueue_jobs_for_source_embeddings_only(&source);
+            match result {
+                Ok((inserted, progress)) => {
+                    let _ = tx.send(super::jobs::JobMessage::Analysis(
+                        analysis_jobs::AnalysisJobMessage::EmbeddingBackfillEnqueueFinished {
+                            inserted,
+                            progress,
+                        },
+                    ));
+                }
+                Err(err) => {
+                    let _ = tx.send(super::jobs::JobMessage::Analysis(
+                        analysis_jobs::AnalysisJobMessage::EmbeddingBackfillEnqueueFailed(
+                            err,
+                        ),
+                    ));
+                }
+            }
+        });
+    }
+
+    /// Clear all analysis results for a source and queue a full re-analysis after confirmation.
+    ///
+    /// Features, embeddings, map layout, clusters and the similarity index are rebuilt;
+    /// tags and other file metadata are kept.
+    pub fn rebuild_analysis_for_source(&mut self, index: usize) {
+        let Some(source) = self.library.sources.get(index).cloned() else {
+            return;
+        };
+        if !self.confirm_warning(
+            "Reset and reanalyze source?",
+            "All analysis results for this source (features, embeddings, map layout and clusters) will be discarded and recomputed. Tags are kept. Continue?",
+        ) {
+            return;
+        }
+        if self.selection_state.ctx.selected_source.as_ref() == Some(&source.id) {
+            self.ui.map.bounds = None;
+            self.ui.map.last_query = None;
+            self.ui.map.cached_points.clear();
+            self.ui.map.selected_sample_ids.clear();
+        }
+        self.set_status(
+            format!("Reanalyzing {}", source.root.display()),
+            StatusTone::Busy,
+        );
+        let tx = self.runtime.jobs.message_sender();
+        std::thread::spawn(move || {
+            let result = analysis_jobs::rebuild_source_analysis(&source);
+            match result {
+                Ok((inserted, progress)) => {
+                    let _ = tx.send(super::jobs::JobMessage::Analysis(
+                        analysis_jobs::AnalysisJobMessage::EnqueueFinished {
+                            inserted,
+                            progress,
+                        },
+                    ));
+                }
+                Err(err) => {
+                    let _ = tx.send(super::jobs::JobMessage::Analysis(
+                        analysis_jobs::AnalysisJobMessage::EnqueueFailed(err),
+                    ));
+                }
+            }
+        });
+    }
+
     /// Recalculate similarity for the visible browser rows by index.
     pub fn recalc_similarity_for_browser_rows(&mut self, rows: &[usize]) -> Result<(), String> {
         let Some(source) = self.current_source() else {
@@ -184,6 +264,34 @@ impl EguiController {
         Ok(())
     }
 
+    /// Re-analyze the given browser rows at full length, ignoring the global duration cap.
+    pub fn analyze_browser_rows_full_length(&mut self, rows: &[usize]) -> Result<(), String> {
+        let Some(source) = self.current_source() else {
+            return Err("Select a source first".to_string());
+        };
+        let mut sample_ids = Vec::new();
+        for &row in rows {
+            let Some(entry_index) = self.visible_browser_index(row) else {
+                continue;
+            };
+            let Some(entry) = self.wav_entry(entry_index) else {
+                continue;
+            };
+            if entry.missing {
+                continue;
+            }
+            sample_ids.push(analysis_jobs::build_sample_id(
+                source.id.as_str(),
+                &entry.relative_path,
+            ));
+        }
+        if sample_ids.is_empty() {
+            return Err("No valid samples selected".to_string());
+        }
+        self.runtime.analysis.allow_full_analysis_for(&sample_ids);
+        self.recalc_similarity_for_browser_rows(rows)
+    }
+
     /// Return true if any sources are configured.
     pub fn has_any_sources(&self) -> bool {
         !self.library.sources.is_empty()
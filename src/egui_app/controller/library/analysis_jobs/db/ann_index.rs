@@ -73,7 +73,8 @@ pub(crate) fn enqueue_rebuild_ann_index_job(
                  attempts = 0,
                  created_at = excluded.created_at,
                  running_at = NULL,
-                 last_error = NULL",
+                 last_error = NULL,
+                 retry_at = NULL",
             params![
                 sample_id,
                 source_id,
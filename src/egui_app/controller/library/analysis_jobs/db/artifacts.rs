@@ -57,6 +57,36 @@ pub(crate) fn invalidate_analysis_artifacts(
     Ok(())
 }
 
+/// Drop every analysis artifact for one source: jobs, features, embeddings, layout and clusters.
+///
+/// Cached results keyed by the source's content hashes are dropped too so the next
+/// analysis recomputes them. Sample rows and file metadata (tags, notes) are kept.
+pub(crate) fn clear_source_analysis(conn: &mut Connection, source_id: &str) -> Result<(), String> {
+    let prefix = format!("{source_id}::%");
+    let tx = conn
+        .transaction_with_behavior(TransactionBehavior::Immediate)
+        .map_err(|err| format!("Failed to start analysis reset transaction: {err}"))?;
+    for sql in [
+        "DELETE FROM analysis_cache_features WHERE content_hash IN
+            (SELECT content_hash FROM samples WHERE sample_id LIKE ?1)",
+        "DELETE FROM analysis_cache_embeddings WHERE content_hash IN
+            (SELECT content_hash FROM samples WHERE sample_id LIKE ?1)",
+        "DELETE FROM analysis_jobs WHERE sample_id LIKE ?1",
+        "DELETE FROM features WHERE sample_id LIKE ?1",
+        "DELETE FROM embeddings WHERE sample_id LIKE ?1",
+        "DELETE FROM analysis_features WHERE sample_id LIKE ?1",
+        "DELETE FROM layout_umap WHERE sample_id LIKE ?1",
+        "DELETE FROM hdbscan_clusters WHERE sample_id LIKE ?1",
+        "UPDATE samples SET analysis_version = NULL WHERE sample_id LIKE ?1",
+    ] {
+        tx.execute(sql, params![prefix])
+            .map_err(|err| format!("Failed to reset source analysis: {err}"))?;
+    }
+    tx.commit()
+        .map_err(|err| format!("Failed to commit analysis reset transaction: {err}"))?;
+    Ok(())
+}
+
 pub(crate) fn update_analysis_metadata(
     conn: &Connection,
     sample_id: &str,
@@ -140,6 +170,49 @@ pub(crate) fn upsert_analysis_features(
     Ok(())
 }
 
+const EMBEDDING_STORAGE_KEY: &str = "embedding_storage_v1";
+const EMBEDDING_STORAGE_INT8: &str = "int8";
+
+/// Return whether new embeddings for this source are stored int8-quantized.
+///
+/// Writers resolve this once per batch and pass it to [`upsert_embedding`].
+pub(crate) fn quantized_embedding_storage(conn: &Connection) -> Result<bool, String> {
+    let value: Option<String> = conn
+        .query_row(
+            "SELECT value FROM metadata WHERE key = ?1",
+            params![EMBEDDING_STORAGE_KEY],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|err| format!("Failed to read embedding storage option: {err}"))?;
+    Ok(value.as_deref() == Some(EMBEDDING_STORAGE_INT8))
+}
+
+/// Choose whether new embeddings for this source are stored int8-quantized.
+///
+/// Existing rows keep their dtype; readers decode both encodings.
+pub(crate) fn set_quantized_embedding_storage(
+    conn: &Connection,
+    enabled: bool,
+) -> Result<(), String> {
+    if enabled {
+        conn.execute(
+            "INSERT INTO metadata (key, value)
+             VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            params![EMBEDDING_STORAGE_KEY, EMBEDDING_STORAGE_INT8],
+        )
+    } else {
+        conn.execute(
+            "DELETE FROM metadata WHERE key = ?1",
+            params![EMBEDDING_STORAGE_KEY],
+        )
+    }
+    .map_err(|err| format!("Failed to save embedding storage option: {err}"))?;
+    Ok(())
+}
+
+/// Upsert an embedding row, int8-quantizing f32 vectors when `quantize` is set.
 pub(crate) fn upsert_embedding(
     conn: &Connection,
     sample_id: &str,
@@ -149,7 +222,20 @@ pub(crate) fn upsert_embedding(
     l2_normed: bool,
     vec_blob: &[u8],
     created_at: i64,
+    quantize: bool,
 ) -> Result<(), String> {
+    let quantized;
+    let f32_input = dtype == crate::analysis::similarity::SIMILARITY_DTYPE_F32;
+    let (dtype, vec_blob) = if quantize && f32_input {
+        let values = crate::analysis::decode_f32_le_blob(vec_blob)?;
+        quantized = crate::analysis::vector::encode_int8(&values);
+        (
+            crate::analysis::similarity::SIMILARITY_DTYPE_I8,
+            quantized.as_slice(),
+        )
+    } else {
+        (dtype, vec_blob)
+    };
     conn.execute(
         "INSERT INTO embeddings (sample_id, model_id, dim, dtype, l2_normed, vec, created_at)
          VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
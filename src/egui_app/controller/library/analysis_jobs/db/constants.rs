@@ -4,6 +4,12 @@ pub(crate) const REBUILD_INDEX_JOB_TYPE: &str =
     "rebuild_index_v1";
 pub(crate) const EMBEDDING_BACKFILL_JOB_TYPE: &str =
     "embedding_backfill_v1";
+pub(crate) const DECODE_OUTCOME_DECODED: &str = "decoded";
+pub(crate) const DECODE_OUTCOME_SKIPPED: &str = "skipped";
+pub(crate) const DECODE_OUTCOME_FAILED: &str = "failed";
+pub(crate) const DECODE_OUTCOME_NOT_NEEDED: &str = "not_needed";
+pub(crate) const SAMPLE_RATE_MISMATCH_WARNING: &str =
+    "Sample rate in the file header looks wrong; analysis may be unreliable";
 #[cfg(test)]
 pub(crate) const DEFAULT_JOB_TYPE: &str =
     ANALYZE_SAMPLE_JOB_TYPE;
@@ -1,4 +1,5 @@
 use super::types::SampleMetadata;
+use crate::sample_sources::path_text::encode_path_text;
 use rusqlite::params_from_iter;
 use rusqlite::types::Value;
 use rusqlite::{Connection, TransactionBehavior};
@@ -67,7 +68,8 @@ fn enqueue_jobs_tx(
                 attempts = 0,
                 created_at = excluded.created_at,
                 running_at = NULL,
-                last_error = NULL",
+                last_error = NULL,
+                retry_at = NULL",
         );
         let changed = tx
             .execute(&sql, params_from_iter(params))
@@ -79,7 +81,7 @@ fn enqueue_jobs_tx(
 
 fn relative_path_from_sample_id(sample_id: &str) -> Result<String, String> {
     let (_source, relative_path) = super::parse_sample_id(sample_id)?;
-    Ok(relative_path.to_string_lossy().replace('\\', "/"))
+    Ok(encode_path_text(&relative_path))
 }
 
 pub(crate) fn upsert_samples(
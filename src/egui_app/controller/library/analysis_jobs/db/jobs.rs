@@ -1,4 +1,4 @@
-use super::types::ClaimedJob;
+use super::types::{ClaimedJob, DecodeReport};
 use rusqlite::{Connection, OptionalExtension, TransactionBehavior, params, params_from_iter};
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
@@ -212,6 +212,7 @@ pub(crate) fn claim_next_jobs(
                          ) AS rn
                      FROM analysis_jobs AS pending
                      WHERE pending.status = 'pending'
+                       AND (pending.retry_at IS NULL OR pending.retry_at <= ?2)
                        AND NOT EXISTS (
                            SELECT 1
                            FROM analysis_jobs AS running
@@ -228,7 +229,7 @@ pub(crate) fn claim_next_jobs(
                      LIMIT ?1
                  )
                  UPDATE analysis_jobs
-                 SET status = 'running', attempts = attempts + 1, running_at = ?2
+                 SET status = 'running', attempts = attempts + 1, running_at = ?2, retry_at = NULL
                  WHERE id IN (SELECT id FROM to_claim)
                  RETURNING id, sample_id, content_hash, job_type",
             )
@@ -267,12 +268,17 @@ pub(crate) fn claim_next_jobs(
 pub(crate) fn mark_done(
     conn: &Connection,
     job_id: i64,
+    decode: Option<DecodeReport>,
 ) -> Result<(), String> {
+    let (outcome, decoded, warning, silent) = decode_columns(decode);
     conn.execute(
         "UPDATE analysis_jobs
-         SET status = 'done', last_error = NULL, running_at = NULL
+         SET status = 'done', last_error = NULL, running_at = NULL,
+             decode_outcome = COALESCE(?2, decode_outcome),
+             analysis_warning = CASE WHEN ?3 THEN ?4 ELSE analysis_warning END,
+             silent = CASE WHEN ?3 THEN ?5 ELSE silent END
          WHERE id = ?1 AND status = 'running'",
-        params![job_id],
+        params![job_id, outcome, decoded, warning, silent],
     )
     .map_err(|err| format!("Failed to mark analysis job done: {err}"))?;
     Ok(())
@@ -282,17 +288,70 @@ pub(crate) fn mark_failed_with_reason(
     conn: &Connection,
     job_id: i64,
     error: &str,
+    decode: Option<DecodeReport>,
 ) -> Result<(), String> {
+    let (outcome, decoded, warning, silent) = decode_columns(decode);
     conn.execute(
         "UPDATE analysis_jobs
-         SET status = 'failed', last_error = ?2, running_at = NULL
+         SET status = 'failed', last_error = ?2, running_at = NULL,
+             decode_outcome = COALESCE(?3, decode_outcome),
+             analysis_warning = CASE WHEN ?4 THEN ?5 ELSE analysis_warning END,
+             silent = CASE WHEN ?4 THEN ?6 ELSE silent END
          WHERE id = ?1 AND status IN ('running','pending')",
-        params![job_id, error],
+        params![job_id, error, outcome, decoded, warning, silent],
     )
     .map_err(|err| format!("Failed to mark analysis job failed: {err}"))?;
     Ok(())
 }
 
+/// Return how many times a job has been claimed, or `None` when the row is gone.
+pub(crate) fn job_attempts(conn: &Connection, job_id: i64) -> Result<Option<i64>, String> {
+    conn.query_row(
+        "SELECT attempts FROM analysis_jobs WHERE id = ?1",
+        params![job_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|err| format!("Failed to read analysis job attempts: {err}"))
+}
+
+/// Return a failed job to pending, hidden from claims until `retry_at` (epoch seconds).
+pub(crate) fn mark_retry_pending(
+    conn: &Connection,
+    job_id: i64,
+    error: &str,
+    retry_at: i64,
+    decode: Option<DecodeReport>,
+) -> Result<(), String> {
+    let (outcome, decoded, warning, silent) = decode_columns(decode);
+    conn.execute(
+        "UPDATE analysis_jobs
+         SET status = 'pending', last_error = ?2, running_at = NULL, retry_at = ?3,
+             decode_outcome = COALESCE(?4, decode_outcome),
+             analysis_warning = CASE WHEN ?5 THEN ?6 ELSE analysis_warning END,
+             silent = CASE WHEN ?5 THEN ?7 ELSE silent END
+         WHERE id = ?1 AND status IN ('running','pending')",
+        params![job_id, error, retry_at, outcome, decoded, warning, silent],
+    )
+    .map_err(|err| format!("Failed to schedule analysis job retry: {err}"))?;
+    Ok(())
+}
+
+/// Decode outcome, whether to replace the stored warning and silence flag, and their values.
+fn decode_columns(
+    decode: Option<DecodeReport>,
+) -> (Option<&'static str>, bool, Option<&'static str>, bool) {
+    match decode {
+        Some(report) => (
+            Some(report.outcome),
+            report.decoded(),
+            report.warning,
+            report.silent,
+        ),
+        None => (None, false, None, false),
+    }
+}
+
 pub(crate) fn mark_pending(
     conn: &Connection,
     job_id: i64,
@@ -14,8 +14,9 @@ mod tests;
 
 pub(crate) use artifacts::{
     CachedEmbedding, CachedFeatures, cached_embedding_by_hash, cached_features_by_hash,
-    invalidate_analysis_artifacts, update_analysis_metadata, update_sample_duration,
-    update_sample_long_mark,
+    clear_source_analysis, invalidate_analysis_artifacts, quantized_embedding_storage,
+    set_quantized_embedding_storage, update_analysis_metadata,
+    update_sample_duration, update_sample_long_mark,
     upsert_analysis_features, upsert_cached_embedding, upsert_cached_features, upsert_embedding,
 };
 pub(crate) use ann_index::{
@@ -30,20 +31,23 @@ pub(crate) use connection::open_source_db;
 #[cfg(test)]
 pub(crate) use constants::DEFAULT_JOB_TYPE;
 pub(crate) use constants::{
-    ANALYZE_SAMPLE_JOB_TYPE, EMBEDDING_BACKFILL_JOB_TYPE, REBUILD_INDEX_JOB_TYPE,
+    ANALYZE_SAMPLE_JOB_TYPE, DECODE_OUTCOME_DECODED, DECODE_OUTCOME_FAILED,
+    DECODE_OUTCOME_NOT_NEEDED, DECODE_OUTCOME_SKIPPED, EMBEDDING_BACKFILL_JOB_TYPE,
+    REBUILD_INDEX_JOB_TYPE, SAMPLE_RATE_MISMATCH_WARNING,
 };
 pub(crate) use enqueue::{enqueue_jobs, upsert_samples};
 pub(crate) use ids::{build_sample_id, parse_sample_id};
 #[cfg(test)]
 pub(crate) use jobs::claim_next_job;
 pub(crate) use jobs::{
-    SampleAnalysisState, claim_next_jobs, mark_done, mark_failed_with_reason,
-    mark_pending, sample_analysis_states, sample_bpm, sample_content_hash,
-    sample_ids_missing_duration, touch_running_at, update_sample_bpms,
+    SampleAnalysisState, claim_next_jobs, job_attempts, mark_done, mark_failed_with_reason,
+    mark_pending, mark_retry_pending, sample_analysis_states, sample_bpm,
+    sample_content_hash, sample_ids_missing_duration, touch_running_at, update_sample_bpms,
 };
 #[cfg(test)]
 pub(crate) use jobs::update_sample_bpm;
 pub(crate) use progress::{
     current_embedding_backfill_progress, current_progress, current_running_jobs,
+    record_analysis_completed_at,
 };
-pub(crate) use types::{ClaimedJob, SampleMetadata};
+pub(crate) use types::{ClaimedJob, DecodeReport, SampleMetadata};
@@ -1,5 +1,6 @@
 use super::super::types::{AnalysisProgress, RunningJobInfo};
 use super::constants::{ANALYZE_SAMPLE_JOB_TYPE, EMBEDDING_BACKFILL_JOB_TYPE};
+use crate::sample_sources::db::META_LAST_ANALYSIS_COMPLETED_AT;
 use rusqlite::Connection;
 
 pub(crate) fn current_progress(
@@ -14,6 +15,21 @@ pub(crate) fn current_embedding_backfill_progress(
     current_progress_for_job_type(conn, EMBEDDING_BACKFILL_JOB_TYPE, false)
 }
 
+/// Record when the source's analysis queue last drained.
+pub(crate) fn record_analysis_completed_at(
+    conn: &Connection,
+    completed_at: i64,
+) -> Result<(), String> {
+    conn.execute(
+        "INSERT INTO metadata (key, value)
+         VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        rusqlite::params![META_LAST_ANALYSIS_COMPLETED_AT, completed_at.to_string()],
+    )
+    .map_err(|err| format!("Failed to record analysis completion time: {err}"))?;
+    Ok(())
+}
+
 pub(crate) fn current_running_jobs(
     conn: &Connection,
     limit: usize,
@@ -16,6 +16,7 @@ fn conn_with_schema() -> Connection {
             created_at INTEGER NOT NULL,
             running_at INTEGER,
             last_error TEXT,
+            retry_at INTEGER,
             UNIQUE(sample_id, job_type)
         );
         CREATE TABLE samples (
@@ -246,6 +247,39 @@ fn ann_index_dirty_marker_round_trips() {
     assert!(cleared.is_none());
 }
 
+#[test]
+fn quantized_storage_option_stores_int8_embeddings() {
+    let conn = conn_with_schema();
+    let values = vec![0.6_f32, -0.8, 0.0];
+    let blob = crate::analysis::vector::encode_f32_le_blob(&values);
+    let f32_dtype = crate::analysis::similarity::SIMILARITY_DTYPE_F32;
+    set_quantized_embedding_storage(&conn, true).unwrap();
+    let quantize = quantized_embedding_storage(&conn).unwrap();
+    upsert_embedding(&conn, "s::a.wav", "model", 3, f32_dtype, true, &blob, 1, quantize).unwrap();
+    set_quantized_embedding_storage(&conn, false).unwrap();
+    let quantize = quantized_embedding_storage(&conn).unwrap();
+    upsert_embedding(&conn, "s::b.wav", "model", 3, f32_dtype, true, &blob, 1, quantize).unwrap();
+
+    let load = |sample_id: &str| -> (String, Vec<u8>) {
+        conn.query_row(
+            "SELECT dtype, vec FROM embeddings WHERE sample_id = ?1",
+            params![sample_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap()
+    };
+    let (dtype, stored) = load("s::a.wav");
+    assert_eq!(dtype, crate::analysis::similarity::SIMILARITY_DTYPE_I8);
+    assert_eq!(stored.len(), 4 + values.len());
+    let restored = crate::analysis::vector::decode_embedding_blob(&stored, &dtype).unwrap();
+    for (a, b) in values.iter().zip(&restored) {
+        assert!((a - b).abs() < 0.01);
+    }
+    let (dtype, stored) = load("s::b.wav");
+    assert_eq!(dtype, f32_dtype);
+    assert_eq!(stored, blob);
+}
+
 #[test]
 fn enqueue_rebuild_job_dedupes_pending() {
     let conn = conn_with_schema();
@@ -297,7 +331,7 @@ fn mark_done_clears_error_and_updates_status() {
     let job_id: i64 = conn
         .query_row("SELECT id FROM analysis_jobs", [], |row| row.get(0))
         .unwrap();
-    mark_done(&conn, job_id).unwrap();
+    mark_done(&conn, job_id, None).unwrap();
     let (status, last_error): (String, Option<String>) = conn
         .query_row(
             "SELECT status, last_error FROM analysis_jobs WHERE id = ?1",
@@ -321,7 +355,7 @@ fn mark_failed_updates_status_and_error() {
     let job_id: i64 = conn
         .query_row("SELECT id FROM analysis_jobs", [], |row| row.get(0))
         .unwrap();
-    mark_failed_with_reason(&conn, job_id, "boom").unwrap();
+    mark_failed_with_reason(&conn, job_id, "boom", None).unwrap();
     let (status, last_error): (String, Option<String>) = conn
         .query_row(
             "SELECT status, last_error FROM analysis_jobs WHERE id = ?1",
@@ -7,6 +7,26 @@ pub(crate) struct ClaimedJob {
     pub(crate) source_root: std::path::PathBuf,
 }
 
+/// Decode results written together with a job's final status update.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DecodeReport {
+    /// One of the `DECODE_OUTCOME_*` labels.
+    pub(crate) outcome: &'static str,
+    /// Non-fatal warning raised while decoding, or `None` to clear a stale one.
+    pub(crate) warning: Option<&'static str>,
+    /// Whether the decoded audio peaks below the silence threshold.
+    pub(crate) silent: bool,
+    /// Whether decoding failed because the file was briefly unavailable, so the job is retried.
+    pub(crate) transient: bool,
+}
+
+impl DecodeReport {
+    /// Whether audio was decoded, so the stored warning should be replaced.
+    pub(crate) fn decoded(&self) -> bool {
+        self.outcome == super::constants::DECODE_OUTCOME_DECODED
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct SampleMetadata {
     pub(crate) sample_id: String,
@@ -1,4 +1,5 @@
 use super::enqueue_helpers::now_epoch_seconds;
+use super::scan;
 use crate::egui_app::controller::library::analysis_jobs::db;
 use crate::egui_app::controller::library::analysis_jobs::wakeup;
 use crate::egui_app::controller::library::analysis_jobs::types::AnalysisProgress;
@@ -16,6 +17,28 @@ pub(crate) fn enqueue_jobs_for_embedding_backfill(
     enqueue_embedding_backfill(request)
 }
 
+/// Queue embedding-only analysis for every sample in a source.
+///
+/// Samples are registered as usual, but only embedding backfill jobs are queued, so the
+/// full analysis job is skipped and no feature rows are stored.
+pub(crate) fn enqueue_jobs_for_source_embeddings_only(
+    source: &crate::sample_sources::SampleSource,
+) -> Result<(usize, AnalysisProgress), String> {
+    let mut conn = db::open_source_db(&source.root)?;
+    let staged_samples = scan::stage_samples_for_source(source, false)?;
+    if staged_samples.is_empty() {
+        info!(
+            "Embedding-only enqueue skipped: no staged samples (source_id={})",
+            source.id.as_str()
+        );
+        return Ok((0, db::current_progress(&conn)?));
+    }
+    db::upsert_samples(&mut conn, &staged_samples)?;
+    drop(conn);
+    let request = EnqueueEmbeddingBackfillRequest { source };
+    enqueue_embedding_backfill(request)
+}
+
 pub(crate) fn enqueue_jobs_for_embedding_samples(
     source: &crate::sample_sources::SampleSource,
     sample_ids: &[String],
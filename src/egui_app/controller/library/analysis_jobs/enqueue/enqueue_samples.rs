@@ -152,6 +152,23 @@ fn enqueue_source_backfill(
     )
 }
 
+/// Clear all analysis results for a source, then queue a full re-analysis.
+///
+/// Tags and other file metadata are left untouched.
+pub(crate) fn rebuild_source_analysis(
+    source: &crate::sample_sources::SampleSource,
+) -> Result<(usize, AnalysisProgress), String> {
+    let mut conn = db::open_source_db(&source.root)?;
+    db::clear_source_analysis(&mut conn, source.id.as_str())?;
+    crate::analysis::rebuild_ann_index(&conn)?;
+    drop(conn);
+    info!(
+        "Analysis results cleared for rebuild (source_id={})",
+        source.id.as_str()
+    );
+    enqueue_jobs_for_source_backfill_full(source)
+}
+
 struct EnqueueMissingFeaturesRequest<'a> {
     source: &'a crate::sample_sources::SampleSource,
 }
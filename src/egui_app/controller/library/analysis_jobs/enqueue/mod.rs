@@ -7,11 +7,13 @@ mod scan;
 
 pub(crate) use enqueue_embeddings::{
     enqueue_jobs_for_embedding_backfill, enqueue_jobs_for_embedding_samples,
+    enqueue_jobs_for_source_embeddings_only,
 };
 pub(crate) use enqueue_samples::enqueue_jobs_for_source;
 pub(crate) use enqueue_samples::enqueue_jobs_for_source_backfill;
 pub(crate) use enqueue_samples::enqueue_jobs_for_source_backfill_full;
 pub(crate) use enqueue_samples::enqueue_jobs_for_source_missing_features;
+pub(crate) use enqueue_samples::rebuild_source_analysis;
 pub(crate) use enqueue_samples::update_missing_durations_for_source;
 pub(crate) use enqueue_helpers::fast_content_hash;
 
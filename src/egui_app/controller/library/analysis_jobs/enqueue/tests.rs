@@ -2,6 +2,7 @@ use super::enqueue_embeddings::enqueue_jobs_for_embedding_backfill;
 use super::enqueue_samples::{
     enqueue_jobs_for_source, enqueue_jobs_for_source_backfill,
     enqueue_jobs_for_source_backfill_full, enqueue_jobs_for_source_missing_features,
+    rebuild_source_analysis,
 };
 use super::super::wakeup;
 use crate::app_dirs::ConfigBaseGuard;
@@ -234,6 +235,55 @@ fn backfill_full_enqueues_even_when_up_to_date() {
     assert_eq!(second_inserted, 0);
 }
 
+#[test]
+fn rebuild_clears_analysis_but_keeps_tags_and_queues_full_backfill() {
+    let env = TestEnv::new();
+    env.create_files(&["Pack/a.wav", "Pack/b.wav"]);
+    seed_source_db(&env.source, &[("Pack/a.wav", "ha"), ("Pack/b.wav", "hb")]);
+    let source_db = SourceDatabase::open(&env.source.root).unwrap();
+    source_db
+        .set_tag(Path::new("Pack/a.wav"), crate::sample_sources::Rating::KEEP_1)
+        .unwrap();
+
+    let conn = db::open_source_db(&env.source.root).unwrap();
+    clear_analysis_tables(&conn);
+    let version = crate::analysis::version::analysis_version();
+    for (rel, hash) in [("Pack/a.wav", "ha"), ("Pack/b.wav", "hb")] {
+        let sample_id = sample_id(&env.source, rel);
+        insert_sample_row(&conn, &sample_id, hash, Some(version));
+        insert_features_row(&conn, &sample_id);
+        insert_embeddings_row(
+            &conn,
+            &sample_id,
+            crate::analysis::similarity::SIMILARITY_MODEL_ID,
+        );
+    }
+
+    let (inserted, _progress) = rebuild_source_analysis(&env.source).unwrap();
+    assert_eq!(inserted, 2);
+
+    let features: i64 = conn
+        .query_row("SELECT COUNT(*) FROM features", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(features, 0);
+    let embeddings: i64 = conn
+        .query_row("SELECT COUNT(*) FROM embeddings", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(embeddings, 0);
+    let pending: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM analysis_jobs WHERE status = 'pending' AND job_type = ?1",
+            params![db::ANALYZE_SAMPLE_JOB_TYPE],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(pending, 2);
+    assert_eq!(
+        source_db.tag_for_path(Path::new("Pack/a.wav")).unwrap(),
+        Some(crate::sample_sources::Rating::KEEP_1)
+    );
+}
+
 #[test]
 fn hard_sync_skips_failed_jobs_but_force_requeue_restores() {
     let env = TestEnv::new();
@@ -1,6 +1,7 @@
 use super::db;
+use super::types::DecodeOutcomeCounts;
 use rusqlite::{Connection, params};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 pub(crate) fn failed_samples_for_source(
@@ -59,6 +60,117 @@ fn failed_samples_for_source_conn(
     Ok(out)
 }
 
+/// Non-fatal analysis warnings for a source, keyed by relative path.
+pub(crate) fn analysis_warnings_for_source(
+    source: &crate::sample_sources::SampleSource,
+) -> Result<HashMap<PathBuf, String>, String> {
+    let conn = db::open_source_db(&source.root)?;
+    analysis_warnings_for_source_conn(&conn, &source.id)
+}
+
+fn analysis_warnings_for_source_conn(
+    conn: &Connection,
+    source_id: &crate::sample_sources::SourceId,
+) -> Result<HashMap<PathBuf, String>, String> {
+    let prefix = format!("{}::%", source_id.as_str());
+    let mut stmt = conn
+        .prepare(
+            "SELECT sample_id, analysis_warning
+             FROM analysis_jobs
+             WHERE sample_id LIKE ?1 AND job_type = ?2
+               AND status != 'failed' AND analysis_warning IS NOT NULL",
+        )
+        .map_err(|err| format!("Failed to query analysis warnings: {err}"))?;
+    let rows = stmt
+        .query_map(params![prefix, db::ANALYZE_SAMPLE_JOB_TYPE], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|err| format!("Failed to query analysis warnings: {err}"))?;
+    let mut out = HashMap::new();
+    for row in rows {
+        let (sample_id, warning) =
+            row.map_err(|err| format!("Failed to decode analysis warning row: {err}"))?;
+        let (_source, relative_path) = db::parse_sample_id(&sample_id)?;
+        out.insert(relative_path, warning);
+    }
+    Ok(out)
+}
+
+/// Relative paths in a source whose decoded audio analysis flagged as silent.
+pub(crate) fn silent_samples_for_source(
+    source: &crate::sample_sources::SampleSource,
+) -> Result<HashSet<PathBuf>, String> {
+    let conn = db::open_source_db(&source.root)?;
+    silent_samples_for_source_conn(&conn, &source.id)
+}
+
+fn silent_samples_for_source_conn(
+    conn: &Connection,
+    source_id: &crate::sample_sources::SourceId,
+) -> Result<HashSet<PathBuf>, String> {
+    let prefix = format!("{}::%", source_id.as_str());
+    let mut stmt = conn
+        .prepare(
+            "SELECT sample_id
+             FROM analysis_jobs
+             WHERE sample_id LIKE ?1 AND job_type = ?2 AND status != 'failed' AND silent = 1",
+        )
+        .map_err(|err| format!("Failed to query silent samples: {err}"))?;
+    let rows = stmt
+        .query_map(params![prefix, db::ANALYZE_SAMPLE_JOB_TYPE], |row| {
+            row.get::<_, String>(0)
+        })
+        .map_err(|err| format!("Failed to query silent samples: {err}"))?;
+    let mut out = HashSet::new();
+    for row in rows {
+        let sample_id = row.map_err(|err| format!("Failed to decode silent sample row: {err}"))?;
+        let (_source, relative_path) = db::parse_sample_id(&sample_id)?;
+        out.insert(relative_path);
+    }
+    Ok(out)
+}
+
+pub(crate) fn decode_outcome_counts_for_source(
+    source: &crate::sample_sources::SampleSource,
+) -> Result<DecodeOutcomeCounts, String> {
+    let conn = db::open_source_db(&source.root)?;
+    decode_outcome_counts_for_source_conn(&conn, &source.id)
+}
+
+fn decode_outcome_counts_for_source_conn(
+    conn: &Connection,
+    source_id: &crate::sample_sources::SourceId,
+) -> Result<DecodeOutcomeCounts, String> {
+    let prefix = format!("{}::%", source_id.as_str());
+    let mut stmt = conn
+        .prepare(
+            "SELECT decode_outcome, COUNT(*)
+             FROM analysis_jobs
+             WHERE sample_id LIKE ?1 AND job_type = ?2 AND decode_outcome IS NOT NULL
+             GROUP BY decode_outcome",
+        )
+        .map_err(|err| format!("Failed to query decode outcomes: {err}"))?;
+    let rows = stmt
+        .query_map(params![prefix, db::ANALYZE_SAMPLE_JOB_TYPE], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })
+        .map_err(|err| format!("Failed to query decode outcomes: {err}"))?;
+    let mut counts = DecodeOutcomeCounts::default();
+    for row in rows {
+        let (outcome, count) =
+            row.map_err(|err| format!("Failed to decode decode outcome row: {err}"))?;
+        let count = count.max(0) as usize;
+        match outcome.as_str() {
+            db::DECODE_OUTCOME_DECODED => counts.decoded += count,
+            db::DECODE_OUTCOME_SKIPPED => counts.skipped += count,
+            db::DECODE_OUTCOME_FAILED => counts.failed += count,
+            db::DECODE_OUTCOME_NOT_NEEDED => counts.not_needed += count,
+            _ => {}
+        }
+    }
+    Ok(counts)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,4 +238,77 @@ mod tests {
             Some("Analysis failed")
         );
     }
+
+    #[test]
+    fn counts_decode_outcomes_for_source() {
+        let config_dir = tempdir().unwrap();
+        let _guard = ConfigBaseGuard::set(config_dir.path().to_path_buf());
+        let source_root = tempdir().unwrap();
+        let source = crate::sample_sources::SampleSource::new_with_id(
+            crate::sample_sources::SourceId::from_string("s1"),
+            source_root.path().to_path_buf(),
+        );
+        let conn = db::open_source_db(&source.root).unwrap();
+        conn.execute_batch("DELETE FROM analysis_jobs;").unwrap();
+        for (sample_id, status, outcome) in [
+            ("s1::long.wav", "done", Some(db::DECODE_OUTCOME_SKIPPED)),
+            ("s1::broken.wav", "failed", Some(db::DECODE_OUTCOME_FAILED)),
+            ("s1::queued.wav", "pending", None),
+            ("s2::other.wav", "failed", Some(db::DECODE_OUTCOME_FAILED)),
+        ] {
+            conn.execute(
+                "INSERT INTO analysis_jobs
+                    (sample_id, job_type, status, attempts, created_at, decode_outcome)
+                 VALUES (?1, ?2, ?3, 1, 0, ?4)",
+                params![sample_id, db::ANALYZE_SAMPLE_JOB_TYPE, status, outcome],
+            )
+            .unwrap();
+        }
+
+        let counts = decode_outcome_counts_for_source_conn(&conn, &source.id).unwrap();
+        assert_eq!(
+            counts,
+            DecodeOutcomeCounts {
+                decoded: 0,
+                skipped: 1,
+                failed: 1,
+                not_needed: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn silent_flag_is_reported_separately_from_warnings() {
+        let config_dir = tempdir().unwrap();
+        let _guard = ConfigBaseGuard::set(config_dir.path().to_path_buf());
+        let source_root = tempdir().unwrap();
+        let source = crate::sample_sources::SampleSource::new_with_id(
+            crate::sample_sources::SourceId::from_string("s1"),
+            source_root.path().to_path_buf(),
+        );
+        let conn = db::open_source_db(&source.root).unwrap();
+        conn.execute_batch("DELETE FROM analysis_jobs;").unwrap();
+        for (sample_id, warning, silent) in [
+            ("s1::empty.wav", Some(db::SAMPLE_RATE_MISMATCH_WARNING), true),
+            ("s1::kick.wav", Some(db::SAMPLE_RATE_MISMATCH_WARNING), false),
+            ("s1::snare.wav", None, false),
+        ] {
+            conn.execute(
+                "INSERT INTO analysis_jobs
+                    (sample_id, job_type, status, attempts, created_at, analysis_warning, silent)
+                 VALUES (?1, ?2, 'done', 1, 0, ?3, ?4)",
+                params![sample_id, db::ANALYZE_SAMPLE_JOB_TYPE, warning, silent],
+            )
+            .unwrap();
+        }
+
+        let silent = silent_samples_for_source_conn(&conn, &source.id).unwrap();
+        assert_eq!(silent, HashSet::from([PathBuf::from("empty.wav")]));
+        let warnings = analysis_warnings_for_source_conn(&conn, &source.id).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings.get(&PathBuf::from("empty.wav")).map(String::as_str),
+            Some(db::SAMPLE_RATE_MISMATCH_WARNING)
+        );
+    }
 }
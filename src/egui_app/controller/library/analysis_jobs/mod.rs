@@ -9,6 +9,9 @@ mod wakeup;
 
 pub(crate) use db::open_source_db;
 pub(crate) use db::purge_orphaned_samples;
+pub(crate) use db::set_quantized_embedding_storage;
+#[cfg(test)]
+pub(crate) use db::quantized_embedding_storage;
 pub(crate) use db::{
     build_sample_id, parse_sample_id, sample_bpm, update_sample_bpms,
     update_sample_duration, update_sample_long_mark,
@@ -22,10 +25,15 @@ pub(crate) use enqueue::enqueue_jobs_for_source_backfill;
 pub(crate) use enqueue::enqueue_jobs_for_source_backfill_full;
 pub(crate) use enqueue::enqueue_jobs_for_source_missing_features;
 pub(crate) use enqueue::{enqueue_jobs_for_embedding_backfill, enqueue_jobs_for_embedding_samples};
+pub(crate) use enqueue::enqueue_jobs_for_source_embeddings_only;
 pub(crate) use enqueue::fast_content_hash;
-pub(crate) use failures::failed_samples_for_source;
-pub(crate) use pool::AnalysisWorkerPool;
-pub(crate) use types::{AnalysisJobMessage, AnalysisProgress, RunningJobInfo};
+pub(crate) use enqueue::rebuild_source_analysis;
+pub(crate) use failures::{
+    analysis_warnings_for_source, decode_outcome_counts_for_source, failed_samples_for_source,
+    silent_samples_for_source,
+};
+pub(crate) use pool::{AnalysisThroughput, AnalysisWorkerPool};
+pub(crate) use types::{AnalysisJobMessage, AnalysisProgress, DecodeOutcomeCounts, RunningJobInfo};
 
 pub(crate) fn current_progress_for_source(
     source: &crate::sample_sources::SampleSource,
@@ -71,11 +71,46 @@ pub(crate) fn worker_count_with_override(
             }
         }
     }
-    std::thread::available_parallelism()
-        .map(|n| n.get())
-        .unwrap_or(1)
-        .saturating_sub(2)
-        .max(1)
+    default_worker_count(WorkerResources::detect())
+}
+
+/// Memory left for the UI, OS and caches before sizing analysis workers.
+const RESERVED_MEMORY_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+/// Rough peak memory of one analysis worker (decode buffers plus features).
+const MEMORY_PER_WORKER_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Host resources that bound how many analysis workers can run without starving the UI.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct WorkerResources {
+    pub(crate) cpu_count: usize,
+    pub(crate) total_memory_bytes: Option<u64>,
+}
+
+impl WorkerResources {
+    #[cfg_attr(test, allow(dead_code))]
+    pub(crate) fn detect() -> Self {
+        let cpu_count = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let mut system = sysinfo::System::new();
+        system.refresh_memory();
+        let total_memory = system.total_memory();
+        Self {
+            cpu_count,
+            total_memory_bytes: (total_memory > 0).then_some(total_memory),
+        }
+    }
+}
+
+/// Default worker count: CPUs minus two, capped by memory.
+pub(crate) fn default_worker_count(resources: WorkerResources) -> usize {
+    let mut workers = resources.cpu_count.saturating_sub(2).max(1);
+    if let Some(total) = resources.total_memory_bytes {
+        let budget = total.saturating_sub(RESERVED_MEMORY_BYTES);
+        let by_memory = (budget / MEMORY_PER_WORKER_BYTES).max(1) as usize;
+        workers = workers.min(by_memory);
+    }
+    workers
 }
 
 #[cfg_attr(test, allow(dead_code))]
@@ -99,7 +134,11 @@ pub(crate) fn decode_worker_count_with_override(
     std::cmp::min(worker_count.saturating_mul(2).max(2), max_workers)
 }
 
-pub(crate) fn claim_batch_size() -> usize {
+/// Jobs leased per claim round: the settings override, then the env var, then 64.
+pub(crate) fn claim_batch_size_with_override(override_size: u32) -> usize {
+    if override_size >= 1 {
+        return override_size as usize;
+    }
     if let Ok(value) = std::env::var("SEMPAL_ANALYSIS_CLAIM_BATCH") {
         if let Ok(parsed) = value.trim().parse::<usize>() {
             if parsed >= 1 {
@@ -141,19 +180,41 @@ mod tests {
         unsafe {
             std::env::set_var("SEMPAL_ANALYSIS_CLAIM_BATCH", "7");
         }
-        let value = claim_batch_size();
+        let value = claim_batch_size_with_override(0);
         unsafe {
             std::env::remove_var("SEMPAL_ANALYSIS_CLAIM_BATCH");
         }
         assert_eq!(value, 7);
     }
 
+    #[test]
+    fn claim_batch_size_prefers_settings_override() {
+        assert_eq!(claim_batch_size_with_override(12), 12);
+    }
+
+    #[test]
+    fn low_memory_reduces_default_worker_count() {
+        let roomy = WorkerResources {
+            cpu_count: 16,
+            total_memory_bytes: Some(64 * 1024 * 1024 * 1024),
+        };
+        assert_eq!(default_worker_count(roomy), 14);
+
+        let low_memory = WorkerResources {
+            total_memory_bytes: Some(4 * 1024 * 1024 * 1024),
+            ..roomy
+        };
+        let workers = default_worker_count(low_memory);
+        assert!(workers < low_memory.cpu_count);
+        assert_eq!(workers, 4);
+    }
+
     #[test]
     fn claim_batch_size_defaults_when_invalid() {
         unsafe {
             std::env::set_var("SEMPAL_ANALYSIS_CLAIM_BATCH", "0");
         }
-        let value = claim_batch_size();
+        let value = claim_batch_size_with_override(0);
         unsafe {
             std::env::remove_var("SEMPAL_ANALYSIS_CLAIM_BATCH");
         }
@@ -15,6 +15,7 @@ use std::time::{Duration, Instant};
 pub(crate) struct DeferredJobUpdate {
     pub(crate) job: analysis_db::ClaimedJob,
     pub(crate) error: String,
+    pub(crate) decode: Option<analysis_db::DecodeReport>,
 }
 
 pub(crate) fn finalize_immediate_job(
@@ -23,6 +24,7 @@ pub(crate) fn finalize_immediate_job(
     tx: &JobMessageSender,
     job: analysis_db::ClaimedJob,
     outcome: Result<(), String>,
+    decode: Option<analysis_db::DecodeReport>,
     log_jobs: bool,
     progress_cache: &Arc<RwLock<ProgressCache>>,
     progress_wakeup: &ProgressPollerWakeup,
@@ -50,16 +52,17 @@ pub(crate) fn finalize_immediate_job(
             return Some(DeferredJobUpdate {
                 job,
                 error: error_for_open,
+                decode,
             });
         }
     };
     match outcome {
         Ok(()) => {
-            update_job_status_with_retry(|| analysis_db::mark_done(conn, job.id));
+            update_job_status_with_retry(|| analysis_db::mark_done(conn, job.id, decode));
         }
         Err(err) => {
             update_job_status_with_retry(|| {
-                analysis_db::mark_failed_with_reason(conn, job.id, &err)
+                super::lease::fail_or_retry(conn, job.id, &err, decode)
             });
         }
     }
@@ -126,6 +129,7 @@ pub(crate) fn flush_deferred_updates(
             tx,
             deferred.job,
             Err(deferred.error),
+            deferred.decode,
             log_jobs,
             progress_cache,
             progress_wakeup,
@@ -1,8 +1,20 @@
 //! Claim lease helpers for analysis jobs.
 
 use crate::egui_app::controller::library::analysis_jobs::db;
+use crate::egui_app::controller::library::analysis_jobs::stale_running_job_seconds;
 use crate::sample_sources::SourceId;
 use std::collections::HashSet;
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Automatic retries for a failed job before it is marked permanently failed.
+pub(crate) const MAX_TRANSIENT_RETRIES: i64 = 2;
+/// Delay before the first retry; doubles with each further attempt.
+const RETRY_BACKOFF_BASE_SECS: i64 = 5;
+/// Windows `ERROR_SHARING_VIOLATION` and `ERROR_LOCK_VIOLATION`, raised while another
+/// process holds the file open.
+#[cfg(target_os = "windows")]
+const WINDOWS_FILE_IN_USE_ERRORS: [i32; 2] = [32, 33];
 
 /// Returns true when the job is allowed for the current source filter.
 pub(crate) fn job_allowed(
@@ -23,3 +35,84 @@ pub(crate) fn job_allowed(
 pub(crate) fn release_claim(conn: &rusqlite::Connection, job_id: i64) {
     let _ = db::mark_pending(conn, job_id);
 }
+
+/// Backoff before re-claiming a job that failed on `attempt` (1-based).
+///
+/// Capped at half the stale-job window so a retry never waits longer than a
+/// hung worker would be tolerated.
+pub(crate) fn retry_backoff_seconds(attempt: i64, stale_secs: i64) -> i64 {
+    let exponent = attempt.saturating_sub(1).clamp(0, 16) as u32;
+    let cap = (stale_secs / 2).max(RETRY_BACKOFF_BASE_SECS);
+    (RETRY_BACKOFF_BASE_SECS << exponent).min(cap)
+}
+
+/// Whether opening a sample failed for a reason that can clear up on its own.
+///
+/// Missing files and permission errors are permanent, so only locks, timeouts and
+/// interrupted reads are retried.
+pub(crate) fn is_transient_io_error(err: &io::Error) -> bool {
+    #[cfg(target_os = "windows")]
+    if err
+        .raw_os_error()
+        .is_some_and(|code| WINDOWS_FILE_IN_USE_ERRORS.contains(&code))
+    {
+        return true;
+    }
+    matches!(
+        err.kind(),
+        io::ErrorKind::WouldBlock
+            | io::ErrorKind::TimedOut
+            | io::ErrorKind::Interrupted
+            | io::ErrorKind::ResourceBusy
+    )
+}
+
+/// Re-queue a failure whose decode was classified transient, with backoff; other
+/// failures, or ones whose retries are spent, are marked failed.
+pub(crate) fn fail_or_retry(
+    conn: &rusqlite::Connection,
+    job_id: i64,
+    error: &str,
+    decode: Option<db::DecodeReport>,
+) -> Result<(), String> {
+    if !decode.is_some_and(|report| report.transient) {
+        return db::mark_failed_with_reason(conn, job_id, error, decode);
+    }
+    let Some(attempts) = db::job_attempts(conn, job_id)? else {
+        return Ok(());
+    };
+    if attempts > MAX_TRANSIENT_RETRIES {
+        return db::mark_failed_with_reason(conn, job_id, error, decode);
+    }
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or(0);
+    let delay = retry_backoff_seconds(attempts, stale_running_job_seconds());
+    db::mark_retry_pending(conn, job_id, error, now + delay, decode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_and_respects_stale_window() {
+        assert_eq!(retry_backoff_seconds(1, 120), 5);
+        assert_eq!(retry_backoff_seconds(2, 120), 10);
+        assert_eq!(retry_backoff_seconds(3, 120), 20);
+        assert_eq!(retry_backoff_seconds(10, 120), 60);
+        assert_eq!(retry_backoff_seconds(10, 600), 300);
+    }
+
+    #[test]
+    fn only_lock_and_interruption_io_errors_are_transient() {
+        let error = |kind| io::Error::new(kind, "sample.wav");
+        assert!(is_transient_io_error(&error(io::ErrorKind::WouldBlock)));
+        assert!(is_transient_io_error(&error(io::ErrorKind::TimedOut)));
+        assert!(is_transient_io_error(&error(io::ErrorKind::Interrupted)));
+        assert!(!is_transient_io_error(&error(io::ErrorKind::NotFound)));
+        assert!(!is_transient_io_error(&error(io::ErrorKind::PermissionDenied)));
+        assert!(!is_transient_io_error(&error(io::ErrorKind::InvalidData)));
+    }
+}
@@ -12,6 +12,7 @@ use std::sync::{
 use std::thread::JoinHandle;
 use std::time::{Duration, Instant};
 
+use super::metrics::ThroughputMetrics;
 use super::progress_cache::ProgressCache;
 use crate::egui_app::controller::library::analysis_jobs::wakeup::ClaimWakeup;
 
@@ -47,16 +48,20 @@ pub(crate) fn spawn_decoder_worker(
     shutdown: Arc<AtomicBool>,
     pause_claiming: Arc<AtomicBool>,
     allowed_source_ids: Arc<RwLock<Option<HashSet<crate::sample_sources::SourceId>>>>,
+    priority_source_id: Arc<RwLock<Option<crate::sample_sources::SourceId>>>,
     max_duration_bits: Arc<AtomicU32>,
+    uncapped_sample_ids: Arc<RwLock<HashSet<String>>>,
     analysis_sample_rate: Arc<AtomicU32>,
     decode_queue_target: usize,
     claim_wakeup: Arc<ClaimWakeup>,
     reset_done: Arc<Mutex<HashSet<std::path::PathBuf>>>,
+    claim_batch_override: Arc<AtomicU32>,
+    metrics: Arc<ThroughputMetrics>,
 ) -> JoinHandle<()> {
     std::thread::spawn(move || {
         lower_worker_priority();
         let log_jobs = logging::analysis_log_enabled();
-        let mut selector = selection::ClaimSelector::new(reset_done);
+        let mut selector = selection::ClaimSelector::new(reset_done, claim_batch_override);
         let decode_queue_target = decode_queue_target.max(1);
         let mut connections: HashMap<std::path::PathBuf, Connection> = HashMap::new();
         let mut wake_counter = 0u64;
@@ -80,7 +85,11 @@ pub(crate) fn spawn_decoder_worker(
                 .read()
                 .ok()
                 .and_then(|guard| guard.clone());
-            let job = match selector.select_next(allowed.as_ref()) {
+            let priority = priority_source_id
+                .read()
+                .ok()
+                .and_then(|guard| guard.clone());
+            let job = match selector.select_next(allowed.as_ref(), priority.as_ref()) {
                 selection::ClaimSelection::Job(job) => job,
                 selection::ClaimSelection::NoSources => {
                     let _ = claim_wakeup.wait_for(
@@ -123,7 +132,17 @@ pub(crate) fn spawn_decoder_worker(
                 None
             };
             let outcome = if job.job_type == analysis_db::ANALYZE_SAMPLE_JOB_TYPE {
-                decode_analysis_job(&job, &max_duration_bits, &analysis_sample_rate)
+                let decode_started = Instant::now();
+                let outcome = decode_analysis_job(
+                    &job,
+                    &max_duration_bits,
+                    &uncapped_sample_ids,
+                    &analysis_sample_rate,
+                );
+                if matches!(outcome, DecodeOutcome::Decoded(_)) {
+                    metrics.record_decode(decode_started.elapsed());
+                }
+                outcome
             } else {
                 DecodeOutcome::NotNeeded
             };
@@ -139,7 +158,7 @@ pub(crate) fn spawn_decoder_worker(
                     DecodeOutcome::Skipped { .. } => {
                         eprintln!("analysis decode skipped: {}", job.sample_id);
                     }
-                    DecodeOutcome::Failed(err) => {
+                    DecodeOutcome::Failed(err) | DecodeOutcome::Unavailable(err) => {
                         eprintln!("analysis decode failed: {} ({})", job.sample_id, err);
                     }
                     DecodeOutcome::NotNeeded => {
@@ -171,10 +190,12 @@ pub(crate) fn spawn_compute_worker(
     use_cache: Arc<AtomicBool>,
     allowed_source_ids: Arc<RwLock<Option<HashSet<crate::sample_sources::SourceId>>>>,
     max_duration_bits: Arc<AtomicU32>,
+    uncapped_sample_ids: Arc<RwLock<HashSet<String>>>,
     analysis_sample_rate: Arc<AtomicU32>,
     analysis_version_override: Arc<std::sync::RwLock<Option<String>>>,
     progress_cache: Arc<RwLock<ProgressCache>>,
     progress_wakeup: Arc<super::job_progress::ProgressPollerWakeup>,
+    metrics: Arc<ThroughputMetrics>,
 ) -> JoinHandle<()> {
     std::thread::spawn(move || {
         lower_worker_priority();
@@ -185,10 +206,20 @@ pub(crate) fn spawn_compute_worker(
         let mut deferred_updates: Vec<db::DeferredJobUpdate> = Vec::new();
         let embedding_batch_max = crate::analysis::similarity::SIMILARITY_BATCH_MAX;
         loop {
-            if shutdown.load(Ordering::Relaxed) {
-                break;
-            }
-            if cancel.load(Ordering::Relaxed) {
+            if shutdown.load(Ordering::Relaxed) || cancel.load(Ordering::Relaxed) {
+                // Finalize work that already completed so a cancel or shutdown never drops it.
+                db::flush_deferred_updates(
+                    &mut connections,
+                    &decode_queue,
+                    &tx,
+                    &progress_cache,
+                    &progress_wakeup,
+                    &mut deferred_updates,
+                    log_jobs,
+                );
+                if shutdown.load(Ordering::Relaxed) {
+                    break;
+                }
                 std::thread::sleep(Duration::from_millis(50));
                 continue;
             }
@@ -213,13 +244,15 @@ pub(crate) fn spawn_compute_worker(
             if log_queue && last_queue_log.elapsed() >= Duration::from_secs(2) {
                 last_queue_log = Instant::now();
                 eprintln!(
-                    "analysis queue: decoded={}, max={}, batch={}, wait_ms={}",
+                    "analysis queue: decoded={}, max={}, batch={}, wait_ms={}, {}",
                     decode_queue.len(),
                     decode_queue.max_size(),
                     batch.len(),
-                    wait_ms
+                    wait_ms,
+                    metrics.snapshot().summary()
                 );
             }
+            let compute_started = Instant::now();
             let max_analysis_duration_seconds =
                 f32::from_bits(max_duration_bits.load(Ordering::Relaxed));
             let analysis_sample_rate = analysis_sample_rate.load(Ordering::Relaxed).max(1);
@@ -237,6 +270,7 @@ pub(crate) fn spawn_compute_worker(
                 )>,
             > = HashMap::new();
             let mut immediate_jobs: Vec<(analysis_db::ClaimedJob, Result<(), String>)> = Vec::new();
+            let mut decode_reports: HashMap<i64, analysis_db::DecodeReport> = HashMap::new();
 
             for work in batch {
                 let allowed = allowed_source_ids
@@ -264,6 +298,7 @@ pub(crate) fn spawn_compute_worker(
                         work.job.sample_id, work.job.job_type
                     );
                 }
+                decode_reports.insert(work.job.id, decode_report(&work.outcome));
                 let job_fallback = work.job.clone();
                 let mut batch_job: Option<(
                     analysis_db::ClaimedJob,
@@ -303,7 +338,7 @@ pub(crate) fn spawn_compute_worker(
                                 immediate_job = Some((work.job, res));
                                 Ok(())
                             }
-                            DecodeOutcome::Failed(err) => {
+                            DecodeOutcome::Failed(err) | DecodeOutcome::Unavailable(err) => {
                                 immediate_job = Some((work.job, Err(err)));
                                 Ok(())
                             }
@@ -355,6 +390,15 @@ pub(crate) fn spawn_compute_worker(
                         continue;
                     }
                 };
+                let quantize_embeddings = match analysis_db::quantized_embedding_storage(conn) {
+                    Ok(enabled) => enabled,
+                    Err(err) => {
+                        for (job, _) in jobs {
+                            immediate_jobs.push((job, Err(err.clone())));
+                        }
+                        continue;
+                    }
+                };
                 let jobs_for_failure: Vec<analysis_db::ClaimedJob> =
                     jobs.iter().map(|(job, _)| job.clone()).collect();
                 let analysis_context = super::job_execution::AnalysisContext {
@@ -362,6 +406,7 @@ pub(crate) fn spawn_compute_worker(
                     max_analysis_duration_seconds,
                     analysis_sample_rate,
                     analysis_version: analysis_version.as_str(),
+                    quantize_embeddings,
                 };
                 let batch_outcomes = catch_unwind(AssertUnwindSafe(|| {
                     run_analysis_jobs_with_decoded_batch(conn, jobs, &analysis_context)
@@ -376,14 +421,18 @@ pub(crate) fn spawn_compute_worker(
                 });
                 immediate_jobs.extend(batch_outcomes);
             }
+            metrics.record_compute(immediate_jobs.len(), compute_started.elapsed());
+            release_uncapped_samples(&uncapped_sample_ids, &immediate_jobs);
 
             for (job, outcome) in immediate_jobs {
+                let job_id = job.id;
                 if let Some(deferred) = db::finalize_immediate_job(
                     &mut connections,
                     &decode_queue,
                     &tx,
                     job,
                     outcome,
+                    decode_reports.remove(&job_id),
                     log_jobs,
                     &progress_cache,
                     &progress_wakeup,
@@ -409,9 +458,44 @@ pub(crate) fn spawn_compute_worker(
     })
 }
 
+/// Drop per-sample duration cap overrides once their jobs have run.
+fn release_uncapped_samples(
+    uncapped_sample_ids: &RwLock<HashSet<String>>,
+    finished: &[(analysis_db::ClaimedJob, Result<(), String>)],
+) {
+    if uncapped_sample_ids.read().is_ok_and(|ids| ids.is_empty()) {
+        return;
+    }
+    if let Ok(mut ids) = uncapped_sample_ids.write() {
+        for (job, _) in finished {
+            ids.remove(&job.sample_id);
+        }
+    }
+}
+
+/// Decode outcome and warnings stored with the job's final status instead of separate writes.
+fn decode_report(outcome: &DecodeOutcome) -> analysis_db::DecodeReport {
+    let (warning, silent) = match outcome {
+        DecodeOutcome::Decoded(audio) => (
+            audio
+                .sample_rate_mismatch
+                .then_some(analysis_db::SAMPLE_RATE_MISMATCH_WARNING),
+            audio.silent,
+        ),
+        _ => (None, false),
+    };
+    analysis_db::DecodeReport {
+        outcome: outcome.label(),
+        warning,
+        silent,
+        transient: matches!(outcome, DecodeOutcome::Unavailable(_)),
+    }
+}
+
 fn decode_analysis_job(
     job: &analysis_db::ClaimedJob,
     max_duration_bits: &AtomicU32,
+    uncapped_sample_ids: &RwLock<HashSet<String>>,
     analysis_sample_rate: &AtomicU32,
 ) -> DecodeOutcome {
     let (_source_id, relative_path) = match analysis_db::parse_sample_id(&job.sample_id) {
@@ -421,7 +505,13 @@ fn decode_analysis_job(
     let absolute = job.source_root.join(&relative_path);
     let max_analysis_duration_seconds = f32::from_bits(max_duration_bits.load(Ordering::Relaxed));
     let sample_rate = analysis_sample_rate.load(Ordering::Relaxed).max(1);
-    if max_analysis_duration_seconds.is_finite() && max_analysis_duration_seconds > 0.0 {
+    let uncapped = uncapped_sample_ids
+        .read()
+        .is_ok_and(|ids| ids.contains(&job.sample_id));
+    if !uncapped
+        && max_analysis_duration_seconds.is_finite()
+        && max_analysis_duration_seconds > 0.0
+    {
         if let Ok(probe) = crate::analysis::audio::probe_metadata(&absolute) {
             if let Some(duration_seconds) = probe.duration_seconds {
                 if duration_seconds > max_analysis_duration_seconds {
@@ -438,7 +528,11 @@ fn decode_analysis_job(
     }
     match crate::analysis::audio::decode_for_analysis_with_rate(&absolute, sample_rate) {
         Ok(decoded) => DecodeOutcome::Decoded(decoded),
-        Err(err) => DecodeOutcome::Failed(err),
+        // The decoder reports errors as text, so reopen the file to classify the failure.
+        Err(err) => match std::fs::File::open(&absolute) {
+            Err(io_err) if lease::is_transient_io_error(&io_err) => DecodeOutcome::Unavailable(err),
+            _ => DecodeOutcome::Failed(err),
+        },
     }
 }
 
@@ -195,9 +195,25 @@ pub(crate) enum DecodeOutcome {
         sample_rate: u32,
     },
     Failed(String),
+    /// Decoding failed because the file was locked or a read was interrupted.
+    Unavailable(String),
     NotNeeded,
 }
 
+impl DecodeOutcome {
+    /// Stable label persisted in `analysis_jobs.decode_outcome`.
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            DecodeOutcome::Decoded(_) => db::DECODE_OUTCOME_DECODED,
+            DecodeOutcome::Skipped { .. } => db::DECODE_OUTCOME_SKIPPED,
+            DecodeOutcome::Failed(_) | DecodeOutcome::Unavailable(_) => {
+                db::DECODE_OUTCOME_FAILED
+            }
+            DecodeOutcome::NotNeeded => db::DECODE_OUTCOME_NOT_NEEDED,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,11 @@
 //! Claim selection helpers for analysis jobs.
 
-use super::claim::{SourceClaimDb, claim_batch_size, refresh_sources};
+use super::claim::{SourceClaimDb, claim_batch_size_with_override, refresh_sources};
 use crate::egui_app::controller::library::analysis_jobs::db;
 use crate::sample_sources::SourceId;
 use std::collections::{HashSet, VecDeque};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
@@ -24,20 +25,23 @@ pub(crate) struct ClaimSelector {
     last_refresh: Instant,
     next_source: usize,
     local_queue: VecDeque<db::ClaimedJob>,
-    claim_batch: usize,
+    claim_batch_override: Arc<AtomicU32>,
     reset_done: Arc<Mutex<HashSet<PathBuf>>>,
     last_source_count: usize,
 }
 
 impl ClaimSelector {
     /// Creates a new claim selector for decoding workers.
-    pub(crate) fn new(reset_done: Arc<Mutex<HashSet<PathBuf>>>) -> Self {
+    pub(crate) fn new(
+        reset_done: Arc<Mutex<HashSet<PathBuf>>>,
+        claim_batch_override: Arc<AtomicU32>,
+    ) -> Self {
         Self {
             sources: Vec::new(),
             last_refresh: Instant::now() - super::claim::SOURCE_REFRESH_INTERVAL,
             next_source: 0,
             local_queue: VecDeque::new(),
-            claim_batch: claim_batch_size(),
+            claim_batch_override,
             reset_done,
             last_source_count: 0,
         }
@@ -55,21 +59,29 @@ impl ClaimSelector {
             last_refresh: Instant::now(),
             next_source: 0,
             local_queue: VecDeque::new(),
-            claim_batch: claim_batch.max(1),
+            claim_batch_override: Arc::new(AtomicU32::new(claim_batch.max(1) as u32)),
             reset_done,
         }
     }
 
+    #[cfg(test)]
+    pub(crate) fn claim_batch_override_for_tests(&self) -> Arc<AtomicU32> {
+        self.claim_batch_override.clone()
+    }
+
     /// Selects the next job if one is available.
+    ///
+    /// Jobs from `priority_source_id` are claimed before the round-robin over other sources.
     pub(crate) fn select_next(
         &mut self,
         allowed_source_ids: Option<&HashSet<SourceId>>,
+        priority_source_id: Option<&SourceId>,
     ) -> ClaimSelection {
         self.refresh_sources_if_needed(allowed_source_ids);
         if self.sources.is_empty() {
             return ClaimSelection::NoSources;
         }
-        if self.local_queue.is_empty() && !self.fill_local_queue() {
+        if self.local_queue.is_empty() && !self.fill_local_queue(priority_source_id) {
             return ClaimSelection::Idle;
         }
         self.pop_local()
@@ -91,28 +103,40 @@ impl ClaimSelector {
         }
     }
 
-    fn fill_local_queue(&mut self) -> bool {
+    fn fill_local_queue(&mut self, priority_source_id: Option<&SourceId>) -> bool {
+        if let Some(priority) = priority_source_id
+            && let Some(idx) = self
+                .sources
+                .iter()
+                .position(|source| &source.source.id == priority)
+            && self.claim_from(idx)
+        {
+            return true;
+        }
         let source_count = self.sources.len();
         for _ in 0..source_count {
             let idx = self.next_source % source_count;
             self.next_source = self.next_source.wrapping_add(1);
-            let source = &mut self.sources[idx];
-            let jobs = match db::claim_next_jobs(
-                &mut source.conn,
-                &source.source.root,
-                self.claim_batch,
-            ) {
-                Ok(jobs) => jobs,
-                Err(_) => continue,
-            };
-            if !jobs.is_empty() {
-                self.local_queue.extend(jobs);
+            if self.claim_from(idx) {
                 return true;
             }
         }
         false
     }
 
+    fn claim_from(&mut self, idx: usize) -> bool {
+        let claim_batch =
+            claim_batch_size_with_override(self.claim_batch_override.load(Ordering::Relaxed));
+        let source = &mut self.sources[idx];
+        match db::claim_next_jobs(&mut source.conn, &source.source.root, claim_batch) {
+            Ok(jobs) if !jobs.is_empty() => {
+                self.local_queue.extend(jobs);
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn pop_local(&mut self) -> ClaimSelection {
         match self.local_queue.pop_front() {
             Some(job) => ClaimSelection::Job(job),
@@ -60,11 +60,11 @@ fn claim_selection_orders_sources_round_robin() {
         1,
         reset_done,
     );
-    let first = match selector.select_next(None) {
+    let first = match selector.select_next(None, None) {
         selection::ClaimSelection::Job(job) => job,
         _ => panic!("expected a job from first source"),
     };
-    let second = match selector.select_next(None) {
+    let second = match selector.select_next(None, None) {
         selection::ClaimSelection::Job(job) => job,
         _ => panic!("expected a job from second source"),
     };
@@ -73,6 +73,114 @@ fn claim_selection_orders_sources_round_robin() {
     assert!(second.sample_id.ends_with("b.wav"));
 }
 
+#[test]
+fn larger_claim_batch_leases_more_jobs_per_round() {
+    let dir = TempDir::new().unwrap();
+    let source = SampleSource::new(dir.path().to_path_buf());
+    let conn = analysis_db::open_source_db(&source.root).unwrap();
+    for index in 0..8 {
+        let name = format!("s{index}.wav");
+        conn.execute(
+            "INSERT INTO analysis_jobs (sample_id, source_id, relative_path, job_type, status, attempts, created_at)
+             VALUES (?1, ?2, ?3, ?4, 'pending', 0, 0)",
+            rusqlite::params![
+                format!("{}::{name}", source.id),
+                source.id.to_string(),
+                name,
+                analysis_db::ANALYZE_SAMPLE_JOB_TYPE
+            ],
+        )
+        .unwrap();
+    }
+    let check_conn = analysis_db::open_source_db(&source.root).unwrap();
+    let running = || -> i64 {
+        check_conn
+            .query_row(
+                "SELECT COUNT(*) FROM analysis_jobs WHERE status = 'running'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap()
+    };
+    let reset_done = Arc::new(Mutex::new(HashSet::new()));
+    let mut selector = selection::ClaimSelector::with_sources_for_tests(
+        vec![super::claim::SourceClaimDb { source, conn }],
+        1,
+        reset_done,
+    );
+
+    assert!(matches!(
+        selector.select_next(None, None),
+        selection::ClaimSelection::Job(_)
+    ));
+    assert_eq!(running(), 1);
+
+    selector
+        .claim_batch_override_for_tests()
+        .store(4, std::sync::atomic::Ordering::Relaxed);
+    assert!(matches!(
+        selector.select_next(None, None),
+        selection::ClaimSelection::Job(_)
+    ));
+    assert_eq!(running(), 5);
+}
+
+#[test]
+fn claim_selection_prefers_priority_source() {
+    let dir_a = TempDir::new().unwrap();
+    let dir_b = TempDir::new().unwrap();
+    let source_a = SampleSource::new(dir_a.path().to_path_buf());
+    let source_b = SampleSource::new(dir_b.path().to_path_buf());
+    let conn_a = analysis_db::open_source_db(&source_a.root).unwrap();
+    let conn_b = analysis_db::open_source_db(&source_b.root).unwrap();
+    for (conn, source, names) in [
+        (&conn_a, &source_a, ["a1.wav", "a2.wav"]),
+        (&conn_b, &source_b, ["b1.wav", "b2.wav"]),
+    ] {
+        for name in names {
+            conn.execute(
+                "INSERT INTO analysis_jobs (sample_id, source_id, relative_path, job_type, status, attempts, created_at)
+                 VALUES (?1, ?2, ?3, ?4, 'pending', 0, 0)",
+                rusqlite::params![
+                    format!("{}::{name}", source.id),
+                    source.id.to_string(),
+                    name,
+                    analysis_db::ANALYZE_SAMPLE_JOB_TYPE
+                ],
+            )
+            .unwrap();
+        }
+    }
+    let priority = source_b.id.clone();
+    let reset_done = Arc::new(Mutex::new(HashSet::new()));
+    let mut selector = selection::ClaimSelector::with_sources_for_tests(
+        vec![
+            super::claim::SourceClaimDb {
+                source: source_a,
+                conn: conn_a,
+            },
+            super::claim::SourceClaimDb {
+                source: source_b,
+                conn: conn_b,
+            },
+        ],
+        1,
+        reset_done,
+    );
+    let mut claimed = Vec::new();
+    for _ in 0..4 {
+        match selector.select_next(None, Some(&priority)) {
+            selection::ClaimSelection::Job(job) => claimed.push(job.sample_id),
+            _ => panic!("expected a pending job"),
+        }
+    }
+
+    assert!(claimed[0].contains("::b"));
+    assert!(claimed[1].contains("::b"));
+    assert!(claimed[2].contains("::a"));
+    assert!(claimed[3].contains("::a"));
+}
+
 #[test]
 fn clears_inflight_when_db_open_fails() {
     let file = NamedTempFile::new().unwrap();
@@ -98,6 +206,7 @@ fn clears_inflight_when_db_open_fails() {
         &tx,
         job,
         Err("failed".to_string()),
+        None,
         false,
         &progress_cache,
         &progress_wakeup,
@@ -170,12 +279,13 @@ fn mid_loop_db_open_failure_clears_inflight_and_marks_failed() {
     let conn = analysis_db::open_source_db(&source_root).unwrap();
     conn.execute(
         "INSERT INTO analysis_jobs (sample_id, source_id, relative_path, job_type, status, attempts, created_at, running_at)
-         VALUES (?1, ?2, ?3, ?4, 'running', 1, 0, 0)",
+         VALUES (?1, ?2, ?3, ?4, 'running', ?5, 0, 0)",
         rusqlite::params![
             "source::a.wav",
             "source",
             "a.wav",
-            analysis_db::ANALYZE_SAMPLE_JOB_TYPE
+            analysis_db::ANALYZE_SAMPLE_JOB_TYPE,
+            lease::MAX_TRANSIENT_RETRIES + 1
         ],
     )
     .unwrap();
@@ -210,6 +320,7 @@ fn mid_loop_db_open_failure_clears_inflight_and_marks_failed() {
         &tx,
         job.clone(),
         Err("Failed to open source DB".to_string()),
+        None,
         false,
         &progress_cache,
         &progress_wakeup,
@@ -240,3 +351,327 @@ fn mid_loop_db_open_failure_clears_inflight_and_marks_failed() {
     assert_eq!(status, "failed");
     assert_eq!(last_error.as_deref(), Some("Failed to open source DB"));
 }
+
+#[test]
+fn deferred_update_writes_decode_columns_with_status() {
+    let dir = TempDir::new().unwrap();
+    let mut conn = analysis_db::open_source_db(dir.path()).unwrap();
+    insert_pending_job(&conn, "source::pitched.wav");
+    let job = analysis_db::claim_next_job(&mut conn, dir.path()).unwrap().unwrap();
+    let queue = DecodedQueue::new(4);
+    let (tx, _rx) = mpsc::sync_channel::<JobMessage>(4);
+    let tx = JobMessageSender::new(tx);
+    let progress_cache = Arc::new(RwLock::new(ProgressCache::default()));
+    let progress_wakeup = ProgressPollerWakeup::new();
+    let mut connections = HashMap::new();
+    let mut deferred_updates = vec![db::DeferredJobUpdate {
+        job,
+        error: "Unsupported sample format".to_string(),
+        decode: Some(analysis_db::DecodeReport {
+            outcome: analysis_db::DECODE_OUTCOME_DECODED,
+            warning: Some(analysis_db::SAMPLE_RATE_MISMATCH_WARNING),
+            silent: true,
+            transient: false,
+        }),
+    }];
+
+    db::flush_deferred_updates(
+        &mut connections,
+        &queue,
+        &tx,
+        &progress_cache,
+        &progress_wakeup,
+        &mut deferred_updates,
+        false,
+    );
+
+    assert!(deferred_updates.is_empty());
+    let (status, outcome, warning, silent): (String, Option<String>, Option<String>, bool) = conn
+        .query_row(
+            "SELECT status, decode_outcome, analysis_warning, silent FROM analysis_jobs
+             WHERE sample_id = ?1",
+            rusqlite::params!["source::pitched.wav"],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .unwrap();
+    assert_eq!(status, "failed");
+    assert_eq!(outcome.as_deref(), Some(analysis_db::DECODE_OUTCOME_DECODED));
+    assert_eq!(warning.as_deref(), Some(analysis_db::SAMPLE_RATE_MISMATCH_WARNING));
+    assert!(silent);
+}
+
+fn insert_pending_job(conn: &rusqlite::Connection, sample_id: &str) {
+    conn.execute(
+        "INSERT INTO analysis_jobs (sample_id, source_id, relative_path, job_type, status, attempts, created_at)
+         VALUES (?1, 'source', ?2, ?3, 'pending', 0, 0)",
+        rusqlite::params![sample_id, sample_id, analysis_db::ANALYZE_SAMPLE_JOB_TYPE],
+    )
+    .unwrap();
+}
+
+fn job_status(conn: &rusqlite::Connection, sample_id: &str) -> String {
+    conn.query_row(
+        "SELECT status FROM analysis_jobs WHERE sample_id = ?1",
+        rusqlite::params![sample_id],
+        |row| row.get(0),
+    )
+    .unwrap()
+}
+
+/// Decode report for a sample that was locked by another process.
+fn unavailable_decode() -> Option<analysis_db::DecodeReport> {
+    Some(analysis_db::DecodeReport {
+        outcome: analysis_db::DECODE_OUTCOME_FAILED,
+        warning: None,
+        silent: false,
+        transient: true,
+    })
+}
+
+/// Make delayed retries claimable now instead of waiting out the backoff.
+fn skip_retry_backoff(conn: &rusqlite::Connection) {
+    conn.execute(
+        "UPDATE analysis_jobs SET retry_at = NULL WHERE status = 'pending'",
+        [],
+    )
+    .unwrap();
+}
+
+#[test]
+fn transient_failure_is_retried_until_success() {
+    let dir = TempDir::new().unwrap();
+    let mut conn = analysis_db::open_source_db(dir.path()).unwrap();
+    insert_pending_job(&conn, "source::flaky.wav");
+
+    let job = analysis_db::claim_next_job(&mut conn, dir.path()).unwrap().unwrap();
+    lease::fail_or_retry(&conn, job.id, "file locked", unavailable_decode()).unwrap();
+    assert_eq!(job_status(&conn, "source::flaky.wav"), "pending");
+    assert!(
+        analysis_db::claim_next_job(&mut conn, dir.path()).unwrap().is_none(),
+        "retry should wait for its backoff"
+    );
+
+    skip_retry_backoff(&conn);
+    let job = analysis_db::claim_next_job(&mut conn, dir.path()).unwrap().unwrap();
+    analysis_db::mark_done(&conn, job.id, None).unwrap();
+    assert_eq!(job_status(&conn, "source::flaky.wav"), "done");
+}
+
+#[test]
+fn job_failing_past_retry_budget_is_marked_failed() {
+    let dir = TempDir::new().unwrap();
+    let mut conn = analysis_db::open_source_db(dir.path()).unwrap();
+    insert_pending_job(&conn, "source::broken.wav");
+
+    for _ in 0..=lease::MAX_TRANSIENT_RETRIES {
+        skip_retry_backoff(&conn);
+        let job = analysis_db::claim_next_job(&mut conn, dir.path()).unwrap().unwrap();
+        lease::fail_or_retry(&conn, job.id, "file locked", unavailable_decode()).unwrap();
+    }
+    assert_eq!(job_status(&conn, "source::broken.wav"), "failed");
+    skip_retry_backoff(&conn);
+    assert!(analysis_db::claim_next_job(&mut conn, dir.path()).unwrap().is_none());
+}
+
+#[test]
+fn permanent_failure_is_marked_failed_without_retry() {
+    let dir = TempDir::new().unwrap();
+    let mut conn = analysis_db::open_source_db(dir.path()).unwrap();
+    insert_pending_job(&conn, "source::corrupt.wav");
+
+    let job = analysis_db::claim_next_job(&mut conn, dir.path()).unwrap().unwrap();
+    lease::fail_or_retry(&conn, job.id, "Unsupported sample format", None).unwrap();
+    assert_eq!(job_status(&conn, "source::corrupt.wav"), "failed");
+}
+
+#[test]
+fn missing_file_decode_is_not_retried() {
+    let dir = TempDir::new().unwrap();
+    let mut conn = analysis_db::open_source_db(dir.path()).unwrap();
+    insert_pending_job(&conn, "source::deleted.wav");
+
+    let job = analysis_db::claim_next_job(&mut conn, dir.path()).unwrap().unwrap();
+    let outcome = decode_analysis_job(
+        &job,
+        &AtomicU32::new(0f32.to_bits()),
+        &RwLock::new(HashSet::new()),
+        &AtomicU32::new(crate::analysis::audio::ANALYSIS_SAMPLE_RATE),
+    );
+    let DecodeOutcome::Failed(err) = &outcome else {
+        panic!("a missing file should be a permanent decode failure");
+    };
+    lease::fail_or_retry(&conn, job.id, err, Some(decode_report(&outcome))).unwrap();
+    assert_eq!(job_status(&conn, "source::deleted.wav"), "failed");
+}
+
+#[test]
+fn retry_keeps_created_at_and_released_claims_keep_attempts() {
+    let dir = TempDir::new().unwrap();
+    let mut conn = analysis_db::open_source_db(dir.path()).unwrap();
+    insert_pending_job(&conn, "source::queued.wav");
+
+    let job = analysis_db::claim_next_job(&mut conn, dir.path()).unwrap().unwrap();
+    lease::release_claim(&conn, job.id);
+    let job = analysis_db::claim_next_job(&mut conn, dir.path()).unwrap().unwrap();
+    lease::fail_or_retry(&conn, job.id, "file locked", unavailable_decode()).unwrap();
+
+    let (created_at, attempts, retry_at): (i64, i64, Option<i64>) = conn
+        .query_row(
+            "SELECT created_at, attempts, retry_at FROM analysis_jobs WHERE id = ?1",
+            rusqlite::params![job.id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(created_at, 0);
+    assert_eq!(attempts, 2);
+    assert!(retry_at.is_some_and(|at| at > 0));
+}
+
+#[test]
+fn canceling_after_batch_keeps_computed_results() {
+    let dir = TempDir::new().unwrap();
+    let source_root = dir.path().to_path_buf();
+    let conn = analysis_db::open_source_db(&source_root).unwrap();
+    conn.execute(
+        "INSERT INTO samples (sample_id, content_hash, size, mtime_ns, duration_seconds, sr_used, analysis_version)
+         VALUES (?1, ?2, 1, 1, NULL, NULL, NULL)",
+        rusqlite::params!["source::a.wav", "hash-a"],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO analysis_jobs (sample_id, source_id, relative_path, job_type, status, attempts, created_at, running_at)
+         VALUES (?1, ?2, ?3, ?4, 'running', 1, 0, 0)",
+        rusqlite::params![
+            "source::a.wav",
+            "source",
+            "a.wav",
+            analysis_db::ANALYZE_SAMPLE_JOB_TYPE
+        ],
+    )
+    .unwrap();
+    let job_id: i64 = conn
+        .query_row(
+            "SELECT id FROM analysis_jobs WHERE sample_id = ?1",
+            rusqlite::params!["source::a.wav"],
+            |row| row.get(0),
+        )
+        .unwrap();
+    let job = analysis_db::ClaimedJob {
+        id: job_id,
+        sample_id: "source::a.wav".to_string(),
+        content_hash: Some("hash-a".to_string()),
+        job_type: analysis_db::ANALYZE_SAMPLE_JOB_TYPE.to_string(),
+        source_root: source_root.clone(),
+    };
+    let tone: Vec<f32> = (0..16_000)
+        .map(|i| (i as f32 * 440.0 * std::f32::consts::TAU / 16_000.0).sin() * 0.5)
+        .collect();
+    let decoded = crate::analysis::audio::prepare_mono_for_analysis(tone, 16_000);
+    let queue = Arc::new(DecodedQueue::new(4));
+    let cancel = Arc::new(AtomicBool::new(false));
+    let shutdown = Arc::new(AtomicBool::new(false));
+    assert!(queue.try_mark_inflight(job.id));
+    assert!(queue.push(
+        DecodedWork {
+            job,
+            outcome: DecodeOutcome::Decoded(decoded),
+        },
+        shutdown.as_ref(),
+    ));
+    // The worker reads this set between computing a batch and finalizing its jobs, so holding
+    // the write lock parks it right after the features are written.
+    let uncapped_sample_ids = Arc::new(RwLock::new(HashSet::new()));
+    let finalize_gate = uncapped_sample_ids.write().unwrap();
+    let (tx, _rx) = mpsc::sync_channel::<JobMessage>(16);
+    let handle = spawn_compute_worker(
+        0,
+        JobMessageSender::new(tx),
+        Arc::new(Mutex::new(None)),
+        queue.clone(),
+        cancel.clone(),
+        shutdown.clone(),
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(RwLock::new(None)),
+        Arc::new(AtomicU32::new(0.0f32.to_bits())),
+        uncapped_sample_ids.clone(),
+        Arc::new(AtomicU32::new(16_000)),
+        Arc::new(RwLock::new(None)),
+        Arc::new(RwLock::new(ProgressCache::default())),
+        Arc::new(ProgressPollerWakeup::new()),
+        Arc::new(ThroughputMetrics::default()),
+    );
+
+    let status_of = |conn: &rusqlite::Connection| -> String {
+        conn.query_row(
+            "SELECT status FROM analysis_jobs WHERE id = ?1",
+            rusqlite::params![job_id],
+            |row| row.get(0),
+        )
+        .unwrap()
+    };
+    let feature_rows = |conn: &rusqlite::Connection| -> i64 {
+        conn.query_row(
+            "SELECT COUNT(*) FROM features WHERE sample_id = ?1",
+            rusqlite::params!["source::a.wav"],
+            |row| row.get(0),
+        )
+        .unwrap()
+    };
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while feature_rows(&conn) == 0 && Instant::now() < deadline {
+        sleep(Duration::from_millis(10));
+    }
+    assert_eq!(feature_rows(&conn), 1, "batch should compute before the gate");
+    assert_eq!(status_of(&conn), "running");
+    cancel.store(true, Ordering::Relaxed);
+    drop(finalize_gate);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while status_of(&conn) == "running" && Instant::now() < deadline {
+        sleep(Duration::from_millis(10));
+    }
+    shutdown.store(true, Ordering::Relaxed);
+    handle.join().unwrap();
+
+    assert_eq!(status_of(&conn), "done");
+    assert_eq!(feature_rows(&conn), 1);
+    assert!(queue.try_mark_inflight(job_id));
+}
+
+#[test]
+fn uncapped_sample_is_decoded_past_duration_cap() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("long.wav");
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: 8_000,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+    for _ in 0..(8_000 * 3) {
+        writer.write_sample::<i16>(0).unwrap();
+    }
+    writer.finalize().unwrap();
+    let job = analysis_db::ClaimedJob {
+        id: 7,
+        sample_id: "source::long.wav".to_string(),
+        content_hash: None,
+        job_type: analysis_db::ANALYZE_SAMPLE_JOB_TYPE.to_string(),
+        source_root: dir.path().to_path_buf(),
+    };
+    let max_duration_bits = AtomicU32::new(1.0f32.to_bits());
+    let sample_rate = AtomicU32::new(8_000);
+    let uncapped = RwLock::new(HashSet::new());
+
+    let capped = decode_analysis_job(&job, &max_duration_bits, &uncapped, &sample_rate);
+    assert!(matches!(capped, DecodeOutcome::Skipped { .. }));
+
+    uncapped.write().unwrap().insert(job.sample_id.clone());
+    let outcome = decode_analysis_job(&job, &max_duration_bits, &uncapped, &sample_rate);
+    assert!(matches!(outcome, DecodeOutcome::Decoded(_)));
+
+    release_uncapped_samples(&uncapped, &[(job.clone(), Ok(()))]);
+    assert!(uncapped.read().unwrap().is_empty());
+    let capped_again = decode_analysis_job(&job, &max_duration_bits, &uncapped, &sample_rate);
+    assert!(matches!(capped_again, DecodeOutcome::Skipped { .. }));
+}
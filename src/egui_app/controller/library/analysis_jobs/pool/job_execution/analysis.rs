@@ -13,6 +13,8 @@ pub(crate) struct AnalysisContext<'a> {
     pub(crate) max_analysis_duration_seconds: f32,
     pub(crate) analysis_sample_rate: u32,
     pub(crate) analysis_version: &'a str,
+    /// Source storage mode for new embeddings, resolved once per batch.
+    pub(crate) quantize_embeddings: bool,
 }
 
 pub(crate) fn run_analysis_job(
@@ -44,11 +46,12 @@ pub(crate) fn run_analysis_job(
                 embedding,
                 embedding_vec,
                 context.analysis_version,
+                context.quantize_embeddings,
             )?;
             return Ok(());
         }
         if let Some(embedding) = cache.embedding.as_ref() {
-            apply_cached_embedding(conn, job, embedding)?;
+            apply_cached_embedding(conn, job, embedding, context.quantize_embeddings)?;
         }
     }
 
@@ -92,6 +95,7 @@ pub(crate) fn run_analysis_job_with_decoded(
         context.analysis_version,
         needs_embedding_upsert,
         true,
+        context.quantize_embeddings,
     )
 }
 
@@ -158,6 +162,7 @@ pub(crate) fn run_analysis_jobs_with_decoded_batch(
                 context.analysis_version,
                 item.needs_embedding_upsert,
                 true,
+                context.quantize_embeddings,
             )
         };
         outcomes.push((item.job, result));
@@ -10,6 +10,7 @@ pub(crate) fn apply_cached_features_and_embedding(
     embedding: &db::CachedEmbedding,
     embedding_vec: &[f32],
     analysis_version: &str,
+    quantize_embeddings: bool,
 ) -> Result<(), String> {
     db::update_analysis_metadata(
         conn,
@@ -35,6 +36,7 @@ pub(crate) fn apply_cached_features_and_embedding(
         embedding.l2_normed,
         &embedding.vec_blob,
         embedding.created_at,
+        quantize_embeddings,
     )?;
     crate::analysis::ann_index::upsert_embedding(conn, &job.sample_id, embedding_vec)?;
     Ok(())
@@ -44,6 +46,7 @@ pub(crate) fn apply_cached_embedding(
     conn: &rusqlite::Connection,
     job: &db::ClaimedJob,
     embedding: &db::CachedEmbedding,
+    quantize_embeddings: bool,
 ) -> Result<(), String> {
     db::upsert_embedding(
         conn,
@@ -54,6 +57,7 @@ pub(crate) fn apply_cached_embedding(
         embedding.l2_normed,
         &embedding.vec_blob,
         embedding.created_at,
+        quantize_embeddings,
     )?;
     Ok(())
 }
@@ -82,6 +86,7 @@ pub(crate) fn finalize_analysis_job(
     analysis_version: &str,
     needs_embedding_upsert: bool,
     do_ann_upsert: bool,
+    quantize_embeddings: bool,
 ) -> Result<(), String> {
     let content_hash = job
         .content_hash
@@ -111,6 +116,7 @@ pub(crate) fn finalize_analysis_job(
             true,
             &embedding_blob,
             created_at,
+            quantize_embeddings,
         )?;
     }
     db::update_analysis_metadata(
@@ -397,9 +397,10 @@ fn write_backfill_results(
     analysis_version: &str,
 ) -> Result<(), String> {
     const INSERT_BATCH: usize = 128;
+    let quantize_embeddings = db::quantized_embedding_storage(conn)?;
     for chunk in results.chunks(INSERT_BATCH) {
         retry_backfill_write_with(
-            || write_backfill_chunk(conn, chunk, analysis_version),
+            || write_backfill_chunk(conn, chunk, analysis_version, quantize_embeddings),
             3,
             Duration::from_millis(50),
         )?;
@@ -415,6 +416,7 @@ fn write_backfill_chunk(
     conn: &rusqlite::Connection,
     chunk: &[EmbeddingResult],
     analysis_version: &str,
+    quantize_embeddings: bool,
 ) -> Result<(), String> {
     conn.execute_batch("BEGIN IMMEDIATE")
         .map_err(|err| format!("Begin embedding backfill tx failed: {err}"))?;
@@ -429,6 +431,7 @@ fn write_backfill_chunk(
             true,
             &embedding_blob,
             result.created_at,
+            quantize_embeddings,
         ) {
             let _ = conn.execute_batch("ROLLBACK");
             return Err(err);
@@ -70,6 +70,7 @@ fn conn_with_schema() -> Connection {
             created_at INTEGER NOT NULL,
             running_at INTEGER,
             last_error TEXT,
+            retry_at INTEGER,
             UNIQUE(sample_id, job_type)
         );",
     )
@@ -278,3 +279,72 @@ fn plan_reuses_content_hash_for_work() {
     assert_eq!(plan.work[0].content_hash, "hash-a");
     assert_eq!(plan.work[0].sample_ids.len(), 2);
 }
+
+#[test]
+fn embeddings_only_enqueue_writes_embeddings_without_features() {
+    let config_dir = tempfile::tempdir().unwrap();
+    let _config_guard = crate::app_dirs::ConfigBaseGuard::set(config_dir.path().to_path_buf());
+    let source_dir = tempfile::tempdir().unwrap();
+    let source = crate::sample_sources::SampleSource::new(source_dir.path().to_path_buf());
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: 16_000,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(source.root.join("tone.wav"), spec).unwrap();
+    for n in 0..16_000 {
+        let value = (std::f32::consts::TAU * 440.0 * n as f32 / 16_000.0).sin() * 0.5;
+        writer.write_sample((value * i16::MAX as f32) as i16).unwrap();
+    }
+    writer.finalize().unwrap();
+    let source_db = crate::sample_sources::SourceDatabase::open(&source.root).unwrap();
+    let mut batch = source_db.write_batch().unwrap();
+    batch
+        .upsert_file_with_hash(Path::new("tone.wav"), 1, 1, "tone-hash")
+        .unwrap();
+    batch.commit().unwrap();
+
+    let (inserted, _progress) =
+        crate::egui_app::controller::library::analysis_jobs::enqueue_jobs_for_source_embeddings_only(
+            &source,
+        )
+        .unwrap();
+    assert_eq!(inserted, 1);
+
+    let conn = db::open_source_db(&source.root).unwrap();
+    let job = conn
+        .query_row(
+            "SELECT id, sample_id, content_hash, job_type FROM analysis_jobs WHERE source_id = ?1",
+            params![source.id.as_str()],
+            |row| {
+                Ok(db::ClaimedJob {
+                    id: row.get(0)?,
+                    sample_id: row.get(1)?,
+                    content_hash: row.get(2)?,
+                    job_type: row.get(3)?,
+                    source_root: source.root.clone(),
+                })
+            },
+        )
+        .unwrap();
+    assert_eq!(job.job_type, db::EMBEDDING_BACKFILL_JOB_TYPE);
+
+    run_embedding_backfill_job(
+        &conn,
+        &job,
+        false,
+        crate::analysis::audio::ANALYSIS_SAMPLE_RATE,
+        crate::analysis::version::analysis_version(),
+    )
+    .unwrap();
+
+    let embeddings: i64 = conn
+        .query_row("SELECT COUNT(*) FROM embeddings", [], |row| row.get(0))
+        .unwrap();
+    let features: i64 = conn
+        .query_row("SELECT COUNT(*) FROM features", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(embeddings, 1);
+    assert_eq!(features, 0);
+}
@@ -29,6 +29,7 @@ pub(crate) fn run_job(
                 max_analysis_duration_seconds,
                 analysis_sample_rate,
                 analysis_version,
+                quantize_embeddings: db::quantized_embedding_storage(conn)?,
             };
             analysis::run_analysis_job(conn, job, &context)
         }
@@ -9,18 +9,18 @@ pub(crate) fn load_embedding_vec_optional(
     model_id: &str,
     expected_dim: usize,
 ) -> Result<Option<Vec<f32>>, String> {
-    let row: Option<Vec<u8>> = conn
+    let row: Option<(String, Vec<u8>)> = conn
         .query_row(
-            "SELECT vec FROM embeddings WHERE sample_id = ?1 AND model_id = ?2",
+            "SELECT dtype, vec FROM embeddings WHERE sample_id = ?1 AND model_id = ?2",
             rusqlite::params![sample_id, model_id],
-            |row| row.get::<_, Vec<u8>>(0),
+            |row| Ok((row.get(0)?, row.get(1)?)),
         )
         .optional()
         .map_err(|err| format!("Failed to load embedding blob for {sample_id}: {err}"))?;
-    let Some(blob) = row else {
+    let Some((dtype, blob)) = row else {
         return Ok(None);
     };
-    let vec = crate::analysis::decode_f32_le_blob(&blob)?;
+    let vec = crate::analysis::vector::decode_embedding_blob(&blob, &dtype)?;
     if vec.len() != expected_dim {
         return Ok(None);
     }
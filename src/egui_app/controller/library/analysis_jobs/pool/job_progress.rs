@@ -72,6 +72,21 @@ impl ProgressPollerWakeup {
 struct ProgressSourceDb {
     source_id: crate::sample_sources::SourceId,
     conn: Connection,
+    /// Whether the last poll saw pending or running jobs for this source.
+    busy: bool,
+}
+
+impl ProgressSourceDb {
+    /// Track queue activity and stamp the completion time once the queue drains.
+    fn note_progress(&mut self, progress: &AnalysisProgress) {
+        let busy = progress.pending > 0 || progress.running > 0;
+        if self.busy && !busy {
+            if let Err(err) = db::record_analysis_completed_at(&self.conn, now_epoch_seconds()) {
+                tracing::warn!("Failed to record analysis completion: {err}");
+            }
+        }
+        self.busy = busy;
+    }
 }
 
 fn refresh_sources(
@@ -100,9 +115,13 @@ fn refresh_sources(
             Ok(conn) => conn,
             Err(_) => continue,
         };
+        let busy = sources
+            .iter()
+            .any(|existing| existing.source_id == source.id && existing.busy);
         next.push(ProgressSourceDb {
             source_id: source.id.clone(),
             conn,
+            busy,
         });
     }
     *sources = next;
@@ -123,6 +142,7 @@ fn current_progress_all(
         let mut updates = Vec::new();
         for source in sources {
             if let Ok(progress) = db::current_progress(&source.conn) {
+                source.note_progress(&progress);
                 total.pending += progress.pending;
                 total.running += progress.running;
                 total.done += progress.done;
@@ -173,6 +193,7 @@ fn cleanup_stale_jobs(
             continue;
         }
         if let Ok(progress) = db::current_progress(&source.conn) {
+            source.note_progress(&progress);
             updates.push((source.source_id.clone(), progress));
         }
     }
@@ -330,6 +351,7 @@ mod tests {
         let mut sources = vec![ProgressSourceDb {
             source_id: crate::sample_sources::SourceId::from_string("source".to_string()),
             conn,
+            busy: false,
         }];
         let cache = Arc::new(RwLock::new(ProgressCache::default()));
         let (tx, _rx) = std::sync::mpsc::sync_channel(1);
@@ -378,6 +400,7 @@ mod tests {
         let mut sources = vec![ProgressSourceDb {
             source_id: crate::sample_sources::SourceId::from_string("source".to_string()),
             conn,
+            busy: false,
         }];
         let cache = Arc::new(RwLock::new(ProgressCache::default()));
         let (tx, rx) = std::sync::mpsc::sync_channel(1);
@@ -399,6 +422,60 @@ mod tests {
         assert_eq!(progress.failed, 1);
     }
 
+    #[test]
+    fn draining_source_queue_records_completion_timestamp() {
+        let dir = TempDir::new().unwrap();
+        let conn = db::open_source_db(dir.path()).unwrap();
+        conn.execute(
+            "INSERT INTO wav_files (path, file_size, modified_ns, missing)
+             VALUES (?1, 1, 0, 0)",
+            rusqlite::params!["a.wav"],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO analysis_jobs (sample_id, source_id, relative_path, job_type, status, attempts, created_at)
+             VALUES (?1, ?2, ?3, ?4, 'pending', 0, ?5)",
+            rusqlite::params![
+                "source::a.wav",
+                "source",
+                "a.wav",
+                db::ANALYZE_SAMPLE_JOB_TYPE,
+                now_epoch_seconds()
+            ],
+        )
+        .unwrap();
+        let mut sources = vec![ProgressSourceDb {
+            source_id: crate::sample_sources::SourceId::from_string("source".to_string()),
+            conn,
+            busy: false,
+        }];
+        let cache = Arc::new(RwLock::new(ProgressCache::default()));
+        let completed_at = |sources: &[ProgressSourceDb]| -> Option<String> {
+            sources[0]
+                .conn
+                .query_row(
+                    "SELECT value FROM metadata WHERE key = ?1",
+                    rusqlite::params![crate::sample_sources::db::META_LAST_ANALYSIS_COMPLETED_AT],
+                    |row| row.get(0),
+                )
+                .ok()
+        };
+
+        let busy = current_progress_all(&mut sources, &cache, true);
+        assert_eq!(busy.pending, 1);
+        assert!(completed_at(&sources).is_none());
+
+        sources[0]
+            .conn
+            .execute("UPDATE analysis_jobs SET status = 'done'", [])
+            .unwrap();
+        let drained = current_progress_all(&mut sources, &cache, true);
+
+        assert_eq!(drained.pending, 0);
+        let stamp: i64 = completed_at(&sources).unwrap().parse().unwrap();
+        assert!(stamp > 0 && stamp <= now_epoch_seconds());
+    }
+
     #[test]
     fn should_refresh_db_when_cache_empty_or_stale() {
         let cache = Arc::new(RwLock::new(ProgressCache::default()));
@@ -0,0 +1,153 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Snapshot of analysis worker throughput for diagnostics.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AnalysisThroughput {
+    /// Completed samples per second of wall time since the first measured job.
+    pub samples_per_sec: f32,
+    /// Average time spent decoding one sample, in milliseconds.
+    pub avg_decode_ms: f32,
+    /// Average time spent computing features for one sample, in milliseconds.
+    pub avg_compute_ms: f32,
+    /// Number of samples whose compute step has been measured.
+    pub samples_completed: u64,
+}
+
+impl AnalysisThroughput {
+    /// Single-line summary for tooltips and logs.
+    pub fn summary(&self) -> String {
+        format!(
+            "{:.1} samples/s • decode {:.1} ms • compute {:.1} ms",
+            self.samples_per_sec, self.avg_decode_ms, self.avg_compute_ms
+        )
+    }
+}
+
+/// Running totals that back [`AnalysisThroughput`].
+#[derive(Debug, Default)]
+pub(crate) struct MetricsAccumulator {
+    decoded: u64,
+    decode_total: Duration,
+    computed: u64,
+    compute_total: Duration,
+    first_started_at: Option<Instant>,
+    last_finished_at: Option<Instant>,
+}
+
+impl MetricsAccumulator {
+    pub(crate) fn record_decode(&mut self, elapsed: Duration) {
+        self.decoded += 1;
+        self.decode_total += elapsed;
+    }
+
+    /// Record a compute pass over `jobs` samples that finished at `finished_at`.
+    pub(crate) fn record_compute(&mut self, jobs: usize, elapsed: Duration, finished_at: Instant) {
+        if jobs == 0 {
+            return;
+        }
+        let started_at = finished_at.checked_sub(elapsed).unwrap_or(finished_at);
+        self.first_started_at = Some(
+            self.first_started_at
+                .map_or(started_at, |first| first.min(started_at)),
+        );
+        self.last_finished_at = Some(
+            self.last_finished_at
+                .map_or(finished_at, |last| last.max(finished_at)),
+        );
+        self.computed += jobs as u64;
+        self.compute_total += elapsed;
+    }
+
+    pub(crate) fn snapshot(&self) -> AnalysisThroughput {
+        let average_ms = |total: Duration, count: u64| {
+            if count == 0 {
+                0.0
+            } else {
+                (total.as_secs_f64() * 1000.0 / count as f64) as f32
+            }
+        };
+        let wall = match (self.first_started_at, self.last_finished_at) {
+            (Some(first), Some(last)) => last.saturating_duration_since(first).as_secs_f64(),
+            _ => 0.0,
+        };
+        let samples_per_sec = if wall > 0.0 {
+            (self.computed as f64 / wall) as f32
+        } else {
+            0.0
+        };
+        AnalysisThroughput {
+            samples_per_sec,
+            avg_decode_ms: average_ms(self.decode_total, self.decoded),
+            avg_compute_ms: average_ms(self.compute_total, self.computed),
+            samples_completed: self.computed,
+        }
+    }
+}
+
+/// Thread-safe throughput metrics shared between analysis workers and the UI.
+#[derive(Debug, Default)]
+pub(crate) struct ThroughputMetrics {
+    inner: Mutex<MetricsAccumulator>,
+}
+
+impl ThroughputMetrics {
+    #[cfg_attr(test, allow(dead_code))]
+    pub(crate) fn record_decode(&self, elapsed: Duration) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.record_decode(elapsed);
+        }
+    }
+
+    #[cfg_attr(test, allow(dead_code))]
+    pub(crate) fn record_compute(&self, jobs: usize, elapsed: Duration) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.record_compute(jobs, elapsed, Instant::now());
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> AnalysisThroughput {
+        self.inner
+            .lock()
+            .map(|inner| inner.snapshot())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_durations_produce_expected_averages() {
+        let mut metrics = MetricsAccumulator::default();
+        metrics.record_decode(Duration::from_millis(10));
+        metrics.record_decode(Duration::from_millis(30));
+        let start = Instant::now();
+        // Two back-to-back batches: 2 jobs in 100 ms, then 2 jobs in 300 ms.
+        metrics.record_compute(
+            2,
+            Duration::from_millis(100),
+            start + Duration::from_millis(100),
+        );
+        metrics.record_compute(
+            2,
+            Duration::from_millis(300),
+            start + Duration::from_millis(400),
+        );
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.samples_completed, 4);
+        assert!((snapshot.avg_decode_ms - 20.0).abs() < 1e-3);
+        assert!((snapshot.avg_compute_ms - 100.0).abs() < 1e-3);
+        assert!((snapshot.samples_per_sec - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn empty_metrics_report_zero() {
+        assert_eq!(
+            MetricsAccumulator::default().snapshot(),
+            AnalysisThroughput::default()
+        );
+    }
+}
@@ -2,12 +2,14 @@ mod job_claim;
 mod job_cleanup;
 mod job_execution;
 mod job_progress;
+mod metrics;
 mod progress_cache;
 
 use crate::egui_app::controller::jobs::JobMessageSender;
 use crate::sample_sources::SourceId;
 use super::wakeup;
 use progress_cache::ProgressCache;
+pub(crate) use metrics::AnalysisThroughput;
 #[cfg(not(test))]
 use std::collections::HashSet;
 use std::sync::{
@@ -25,16 +27,20 @@ pub(crate) struct AnalysisWorkerPool {
     pause_claiming: Arc<AtomicBool>,
     use_cache: Arc<AtomicBool>,
     allowed_source_ids: Arc<RwLock<Option<std::collections::HashSet<SourceId>>>>,
+    priority_source_id: Arc<RwLock<Option<SourceId>>>,
     max_duration_bits: Arc<AtomicU32>,
+    uncapped_sample_ids: Arc<RwLock<std::collections::HashSet<String>>>,
     analysis_sample_rate: Arc<AtomicU32>,
     analysis_version_override: Arc<RwLock<Option<String>>>,
     worker_count_override: Arc<AtomicU32>,
+    claim_batch_override: Arc<AtomicU32>,
     #[cfg_attr(test, allow(dead_code))]
     decode_worker_count_override: Arc<AtomicU32>,
     _progress_cache: Arc<RwLock<ProgressCache>>,
     #[cfg_attr(test, allow(dead_code))]
     progress_wakeup: Arc<job_progress::ProgressPollerWakeup>,
     repaint_signal: Arc<Mutex<Option<egui::Context>>>,
+    metrics: Arc<metrics::ThroughputMetrics>,
     threads: Vec<JoinHandle<()>>,
 }
 
@@ -46,16 +52,20 @@ impl AnalysisWorkerPool {
             pause_claiming: Arc::new(AtomicBool::new(false)),
             use_cache: Arc::new(AtomicBool::new(true)),
             allowed_source_ids: Arc::new(RwLock::new(None)),
+            priority_source_id: Arc::new(RwLock::new(None)),
             max_duration_bits: Arc::new(AtomicU32::new(30.0f32.to_bits())),
+            uncapped_sample_ids: Arc::new(RwLock::new(std::collections::HashSet::new())),
             analysis_sample_rate: Arc::new(AtomicU32::new(
                 crate::analysis::audio::ANALYSIS_SAMPLE_RATE,
             )),
             analysis_version_override: Arc::new(RwLock::new(None)),
             worker_count_override: Arc::new(AtomicU32::new(0)),
+            claim_batch_override: Arc::new(AtomicU32::new(0)),
             decode_worker_count_override: Arc::new(AtomicU32::new(0)),
             _progress_cache: Arc::new(RwLock::new(ProgressCache::default())),
             progress_wakeup: Arc::new(job_progress::ProgressPollerWakeup::new()),
             repaint_signal: Arc::new(Mutex::new(None)),
+            metrics: Arc::new(metrics::ThroughputMetrics::default()),
             threads: Vec::new(),
         }
     }
@@ -71,6 +81,13 @@ impl AnalysisWorkerPool {
             .store(clamped.to_bits(), Ordering::Relaxed);
     }
 
+    /// Let the given samples bypass the global duration cap until their jobs finish.
+    pub(crate) fn allow_full_analysis_for(&self, sample_ids: &[String]) {
+        if let Ok(mut guard) = self.uncapped_sample_ids.write() {
+            guard.extend(sample_ids.iter().cloned());
+        }
+    }
+
     pub(crate) fn set_worker_count(&self, value: u32) {
         let previous = self.worker_count_override.swap(value, Ordering::Relaxed);
         if previous != value {
@@ -78,6 +95,14 @@ impl AnalysisWorkerPool {
         }
     }
 
+    /// Set how many jobs each decoder leases per claim round (0 = auto); applies live.
+    pub(crate) fn set_claim_batch_size(&self, value: u32) {
+        let previous = self.claim_batch_override.swap(value, Ordering::Relaxed);
+        if previous != value {
+            tracing::debug!("Analysis claim batch override set to {}", value);
+        }
+    }
+
     #[cfg_attr(test, allow(dead_code))]
     #[allow(dead_code)]
     pub(crate) fn set_decode_worker_count(&self, value: u32) {
@@ -120,6 +145,17 @@ impl AnalysisWorkerPool {
         wakeup::notify_claim_wakeup();
     }
 
+    /// Prefer claiming jobs from `source_id` before other sources.
+    pub(crate) fn set_priority_source(&self, source_id: Option<SourceId>) {
+        if let Ok(mut guard) = self.priority_source_id.write() {
+            if *guard == source_id {
+                return;
+            }
+            *guard = source_id;
+        }
+        wakeup::notify_claim_wakeup();
+    }
+
     pub(crate) fn pause_claiming(&self) {
         let previous = self.pause_claiming.swap(true, Ordering::Relaxed);
         if !previous {
@@ -135,6 +171,16 @@ impl AnalysisWorkerPool {
         wakeup::notify_claim_wakeup();
     }
 
+    /// Decode/compute throughput measured by the workers since startup.
+    pub(crate) fn throughput(&self) -> AnalysisThroughput {
+        self.metrics.snapshot()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn claiming_paused(&self) -> bool {
+        self.pause_claiming.load(Ordering::Relaxed)
+    }
+
     pub(crate) fn start(
         &mut self,
         message_tx: JobMessageSender,
@@ -176,11 +222,15 @@ impl AnalysisWorkerPool {
                     self.shutdown.clone(),
                     self.pause_claiming.clone(),
                     self.allowed_source_ids.clone(),
+                    self.priority_source_id.clone(),
                     self.max_duration_bits.clone(),
+                    self.uncapped_sample_ids.clone(),
                     self.analysis_sample_rate.clone(),
                     decode_queue_target,
                     claim_wakeup.clone(),
                     reset_done.clone(),
+                    self.claim_batch_override.clone(),
+                    self.metrics.clone(),
                 ));
             }
             for worker_index in 0..worker_count {
@@ -194,10 +244,12 @@ impl AnalysisWorkerPool {
                     self.use_cache.clone(),
                     self.allowed_source_ids.clone(),
                     self.max_duration_bits.clone(),
+                    self.uncapped_sample_ids.clone(),
                     self.analysis_sample_rate.clone(),
                     self.analysis_version_override.clone(),
                     self._progress_cache.clone(),
                     self.progress_wakeup.clone(),
+                    self.metrics.clone(),
                 ));
             }
             self.threads.push(job_progress::spawn_progress_poller(
@@ -214,6 +266,9 @@ impl AnalysisWorkerPool {
 
     pub(crate) fn cancel(&self) {
         self.cancel.store(true, Ordering::Relaxed);
+        if let Ok(mut guard) = self.uncapped_sample_ids.write() {
+            guard.clear();
+        }
         let _ = job_cleanup::reset_running_jobs();
         wakeup::notify_claim_wakeup();
     }
@@ -13,6 +13,15 @@ pub(crate) struct AnalysisProgress {
     pub(crate) samples_pending_or_running: usize,
 }
 
+/// Per-source counts of how analysis jobs were decoded, used to explain missing features.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct DecodeOutcomeCounts {
+    pub(crate) decoded: usize,
+    pub(crate) skipped: usize,
+    pub(crate) failed: usize,
+    pub(crate) not_needed: usize,
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct RunningJobInfo {
     pub(crate) sample_id: String,
@@ -5,7 +5,10 @@ const MAX_MAX_ANALYSIS_DURATION_SECONDS: f32 = 60.0 * 60.0;
 const MIN_LONG_SAMPLE_THRESHOLD_SECONDS: f32 = 1.0;
 const MAX_LONG_SAMPLE_THRESHOLD_SECONDS: f32 = 60.0 * 60.0;
 const MAX_ANALYSIS_WORKER_COUNT: u32 = 64;
+const MAX_ANALYSIS_CLAIM_BATCH: u32 = 1024;
 const MIN_FAST_PREP_SAMPLE_RATE: u32 = 8_000;
+const MAX_SIMILAR_RESULT_COUNT: u32 = 1_000;
+const MAX_SIMILAR_RERANK_CANDIDATES: u32 = 5_000;
 
 pub(crate) fn clamp_max_analysis_duration_seconds(seconds: f32) -> f32 {
     seconds.clamp(
@@ -80,11 +83,23 @@ impl EguiController {
         self.settings.analysis.analysis_worker_count
     }
 
+    /// Return the configured analysis claim batch size (0 = auto).
+    pub fn analysis_claim_batch(&self) -> u32 {
+        self.settings.analysis.analysis_claim_batch
+    }
+
     /// Return the auto-selected analysis worker count for this host.
     pub fn analysis_auto_worker_count(&self) -> u32 {
         crate::egui_app::controller::library::analysis_jobs::default_worker_count()
     }
 
+    /// Return analysis throughput measured by the background workers.
+    pub fn analysis_throughput(
+        &self,
+    ) -> crate::egui_app::controller::library::analysis_jobs::AnalysisThroughput {
+        self.runtime.analysis.throughput()
+    }
+
     /// Return whether fast similarity-prep mode is enabled.
     pub fn similarity_prep_fast_mode_enabled(&self) -> bool {
         self.settings.analysis.fast_similarity_prep
@@ -101,6 +116,45 @@ impl EguiController {
         }
     }
 
+    /// Return whether new similarity embeddings are stored int8-quantized.
+    pub fn quantized_embeddings_enabled(&self) -> bool {
+        self.settings.analysis.quantize_embeddings
+    }
+
+    /// Enable or disable int8 embedding storage for embeddings written from now on.
+    pub fn set_quantized_embeddings_enabled(&mut self, enabled: bool) {
+        if self.settings.analysis.quantize_embeddings == enabled {
+            return;
+        }
+        self.settings.analysis.quantize_embeddings = enabled;
+        for source in self.library.sources.clone() {
+            self.apply_embedding_storage_option(&source);
+        }
+        if let Err(err) = self.persist_config("Failed to save options") {
+            self.set_status(err, StatusTone::Warning);
+        }
+    }
+
+    /// Return whether analysis pauses while a drag or edit gesture is active.
+    pub fn analysis_interaction_pause_enabled(&self) -> bool {
+        self.settings.analysis.pause_analysis_during_interaction
+    }
+
+    /// Enable or disable pausing analysis during drag and edit gestures.
+    pub fn set_analysis_interaction_pause_enabled(&mut self, enabled: bool) {
+        if self.settings.analysis.pause_analysis_during_interaction == enabled {
+            return;
+        }
+        self.settings.analysis.pause_analysis_during_interaction = enabled;
+        if !enabled {
+            self.runtime.performance.interaction_active = false;
+            self.runtime.performance.interaction_resume_at = None;
+        }
+        if let Err(err) = self.persist_config("Failed to save options") {
+            self.set_status(err, StatusTone::Warning);
+        }
+    }
+
     /// Return the sample rate used for fast similarity prep.
     pub fn similarity_prep_fast_sample_rate(&self) -> u32 {
         self.settings.analysis.fast_similarity_prep_sample_rate
@@ -119,6 +173,42 @@ impl EguiController {
         }
     }
 
+    /// Return the maximum number of results returned by similarity searches.
+    pub fn similar_result_count(&self) -> usize {
+        self.settings.analysis.similar_result_count.max(1) as usize
+    }
+
+    /// Return how many ANN candidates are fetched before re-ranking, never fewer than the
+    /// result count.
+    pub fn similar_rerank_candidates(&self) -> usize {
+        let candidates = self.settings.analysis.similar_rerank_candidates as usize;
+        candidates.max(self.similar_result_count())
+    }
+
+    /// Set the maximum number of results returned by similarity searches.
+    pub fn set_similar_result_count(&mut self, count: u32) {
+        let clamped = count.clamp(1, MAX_SIMILAR_RESULT_COUNT);
+        if self.settings.analysis.similar_result_count == clamped {
+            return;
+        }
+        self.settings.analysis.similar_result_count = clamped;
+        if let Err(err) = self.persist_config("Failed to save options") {
+            self.set_status(err, StatusTone::Warning);
+        }
+    }
+
+    /// Set how many ANN candidates are fetched before DSP re-ranking.
+    pub fn set_similar_rerank_candidates(&mut self, candidates: u32) {
+        let clamped = candidates.clamp(1, MAX_SIMILAR_RERANK_CANDIDATES);
+        if self.settings.analysis.similar_rerank_candidates == clamped {
+            return;
+        }
+        self.settings.analysis.similar_rerank_candidates = clamped;
+        if let Err(err) = self.persist_config("Failed to save options") {
+            self.set_status(err, StatusTone::Warning);
+        }
+    }
+
     /// Set a fixed analysis worker count.
     pub fn set_analysis_worker_count(&mut self, value: u32) {
         let clamped = value.min(MAX_ANALYSIS_WORKER_COUNT);
@@ -132,6 +222,19 @@ impl EguiController {
         }
     }
 
+    /// Set how many analysis jobs are leased per claim round (0 = auto).
+    pub fn set_analysis_claim_batch(&mut self, value: u32) {
+        let clamped = value.min(MAX_ANALYSIS_CLAIM_BATCH);
+        if self.settings.analysis.analysis_claim_batch == clamped {
+            return;
+        }
+        self.settings.analysis.analysis_claim_batch = clamped;
+        self.runtime.analysis.set_claim_batch_size(clamped);
+        if let Err(err) = self.persist_config("Failed to save options") {
+            self.set_status(err, StatusTone::Warning);
+        }
+    }
+
     /// Restrict analysis workers to the provided source IDs.
     pub fn set_analysis_worker_allowed_sources(&mut self, sources: Option<Vec<SourceId>>) {
         self.runtime.analysis.set_allowed_sources(sources);
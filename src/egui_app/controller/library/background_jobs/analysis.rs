@@ -64,6 +64,8 @@ pub(crate) fn handle_analysis_message(
                     controller.queue_analysis_failures_refresh(&source);
                     controller.ui_cache.browser.features.remove(&source.id);
                     controller.ui_cache.browser.bpm_values.remove(&source.id);
+                    controller.ui_cache.browser.class_predictions.clear();
+                    controller.refresh_source_coverage(&source.id);
                 }
                 if controller.ui.progress.task == Some(ProgressTaskKind::Analysis) {
                     controller.clear_progress();
@@ -196,7 +196,7 @@ impl EguiController {
                     self.apply_folder_scan_result(message);
                 }
                 JobMessage::SourceWatch(message) => {
-                    self.handle_source_watch_event(&message.source_id);
+                    self.handle_source_watch_event(message);
                 }
                 JobMessage::TrashMove(message) => match message {
                     TrashMoveMessage::SetTotal(total) => {
@@ -240,6 +240,28 @@ impl EguiController {
                         .browser
                         .analysis_failures_pending
                         .remove(&message.source_id);
+                    if message.warnings.is_empty() {
+                        self.ui_cache
+                            .browser
+                            .analysis_warnings
+                            .remove(&message.source_id);
+                    } else {
+                        self.ui_cache
+                            .browser
+                            .analysis_warnings
+                            .insert(message.source_id.clone(), message.warnings);
+                    }
+                    if message.silent.is_empty() {
+                        self.ui_cache
+                            .browser
+                            .silent_samples
+                            .remove(&message.source_id);
+                    } else {
+                        self.ui_cache
+                            .browser
+                            .silent_samples
+                            .insert(message.source_id.clone(), message.silent);
+                    }
                     match message.result {
                         Ok(failures) => {
                             if failures.is_empty() {
@@ -266,6 +288,26 @@ impl EguiController {
                         }
                     }
                 }
+                JobMessage::DecodeOutcomeCountsLoaded(message) => {
+                    self.ui_cache
+                        .browser
+                        .decode_outcomes_pending
+                        .remove(&message.source_id);
+                    match message.result {
+                        Ok(counts) => {
+                            self.ui_cache
+                                .browser
+                                .decode_outcomes
+                                .insert(message.source_id, counts);
+                        }
+                        Err(err) => {
+                            self.set_status(
+                                format!("Failed to load decode outcomes: {err}"),
+                                StatusTone::Warning,
+                            );
+                        }
+                    }
+                }
                 JobMessage::UmapBuilt(message) => {
                     self.runtime.jobs.clear_umap_build();
                     match message.result {
@@ -318,6 +360,15 @@ impl EguiController {
                 JobMessage::SimilarityPrepared(message) => {
                     similarity::handle_similarity_prepared(self, message);
                 }
+                JobMessage::OutlierScanFinished(message) => {
+                    similarity::handle_outlier_scan_finished(self, message);
+                }
+                JobMessage::SourceSummaryLoaded(message) => {
+                    self.apply_source_summary(message);
+                }
+                JobMessage::WaveformDiffLoaded(message) => {
+                    self.apply_waveform_diff_result(message);
+                }
                 JobMessage::UpdateChecked(message) => {
                     updates::handle_update_checked(self, message);
                 }
@@ -354,6 +405,9 @@ impl EguiController {
                             .and_then(|idx| self.ui.browser.visible.position(idx));
                         self.ui.browser.loaded_visible = loaded_index
                             .and_then(|idx| self.ui.browser.visible.position(idx));
+                        if let Some(seed) = self.ui.browser.shuffle_seed {
+                            self.apply_browser_shuffle(seed);
+                        }
                     }
                 }
                 JobMessage::Normalized(message) => {
@@ -379,6 +433,9 @@ impl EguiController {
                                     last_played_at: self.wav_index_for_path(&message.relative_path)
                                         .and_then(|idx| self.wav_entries.entry(idx))
                                         .and_then(|e| e.last_played_at),
+                                    stars: self.wav_index_for_path(&message.relative_path)
+                                        .and_then(|idx| self.wav_entries.entry(idx))
+                                        .and_then(|e| e.stars),
                                 };
 
                                 let is_currently_loaded = self.sample_view.wav.loaded_audio.as_ref().is_some_and(|audio| {
@@ -6,3 +6,10 @@ pub(crate) fn handle_similarity_prepared(
 ) {
     controller.handle_similarity_prep_result(message);
 }
+
+pub(crate) fn handle_outlier_scan_finished(
+    controller: &mut EguiController,
+    message: jobs::OutlierScanResult,
+) {
+    controller.apply_outlier_scan_result(message);
+}
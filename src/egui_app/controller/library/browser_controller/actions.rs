@@ -1,5 +1,6 @@
 use super::helpers::TriageSampleContext;
 use super::*;
+use crate::egui_app::controller::library::level_match::LevelMatchMode;
 use crate::egui_app::state::LoopCrossfadeSettings;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
@@ -19,6 +20,12 @@ pub(crate) trait BrowserActions {
         looped: bool,
         primary_visible_row: usize,
     ) -> Result<(), String>;
+    fn set_stars_browser_samples(
+        &mut self,
+        rows: &[usize],
+        stars: Option<u8>,
+        primary_visible_row: usize,
+    ) -> Result<(), String>;
     fn set_bpm_browser_samples(
         &mut self,
         rows: &[usize],
@@ -27,6 +34,11 @@ pub(crate) trait BrowserActions {
     ) -> Result<(), String>;
     fn normalize_browser_sample(&mut self, row: usize) -> Result<(), String>;
     fn normalize_browser_samples(&mut self, rows: &[usize]) -> Result<(), String>;
+    fn match_browser_sample_levels(
+        &mut self,
+        rows: &[usize],
+        mode: LevelMatchMode,
+    ) -> Result<(), String>;
     fn loop_crossfade_browser_samples(
         &mut self,
         rows: &[usize],
@@ -45,6 +57,15 @@ impl BrowserActions for BrowserController<'_> {
         let result: Result<(), String> = (|| {
             let ctx = self.resolve_browser_sample(row)?;
             self.set_sample_tag_for_source(&ctx.source, &ctx.entry.relative_path, tag, true)?;
+            self.push_tag_undo_entry(
+                "Tag sample",
+                vec![(
+                    ctx.source.id.clone(),
+                    ctx.entry.relative_path.clone(),
+                    ctx.entry.tag,
+                )],
+                tag,
+            );
             self.set_status(
                 format!("Tagged {} as {:?}", ctx.entry.relative_path.display(), tag),
                 StatusTone::Info,
@@ -67,6 +88,7 @@ impl BrowserActions for BrowserController<'_> {
         info!(?rows, ?tag, primary_visible_row, "triage tag: multi row");
         let (contexts, mut last_error) = self.resolve_unique_browser_contexts(rows);
         info!(count = contexts.len(), "triage tag: resolved contexts");
+        let mut previous = Vec::with_capacity(contexts.len());
         for ctx in contexts {
             if let Err(err) =
                 self.set_sample_tag_for_source(&ctx.source, &ctx.entry.relative_path, tag, true)
@@ -77,8 +99,14 @@ impl BrowserActions for BrowserController<'_> {
                     format!("Tagged {} as {:?}", ctx.entry.relative_path.display(), tag),
                     StatusTone::Info,
                 );
+                previous.push((
+                    ctx.source.id.clone(),
+                    ctx.entry.relative_path.clone(),
+                    ctx.entry.tag,
+                ));
             }
         }
+        self.push_tag_undo_entry("Tag samples", previous, tag);
         self.refocus_after_filtered_removal(primary_visible_row);
         if let Some(err) = last_error {
             warn!(?rows, ?tag, error = %err, "triage tag failed for multi row");
@@ -120,6 +148,35 @@ impl BrowserActions for BrowserController<'_> {
         }
     }
 
+    fn set_stars_browser_samples(
+        &mut self,
+        rows: &[usize],
+        stars: Option<u8>,
+        primary_visible_row: usize,
+    ) -> Result<(), String> {
+        let (contexts, mut last_error) = self.resolve_unique_browser_contexts(rows);
+        for ctx in contexts {
+            if let Err(err) =
+                self.set_sample_stars_for_source(&ctx.source, &ctx.entry.relative_path, stars)
+            {
+                last_error = Some(err);
+            } else {
+                let label = match stars {
+                    Some(stars) => format!("Rated {} {stars}/5", ctx.entry.relative_path.display()),
+                    None => format!("Cleared rating for {}", ctx.entry.relative_path.display()),
+                };
+                self.set_status(label, StatusTone::Info);
+            }
+        }
+        self.refocus_after_filtered_removal(primary_visible_row);
+        if let Some(err) = last_error {
+            warn!(?rows, ?stars, error = %err, "star rating failed for multi row");
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
     fn set_bpm_browser_samples(
         &mut self,
         rows: &[usize],
@@ -214,6 +271,39 @@ impl BrowserActions for BrowserController<'_> {
         }
     }
 
+    fn match_browser_sample_levels(
+        &mut self,
+        rows: &[usize],
+        mode: LevelMatchMode,
+    ) -> Result<(), String> {
+        let (contexts, last_error) = self.resolve_unique_browser_contexts(rows);
+        if let Some(err) = last_error {
+            self.set_status(err.clone(), StatusTone::Error);
+            return Err(err);
+        }
+        let Some(source) = contexts.first().map(|ctx| ctx.source.clone()) else {
+            return Ok(());
+        };
+        let paths: Vec<PathBuf> = contexts
+            .into_iter()
+            .map(|ctx| ctx.entry.relative_path)
+            .collect();
+        match self.match_sample_levels(&source, &paths, mode) {
+            Ok(count) => {
+                let sample_label = if count == 1 { "sample" } else { "samples" };
+                self.set_status(
+                    format!("Matched levels of {count} {sample_label}"),
+                    StatusTone::Info,
+                );
+                Ok(())
+            }
+            Err(err) => {
+                self.set_status(err.clone(), StatusTone::Error);
+                Err(err)
+            }
+        }
+    }
+
     fn loop_crossfade_browser_samples(
         &mut self,
         rows: &[usize],
@@ -1,4 +1,5 @@
 use super::*;
+use crate::egui_app::controller::library::level_match::LevelMatchMode;
 use crate::egui_app::state::LoopCrossfadeSettings;
 use tracing::warn;
 
@@ -30,6 +31,17 @@ impl EguiController {
             .set_loop_marker_browser_samples(rows, looped, primary_visible_row)
     }
 
+    /// Set or clear the 1-5 star rating for multiple visible browser rows.
+    pub fn set_stars_browser_samples(
+        &mut self,
+        rows: &[usize],
+        stars: Option<u8>,
+        primary_visible_row: usize,
+    ) -> Result<(), String> {
+        self.browser()
+            .set_stars_browser_samples(rows, stars, primary_visible_row)
+    }
+
     /// Update the stored BPM metadata for multiple visible browser rows.
     pub fn set_bpm_browser_samples(
         &mut self,
@@ -51,6 +63,15 @@ impl EguiController {
         self.browser().normalize_browser_samples(rows)
     }
 
+    /// Bring multiple visible browser rows to a common level (overwrites audio).
+    pub(crate) fn match_browser_sample_levels(
+        &mut self,
+        rows: &[usize],
+        mode: LevelMatchMode,
+    ) -> Result<(), String> {
+        self.browser().match_browser_sample_levels(rows, mode)
+    }
+
     /// Create loop-crossfaded copies of browser rows and select the primary result.
     pub fn loop_crossfade_browser_samples(
         &mut self,
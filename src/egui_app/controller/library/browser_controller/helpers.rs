@@ -81,6 +81,9 @@ impl BrowserController<'_> {
         let last_played_at = entry_index
             .and_then(|idx| self.wav_entries.entry(idx))
             .and_then(|entry| entry.last_played_at);
+        let stars = entry_index
+            .and_then(|idx| self.wav_entries.entry(idx))
+            .and_then(|entry| entry.stars);
         let updated = WavEntry {
             relative_path: ctx.entry.relative_path.clone(),
             file_size,
@@ -90,6 +93,7 @@ impl BrowserController<'_> {
             looped,
             missing: false,
             last_played_at,
+            stars,
         };
 
         let is_currently_loaded = self.sample_view.wav.loaded_audio.as_ref().is_some_and(|audio| {
@@ -224,6 +228,7 @@ impl BrowserController<'_> {
                 looped: ctx.entry.looped,
                 missing: false,
                 last_played_at: ctx.entry.last_played_at,
+                stars: ctx.entry.stars,
             },
         );
 
@@ -0,0 +1,156 @@
+//! Import and export of the embedding classifier used for tag suggestions.
+
+use super::*;
+use super::analysis_jobs;
+use crate::analysis::centroid_classifier::{NearestCentroidModel, load_model_validated};
+use crate::egui_app::controller::state::cache::ClassPrediction;
+use rfd::FileDialog;
+use std::path::Path;
+
+impl EguiController {
+    /// Write the loaded classifier model to `path` as JSON.
+    pub fn export_classifier(&mut self, path: &Path) -> Result<(), String> {
+        let Some(model) = self.library.classifier.as_ref() else {
+            return Err("No classifier loaded to export".to_string());
+        };
+        let json = serde_json::to_string_pretty(model)
+            .map_err(|err| format!("Failed to encode classifier: {err}"))?;
+        std::fs::write(path, json)
+            .map_err(|err| format!("Failed to write {}: {err}", path.display()))?;
+        self.set_status(
+            format!("Exported classifier to {}", path.display()),
+            StatusTone::Info,
+        );
+        Ok(())
+    }
+
+    /// Load and validate a classifier model from `path`, replacing the current one.
+    ///
+    /// The current model is kept when the file fails validation.
+    pub fn import_classifier(&mut self, path: &Path) -> Result<(), String> {
+        let model = load_model_validated(path)?;
+        let class_count = model.classes.len();
+        self.library.classifier = Some(model);
+        self.library.classifier_version = self.library.classifier_version.wrapping_add(1);
+        self.ui_cache.browser.class_predictions.clear();
+        let class_label = if class_count == 1 { "class" } else { "classes" };
+        self.set_status(
+            format!("Loaded classifier with {class_count} {class_label}"),
+            StatusTone::Info,
+        );
+        Ok(())
+    }
+
+    /// Pick a JSON file and export the loaded classifier to it.
+    pub fn export_classifier_via_dialog(&mut self) {
+        let Some(out) = FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .set_file_name("classifier.json")
+            .save_file()
+        else {
+            return;
+        };
+        if let Err(err) = self.export_classifier(&out) {
+            self.set_status(format!("Classifier export failed: {err}"), StatusTone::Error);
+        }
+    }
+
+    /// Pick a JSON file and import a classifier from it.
+    pub fn import_classifier_via_dialog(&mut self) {
+        let Some(path) = FileDialog::new().add_filter("JSON", &["json"]).pick_file() else {
+            return;
+        };
+        if let Err(err) = self.import_classifier(&path) {
+            self.set_status(format!("Classifier import failed: {err}"), StatusTone::Error);
+        }
+    }
+
+    /// Predicted class for a browser row, computed from its stored embedding on first use.
+    pub(crate) fn class_prediction_for_path(&mut self, path: &Path) -> Option<ClassPrediction> {
+        self.library.classifier.as_ref()?;
+        let source = self.current_source()?;
+        let sample_id = analysis_jobs::build_sample_id(source.id.as_str(), path);
+        let db = self.database_for(&source).ok();
+        let model = self.library.classifier.as_ref()?;
+        self.ui_cache.browser.class_predictions.get_or_compute(
+            &sample_id,
+            self.library.classifier_version,
+            || {
+                let embedding = db?.embedding_for_sample_id(&sample_id).ok()??;
+                let (index, confidence) = model.predict_with_confidence(&embedding)?;
+                Some(ClassPrediction {
+                    label: model.classes.get(index)?.clone(),
+                    confidence,
+                })
+            },
+        )
+    }
+
+    /// Return the loaded classifier model, if any.
+    pub(crate) fn classifier(&self) -> Option<&NearestCentroidModel> {
+        self.library.classifier.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::centroid_classifier::EmbeddingClassifier;
+    use crate::analysis::similarity::SIMILARITY_DIM;
+    use crate::egui_app::controller::test_support::dummy_controller;
+    use std::cell::Cell;
+
+    fn embedding(lead: f32) -> Vec<f32> {
+        let mut values = vec![0.0; SIMILARITY_DIM];
+        values[0] = lead;
+        values[1] = 1.0 - lead;
+        values
+    }
+
+    #[test]
+    fn exported_classifier_imports_with_matching_predictions() {
+        let (mut controller, source) = dummy_controller();
+        let kick = embedding(1.0);
+        let snare = embedding(0.0);
+        let model = NearestCentroidModel::fit([
+            ("kick", kick.as_slice()),
+            ("snare", snare.as_slice()),
+        ])
+        .unwrap();
+        let probe = embedding(0.8);
+        let expected = model.predict_class_index(&probe);
+        controller.library.classifier = Some(model.clone());
+        let path = source.root.join("classifier.json");
+
+        controller.export_classifier(&path).unwrap();
+        controller.library.classifier = None;
+        controller.import_classifier(&path).unwrap();
+
+        let imported = controller.classifier().unwrap();
+        assert_eq!(imported, &model);
+        assert_eq!(imported.predict_class_index(&probe), expected);
+        assert_eq!(expected, Some(0));
+    }
+
+    #[test]
+    fn prediction_cache_reuses_stored_result_on_second_lookup() {
+        let (mut controller, _source) = dummy_controller();
+        let computed = Cell::new(0);
+        let compute = || {
+            computed.set(computed.get() + 1);
+            Some(ClassPrediction {
+                label: "kick".to_string(),
+                confidence: 0.9,
+            })
+        };
+        let cache = &mut controller.ui_cache.browser.class_predictions;
+
+        let first = cache.get_or_compute("source::kick.wav", 1, compute);
+        let second = cache.get_or_compute("source::kick.wav", 1, compute);
+
+        assert_eq!(first, second);
+        assert_eq!(computed.get(), 1);
+        cache.get_or_compute("source::kick.wav", 2, compute);
+        assert_eq!(computed.get(), 2);
+    }
+}
@@ -0,0 +1,294 @@
+//! Dump V1 feature vectors for browser samples to CSV for quick inspection.
+
+use super::analysis_jobs;
+use super::*;
+use crate::analysis::vector::{FEATURE_VECTOR_LEN_V1, FEATURE_VERSION_V1, feature_names_v1};
+use crate::egui_app::controller::jobs::{FeatureExportResult, FileOpMessage, FileOpResult};
+use crate::egui_app::state::ProgressTaskKind;
+use rfd::FileDialog;
+use rusqlite::{OptionalExtension, params};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, mpsc::Sender};
+
+impl EguiController {
+    /// Start a background export of named V1 feature columns for samples of the selected source.
+    ///
+    /// Stored feature vectors are used when present; samples without one are analyzed on
+    /// the fly without touching the database. Progress is reported through the file-ops job.
+    pub fn export_features_csv(
+        &mut self,
+        relative_paths: &[PathBuf],
+        out: &Path,
+    ) -> Result<(), String> {
+        let Some(source) = self.current_source() else {
+            return Err("Select a source first".to_string());
+        };
+        if relative_paths.is_empty() {
+            return Err("No samples to export".to_string());
+        }
+        if self.runtime.jobs.file_ops_in_progress() {
+            return Err("Another file operation is already running".to_string());
+        }
+        self.show_status_progress(
+            ProgressTaskKind::FileOps,
+            "Exporting features",
+            relative_paths.len(),
+            true,
+        );
+        let (tx, rx) = std::sync::mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.runtime.jobs.start_file_ops(rx, cancel.clone());
+        let relative_paths = relative_paths.to_vec();
+        let out = out.to_path_buf();
+        std::thread::spawn(move || {
+            let result = write_features_csv(&source, &relative_paths, &out, &cancel, Some(&tx));
+            let _ = tx.send(FileOpMessage::Finished(FileOpResult::FeatureExport(result)));
+        });
+        Ok(())
+    }
+
+    /// Pick a CSV file and export feature vectors for the given visible browser rows.
+    pub fn export_browser_rows_features_csv_via_dialog(&mut self, rows: &[usize]) {
+        let Some(out) = FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .set_file_name("features.csv")
+            .save_file()
+        else {
+            return;
+        };
+        if let Err(err) = self.export_browser_rows_features_csv(rows, &out) {
+            self.set_status(format!("Feature export failed: {err}"), StatusTone::Error);
+        }
+    }
+
+    /// Start exporting feature vectors for the given visible browser rows to `out`.
+    pub fn export_browser_rows_features_csv(
+        &mut self,
+        rows: &[usize],
+        out: &Path,
+    ) -> Result<(), String> {
+        let mut paths = Vec::new();
+        for &row in rows {
+            let Some(entry_index) = self.visible_browser_index(row) else {
+                continue;
+            };
+            let Some(entry) = self.wav_entry(entry_index) else {
+                continue;
+            };
+            if !entry.missing {
+                paths.push(entry.relative_path.clone());
+            }
+        }
+        self.export_features_csv(&paths, out)
+    }
+
+    /// Report a finished background feature export.
+    pub(crate) fn apply_feature_export_result(&mut self, result: FeatureExportResult) {
+        if result.cancelled {
+            self.set_status("Feature export cancelled", StatusTone::Warning);
+            return;
+        }
+        match result.result {
+            Ok(count) => {
+                let sample_label = if count == 1 { "sample" } else { "samples" };
+                self.set_status(
+                    format!(
+                        "Exported features for {count} {sample_label} to {}",
+                        result.out.display()
+                    ),
+                    StatusTone::Info,
+                );
+            }
+            Err(err) => {
+                self.set_status(format!("Feature export failed: {err}"), StatusTone::Error);
+            }
+        }
+    }
+}
+
+/// Write one CSV row per sample, stopping without creating `out` when cancelled.
+fn write_features_csv(
+    source: &SampleSource,
+    relative_paths: &[PathBuf],
+    out: &Path,
+    cancel: &AtomicBool,
+    progress: Option<&Sender<FileOpMessage>>,
+) -> FeatureExportResult {
+    let mut result = FeatureExportResult {
+        out: out.to_path_buf(),
+        result: Ok(0),
+        cancelled: false,
+    };
+    let conn = match analysis_jobs::open_source_db(&source.root) {
+        Ok(conn) => conn,
+        Err(err) => {
+            result.result = Err(err);
+            return result;
+        }
+    };
+    let mut csv = String::from("sample");
+    for name in feature_names_v1() {
+        csv.push(',');
+        csv.push_str(&name);
+    }
+    csv.push('\n');
+    for (completed, relative_path) in relative_paths.iter().enumerate() {
+        if cancel.load(Ordering::Relaxed) {
+            result.cancelled = true;
+            return result;
+        }
+        if let Some(tx) = progress {
+            let _ = tx.send(FileOpMessage::Progress {
+                completed,
+                detail: Some(relative_path.display().to_string()),
+            });
+        }
+        let features = match feature_vector_for_export(&conn, source, relative_path) {
+            Ok(features) => features,
+            Err(err) => {
+                result.result = Err(format!("{}: {err}", relative_path.display()));
+                return result;
+            }
+        };
+        csv.push_str(&csv_field(&relative_path.to_string_lossy()));
+        for value in features {
+            csv.push(',');
+            csv.push_str(&value.to_string());
+        }
+        csv.push('\n');
+    }
+    result.result = std::fs::File::create(out)
+        .map_err(|err| format!("Failed to create {}: {err}", out.display()))
+        .and_then(|mut file| {
+            file.write_all(csv.as_bytes())
+                .map_err(|err| format!("Failed to write {}: {err}", out.display()))
+        })
+        .map(|()| relative_paths.len());
+    result
+}
+
+fn feature_vector_for_export(
+    conn: &rusqlite::Connection,
+    source: &SampleSource,
+    relative_path: &Path,
+) -> Result<Vec<f32>, String> {
+    let sample_id = analysis_jobs::build_sample_id(source.id.as_str(), relative_path);
+    let stored: Option<Vec<u8>> = conn
+        .query_row(
+            "SELECT vec_blob FROM features WHERE sample_id = ?1 AND feat_version = ?2",
+            params![sample_id, FEATURE_VERSION_V1],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|err| format!("Failed to load features: {err}"))?;
+    if let Some(blob) = stored
+        && let Ok(features) = crate::analysis::decode_f32_le_blob(&blob)
+        && features.len() == FEATURE_VECTOR_LEN_V1
+    {
+        return Ok(features);
+    }
+    let decoded = crate::analysis::audio::decode_for_analysis_with_rate(
+        &source.root.join(relative_path),
+        crate::analysis::audio::ANALYSIS_SAMPLE_RATE,
+    )?;
+    crate::analysis::compute_feature_vector_v1_for_mono_samples(
+        &decoded.mono,
+        decoded.sample_rate_used,
+    )
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::egui_app::controller::test_support::dummy_controller;
+
+    fn write_tone(path: &Path, frequency: f32) {
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16_000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(path, spec).unwrap();
+        for n in 0..8_000 {
+            let value = (std::f32::consts::TAU * frequency * n as f32 / 16_000.0).sin() * 0.5;
+            writer.write_sample((value * i16::MAX as f32) as i16).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+
+    #[test]
+    fn two_sample_export_writes_header_and_named_feature_rows() {
+        let (_controller, source) = dummy_controller();
+        write_tone(&source.root.join("low.wav"), 220.0);
+        write_tone(&source.root.join("high.wav"), 3_000.0);
+        let out = source.root.join("features.csv");
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let written = write_features_csv(
+            &source,
+            &[PathBuf::from("low.wav"), PathBuf::from("high.wav")],
+            &out,
+            &AtomicBool::new(false),
+            Some(&tx),
+        );
+
+        assert_eq!(written.result, Ok(2));
+        assert_eq!(rx.try_iter().count(), 2);
+        let csv = std::fs::read_to_string(&out).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        let header: Vec<&str> = lines[0].split(',').collect();
+        assert_eq!(header.len(), FEATURE_VECTOR_LEN_V1 + 1);
+        assert_eq!(header[1], "duration_seconds");
+        for line in &lines[1..] {
+            let fields: Vec<&str> = line.split(',').collect();
+            assert_eq!(fields.len(), FEATURE_VECTOR_LEN_V1 + 1);
+            assert!(fields[1..].iter().all(|field| field.parse::<f32>().is_ok()));
+        }
+        assert!(lines[1].starts_with("low.wav,"));
+        assert!(lines[2].starts_with("high.wav,"));
+    }
+
+    #[test]
+    fn cancelled_export_leaves_no_file() {
+        let (_controller, source) = dummy_controller();
+        write_tone(&source.root.join("low.wav"), 220.0);
+        let out = source.root.join("features.csv");
+
+        let result = write_features_csv(
+            &source,
+            &[PathBuf::from("low.wav")],
+            &out,
+            &AtomicBool::new(true),
+            None,
+        );
+
+        assert!(result.cancelled);
+        assert!(!out.exists());
+    }
+
+    #[test]
+    fn export_runs_as_file_ops_job() {
+        let (mut controller, source) = dummy_controller();
+        controller.library.sources.push(source.clone());
+        write_tone(&source.root.join("low.wav"), 220.0);
+
+        controller
+            .export_features_csv(&[PathBuf::from("low.wav")], &source.root.join("f.csv"))
+            .unwrap();
+
+        assert!(controller.runtime.jobs.file_ops_in_progress());
+        assert_eq!(controller.ui.progress.task, Some(ProgressTaskKind::FileOps));
+    }
+}
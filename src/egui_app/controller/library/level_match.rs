@@ -0,0 +1,219 @@
+//! Bring several samples to a shared peak or loudness level as one undoable edit.
+
+use super::selection_edits::BatchOverwriteEdit;
+use super::wav_io;
+use super::*;
+use crate::analysis::time_domain;
+use crate::egui_app::controller::undo;
+use std::path::{Path, PathBuf};
+
+/// Level measure used when matching samples to each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LevelMatchMode {
+    /// Scale every sample so its peak reaches full scale.
+    Peak,
+    /// Scale every sample to the loudness of the quietest one, so nothing is pushed into clipping.
+    Loudness,
+}
+
+impl LevelMatchMode {
+    fn label(self) -> &'static str {
+        match self {
+            LevelMatchMode::Peak => "peak",
+            LevelMatchMode::Loudness => "loudness",
+        }
+    }
+}
+
+struct MeasuredSample {
+    relative_path: PathBuf,
+    absolute_path: PathBuf,
+    samples: Vec<f32>,
+    spec: hound::WavSpec,
+    level: f32,
+}
+
+/// Measure `samples` in the unit `mode` matches on: linear peak or LUFS.
+pub(crate) fn measure_level(
+    samples: &[f32],
+    channels: usize,
+    sample_rate: u32,
+    mode: LevelMatchMode,
+) -> f32 {
+    match mode {
+        // Unclamped, so float files that already exceed full scale are brought back down.
+        LevelMatchMode::Peak => samples
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold(0.0_f32, |peak, value| peak.max(value.abs())),
+        LevelMatchMode::Loudness => {
+            time_domain::integrated_loudness_lufs(samples, channels, sample_rate)
+        }
+    }
+}
+
+/// Linear gain per level so every measured level lands on the shared target.
+///
+/// Silent inputs get unity gain rather than being boosted without bound.
+pub(crate) fn level_match_gains(levels: &[f32], mode: LevelMatchMode) -> Vec<f32> {
+    match mode {
+        LevelMatchMode::Peak => levels
+            .iter()
+            .map(|&peak| if peak > 0.0 { 1.0 / peak } else { 1.0 })
+            .collect(),
+        LevelMatchMode::Loudness => {
+            let audible = |lufs: f32| lufs > time_domain::LOUDNESS_FLOOR_LUFS;
+            let target = levels
+                .iter()
+                .copied()
+                .filter(|&lufs| audible(lufs))
+                .fold(f32::INFINITY, f32::min);
+            levels
+                .iter()
+                .map(|&lufs| {
+                    if audible(lufs) {
+                        10.0_f32.powf((target - lufs) / 20.0)
+                    } else {
+                        1.0
+                    }
+                })
+                .collect()
+        }
+    }
+}
+
+impl EguiController {
+    /// Match the level of several samples in `source`, overwriting them in place.
+    ///
+    /// All files are measured first, then rewritten with per-file gain, and the whole batch
+    /// is recorded as a single undo step. Returns the number of files rewritten.
+    pub(crate) fn match_sample_levels(
+        &mut self,
+        source: &SampleSource,
+        relative_paths: &[PathBuf],
+        mode: LevelMatchMode,
+    ) -> Result<usize, String> {
+        if relative_paths.len() < 2 {
+            return Err("Select at least two samples to match levels".into());
+        }
+        let mut measured = Vec::with_capacity(relative_paths.len());
+        for relative_path in relative_paths {
+            let absolute_path = source.root.join(relative_path);
+            let (samples, spec) = wav_io::read_samples_for_normalization(&absolute_path)
+                .map_err(|err| format!("{}: {err}", relative_path.display()))?;
+            let channels = spec.channels.max(1) as usize;
+            let level = measure_level(&samples, channels, spec.sample_rate, mode);
+            measured.push(MeasuredSample {
+                relative_path: relative_path.clone(),
+                absolute_path,
+                samples,
+                spec,
+                level,
+            });
+        }
+        let levels: Vec<f32> = measured.iter().map(|sample| sample.level).collect();
+        let gains = level_match_gains(&levels, mode);
+        let mut edits = Vec::with_capacity(measured.len());
+        let mut failure = None;
+        for (sample, gain) in measured.into_iter().zip(gains) {
+            if (gain - 1.0).abs() <= f32::EPSILON {
+                continue;
+            }
+            match self.write_level_matched(source, &sample, gain) {
+                Ok(backup) => edits.push(BatchOverwriteEdit {
+                    source_id: source.id.clone(),
+                    relative_path: sample.relative_path,
+                    absolute_path: sample.absolute_path,
+                    backup,
+                }),
+                Err(err) => {
+                    failure = Some(format!("{}: {err}", sample.relative_path.display()));
+                    break;
+                }
+            }
+        }
+        let count = edits.len();
+        // Files already rewritten stay undoable even when a later one fails.
+        if count > 0 {
+            let label = format!("Match {} of {count} samples", mode.label());
+            self.push_undo_entry(self.batch_overwrite_undo_entry(label, edits));
+        }
+        match failure {
+            Some(err) => Err(err),
+            None => Ok(count),
+        }
+    }
+
+    fn write_level_matched(
+        &mut self,
+        source: &SampleSource,
+        sample: &MeasuredSample,
+        gain: f32,
+    ) -> Result<undo::OverwriteBackup, String> {
+        let backup = undo::OverwriteBackup::capture_before(&sample.absolute_path)?;
+        let scaled: Vec<f32> = sample.samples.iter().map(|value| value * gain).collect();
+        let spec = hound::WavSpec {
+            channels: sample.spec.channels.max(1),
+            sample_rate: sample.spec.sample_rate.max(1),
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        if let Err(err) = wav_io::write_normalized_wav(&sample.absolute_path, &scaled, spec) {
+            let _ = std::fs::copy(&backup.before, &sample.absolute_path);
+            let _ = std::fs::remove_dir_all(&backup.dir);
+            return Err(err);
+        }
+        backup.capture_after(&sample.absolute_path)?;
+        self.sync_rewritten_entry(source, &sample.relative_path, &sample.absolute_path)?;
+        Ok(backup)
+    }
+
+    fn sync_rewritten_entry(
+        &mut self,
+        source: &SampleSource,
+        relative_path: &Path,
+        absolute_path: &Path,
+    ) -> Result<(), String> {
+        let (file_size, modified_ns) = wav_io::file_metadata(absolute_path)?;
+        let tag = self.sample_tag_for(source, relative_path)?;
+        let db = self
+            .database_for(source)
+            .map_err(|err| format!("Database unavailable: {err}"))?;
+        db.upsert_file(relative_path, file_size, modified_ns)
+            .map_err(|err| format!("Failed to sync database entry: {err}"))?;
+        db.set_tag(relative_path, tag)
+            .map_err(|err| format!("Failed to sync tag: {err}"))?;
+        let (last_played_at, looped, stars) = self
+            .wav_index_for_path(relative_path)
+            .and_then(|idx| self.wav_entry(idx))
+            .map(|entry| (entry.last_played_at, entry.looped, entry.stars))
+            .unwrap_or((None, false, None));
+        let entry = WavEntry {
+            relative_path: relative_path.to_path_buf(),
+            file_size,
+            modified_ns,
+            content_hash: None,
+            tag,
+            looped,
+            missing: false,
+            last_played_at,
+            stars,
+        };
+        self.update_cached_entry(source, relative_path, entry);
+        self.refresh_waveform_for_sample(source, relative_path);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loudness_gains_target_the_quietest_audible_sample() {
+        let gains = level_match_gains(&[-12.0, -18.0, -70.0], LevelMatchMode::Loudness);
+        assert!((gains[0] - 10.0_f32.powf(-6.0 / 20.0)).abs() < 1e-6);
+        assert!((gains[1] - 1.0).abs() < 1e-6);
+        assert_eq!(gains[2], 1.0);
+    }
+}
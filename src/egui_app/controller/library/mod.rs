@@ -12,7 +12,10 @@ pub(crate) mod analysis_jobs;
 pub(crate) mod analysis_options;
 pub(crate) mod background_jobs;
 pub(crate) mod browser_controller;
+pub(crate) mod classifier;
 pub(crate) mod drop_targets;
+pub(crate) mod feature_export;
+pub(crate) mod level_match;
 pub(crate) mod missing_samples;
 pub(crate) mod progress;
 pub(crate) mod progress_messages;
@@ -1,5 +1,7 @@
 use super::*;
+use crate::egui_app::controller::source_watcher::SourceWatchEvent;
 use crate::sample_sources::scanner::ScanMode;
+use std::path::PathBuf;
 use std::sync::{Arc, atomic::AtomicBool};
 use std::time::{Duration, Instant};
 
@@ -24,9 +26,50 @@ impl EguiController {
         self.request_auto_quick_sync_for_source_if_due(&source.id, min_interval);
     }
 
-    /// Trigger a quick sync for a source based on a file watcher event.
-    pub(crate) fn handle_source_watch_event(&mut self, source_id: &SourceId) {
-        self.request_auto_quick_sync_for_source_if_due(source_id, WATCHER_SYNC_INTERVAL);
+    /// Sync a source after a file watcher event, limited to the reported paths when possible.
+    pub(crate) fn handle_source_watch_event(&mut self, event: SourceWatchEvent) {
+        if event.needs_full_scan || event.changed_paths.is_empty() {
+            self.request_auto_quick_sync_for_source_if_due(
+                &event.source_id,
+                WATCHER_SYNC_INTERVAL,
+            );
+            return;
+        }
+        self.request_path_sync_for_source(&event.source_id, event.changed_paths);
+    }
+
+    fn request_path_sync_for_source(&mut self, source_id: &SourceId, paths: Vec<PathBuf>) {
+        if self.runtime.jobs.scan_in_progress() {
+            return;
+        }
+        if self.library.missing.sources.contains(source_id) {
+            return;
+        }
+        let Some(source) = self
+            .library
+            .sources
+            .iter()
+            .find(|source| &source.id == source_id)
+            .cloned()
+        else {
+            return;
+        };
+        let cancel = Arc::new(AtomicBool::new(false));
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.runtime.jobs.start_scan(rx, cancel);
+        let source_id = source.id.clone();
+        let root = source.root.clone();
+        std::thread::spawn(move || {
+            let result = SourceDatabase::open(&root)
+                .map_err(crate::sample_sources::scanner::ScanError::from)
+                .and_then(|db| crate::sample_sources::scanner::scan_paths(&db, &paths));
+            let _ = tx.send(ScanJobMessage::Finished(ScanResult {
+                source_id,
+                mode: ScanMode::Quick,
+                kind: ScanKind::Auto,
+                result,
+            }));
+        });
     }
 
     /// Trigger a quick sync for a specific source when the debounce interval elapses.
@@ -74,6 +74,19 @@ pub(crate) fn next_crop_relative_path(
     relative_path: &Path,
     root: &Path,
 ) -> Result<PathBuf, String> {
+    next_numbered_relative_path(relative_path, root, "_crop")
+        .ok_or_else(|| "Could not find available crop filename".into())
+}
+
+pub(crate) fn next_slice_relative_path(
+    relative_path: &Path,
+    root: &Path,
+) -> Result<PathBuf, String> {
+    next_numbered_relative_path(relative_path, root, "_slice")
+        .ok_or_else(|| "Could not find available slice filename".into())
+}
+
+fn next_numbered_relative_path(relative_path: &Path, root: &Path, suffix: &str) -> Option<PathBuf> {
     let parent = relative_path.parent().unwrap_or(Path::new(""));
     let stem = relative_path
         .file_stem()
@@ -81,27 +94,27 @@ pub(crate) fn next_crop_relative_path(
         .unwrap_or("sample");
     let stem = stem.trim();
     let stem = if stem.is_empty() { "sample" } else { stem };
-    let stem = strip_crop_suffix(stem);
+    let stem = strip_numbered_suffix(stem, suffix);
     let ext = relative_path.extension().and_then(|e| e.to_str());
 
     for idx in 1..=999u32 {
         let file_name = match ext {
-            Some(ext) if !ext.is_empty() => format!("{stem}_crop{idx:03}.{ext}"),
-            _ => format!("{stem}_crop{idx:03}"),
+            Some(ext) if !ext.is_empty() => format!("{stem}{suffix}{idx:03}.{ext}"),
+            _ => format!("{stem}{suffix}{idx:03}"),
         };
         let candidate = parent.join(file_name);
         if !root.join(&candidate).exists() {
-            return Ok(candidate);
+            return Some(candidate);
         }
     }
-    Err("Could not find available crop filename".into())
+    None
 }
 
-fn strip_crop_suffix(stem: &str) -> &str {
-    let Some((prefix, suffix)) = stem.rsplit_once("_crop") else {
+fn strip_numbered_suffix<'a>(stem: &'a str, suffix: &str) -> &'a str {
+    let Some((prefix, number)) = stem.rsplit_once(suffix) else {
         return stem;
     };
-    if suffix.len() == 3 && suffix.chars().all(|c| c.is_ascii_digit()) && !prefix.is_empty() {
+    if number.len() == 3 && number.chars().all(|c| c.is_ascii_digit()) && !prefix.is_empty() {
         prefix
     } else {
         stem
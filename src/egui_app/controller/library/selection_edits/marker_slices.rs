@@ -0,0 +1,142 @@
+use super::buffer::{load_selection_buffer, next_slice_relative_path, write_selection_wav};
+use super::undo_entries::NewSliceFile;
+use super::*;
+use std::path::PathBuf;
+
+impl EguiController {
+    /// Split the loaded sample at its markers into separate new samples alongside the original.
+    ///
+    /// User-placed and embedded cue markers both act as cut points; the original file is left
+    /// untouched. Returns the relative paths of the written slices in order.
+    pub(crate) fn slice_loaded_sample_at_markers(&mut self) -> Result<Vec<PathBuf>, String> {
+        let context = self.selection_target()?;
+        let cut_points = marker_cut_points(
+            self.ui
+                .waveform
+                .user_markers
+                .iter()
+                .chain(self.ui.waveform.cue_markers.iter()),
+        );
+        if cut_points.is_empty() {
+            return Err("Place markers inside the sample to slice it".into());
+        }
+        let buffer = load_selection_buffer(&context.absolute_path, SelectionRange::new(0.0, 1.0))?;
+        let channels = buffer.channels.max(1);
+        let total_frames = buffer.samples.len() / channels;
+        let bounds = slice_frame_bounds(&cut_points, total_frames);
+        if bounds.len() < 2 {
+            return Err("Markers are too close together to slice".into());
+        }
+        let spec = hound::WavSpec {
+            channels: buffer.spec_channels,
+            sample_rate: buffer.sample_rate.max(1),
+            bits_per_sample: 32,
+            sample_format: SampleFormat::Float,
+        };
+        let tag = self.sample_tag_for(&context.source, &context.relative_path)?;
+        let db = self
+            .database_for(&context.source)
+            .map_err(|err| format!("Database unavailable: {err}"))?;
+        let mut written = Vec::with_capacity(bounds.len());
+        for (start_frame, end_frame) in bounds {
+            let new_relative =
+                next_slice_relative_path(&context.relative_path, &context.source.root)?;
+            let new_absolute = context.source.root.join(&new_relative);
+            let mut samples = buffer.samples[start_frame * channels..end_frame * channels].to_vec();
+            if self.settings.controls.auto_edge_fades_on_selection_exports {
+                let fade_ms = self.settings.controls.anti_clip_fade_ms.max(0.0);
+                apply_short_edge_fades_to_clip(
+                    &mut samples,
+                    channels,
+                    buffer.sample_rate,
+                    Duration::from_secs_f32(fade_ms / 1000.0),
+                );
+            }
+            write_selection_wav(&new_absolute, &samples, spec)?;
+            let (file_size, modified_ns) = file_metadata(&new_absolute)?;
+            db.upsert_file(&new_relative, file_size, modified_ns)
+                .map_err(|err| format!("Failed to sync database entry: {err}"))?;
+            db.set_tag(&new_relative, tag)
+                .map_err(|err| format!("Failed to sync tag: {err}"))?;
+            written.push((new_relative, new_absolute, file_size, modified_ns));
+        }
+
+        let mut undo_slices = Vec::with_capacity(written.len());
+        for (new_relative, new_absolute, file_size, modified_ns) in &written {
+            self.insert_cached_entry(
+                &context.source,
+                WavEntry {
+                    relative_path: new_relative.clone(),
+                    file_size: *file_size,
+                    modified_ns: *modified_ns,
+                    content_hash: None,
+                    tag,
+                    looped: false,
+                    missing: false,
+                    last_played_at: None,
+                    stars: None,
+                },
+            );
+            self.enqueue_similarity_for_new_sample(
+                &context.source,
+                new_relative,
+                *file_size,
+                *modified_ns,
+            );
+            if let Ok(backup) = undo::OverwriteBackup::capture_before(new_absolute)
+                && backup.capture_after(new_absolute).is_ok()
+            {
+                undo_slices.push(NewSliceFile {
+                    relative_path: new_relative.clone(),
+                    absolute_path: new_absolute.clone(),
+                    backup,
+                });
+            }
+        }
+        let count = written.len();
+        if undo_slices.len() == count {
+            self.push_undo_entry(self.marker_slices_undo_entry(
+                format!("Sliced {} at markers", context.relative_path.display()),
+                context.source.id.clone(),
+                undo_slices,
+                tag,
+            ));
+        }
+        self.set_status(
+            format!("Sliced {} into {count} samples", context.relative_path.display()),
+            StatusTone::Info,
+        );
+        Ok(written
+            .into_iter()
+            .map(|(relative_path, ..)| relative_path)
+            .collect())
+    }
+}
+
+/// Normalized marker positions strictly inside the sample, sorted and deduplicated.
+fn marker_cut_points<'a>(markers: impl Iterator<Item = &'a f32>) -> Vec<f32> {
+    let mut points: Vec<f32> = markers
+        .copied()
+        .filter(|marker| marker.is_finite() && *marker > 0.0 && *marker < 1.0)
+        .collect();
+    points.sort_by(f32::total_cmp);
+    points.dedup();
+    points
+}
+
+/// Frame ranges between consecutive cut points, covering the whole sample without gaps.
+fn slice_frame_bounds(cut_points: &[f32], total_frames: usize) -> Vec<(usize, usize)> {
+    let mut edges = vec![0];
+    edges.extend(
+        cut_points
+            .iter()
+            .map(|point| ((point * total_frames as f32).round() as usize).min(total_frames)),
+    );
+    edges.push(total_frames);
+    edges.dedup();
+    edges
+        .windows(2)
+        .map(|pair| (pair[0], pair[1]))
+        .filter(|(start, end)| end > start)
+        .collect()
+}
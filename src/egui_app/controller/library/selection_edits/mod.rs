@@ -5,7 +5,10 @@ use hound::SampleFormat;
 use std::time::Duration;
 
 mod buffer;
+mod marker_slices;
+mod mono_compat;
 mod ops;
+mod paste;
 mod prompt;
 mod undo_entries;
 
@@ -14,9 +17,14 @@ mod selection_normalize;
 
 use buffer::write_selection_wav;
 use buffer::{SelectionEditBuffer, SelectionTarget};
+use mono_compat::{MONO_LOSS_WARNING_DB, mono_compat_warning};
 pub(crate) use selection_click::repair_clicks_selection as repair_clicks_buffer;
+pub(crate) use undo_entries::BatchOverwriteEdit;
 use selection_normalize::normalize_selection;
 
+use paste::{decode_clipboard_wav, splice_clip_into_selection};
+#[cfg(test)]
+use paste::ClipboardAudio;
 use ops::{
     apply_directional_fade,
     apply_edge_fades,
@@ -49,7 +57,8 @@ pub(crate) enum SelectionEditRequest {
 }
 
 impl EguiController {
-    /// Request a destructive edit, showing a confirmation unless yolo mode is enabled.
+    /// Request a destructive edit, showing a confirmation unless yolo mode is enabled or the
+    /// edit is configured to skip confirmation.
     pub(crate) fn request_destructive_selection_edit(
         &mut self,
         edit: DestructiveSelectionEdit,
@@ -58,7 +67,10 @@ impl EguiController {
             self.set_status(err.clone(), StatusTone::Error);
             return Err(err);
         }
-        if self.settings.controls.destructive_yolo_mode {
+        let controls = &self.settings.controls;
+        if controls.destructive_yolo_mode
+            || !edit.requires_confirmation(&controls.destructive_confirmations)
+        {
             self.ui.waveform.pending_destructive = None;
             self.apply_selection_edit_kind(edit)?;
             return Ok(SelectionEditRequest::Applied);
@@ -186,6 +198,7 @@ impl EguiController {
                 looped: false,
                 missing: false,
                 last_played_at: None,
+                stars: None,
             },
         );
         self.enqueue_similarity_for_new_sample(
@@ -334,6 +347,23 @@ impl EguiController {
         result
     }
 
+    /// Replace the selected span with audio from the system clipboard, resampled to the file rate.
+    pub(crate) fn paste_clipboard_audio_into_selection(&mut self) -> Result<(), String> {
+        let result = match crate::external_clipboard::read_wave_bytes() {
+            Ok(Some(bytes)) => decode_clipboard_wav(&bytes).and_then(|clip| {
+                self.apply_selection_edit("Pasted clipboard audio into", false, |buffer| {
+                    splice_clip_into_selection(buffer, &clip)
+                })
+            }),
+            Ok(None) => Err("Clipboard has no audio to paste".into()),
+            Err(err) => Err(err),
+        };
+        if let Err(err) = &result {
+            self.set_status(err.clone(), StatusTone::Error);
+        }
+        result
+    }
+
     fn apply_selection_edit_kind(&mut self, edit: DestructiveSelectionEdit) -> Result<(), String> {
         match edit {
             DestructiveSelectionEdit::CropSelection => self.crop_waveform_selection(),
@@ -349,6 +379,9 @@ impl EguiController {
             DestructiveSelectionEdit::MuteSelection => self.mute_waveform_selection(),
             DestructiveSelectionEdit::NormalizeSelection => self.normalize_waveform_selection(),
             DestructiveSelectionEdit::ClickRemoval => self.repair_clicks_selection(),
+            DestructiveSelectionEdit::PasteClipboard => {
+                self.paste_clipboard_audio_into_selection()
+            }
         }
     }
 
@@ -387,6 +420,8 @@ impl EguiController {
         if buffer.samples.is_empty() {
             return Err("No audio data after edit".into());
         }
+        let mono_warning =
+            mono_compat_warning(&buffer.samples, buffer.channels, MONO_LOSS_WARNING_DB);
         let spec = hound::WavSpec {
             channels: buffer.spec_channels,
             sample_rate: buffer.sample_rate.max(1),
@@ -407,6 +442,7 @@ impl EguiController {
         let last_played_at = self
             .sample_last_played_for(&context.source, &context.relative_path)?;
         let looped = self.sample_looped_for(&context.source, &context.relative_path)?;
+        let stars = self.sample_stars_for(&context.source, &context.relative_path)?;
         let entry = WavEntry {
             relative_path: context.relative_path.clone(),
             file_size,
@@ -416,6 +452,7 @@ impl EguiController {
             looped,
             missing: false,
             last_played_at,
+            stars,
         };
         self.update_cached_entry(&context.source, &context.relative_path, entry);
         
@@ -456,6 +493,7 @@ impl EguiController {
         }
 
         self.maybe_trigger_pending_playback();
+        self.remember_edit_backup(&context.source.id, &context.relative_path, &backup);
         self.push_undo_entry(self.selection_edit_undo_entry(
             format!("{action_label} {}", context.relative_path.display()),
             context.source.id.clone(),
@@ -463,10 +501,20 @@ impl EguiController {
             context.absolute_path.clone(),
             backup,
         ));
-        self.set_status(
-            format!("{} {}", action_label, context.relative_path.display()),
-            StatusTone::Info,
-        );
+        match mono_warning {
+            Some(warning) => self.set_status_with_undo(
+                format!(
+                    "{} {}; {warning}",
+                    action_label,
+                    context.relative_path.display()
+                ),
+                StatusTone::Warning,
+            ),
+            None => self.set_status_with_undo(
+                format!("{} {}", action_label, context.relative_path.display()),
+                StatusTone::Info,
+            ),
+        }
         Ok(())
     }
 
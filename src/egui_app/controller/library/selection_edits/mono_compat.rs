@@ -0,0 +1,110 @@
+/// Level drop (in dB) from stereo to mono summation that triggers a warning.
+pub(crate) const MONO_LOSS_WARNING_DB: f32 = 6.0;
+
+/// Mid/side energy summary for an interleaved stereo buffer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct StereoImage {
+    /// RMS of both channels taken together.
+    pub(crate) stereo_rms: f32,
+    /// RMS of the mono fold-down `(L + R) / 2`.
+    pub(crate) mid_rms: f32,
+    /// RMS of the side signal `(L - R) / 2`.
+    pub(crate) side_rms: f32,
+    /// Normalized L/R correlation in `[-1, 1]`; 1 is fully in phase.
+    pub(crate) correlation: f32,
+}
+
+impl StereoImage {
+    /// Level lost when summing to mono, in dB. Infinite when the mono sum cancels out.
+    pub(crate) fn mono_loss_db(&self) -> f32 {
+        if self.stereo_rms <= f32::EPSILON {
+            return 0.0;
+        }
+        if self.mid_rms <= f32::EPSILON {
+            return f32::INFINITY;
+        }
+        20.0 * (self.stereo_rms / self.mid_rms).log10()
+    }
+}
+
+/// Measure the mid/side balance of interleaved audio; `None` unless it is stereo.
+pub(crate) fn stereo_image(samples: &[f32], channels: usize) -> Option<StereoImage> {
+    if channels != 2 || samples.len() < 2 {
+        return None;
+    }
+    let mut sum_ll = 0.0f64;
+    let mut sum_rr = 0.0f64;
+    let mut sum_lr = 0.0f64;
+    let mut sum_mid = 0.0f64;
+    let mut sum_side = 0.0f64;
+    let mut frames = 0usize;
+    for frame in samples.chunks_exact(2) {
+        let (left, right) = (frame[0] as f64, frame[1] as f64);
+        let mid = (left + right) * 0.5;
+        let side = (left - right) * 0.5;
+        sum_ll += left * left;
+        sum_rr += right * right;
+        sum_lr += left * right;
+        sum_mid += mid * mid;
+        sum_side += side * side;
+        frames += 1;
+    }
+    let n = frames.max(1) as f64;
+    let denom = (sum_ll * sum_rr).sqrt();
+    let correlation = if denom > 0.0 { sum_lr / denom } else { 1.0 };
+    Some(StereoImage {
+        stereo_rms: ((sum_ll + sum_rr) / (2.0 * n)).sqrt() as f32,
+        mid_rms: (sum_mid / n).sqrt() as f32,
+        side_rms: (sum_side / n).sqrt() as f32,
+        correlation: correlation.clamp(-1.0, 1.0) as f32,
+    })
+}
+
+/// Describe a mono-compatibility problem when folding to mono drops more than `threshold_db`.
+pub(crate) fn mono_compat_warning(
+    samples: &[f32],
+    channels: usize,
+    threshold_db: f32,
+) -> Option<String> {
+    let image = stereo_image(samples, channels)?;
+    let loss = image.mono_loss_db();
+    if loss <= threshold_db {
+        return None;
+    }
+    let loss_label = if loss.is_finite() {
+        format!("{loss:.1} dB")
+    } else {
+        "all level".to_string()
+    };
+    Some(format!(
+        "mono fold-down loses {loss_label} (correlation {:.2})",
+        image.correlation
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stereo(left: &[f32], right_sign: f32) -> Vec<f32> {
+        left.iter()
+            .flat_map(|sample| [*sample, *sample * right_sign])
+            .collect()
+    }
+
+    #[test]
+    fn anti_phase_stereo_warns_but_in_phase_does_not() {
+        let wave: Vec<f32> = (0..256).map(|i| (i as f32 * 0.1).sin() * 0.5).collect();
+
+        let anti_phase = stereo(&wave, -1.0);
+        assert!(mono_compat_warning(&anti_phase, 2, MONO_LOSS_WARNING_DB).is_some());
+        let image = stereo_image(&anti_phase, 2).unwrap();
+        assert!(image.correlation < -0.99);
+
+        let in_phase = stereo(&wave, 1.0);
+        assert!(mono_compat_warning(&in_phase, 2, MONO_LOSS_WARNING_DB).is_none());
+        let image = stereo_image(&in_phase, 2).unwrap();
+        assert!(image.mono_loss_db().abs() < 1e-3);
+        assert!(image.side_rms < 1e-6);
+    }
+}
@@ -0,0 +1,72 @@
+use super::buffer::SelectionEditBuffer;
+use crate::waveform::resample_linear;
+
+/// Interleaved audio decoded from the system clipboard.
+pub(crate) struct ClipboardAudio {
+    pub(crate) samples: Vec<f32>,
+    pub(crate) channels: usize,
+    pub(crate) sample_rate: u32,
+}
+
+/// Decode WAV bytes published on the clipboard into interleaved float samples.
+pub(crate) fn decode_clipboard_wav(bytes: &[u8]) -> Result<ClipboardAudio, String> {
+    let mut reader = hound::WavReader::new(std::io::Cursor::new(bytes))
+        .map_err(|err| format!("Clipboard audio is not a readable WAV: {err}"))?;
+    let spec = reader.spec();
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<_, _>>()
+            .map_err(|err| format!("Failed to read clipboard audio: {err}"))?,
+        hound::SampleFormat::Int => {
+            let scale = 1.0 / (1_i64 << spec.bits_per_sample.saturating_sub(1).min(31)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|sample| sample.map(|value| value as f32 * scale))
+                .collect::<Result<_, _>>()
+                .map_err(|err| format!("Failed to read clipboard audio: {err}"))?
+        }
+    };
+    if samples.is_empty() {
+        return Err("Clipboard audio is empty".into());
+    }
+    Ok(ClipboardAudio {
+        samples,
+        channels: spec.channels.max(1) as usize,
+        sample_rate: spec.sample_rate.max(1),
+    })
+}
+
+/// Replace the selected frames with `clip`, matched to the buffer's channel count and rate.
+pub(crate) fn splice_clip_into_selection(
+    buffer: &mut SelectionEditBuffer,
+    clip: &ClipboardAudio,
+) -> Result<(), String> {
+    let channels = buffer.channels.max(1);
+    let remapped = remap_channels(&clip.samples, clip.channels.max(1), channels);
+    let pasted = resample_linear(&remapped, channels, clip.sample_rate, buffer.sample_rate);
+    if pasted.is_empty() {
+        return Err("Clipboard audio has no frames to paste".into());
+    }
+    let total_frames = buffer.samples.len() / channels;
+    let start = buffer.start_frame.min(total_frames) * channels;
+    let end = buffer.end_frame.min(total_frames) * channels;
+    buffer.samples.splice(start..end.max(start), pasted);
+    Ok(())
+}
+
+fn remap_channels(samples: &[f32], from: usize, to: usize) -> Vec<f32> {
+    if from == to {
+        return samples.to_vec();
+    }
+    let frames = samples.len() / from;
+    let mut out = Vec::with_capacity(frames * to);
+    for frame in samples.chunks_exact(from) {
+        if to == 1 {
+            out.push(frame.iter().sum::<f32>() / from as f32);
+        } else {
+            out.extend((0..to).map(|ch| frame[ch % from]));
+        }
+    }
+    out
+}
@@ -1,6 +1,37 @@
 use crate::egui_app::state::{DestructiveEditPrompt, DestructiveSelectionEdit};
+use crate::sample_sources::config::DestructiveConfirmations;
 
 impl DestructiveSelectionEdit {
+    /// Whether this edit asks for confirmation under the given per-operation settings.
+    pub(crate) fn requires_confirmation(self, confirmations: &DestructiveConfirmations) -> bool {
+        let mut confirmations = *confirmations;
+        *self.confirmation_flag(&mut confirmations)
+    }
+
+    /// Update whether this edit asks for confirmation.
+    pub(crate) fn set_requires_confirmation(
+        self,
+        confirmations: &mut DestructiveConfirmations,
+        required: bool,
+    ) {
+        *self.confirmation_flag(confirmations) = required;
+    }
+
+    fn confirmation_flag(self, confirmations: &mut DestructiveConfirmations) -> &mut bool {
+        match self {
+            DestructiveSelectionEdit::CropSelection => &mut confirmations.crop,
+            DestructiveSelectionEdit::TrimSelection => &mut confirmations.trim,
+            DestructiveSelectionEdit::ReverseSelection => &mut confirmations.reverse,
+            DestructiveSelectionEdit::FadeLeftToRight
+            | DestructiveSelectionEdit::FadeRightToLeft => &mut confirmations.fade,
+            DestructiveSelectionEdit::ShortEdgeFades => &mut confirmations.short_edge_fades,
+            DestructiveSelectionEdit::MuteSelection => &mut confirmations.mute,
+            DestructiveSelectionEdit::NormalizeSelection => &mut confirmations.normalize,
+            DestructiveSelectionEdit::ClickRemoval => &mut confirmations.click_removal,
+            DestructiveSelectionEdit::PasteClipboard => &mut confirmations.paste_clipboard,
+        }
+    }
+
     fn title(&self) -> &'static str {
         match self {
             DestructiveSelectionEdit::CropSelection => "Crop selection",
@@ -12,6 +43,7 @@ impl DestructiveSelectionEdit {
             DestructiveSelectionEdit::MuteSelection => "Mute selection",
             DestructiveSelectionEdit::NormalizeSelection => "Normalize selection",
             DestructiveSelectionEdit::ClickRemoval => "Remove clicks in selection",
+            DestructiveSelectionEdit::PasteClipboard => "Paste audio over selection",
         }
     }
 
@@ -44,6 +76,9 @@ impl DestructiveSelectionEdit {
             DestructiveSelectionEdit::ClickRemoval => {
                 "This will overwrite the selection with an interpolated repair to remove clicks."
             }
+            DestructiveSelectionEdit::PasteClipboard => {
+                "This will overwrite the selection with audio from the system clipboard."
+            }
         }
     }
 }
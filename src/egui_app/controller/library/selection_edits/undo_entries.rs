@@ -1,9 +1,23 @@
 use crate::egui_app::controller::jobs::UndoFileJob;
 use super::super::undo;
 use super::super::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 impl EguiController {
+    /// Keep a reference to the pre-edit copy so the A-B view can compare against it.
+    pub(crate) fn remember_edit_backup(
+        &mut self,
+        source_id: &SourceId,
+        relative_path: &Path,
+        backup: &undo::OverwriteBackup,
+    ) {
+        self.history.last_edit_backup = Some(EditBackupRef {
+            source_id: source_id.clone(),
+            relative_path: relative_path.to_path_buf(),
+            before: backup.before.clone(),
+        });
+    }
+
     pub(crate) fn selection_edit_undo_entry(
         &self,
         label: String,
@@ -114,4 +128,153 @@ impl EguiController {
         .with_cleanup_dir(backup_dir)
     }
 
+    /// Build one undo entry that removes (and on redo restores) every new slice file together.
+    pub(crate) fn marker_slices_undo_entry(
+        &self,
+        label: String,
+        source_id: SourceId,
+        slices: Vec<NewSliceFile>,
+        tag: crate::sample_sources::Rating,
+    ) -> undo::UndoEntry<EguiController> {
+        let backup_dirs: Vec<PathBuf> =
+            slices.iter().map(|slice| slice.backup.dir.clone()).collect();
+        let undo_source_id = source_id.clone();
+        let redo_source_id = source_id;
+        let undo_slices: Vec<(PathBuf, PathBuf)> = slices
+            .iter()
+            .map(|slice| (slice.relative_path.clone(), slice.absolute_path.clone()))
+            .collect();
+        let redo_slices: Vec<(PathBuf, PathBuf, PathBuf)> = slices
+            .iter()
+            .map(|slice| {
+                (
+                    slice.relative_path.clone(),
+                    slice.absolute_path.clone(),
+                    slice.backup.after.clone(),
+                )
+            })
+            .collect();
+        let mut entry = undo::UndoEntry::<EguiController>::new(
+            label,
+            move |controller: &mut EguiController| {
+                let root = controller.source_root_for_undo(&undo_source_id)?;
+                let jobs = undo_slices
+                    .iter()
+                    .map(|(relative_path, absolute_path)| UndoFileJob::RemoveSample {
+                        source_id: undo_source_id.clone(),
+                        source_root: root.clone(),
+                        relative_path: relative_path.clone(),
+                        absolute_path: absolute_path.clone(),
+                    })
+                    .collect();
+                Ok(undo::UndoExecution::Deferred(UndoFileJob::Batch(jobs)))
+            },
+            move |controller: &mut EguiController| {
+                let root = controller.source_root_for_undo(&redo_source_id)?;
+                let jobs = redo_slices
+                    .iter()
+                    .map(|(relative_path, absolute_path, backup_path)| {
+                        UndoFileJob::RestoreSample {
+                            source_id: redo_source_id.clone(),
+                            source_root: root.clone(),
+                            relative_path: relative_path.clone(),
+                            absolute_path: absolute_path.clone(),
+                            backup_path: backup_path.clone(),
+                            tag,
+                        }
+                    })
+                    .collect();
+                Ok(undo::UndoExecution::Deferred(UndoFileJob::Batch(jobs)))
+            },
+        );
+        for dir in backup_dirs {
+            entry = entry.with_cleanup_dir(dir);
+        }
+        entry
+    }
+
+    fn source_root_for_undo(&self, source_id: &SourceId) -> Result<PathBuf, String> {
+        self.library
+            .sources
+            .iter()
+            .find(|s| &s.id == source_id)
+            .map(|source| source.root.clone())
+            .ok_or_else(|| "Source not available".to_string())
+    }
+
+    /// Build one undo entry that restores several overwritten files together.
+    pub(crate) fn batch_overwrite_undo_entry(
+        &self,
+        label: String,
+        edits: Vec<BatchOverwriteEdit>,
+    ) -> undo::UndoEntry<EguiController> {
+        let backup_dirs: Vec<PathBuf> = edits.iter().map(|edit| edit.backup.dir.clone()).collect();
+        let undo_edits: Vec<(SourceId, PathBuf, PathBuf, PathBuf)> = edits
+            .iter()
+            .map(|edit| edit.job_target(&edit.backup.before))
+            .collect();
+        let redo_edits: Vec<(SourceId, PathBuf, PathBuf, PathBuf)> = edits
+            .iter()
+            .map(|edit| edit.job_target(&edit.backup.after))
+            .collect();
+        let mut entry = undo::UndoEntry::<EguiController>::new(
+            label,
+            move |controller: &mut EguiController| controller.batch_overwrite_job(&undo_edits),
+            move |controller: &mut EguiController| controller.batch_overwrite_job(&redo_edits),
+        );
+        for dir in backup_dirs {
+            entry = entry.with_cleanup_dir(dir);
+        }
+        entry
+    }
+
+    fn batch_overwrite_job(
+        &self,
+        targets: &[(SourceId, PathBuf, PathBuf, PathBuf)],
+    ) -> undo::UndoResult {
+        let mut jobs = Vec::with_capacity(targets.len());
+        for (source_id, relative_path, absolute_path, backup_path) in targets {
+            let source = self
+                .library
+                .sources
+                .iter()
+                .find(|s| &s.id == source_id)
+                .cloned()
+                .ok_or_else(|| "Source not available".to_string())?;
+            jobs.push(UndoFileJob::Overwrite {
+                source_id: source_id.clone(),
+                source_root: source.root,
+                relative_path: relative_path.clone(),
+                absolute_path: absolute_path.clone(),
+                backup_path: backup_path.clone(),
+            });
+        }
+        Ok(undo::UndoExecution::Deferred(UndoFileJob::Batch(jobs)))
+    }
+}
+
+/// One file overwritten as part of a grouped edit.
+pub(crate) struct BatchOverwriteEdit {
+    pub(crate) source_id: SourceId,
+    pub(crate) relative_path: PathBuf,
+    pub(crate) absolute_path: PathBuf,
+    pub(crate) backup: undo::OverwriteBackup,
+}
+
+impl BatchOverwriteEdit {
+    fn job_target(&self, backup_path: &Path) -> (SourceId, PathBuf, PathBuf, PathBuf) {
+        (
+            self.source_id.clone(),
+            self.relative_path.clone(),
+            self.absolute_path.clone(),
+            backup_path.to_path_buf(),
+        )
+    }
+}
+
+/// One new sample file written by slicing at markers.
+pub(crate) struct NewSliceFile {
+    pub(crate) relative_path: PathBuf,
+    pub(crate) absolute_path: PathBuf,
+    pub(crate) backup: undo::OverwriteBackup,
 }
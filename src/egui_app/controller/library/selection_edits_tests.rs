@@ -284,3 +284,43 @@ fn selection_target_range_defaults_to_full_sample() {
     let target = selection_target_range(None, None);
     assert_eq!(target, SelectionRange::new(0.0, 1.0));
 }
+
+#[test]
+fn pasting_longer_clip_grows_file_by_length_difference() {
+    let mut buffer = SelectionEditBuffer {
+        samples: vec![0.0_f32; 200],
+        channels: 1,
+        sample_rate: 48_000,
+        spec_channels: 1,
+        start_frame: 50,
+        end_frame: 100,
+    };
+    let clip = ClipboardAudio {
+        samples: vec![0.5_f32; 200],
+        channels: 2,
+        sample_rate: 48_000,
+    };
+    splice_clip_into_selection(&mut buffer, &clip).unwrap();
+    assert_eq!(buffer.samples.len(), 250);
+    assert!(buffer.samples[50..150].iter().all(|s| (*s - 0.5).abs() < 1e-6));
+    assert_eq!(buffer.samples[150], 0.0);
+}
+
+#[test]
+fn pasted_clip_is_resampled_to_file_rate() {
+    let mut buffer = SelectionEditBuffer {
+        samples: vec![0.0_f32; 200],
+        channels: 1,
+        sample_rate: 48_000,
+        spec_channels: 1,
+        start_frame: 50,
+        end_frame: 100,
+    };
+    let clip = ClipboardAudio {
+        samples: vec![0.25_f32; 100],
+        channels: 1,
+        sample_rate: 24_000,
+    };
+    splice_clip_into_selection(&mut buffer, &clip).unwrap();
+    assert_eq!(buffer.samples.len(), 350);
+}
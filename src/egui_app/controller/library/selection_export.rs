@@ -4,7 +4,9 @@ use crate::sample_sources::Rating;
 use std::fs;
 use std::time::{Duration, SystemTime};
 
-use crate::egui_app::controller::playback::audio_samples::{crop_samples, decode_samples_from_bytes, write_wav};
+use crate::egui_app::controller::playback::audio_samples::{
+    crop_samples, decode_samples_from_bytes, write_wav_with_markers,
+};
 use rusqlite::params;
 
 impl EguiController {
@@ -33,7 +35,7 @@ impl EguiController {
             spec.sample_rate,
             spec.channels,
         );
-        write_wav(&target_abs, &samples, spec.sample_rate, spec.channels)?;
+        self.write_selection_clip(&target_abs, &samples, spec, bounds)?;
         let (looped, bpm) = self.selection_export_metadata();
         self.record_selection_entry(
             &source,
@@ -79,7 +81,7 @@ impl EguiController {
             spec.sample_rate,
             spec.channels,
         );
-        write_wav(&target_abs, &samples, spec.sample_rate, spec.channels)?;
+        self.write_selection_clip(&target_abs, &samples, spec, bounds)?;
         let (looped, bpm) = self.selection_export_metadata();
         self.record_selection_entry(
             &source,
@@ -176,7 +178,7 @@ impl EguiController {
             spec.sample_rate,
             spec.channels,
         );
-        write_wav(&target_abs, &samples, spec.sample_rate, spec.channels)?;
+        self.write_selection_clip(&target_abs, &samples, spec, bounds)?;
         let source = SampleSource {
             id: SourceId::new(),
             root: clip_root.to_path_buf(),
@@ -208,6 +210,49 @@ impl EguiController {
         Ok(audio.clone())
     }
 
+    fn write_selection_clip(
+        &self,
+        target: &Path,
+        samples: &[f32],
+        spec: hound::WavSpec,
+        bounds: SelectionRange,
+    ) -> Result<(), String> {
+        let markers = if self.settings.controls.markers_on_selection_exports {
+            let frames = samples.len() / spec.channels.max(1) as usize;
+            self.selection_export_markers(bounds, frames)
+        } else {
+            Vec::new()
+        };
+        write_wav_with_markers(target, samples, spec.sample_rate, spec.channels, &markers)
+    }
+
+    /// Cue, user and visible transient markers inside `bounds`, as frame offsets into the clip.
+    fn selection_export_markers(&self, bounds: SelectionRange, clip_frames: usize) -> Vec<u32> {
+        let waveform = &self.ui.waveform;
+        let transients = waveform
+            .transient_markers_enabled
+            .then_some(waveform.transients.as_slice())
+            .unwrap_or_default();
+        let width = bounds.width();
+        if width <= 0.0 || clip_frames == 0 {
+            return Vec::new();
+        }
+        let mut markers: Vec<u32> = waveform
+            .cue_markers
+            .iter()
+            .chain(&waveform.user_markers)
+            .chain(transients)
+            .filter(|&&position| position >= bounds.start() && position < bounds.end())
+            .map(|&position| {
+                let offset = (position - bounds.start()) / width * clip_frames as f32;
+                (offset.round() as usize).min(clip_frames - 1) as u32
+            })
+            .collect();
+        markers.sort_unstable();
+        markers.dedup();
+        markers
+    }
+
     fn selection_export_metadata(&self) -> (bool, Option<f32>) {
         let looped = self.ui.waveform.loop_enabled;
         let bpm = self
@@ -301,6 +346,7 @@ impl EguiController {
             looped,
             missing: false,
             last_played_at: None,
+            stars: None,
         };
         if register_in_source {
             let db = self
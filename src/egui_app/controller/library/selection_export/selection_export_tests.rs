@@ -142,3 +142,44 @@ fn export_selection_clip_applies_short_edge_fades_when_enabled() {
     assert!((samples[1] - 1.0).abs() < 1e-6);
     assert!((samples[6] - 1.0).abs() < 1e-6);
 }
+
+#[test]
+fn export_selection_clip_writes_markers_as_cue_points_when_enabled() {
+    let temp = tempdir().unwrap();
+    let source_root = temp.path().join("source");
+    std::fs::create_dir_all(&source_root).unwrap();
+
+    let renderer = crate::waveform::WaveformRenderer::new(12, 12);
+    let mut controller = EguiController::new(renderer, None);
+    let source = SampleSource::new(source_root.clone());
+    controller.library.sources.push(source.clone());
+    controller.settings.controls.auto_edge_fades_on_selection_exports = false;
+    controller.settings.controls.markers_on_selection_exports = true;
+    controller.ui.controls.markers_on_selection_exports = true;
+
+    let wav_path = source_root.join("markers.wav");
+    write_test_wav(&wav_path, &[0.5; 16]);
+    controller
+        .load_waveform_for_selection(&source, Path::new("markers.wav"))
+        .unwrap();
+    controller.ui.waveform.cue_markers = vec![0.375, 0.9];
+    controller.ui.waveform.user_markers = vec![0.5];
+    controller.ui.waveform.transient_markers_enabled = true;
+    controller.ui.waveform.transients = vec![0.625];
+
+    let entry = controller
+        .export_selection_clip(
+            &source.id,
+            Path::new("markers.wav"),
+            SelectionRange::new(0.25, 0.75),
+            None,
+            true,
+            true,
+        )
+        .unwrap();
+
+    let bytes = std::fs::read(source_root.join(&entry.relative_path)).unwrap();
+    assert_eq!(crate::wav_sanitize::read_cue_points(&bytes), vec![2, 4, 6]);
+    let mut reader = WavReader::new(std::io::Cursor::new(bytes)).unwrap();
+    assert_eq!(reader.samples::<f32>().count(), 8);
+}
@@ -60,6 +60,7 @@ impl EguiController {
         self.apply_similarity_prep_fast_mode();
         self.apply_similarity_prep_full_analysis(force_full_analysis);
         self.apply_similarity_prep_worker_boost();
+        self.apply_embedding_storage_option(&source);
         self.show_similarity_prep_start(&source);
         if plan.skip_scan {
             self.ensure_similarity_prep_progress(0, true);
@@ -92,6 +93,15 @@ impl EguiController {
             || self.runtime.jobs.umap_cluster_build_in_progress()
     }
 
+    /// Return `(completed, total)` embedding counts for the running similarity prep.
+    pub fn similarity_prep_embedding_progress(&self) -> Option<(usize, usize)> {
+        self.runtime
+            .similarity_prep
+            .as_ref()
+            .and_then(|state| state.embedding_progress)
+            .filter(|(_, total)| *total > 0)
+    }
+
     /// Return true if the last similarity prep run recorded an error.
     pub fn similarity_prep_has_error(&self) -> bool {
         self.runtime.similarity_prep_last_error.is_some()
@@ -1,5 +1,5 @@
+use super::{DbSimilarityPrepStore, SimilarityPrepStore, state, state::SimilarityPrepStage};
 use crate::egui_app::controller::EguiController;
-use super::{DbSimilarityPrepStore, SimilarityPrepStore, state::SimilarityPrepStage};
 use crate::egui_app::state::AnalysisProgressSnapshot;
 use tracing::info;
 
@@ -79,6 +79,10 @@ impl EguiController {
                     if embed_progress.pending > 0 || embed_progress.running > 0 {
                         self.ensure_similarity_prep_progress(embed_progress.total(), true);
                         self.set_similarity_embedding_detail();
+                        self.record_similarity_embedding_progress(
+                            embed_progress.completed(),
+                            embed_progress.total(),
+                        );
                         self.ui
                             .progress
                             .set_counts(embed_progress.total(), embed_progress.completed());
@@ -148,4 +152,10 @@ impl EguiController {
             }
         }
     }
+
+    fn record_similarity_embedding_progress(&mut self, completed: usize, total: usize) {
+        if let Some(prep) = self.runtime.similarity_prep.as_mut() {
+            state::record_embedding_progress(prep, completed, total);
+        }
+    }
 }
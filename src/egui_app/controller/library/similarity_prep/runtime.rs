@@ -56,6 +56,19 @@ impl EguiController {
         self.runtime.analysis.set_analysis_cache_enabled(true);
     }
 
+    /// Record the embedding storage preference in the source database before analysis writes.
+    pub(crate) fn apply_embedding_storage_option(&self, source: &SampleSource) {
+        let quantize = self.settings.analysis.quantize_embeddings;
+        let result = analysis_jobs::open_source_db(&source.root)
+            .and_then(|conn| analysis_jobs::set_quantized_embedding_storage(&conn, quantize));
+        if let Err(err) = result {
+            tracing::warn!(
+                "Failed to apply embedding storage option for {}: {err}",
+                source.id.as_str()
+            );
+        }
+    }
+
     pub(crate) fn apply_similarity_prep_worker_boost(&mut self) {
         if self.settings.analysis.analysis_worker_count != 0 {
             return;
@@ -33,6 +33,7 @@ pub(crate) fn build_initial_state(init: SimilarityPrepInit) -> SimilarityPrepSta
         scan_completed_at: init.scan_completed_at,
         skip_backfill: init.skip_backfill,
         force_full_analysis: init.force_full_analysis,
+        embedding_progress: None,
     }
 }
 
@@ -50,6 +51,21 @@ pub(crate) fn apply_scan_finished(
     }
 }
 
+/// Record embedding counts, keeping `completed` from moving backwards while `total` is stable.
+pub(crate) fn record_embedding_progress(
+    state: &mut SimilarityPrepState,
+    completed: usize,
+    total: usize,
+) -> (usize, usize) {
+    let completed = match state.embedding_progress {
+        Some((previous, previous_total)) if previous_total == total => previous.max(completed),
+        _ => completed,
+    };
+    let progress = (completed.min(total), total);
+    state.embedding_progress = Some(progress);
+    progress
+}
+
 pub(crate) fn start_finalize_if_ready(
     state: &mut SimilarityPrepState,
 ) -> Option<SimilarityFinalizeRequest> {
@@ -57,6 +73,9 @@ pub(crate) fn start_finalize_if_ready(
         return None;
     }
     state.stage = SimilarityPrepStage::Finalizing;
+    if let Some((_, total)) = state.embedding_progress {
+        state.embedding_progress = Some((total, total));
+    }
     Some(SimilarityFinalizeRequest {
         source_id: state.source_id.clone(),
         umap_version: state.umap_version.clone(),
@@ -131,4 +150,21 @@ mod tests {
         assert_eq!(state.stage, SimilarityPrepStage::Finalizing);
         assert_eq!(request.umap_version, "v1");
     }
+
+    #[test]
+    fn embedding_progress_increases_to_completion() {
+        let mut state = build_initial_state(build_init(true, false, false));
+        assert_eq!(state.embedding_progress, None);
+
+        let mut reported = Vec::new();
+        for completed in [0, 3, 2, 7, 10] {
+            reported.push(record_embedding_progress(&mut state, completed, 10));
+        }
+        assert_eq!(reported, vec![(0, 10), (3, 10), (3, 10), (7, 10), (10, 10)]);
+        assert!(reported.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+
+        record_embedding_progress(&mut state, 9, 12);
+        start_finalize_if_ready(&mut state).expect("finalize request");
+        assert_eq!(state.embedding_progress, Some((12, 12)));
+    }
 }
@@ -101,6 +101,7 @@ impl EguiController {
                         store.record_prep_scan_timestamp(&source, scan_completed_at);
                     }
                 }
+                self.refresh_source_coverage(&result.source_id);
                 self.show_similarity_prep_ready(&outcome);
             }
             Err(err) => {
@@ -15,6 +15,8 @@ pub(crate) struct SourceCacheInvalidator<'a> {
     bpm_cache: &'a mut HashMap<SourceId, HashMap<PathBuf, Option<f32>>>,
     duration_cache: &'a mut HashMap<SourceId, HashMap<PathBuf, f32>>,
     analysis_failures_cache: &'a mut HashMap<SourceId, HashMap<PathBuf, String>>,
+    analysis_warnings_cache: &'a mut HashMap<SourceId, HashMap<PathBuf, String>>,
+    silent_samples_cache: &'a mut HashMap<SourceId, HashSet<PathBuf>>,
     feature_cache: &'a mut HashMap<SourceId, FeatureCache>,
     missing_wavs: &'a mut HashMap<SourceId, HashSet<PathBuf>>,
     folder_browsers: &'a mut HashMap<SourceId, crate::egui_app::controller::library::source_folders::FolderBrowserModel>,
@@ -33,6 +35,8 @@ impl<'a> SourceCacheInvalidator<'a> {
             &mut ui_cache.browser.bpm_values,
             &mut ui_cache.browser.durations,
             &mut ui_cache.browser.analysis_failures,
+            &mut ui_cache.browser.analysis_warnings,
+            &mut ui_cache.browser.silent_samples,
             &mut ui_cache.browser.features,
             &mut missing.wavs,
             &mut ui_cache.folders.models,
@@ -46,6 +50,8 @@ impl<'a> SourceCacheInvalidator<'a> {
         bpm_cache: &'a mut HashMap<SourceId, HashMap<PathBuf, Option<f32>>>,
         duration_cache: &'a mut HashMap<SourceId, HashMap<PathBuf, f32>>,
         analysis_failures_cache: &'a mut HashMap<SourceId, HashMap<PathBuf, String>>,
+        analysis_warnings_cache: &'a mut HashMap<SourceId, HashMap<PathBuf, String>>,
+        silent_samples_cache: &'a mut HashMap<SourceId, HashSet<PathBuf>>,
         feature_cache: &'a mut HashMap<SourceId, FeatureCache>,
         missing_wavs: &'a mut HashMap<SourceId, HashSet<PathBuf>>,
         folder_browsers: &'a mut HashMap<SourceId, crate::egui_app::controller::library::source_folders::FolderBrowserModel>,
@@ -57,6 +63,8 @@ impl<'a> SourceCacheInvalidator<'a> {
             bpm_cache,
             duration_cache,
             analysis_failures_cache,
+            analysis_warnings_cache,
+            silent_samples_cache,
             feature_cache,
             missing_wavs,
             folder_browsers,
@@ -69,6 +77,8 @@ impl<'a> SourceCacheInvalidator<'a> {
         self.bpm_cache.remove(source_id);
         self.duration_cache.remove(source_id);
         self.analysis_failures_cache.remove(source_id);
+        self.analysis_warnings_cache.remove(source_id);
+        self.silent_samples_cache.remove(source_id);
         self.feature_cache.remove(source_id);
         self.missing_wavs.remove(source_id);
     }
@@ -1,4 +1,6 @@
 use super::*;
+use crate::egui_app::controller::undo;
+use crate::sample_sources::Rating;
 use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
@@ -15,6 +17,58 @@ impl EguiController {
         entries
     }
 
+    /// Reset every tag under `folder` back to neutral as one undoable batch.
+    ///
+    /// Returns the number of samples whose tag changed.
+    pub fn reset_tags_under_folder(
+        &mut self,
+        source: &SampleSource,
+        folder: &Path,
+    ) -> Result<usize, String> {
+        let db = self
+            .database_for(source)
+            .map_err(|err| format!("Database unavailable: {err}"))?;
+        let entries = db
+            .list_files()
+            .map_err(|err| format!("Failed to read samples: {err}"))?;
+        let prior: Vec<(PathBuf, Rating)> = entries
+            .into_iter()
+            .filter(|entry| {
+                entry.relative_path.starts_with(folder) && entry.tag != Rating::NEUTRAL
+            })
+            .map(|entry| (entry.relative_path, entry.tag))
+            .collect();
+        if prior.is_empty() {
+            return Ok(0);
+        }
+        let reset: Vec<(PathBuf, Rating)> = prior
+            .iter()
+            .map(|(path, _)| (path.clone(), Rating::NEUTRAL))
+            .collect();
+        self.set_sample_tags_for_source(source, &reset)?;
+        let undo_source_id = source.id.clone();
+        let redo_source_id = source.id.clone();
+        let count = prior.len();
+        self.push_undo_entry(undo::UndoEntry::<EguiController>::new(
+            "Reset folder tags",
+            move |controller: &mut EguiController| {
+                let source = controller
+                    .find_source_by_id(&undo_source_id)
+                    .ok_or_else(|| "Source not available".to_string())?;
+                controller.set_sample_tags_for_source(&source, &prior)?;
+                Ok(undo::UndoExecution::Applied)
+            },
+            move |controller: &mut EguiController| {
+                let source = controller
+                    .find_source_by_id(&redo_source_id)
+                    .ok_or_else(|| "Source not available".to_string())?;
+                controller.set_sample_tags_for_source(&source, &reset)?;
+                Ok(undo::UndoExecution::Applied)
+            },
+        ));
+        Ok(count)
+    }
+
     pub(crate) fn rewrite_entries_for_folder(
         &mut self,
         source: &SampleSource,
@@ -62,6 +116,9 @@ impl EguiController {
                     .set_last_played_at(&updated_path, last_played_at)
                     .map_err(|err| format!("Failed to copy playback age: {err}"))?;
             }
+            batch
+                .set_stars(&updated_path, entry.stars)
+                .map_err(|err| format!("Failed to copy star rating: {err}"))?;
         }
         batch
             .commit()
@@ -95,6 +95,7 @@ impl EguiController {
         SourceDatabase::open(&normalized)
             .map_err(|err| format!("Failed to create database: {err}"))?;
         let _ = self.cache_db(&source);
+        self.apply_embedding_storage_option(&source);
         self.library.sources.push(source.clone());
         self.refresh_source_watcher();
         self.select_source(Some(source.id.clone()));
@@ -155,13 +156,19 @@ impl EguiController {
     }
 
     pub(crate) fn refresh_sources_ui(&mut self) {
+        let previous = std::mem::take(&mut self.ui.sources.rows);
         self.ui.sources.rows = self
             .library
             .sources
             .iter()
             .map(|source| {
                 let missing = self.library.missing.sources.contains(&source.id);
-                view_model::source_row(source, missing)
+                let mut row = view_model::source_row(source, missing);
+                if !missing && let Some(old) = previous.iter().find(|old| old.id == row.id) {
+                    row.coverage = old.coverage;
+                    row.last_analyzed_at = old.last_analyzed_at;
+                }
+                row
             })
             .collect();
         self.ui.sources.menu_row = None;
@@ -173,6 +180,36 @@ impl EguiController {
             .and_then(|id| self.library.sources.iter().position(|s| &s.id == id));
         self.ui.sources.scroll_to = self.ui.sources.selected;
         self.refresh_drop_targets_ui();
+        let present: Vec<SampleSource> = self
+            .library
+            .sources
+            .iter()
+            .filter(|source| !self.library.missing.sources.contains(&source.id))
+            .cloned()
+            .collect();
+        self.runtime.jobs.load_source_summaries(present);
+    }
+
+    /// Re-read analysis coverage and completion time for one source row after analysis finishes.
+    pub(crate) fn refresh_source_coverage(&mut self, source_id: &SourceId) {
+        let Some(source) = self.find_source_by_id(source_id) else {
+            return;
+        };
+        self.runtime.jobs.load_source_summaries(vec![source]);
+    }
+
+    /// Apply a source summary loaded in the background to its sidebar row.
+    pub(crate) fn apply_source_summary(&mut self, summary: jobs::SourceSummaryResult) {
+        if let Some(row) = self
+            .ui
+            .sources
+            .rows
+            .iter_mut()
+            .find(|row| row.id == summary.source_id && !row.missing)
+        {
+            row.coverage = summary.coverage;
+            row.last_analyzed_at = summary.last_analyzed_at;
+        }
     }
 
     /// Update the file watcher configuration based on current source availability.
@@ -273,6 +310,11 @@ impl EguiController {
         {
             self.selection_state.ctx.last_selected_browsable_source = Some(source_id.clone());
         }
+        if let Some(previous) = self.selection_state.ctx.selected_source.clone() {
+            self.ui.map.save_view(previous);
+        }
+        self.ui.map.restore_view(id.as_ref());
+        self.runtime.analysis.set_priority_source(id.clone());
         self.selection_state.ctx.selected_source = id;
         self.sample_view.wav.selected_wav = None;
         self.clear_focused_similarity_highlight();
@@ -280,6 +322,7 @@ impl EguiController {
         self.ui.map.bounds = None;
         self.ui.map.last_query = None;
         self.ui.map.cached_points.clear();
+        self.ui.map.selected_sample_ids.clear();
         self.ui.map.outdated = if let Some(source) = self.current_source() {
             let scan_at = crate::egui_app::controller::library::similarity_prep::db::read_source_scan_timestamp(&source);
             let prep_at = crate::egui_app::controller::library::similarity_prep::db::read_source_prep_timestamp(&source);
@@ -400,3 +443,24 @@ impl EguiController {
         }
     }
 }
+
+/// Read coverage and the last analysis time for a source; runs on a background thread.
+pub(crate) fn source_analysis_summary(
+    source: &SampleSource,
+) -> (Option<AnalysisCoverage>, Option<i64>) {
+    let Ok(db) = SourceDatabase::open_read_only(&source.root) else {
+        return (None, None);
+    };
+    let last_analyzed_at = db
+        .get_metadata(crate::sample_sources::db::META_LAST_ANALYSIS_COMPLETED_AT)
+        .ok()
+        .flatten()
+        .and_then(|value| value.parse().ok());
+    let coverage = db
+        .analysis_coverage(
+            source.id.as_str(),
+            crate::analysis::similarity::SIMILARITY_MODEL_ID,
+        )
+        .ok();
+    (coverage, last_analyzed_at)
+}
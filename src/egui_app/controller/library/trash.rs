@@ -1,4 +1,4 @@
-use trash_move::TrashMoveFinished;
+use trash_move::{TrashMoveFinished, TrashTarget};
 #[cfg(test)]
 use trash_move::TrashMoveMessage;
 #[cfg(not(test))]
@@ -7,7 +7,7 @@ use trash_move::run_trash_move_task;
 use trash_move::run_trash_move_task_with_progress;
 use super::*;
 use crate::egui_app::state::ProgressTaskKind;
-use crate::sample_sources::config::normalize_path;
+use crate::sample_sources::config::{TrashStrategy, normalize_path};
 use rfd::{FileDialog, MessageButtons, MessageDialog, MessageDialogResult, MessageLevel};
 use std::fs;
 use std::path::PathBuf;
@@ -16,6 +16,7 @@ use std::sync::atomic::Ordering;
 #[cfg(not(test))]
 use std::sync::mpsc::channel;
 use std::sync::{Arc, atomic::AtomicBool};
+use tracing::{info, warn};
 
 impl EguiController {
     /// Open a folder picker and persist the chosen trash folder.
@@ -46,32 +47,63 @@ impl EguiController {
         }
     }
 
-    /// Move all samples tagged as Trash into the configured trash folder after confirmation.
+    /// How samples tagged as Trash are currently disposed of.
+    pub fn trash_strategy(&self) -> TrashStrategy {
+        self.settings.trash_strategy
+    }
+
+    /// Choose how samples tagged as Trash are disposed of and persist it.
+    pub fn set_trash_strategy(&mut self, strategy: TrashStrategy) {
+        if self.settings.trash_strategy == strategy {
+            return;
+        }
+        self.settings.trash_strategy = strategy;
+        if let Err(err) = self.persist_config("Failed to save trash strategy") {
+            self.set_status(err, StatusTone::Error);
+        }
+    }
+
+    /// Dispose of all samples tagged as Trash using the configured strategy after confirmation.
     pub fn move_all_trashed_to_folder(&mut self) {
         if self.runtime.jobs.trash_move_in_progress() {
             self.set_status("Trash move already in progress", StatusTone::Warning);
             return;
         }
-        if self.settings.trash_folder.is_none() {
+        let strategy = self.settings.trash_strategy;
+        if strategy == TrashStrategy::MoveToFolder && self.settings.trash_folder.is_none() {
             self.pick_trash_folder();
             return;
         }
-        if !self.confirm_warning(
-            "Move trashed samples?",
-            "All samples tagged as Trash will be moved to the configured trash folder. Continue?",
-        ) {
+        let (title, description) = match strategy {
+            TrashStrategy::MoveToFolder => (
+                "Move trashed samples?",
+                "All samples tagged as Trash will be moved to the configured trash folder. Continue?",
+            ),
+            TrashStrategy::OsTrash => (
+                "Send trashed samples to the system trash?",
+                "All samples tagged as Trash will be sent to the system trash. Continue?",
+            ),
+            TrashStrategy::DeletePermanently => (
+                "Delete trashed samples permanently?",
+                "All samples tagged as Trash will be permanently deleted. This cannot be undone. Continue?",
+            ),
+        };
+        if !self.confirm_warning(title, description) {
             return;
         }
-        let Ok(trash_root) = self.ensure_trash_folder_ready() else {
-            return;
+        let target = match strategy {
+            TrashStrategy::MoveToFolder => {
+                let Ok(trash_root) = self.ensure_trash_folder_ready() else {
+                    return;
+                };
+                TrashTarget::Folder(trash_root)
+            }
+            TrashStrategy::OsTrash => TrashTarget::OsTrash,
+            TrashStrategy::DeletePermanently => TrashTarget::Delete,
         };
-        self.set_status("Moving trashed samples...", StatusTone::Busy);
-        self.show_status_progress(
-            ProgressTaskKind::TrashMove,
-            "Moving trashed samples",
-            0,
-            true,
-        );
+        let progress_label = trash_progress_label(strategy);
+        self.set_status(format!("{progress_label}..."), StatusTone::Busy);
+        self.show_status_progress(ProgressTaskKind::TrashMove, progress_label, 0, true);
         self.update_progress_detail("Scanning for trashed samples...");
 
         let cancel = Arc::new(AtomicBool::new(false));
@@ -82,7 +114,6 @@ impl EguiController {
             let cancel_after = self.runtime.progress_cancel_after;
             let finished = run_trash_move_task_with_progress(
                 sources,
-                trash_root,
                 cancel.clone(),
                 |message| match message {
                     TrashMoveMessage::SetTotal(total) => {
@@ -103,7 +134,7 @@ impl EguiController {
                     }
                     TrashMoveMessage::Finished(_) => {}
                 },
-                trash_move::move_to_trash,
+                |source, entry| trash_move::dispose_trashed(&target, source, entry),
             );
             self.apply_trash_move_finished(finished);
         }
@@ -113,7 +144,7 @@ impl EguiController {
             let (tx, rx) = channel();
             self.runtime.jobs.start_trash_move(rx, cancel.clone());
             std::thread::spawn(move || {
-                let _ = run_trash_move_task(sources, trash_root, cancel, Some(&tx));
+                let _ = run_trash_move_task(sources, target, cancel, Some(&tx));
             });
         }
     }
@@ -224,14 +255,22 @@ impl EguiController {
         } else if result.total == 0 {
             self.set_status("No trashed samples to move", StatusTone::Info);
         } else if result.errors.is_empty() {
-            self.set_status(
-                format!("Moved {} trashed sample(s)", result.moved),
-                StatusTone::Info,
-            );
+            let verb = trash_done_verb(self.settings.trash_strategy);
+            let renamed = result.files.iter().filter(|file| file.renamed()).count();
+            let message = if renamed == 0 {
+                format!("{verb} {} trashed sample(s)", result.moved)
+            } else {
+                format!(
+                    "{verb} {} trashed sample(s), {renamed} renamed to avoid overwriting",
+                    result.moved
+                )
+            };
+            self.set_status(message, StatusTone::Info);
         } else {
             self.set_status(
                 format!(
-                    "Moved {} sample(s) with {} error(s)",
+                    "{} {} sample(s) with {} error(s)",
+                    trash_done_verb(self.settings.trash_strategy),
                     result.moved,
                     result.errors.len()
                 ),
@@ -239,6 +278,22 @@ impl EguiController {
             );
         }
 
+        for file in &result.files {
+            match &file.outcome {
+                Ok(disposal) => info!(
+                    source_id = %file.source_id,
+                    path = %file.relative_path.display(),
+                    ?disposal,
+                    "trash move: disposed of sample"
+                ),
+                Err(err) => warn!(
+                    source_id = %file.source_id,
+                    path = %file.relative_path.display(),
+                    error = %err,
+                    "trash move: sample not moved"
+                ),
+            }
+        }
         for err in result.errors {
             eprintln!("Trash move error: {err}");
         }
@@ -284,7 +339,7 @@ impl EguiController {
         Ok(path)
     }
 
-    fn confirm_warning(&self, title: &str, description: &str) -> bool {
+    pub(crate) fn confirm_warning(&self, title: &str, description: &str) -> bool {
         if cfg!(test) {
             return true;
         }
@@ -299,3 +354,19 @@ impl EguiController {
         )
     }
 }
+
+fn trash_progress_label(strategy: TrashStrategy) -> &'static str {
+    match strategy {
+        TrashStrategy::MoveToFolder => "Moving trashed samples",
+        TrashStrategy::OsTrash => "Sending trashed samples to the system trash",
+        TrashStrategy::DeletePermanently => "Deleting trashed samples",
+    }
+}
+
+fn trash_done_verb(strategy: TrashStrategy) -> &'static str {
+    match strategy {
+        TrashStrategy::MoveToFolder => "Moved",
+        TrashStrategy::OsTrash => "Sent to system trash",
+        TrashStrategy::DeletePermanently => "Deleted",
+    }
+}
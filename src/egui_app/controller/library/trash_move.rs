@@ -1,4 +1,5 @@
 use super::*;
+use std::ffi::OsString;
 use std::fs;
 use std::path::{Path, PathBuf};
 #[cfg(not(test))]
@@ -26,38 +27,72 @@ pub(crate) struct TrashMoveFinished {
     pub(crate) cancelled: bool,
     pub(crate) errors: Vec<String>,
     pub(crate) affected_sources: Vec<SourceId>,
+    pub(crate) files: Vec<TrashMoveFileResult>,
+}
+
+/// Outcome of moving a single trashed sample.
+#[derive(Clone, Debug)]
+pub(crate) struct TrashMoveFileResult {
+    pub(crate) source_id: SourceId,
+    pub(crate) relative_path: PathBuf,
+    /// Where the sample went, or the reason it could not be disposed of.
+    pub(crate) outcome: Result<TrashDisposal, String>,
+}
+
+impl TrashMoveFileResult {
+    /// True when the destination was renamed to avoid overwriting an existing file.
+    pub(crate) fn renamed(&self) -> bool {
+        matches!(self.outcome, Ok(TrashDisposal::Moved { renamed: true, .. }))
+    }
+}
+
+/// Where samples tagged as Trash are sent.
+#[derive(Clone, Debug)]
+pub(crate) enum TrashTarget {
+    /// Move into this folder, mirroring the source-relative path.
+    Folder(PathBuf),
+    /// Hand the file to the platform recycle bin.
+    OsTrash,
+    /// Remove the file permanently.
+    Delete,
+}
+
+/// What happened to a sample that was disposed of successfully.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum TrashDisposal {
+    Moved { destination: PathBuf, renamed: bool },
+    OsTrash,
+    Deleted,
 }
 
 #[cfg(not(test))]
 pub(crate) fn run_trash_move_task(
     sources: Vec<SampleSource>,
-    trash_root: PathBuf,
+    target: TrashTarget,
     cancel: Arc<AtomicBool>,
     sender: Option<&Sender<TrashMoveMessage>>,
 ) -> TrashMoveFinished {
     run_trash_move_task_with_progress(
         sources,
-        trash_root,
         cancel,
         |message| {
             if let Some(tx) = sender {
                 let _ = tx.send(message);
             }
         },
-        move_to_trash,
+        |source, entry| dispose_trashed(&target, source, entry),
     )
 }
 
 pub(crate) fn run_trash_move_task_with_progress<F, M>(
     sources: Vec<SampleSource>,
-    trash_root: PathBuf,
     cancel: Arc<AtomicBool>,
     mut on_message: F,
     mut mover: M,
 ) -> TrashMoveFinished
 where
     F: FnMut(TrashMoveMessage),
-    M: FnMut(&SampleSource, &WavEntry, &Path) -> Result<(), String>,
+    M: FnMut(&SampleSource, &WavEntry) -> Result<TrashDisposal, String>,
 {
     let mut errors = Vec::new();
     let mut trashed_by_source: Vec<(SampleSource, Vec<WavEntry>)> = Vec::new();
@@ -97,6 +132,7 @@ where
             cancelled: cancel.load(Ordering::Relaxed),
             errors,
             affected_sources: Vec::new(),
+            files: Vec::new(),
         };
         on_message(TrashMoveMessage::Finished(finished.clone()));
         return finished;
@@ -104,6 +140,7 @@ where
 
     let mut moved = 0usize;
     let mut completed = 0usize;
+    let mut files = Vec::with_capacity(total);
     let mut affected_sources: std::collections::HashSet<SourceId> =
         std::collections::HashSet::new();
 
@@ -132,17 +169,28 @@ where
 
             // 1. Mark as missing in database (Write-Ahead)
             if let Err(err) = db.set_missing(&entry.relative_path, true) {
-                errors.push(format!(
+                let err = format!(
                     "Failed to mark {} as missing before move: {err}",
                     entry.relative_path.display()
-                ));
+                );
+                errors.push(err.clone());
+                files.push(TrashMoveFileResult {
+                    source_id: source.id.clone(),
+                    relative_path: entry.relative_path.clone(),
+                    outcome: Err(err),
+                });
                 completed += 1;
                 continue;
             }
 
             // 2. Perform filesystem move
-            match mover(&source, &entry, &trash_root) {
-                Ok(()) => {
+            match mover(&source, &entry) {
+                Ok(disposal) => {
+                    files.push(TrashMoveFileResult {
+                        source_id: source.id.clone(),
+                        relative_path: entry.relative_path.clone(),
+                        outcome: Ok(disposal),
+                    });
                     // 3. Remove from database
                     if let Err(err) = db.remove_file(&entry.relative_path) {
                         errors.push(format!(
@@ -157,6 +205,11 @@ where
                 }
                 Err(err) => {
                     // 4. Rollback: Unmark as missing if move failed
+                    files.push(TrashMoveFileResult {
+                        source_id: source.id.clone(),
+                        relative_path: entry.relative_path.clone(),
+                        outcome: Err(err.clone()),
+                    });
                     errors.push(err);
                     if let Err(rollback_err) = db.set_missing(&entry.relative_path, false) {
                         errors.push(format!(
@@ -181,13 +234,16 @@ where
         cancelled: cancel.load(Ordering::Relaxed),
         errors,
         affected_sources: affected_sources.into_iter().collect(),
+        files,
     };
     on_message(TrashMoveMessage::Finished(finished.clone()));
     finished
 }
 
+/// Pick a destination under `root` that does not exist yet, appending `_N` to the file stem
+/// when the mirrored path is already taken.
 fn unique_destination(root: &Path, relative: &Path) -> Result<PathBuf, String> {
-    let mut candidate = root.join(relative);
+    let candidate = root.join(relative);
     if !candidate.exists() {
         return Ok(candidate);
     }
@@ -197,24 +253,74 @@ fn unique_destination(root: &Path, relative: &Path) -> Result<PathBuf, String> {
         .unwrap_or_else(|| root.to_path_buf());
     let stem = relative
         .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("file");
-    let ext = relative.extension().and_then(|e| e.to_str()).unwrap_or("");
+        .map(|stem| stem.to_os_string())
+        .unwrap_or_else(|| OsString::from("file"));
+    let ext = relative.extension();
     for idx in 1..=1000 {
-        let mut name = format!("{stem}_{idx}");
-        if !ext.is_empty() {
-            name.push('.');
-            name.push_str(ext);
+        let mut name = stem.clone();
+        name.push(format!("_{idx}"));
+        if let Some(ext) = ext {
+            name.push(".");
+            name.push(ext);
         }
-        candidate = parent.join(name);
+        let candidate = parent.join(name);
         if !candidate.exists() {
             return Ok(candidate);
         }
     }
-    Err("Could not create unique trash destination".into())
+    Err(format!(
+        "Could not create unique trash destination for {}",
+        relative.display()
+    ))
+}
+
+/// Dispose of a trashed sample according to `target`.
+pub(crate) fn dispose_trashed(
+    target: &TrashTarget,
+    source: &SampleSource,
+    entry: &WavEntry,
+) -> Result<TrashDisposal, String> {
+    dispose_trashed_with(
+        target,
+        source,
+        entry,
+        crate::egui_app::controller::ui::os_explorer::send_to_os_trash,
+    )
+}
+
+fn dispose_trashed_with<T>(
+    target: &TrashTarget,
+    source: &SampleSource,
+    entry: &WavEntry,
+    os_trash: T,
+) -> Result<TrashDisposal, String>
+where
+    T: FnOnce(&Path) -> Result<(), String>,
+{
+    match target {
+        TrashTarget::Folder(trash_root) => move_to_trash(source, entry, trash_root),
+        TrashTarget::OsTrash => {
+            let absolute = source.root.join(&entry.relative_path);
+            if !absolute.is_file() {
+                return Err(format!("File not found for trash: {}", absolute.display()));
+            }
+            os_trash(&absolute)?;
+            Ok(TrashDisposal::OsTrash)
+        }
+        TrashTarget::Delete => {
+            let absolute = source.root.join(&entry.relative_path);
+            fs::remove_file(&absolute)
+                .map_err(|err| format!("Failed to delete {}: {err}", absolute.display()))?;
+            Ok(TrashDisposal::Deleted)
+        }
+    }
 }
 
-pub(crate) fn move_to_trash(source: &SampleSource, entry: &WavEntry, trash_root: &Path) -> Result<(), String> {
+pub(crate) fn move_to_trash(
+    source: &SampleSource,
+    entry: &WavEntry,
+    trash_root: &Path,
+) -> Result<TrashDisposal, String> {
     let absolute = source.root.join(&entry.relative_path);
     if !absolute.is_file() {
         return Err(format!("File not found for trash: {}", absolute.display()));
@@ -225,7 +331,7 @@ pub(crate) fn move_to_trash(source: &SampleSource, entry: &WavEntry, trash_root:
             .map_err(|err| format!("Unable to prepare trash folder {}: {err}", parent.display()))?;
     }
     if let Err(err) = fs::rename(&absolute, &destination) {
-        fs::copy(&absolute, &destination).map_err(|copy_err| {
+        copy_without_overwrite(&absolute, &destination).map_err(|copy_err| {
             format!(
                 "Failed to move {} to trash: rename error {err}; copy error {copy_err}",
                 absolute.display()
@@ -238,7 +344,22 @@ pub(crate) fn move_to_trash(source: &SampleSource, entry: &WavEntry, trash_root:
             )
         })?;
     }
-    Ok(())
+    let renamed = destination != trash_root.join(&entry.relative_path);
+    Ok(TrashDisposal::Moved {
+        destination,
+        renamed,
+    })
+}
+
+/// Copy `from` to `to`, failing instead of clobbering a file that appeared at `to`.
+fn copy_without_overwrite(from: &Path, to: &Path) -> std::io::Result<()> {
+    let mut reader = fs::File::open(from)?;
+    let mut writer = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(to)?;
+    std::io::copy(&mut reader, &mut writer)?;
+    writer.sync_all()
 }
 
 #[cfg(test)]
@@ -265,15 +386,13 @@ mod tests {
             root: source_root.clone(),
         };
 
-        let trash_root = dir.path().join("trash");
         let cancel = Arc::new(AtomicBool::new(false));
 
         let finished = run_trash_move_task_with_progress(
             vec![source],
-            trash_root,
             cancel,
             |_| {},
-            |_source, _entry, _root| Err("Simulated IO Error".to_string()),
+            |_source, _entry| Err("Simulated IO Error".to_string()),
         );
 
         assert!(!finished.errors.is_empty());
@@ -294,15 +413,13 @@ mod tests {
             root: source_root.clone(),
         };
 
-        let trash_root = dir.path().join("trash");
         let cancel = Arc::new(AtomicBool::new(false));
 
         let finished = run_trash_move_task_with_progress(
             vec![source],
-            trash_root,
             cancel,
             |_| {},
-            |_source, _entry, _root| Ok(()),
+            |_source, _entry| Ok(TrashDisposal::Deleted),
         );
 
         assert!(finished.errors.is_empty());
@@ -310,4 +427,59 @@ mod tests {
         let files = db.list_files().unwrap();
         assert_eq!(files.len(), 0, "Should remove file from DB on success");
     }
+
+    #[test]
+    fn os_trash_uses_backend_while_folder_strategy_relocates() {
+        let dir = tempdir().unwrap();
+        let source = SampleSource {
+            id: SourceId::new(),
+            root: dir.path().join("source"),
+        };
+        fs::create_dir_all(&source.root).unwrap();
+        fs::write(source.root.join("kick.wav"), b"kick").unwrap();
+        fs::write(source.root.join("snare.wav"), b"snare").unwrap();
+        let entry = |name: &str| WavEntry {
+            relative_path: PathBuf::from(name),
+            file_size: 0,
+            modified_ns: 0,
+            content_hash: None,
+            tag: Rating::TRASH_3,
+            looped: false,
+            missing: false,
+            last_played_at: None,
+            stars: None,
+        };
+
+        let mut sent = Vec::new();
+        let disposal = dispose_trashed_with(
+            &TrashTarget::OsTrash,
+            &source,
+            &entry("kick.wav"),
+            |path: &Path| {
+                sent.push(path.to_path_buf());
+                Ok(())
+            },
+        )
+        .unwrap();
+        assert_eq!(disposal, TrashDisposal::OsTrash);
+        assert_eq!(sent, vec![source.root.join("kick.wav")]);
+
+        let trash_root = dir.path().join("trash");
+        let disposal = dispose_trashed_with(
+            &TrashTarget::Folder(trash_root.clone()),
+            &source,
+            &entry("snare.wav"),
+            |_path: &Path| panic!("folder strategy must not use the system trash"),
+        )
+        .unwrap();
+        assert_eq!(
+            disposal,
+            TrashDisposal::Moved {
+                destination: trash_root.join("snare.wav"),
+                renamed: false,
+            }
+        );
+        assert!(trash_root.join("snare.wav").is_file());
+        assert!(!source.root.join("snare.wav").exists());
+    }
 }
@@ -3,6 +3,7 @@ use crate::egui_app::controller::library::wav_io;
 use super::*;
 use crate::egui_app::view_model;
 use std::collections::HashMap;
+use crate::sample_sources::path_text::normalize_path_separators;
 use crate::waveform::DecodedWaveform;
 use std::path::{Path, PathBuf};
 
@@ -15,6 +16,10 @@ pub(crate) mod browser_search_worker;
 mod feature_cache;
 mod selection_ops;
 mod similar;
+pub(crate) use similar::rank_source_outliers;
+mod user_markers;
+mod waveform_diff;
+pub(crate) use waveform_diff::load_waveform_ab_diff;
 mod waveform_loading;
 pub mod waveform_rendering;
 
@@ -83,6 +88,14 @@ impl EguiController {
         bpm
     }
 
+    /// Describe the probed sample rate, channels and duration of a sample, for row tooltips.
+    pub(crate) fn audio_metadata_summary_for_path(&mut self, path: &Path) -> Option<String> {
+        let source = self.current_source()?;
+        let db = self.database_for(&source).ok()?;
+        let metadata = db.audio_metadata_for_path(path).ok().flatten()?;
+        format_audio_metadata(&metadata)
+    }
+
     /// Visible wav indices after applying the active sample browser filter.
     pub fn visible_browser_rows(&self) -> &crate::egui_app::state::VisibleRows {
         &self.ui.browser.visible
@@ -138,8 +151,8 @@ impl EguiController {
     }
 
     pub(crate) fn wav_index_for_path(&mut self, path: &Path) -> Option<usize> {
-        let normalized = path.to_string_lossy().replace('\\', "/");
-        if let Some(index) = self.wav_entries.lookup.get(Path::new(&normalized)).copied() {
+        let normalized = normalize_path_separators(path);
+        if let Some(index) = self.wav_entries.lookup.get(&normalized).copied() {
             return Some(index);
         }
         let source = self.current_source()?;
@@ -256,6 +269,31 @@ impl EguiController {
             .map_err(|err| format!("Failed to read database: {err}"))
     }
 
+    pub(crate) fn sample_stars_for(
+        &mut self,
+        source: &SampleSource,
+        relative_path: &Path,
+    ) -> Result<Option<u8>, String> {
+        if let Some(cache) = self.cache.wav.entries.get(&source.id) {
+            if let Some(index) = cache.lookup.get(relative_path).copied()
+                && let Some(entry) = cache.entry(index)
+            {
+                return Ok(entry.stars);
+            }
+        }
+        if self.selection_state.ctx.selected_source.as_ref() == Some(&source.id)
+            && let Some(index) = self.wav_index_for_path(relative_path)
+            && let Some(entry) = self.wav_entries.entry(index)
+        {
+            return Ok(entry.stars);
+        }
+        let db = self
+            .database_for(source)
+            .map_err(|err| format!("Database unavailable: {err}"))?;
+        db.stars_for_path(relative_path)
+            .map_err(|err| format!("Failed to read database: {err}"))
+    }
+
     /// Persist a rename or path change in the per-source database.
     pub(crate) fn rewrite_db_entry_for_source(
         &mut self,
@@ -276,6 +314,9 @@ impl EguiController {
             .looped_for_path(old_relative)
             .map_err(|err| format!("Failed to load loop marker: {err}"))?
             .unwrap_or(false);
+        let stars = db
+            .stars_for_path(old_relative)
+            .map_err(|err| format!("Failed to load star rating: {err}"))?;
         let mut batch = db
             .write_batch()
             .map_err(|err| format!("Failed to start database update: {err}"))?;
@@ -296,6 +337,9 @@ impl EguiController {
                 .set_last_played_at(new_relative, last_played_at)
                 .map_err(|err| format!("Failed to copy playback age: {err}"))?;
         }
+        batch
+            .set_stars(new_relative, stars)
+            .map_err(|err| format!("Failed to copy star rating: {err}"))?;
         batch
             .commit()
             .map_err(|err| format!("Failed to save rename: {err}"))
@@ -574,6 +618,9 @@ impl EguiController {
                     if let Some(last_played_at) = entry.last_played_at {
                         let _ = batch.set_last_played_at(&entry.relative_path, last_played_at);
                     }
+                    if entry.stars.is_some() {
+                        let _ = batch.set_stars(&entry.relative_path, entry.stars);
+                    }
                 }
                 let _ = batch.commit();
             }
@@ -612,6 +659,11 @@ impl EguiController {
         browser_search::set_browser_rating_filter(self, level, additive);
     }
 
+    /// Show only samples that analysis flagged as silent.
+    pub fn set_browser_silent_only(&mut self, silent_only: bool) {
+        browser_search::set_browser_silent_only(self, silent_only);
+    }
+
     /// Clear any active rating-level filters in the browser list.
     pub fn clear_browser_rating_filter(&mut self) {
         browser_search::clear_browser_rating_filter(self);
@@ -632,6 +684,11 @@ impl EguiController {
         browser_search::set_browser_search(self, query);
     }
 
+    /// Empty the browser search query and show the unfiltered list again.
+    pub(crate) fn clear_browser_search(&mut self) {
+        browser_search::set_browser_search(self, String::new());
+    }
+
     /// Filter the browser to show similar samples for the chosen visible row.
     pub fn find_similar_for_visible_row(&mut self, row: usize) -> Result<(), String> {
         similar::find_similar_for_visible_row(self, row)
@@ -664,6 +721,25 @@ impl EguiController {
         similar::find_similar_for_sample_id(self, sample_id)
     }
 
+    /// Filter the browser to samples similar to the average of several anchor sample_ids.
+    pub fn find_similar_for_sample_ids(&mut self, sample_ids: &[String]) -> Result<(), String> {
+        similar::find_similar_for_sample_ids(self, sample_ids)
+    }
+
+    /// Start ranking the selected source's most atypical samples in the background.
+    ///
+    /// Each sample is scored by its mean embedding distance to its nearest neighbours.
+    pub fn find_outliers_for_selected_source(&mut self) -> Result<(), String> {
+        similar::find_outliers_for_selected_source(self)
+    }
+
+    pub(crate) fn apply_outlier_scan_result(
+        &mut self,
+        message: crate::egui_app::controller::jobs::OutlierScanResult,
+    ) {
+        similar::apply_outlier_scan_result(self, message);
+    }
+
     /// Filter the browser to show similar samples for an external audio clip.
     pub fn find_similar_for_audio_path(&mut self, path: &Path) -> Result<(), String> {
         similar::find_similar_for_audio_path(self, path)
@@ -772,6 +848,65 @@ impl EguiController {
             .map(|s| s.as_str())
     }
 
+    /// Return the non-fatal analysis warning for a wav entry, if any.
+    pub fn analysis_warning_for_entry(&mut self, index: usize) -> Option<&str> {
+        let source_id = self.selection_state.ctx.selected_source.clone()?;
+        let path = self
+            .wav_entry(index)
+            .map(|entry| entry.relative_path.clone())?;
+        self.ui_cache
+            .browser
+            .analysis_warnings
+            .get(&source_id)
+            .and_then(|warnings| warnings.get(&path))
+            .map(|s| s.as_str())
+    }
+
+    /// Relative paths in the selected source that analysis flagged as silent.
+    pub(crate) fn silent_sample_paths(&self) -> Vec<PathBuf> {
+        let Some(source_id) = self.selection_state.ctx.selected_source.as_ref() else {
+            return Vec::new();
+        };
+        self.ui_cache
+            .browser
+            .silent_samples
+            .get(source_id)
+            .map(|paths| paths.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Tag every silent sample in the selected source as trash.
+    pub fn trash_silent_samples(&mut self) {
+        let Some(source) = self.current_source() else {
+            return;
+        };
+        let updates: Vec<(PathBuf, crate::sample_sources::Rating)> = self
+            .silent_sample_paths()
+            .into_iter()
+            .filter(|path| {
+                self.wav_index_for_path(path)
+                    .and_then(|index| self.wav_entry(index))
+                    .is_some_and(|entry| !entry.tag.is_trash())
+            })
+            .map(|path| (path, crate::sample_sources::Rating::TRASH_3))
+            .collect();
+        if updates.is_empty() {
+            self.set_status("No silent samples to trash", StatusTone::Info);
+            return;
+        }
+        let count = updates.len();
+        match self.set_sample_tags_for_source(&source, &updates) {
+            Ok(()) => self.set_status(
+                format!(
+                    "Tagged {count} silent sample{} as trash",
+                    if count == 1 { "" } else { "s" }
+                ),
+                StatusTone::Info,
+            ),
+            Err(err) => self.set_status(err, StatusTone::Error),
+        }
+    }
+
     /// Retrieve a cached label for a wav entry by index.
     pub fn wav_label(&mut self, index: usize) -> Option<String> {
         self.label_for_ref(index).map(str::to_string)
@@ -807,6 +942,15 @@ impl EguiController {
         selection_ops::set_sample_tag_for_source(self, source, path, target_tag, require_present)
     }
 
+    /// Apply several tag updates for one source in a single database batch.
+    pub(crate) fn set_sample_tags_for_source(
+        &mut self,
+        source: &SampleSource,
+        updates: &[(PathBuf, crate::sample_sources::Rating)],
+    ) -> Result<(), String> {
+        selection_ops::set_sample_tags_for_source(self, source, updates)
+    }
+
     /// Update the loop marker for a sample path within a specific source.
     pub(crate) fn set_sample_looped_for_source(
         &mut self,
@@ -818,5 +962,32 @@ impl EguiController {
         selection_ops::set_sample_looped_for_source(self, source, path, looped, require_present)
     }
 
+    /// Update the star rating for a sample path within a specific source.
+    pub(crate) fn set_sample_stars_for_source(
+        &mut self,
+        source: &SampleSource,
+        path: &Path,
+        stars: Option<u8>,
+    ) -> Result<(), String> {
+        selection_ops::set_sample_stars_for_source(self, source, path, stars)
+    }
+
     // waveform loading helpers moved to `waveform_loading` submodule.
 }
+
+fn format_audio_metadata(metadata: &crate::sample_sources::AudioMetadata) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(rate) = metadata.sample_rate {
+        parts.push(format!("{:.1} kHz", rate as f32 / 1000.0));
+    }
+    match metadata.channels {
+        Some(1) => parts.push("mono".to_string()),
+        Some(2) => parts.push("stereo".to_string()),
+        Some(channels) => parts.push(format!("{channels} ch")),
+        None => {}
+    }
+    if let Some(seconds) = metadata.duration_seconds {
+        parts.push(format!("{seconds:.2} s"));
+    }
+    (!parts.is_empty()).then(|| parts.join(" · "))
+}
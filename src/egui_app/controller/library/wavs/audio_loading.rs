@@ -32,6 +32,7 @@ impl EguiController {
         };
         let duration_seconds = decoded.duration_seconds;
         let sample_rate = decoded.sample_rate;
+        let clipped_samples = decoded.clipped_sample_count();
         let cache_key = CacheKey::new(&source.id, &pending.relative_path);
         if !stretched {
             self.audio
@@ -52,9 +53,12 @@ impl EguiController {
             self.set_status(err, StatusTone::Error);
             return;
         }
-        let message =
-            Self::loaded_status_text(&pending.relative_path, duration_seconds, sample_rate);
-        self.set_status(message, StatusTone::Info);
+        self.set_loaded_status(
+            &pending.relative_path,
+            duration_seconds,
+            sample_rate,
+            clipped_samples,
+        );
         if matches!(pending.intent, AudioLoadIntent::Selection) {
             self.refresh_similarity_sort_for_loaded_sample();
         }
@@ -132,6 +136,12 @@ impl EguiController {
             root: source.root.clone(),
             relative_path: relative_path.to_path_buf(),
             stretch_ratio,
+            transient_max_markers: self
+                .settings
+                .controls
+                .transient_max_markers
+                .map(|cap| cap as usize),
+            transient_tuning: self.ui.waveform.transient_tuning,
         };
         self.runtime.jobs.set_pending_audio(None);
         self.runtime.jobs.set_pending_playback(pending_playback);
@@ -180,12 +190,23 @@ impl EguiController {
         let Some(hit) = self.audio.cache.get(&key, metadata) else {
             return Ok(false);
         };
+        // Custom transient tuning needs the novelty curve, which the loader computes off the
+        // UI thread; only reuse the cached audio when that curve is already on hand.
+        let novelty_cached = self
+            .sample_view
+            .waveform
+            .transient_novelty
+            .as_ref()
+            .is_some_and(|(token, _)| *token == hit.decoded.cache_token);
+        if self.ui.waveform.transient_tuning.is_some() && !novelty_cached {
+            return Ok(false);
+        }
         let duration_seconds = hit.decoded.duration_seconds;
         let sample_rate = hit.decoded.sample_rate;
+        let clipped_samples = hit.decoded.clipped_sample_count();
         let preserve_selections = self.sample_view.wav.loaded_wav.as_deref() == Some(relative_path);
         self.finish_waveform_load(source, relative_path, hit.decoded, hit.bytes, intent, preserve_selections, None)?;
-        let message = Self::loaded_status_text(relative_path, duration_seconds, sample_rate);
-        self.set_status(message, StatusTone::Info);
+        self.set_loaded_status(relative_path, duration_seconds, sample_rate, clipped_samples);
         if matches!(intent, AudioLoadIntent::Selection) {
             self.refresh_similarity_sort_for_loaded_sample();
         }
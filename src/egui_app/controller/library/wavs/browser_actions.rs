@@ -225,6 +225,15 @@ impl EguiController {
         }
     }
 
+    /// Reveal the focused sample browser item in the OS file manager.
+    pub fn reveal_focused_sample_in_file_manager(&mut self) {
+        let Some(relative_path) = self.focused_browser_path() else {
+            self.set_status("Focus a sample to reveal it", StatusTone::Info);
+            return;
+        };
+        self.reveal_browser_sample_in_file_explorer(&relative_path);
+    }
+
     /// Clear sample browser focus/selection when another surface takes focus.
     pub fn blur_browser_focus(&mut self) {
         if matches!(self.ui.focus.context, FocusContext::Waveform) {
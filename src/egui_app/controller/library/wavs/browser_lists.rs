@@ -1,5 +1,8 @@
 use super::*;
-use crate::egui_app::state::FocusContext;
+use crate::egui_app::state::{FocusContext, VisibleRows};
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 
 impl EguiController {
     pub(crate) fn rebuild_browser_lists(&mut self) {
@@ -67,6 +70,58 @@ impl EguiController {
         {
             self.ui.browser.selection_anchor_visible = self.ui.browser.selected_visible;
         }
+        if let Some(seed) = self.ui.browser.shuffle_seed {
+            self.apply_browser_shuffle(seed);
+        }
+    }
+
+    /// Reorder the visible browser rows with a seeded shuffle.
+    ///
+    /// The same seed always yields the same order for the same visible rows, so a
+    /// review pass can be resumed. ChaCha8 keeps that order stable across `rand`
+    /// releases and platforms. The seed stays active across list rebuilds until
+    /// [`Self::clear_browser_shuffle`] is called.
+    pub fn shuffle_visible_with_seed(&mut self, seed: u64) {
+        if self.ui.browser.shuffle_seed.is_some() {
+            self.ui.browser.shuffle_seed = None;
+            self.rebuild_browser_lists();
+        }
+        self.ui.browser.shuffle_seed = Some(seed);
+        self.apply_browser_shuffle(seed);
+        let count = self.ui.browser.visible.len();
+        self.set_status(
+            format!("Shuffled {count} samples (seed {seed})"),
+            StatusTone::Info,
+        );
+    }
+
+    /// Drop the active shuffle and restore the sorted browser order.
+    pub fn clear_browser_shuffle(&mut self) {
+        if self.ui.browser.shuffle_seed.take().is_some() {
+            self.rebuild_browser_lists();
+            self.set_status("Shuffle cleared", StatusTone::Info);
+        }
+    }
+
+    /// Seed of the active browser shuffle, if any.
+    pub fn browser_shuffle_seed(&self) -> Option<u64> {
+        self.ui.browser.shuffle_seed
+    }
+
+    pub(crate) fn apply_browser_shuffle(&mut self, seed: u64) {
+        let browser = &self.ui.browser;
+        let focused = browser.selected_visible.and_then(|row| browser.visible.get(row));
+        let loaded = browser.loaded_visible.and_then(|row| browser.visible.get(row));
+        let anchor = browser
+            .selection_anchor_visible
+            .and_then(|row| browser.visible.get(row));
+        let mut rows: Vec<usize> = browser.visible.iter().collect();
+        rows.shuffle(&mut ChaCha8Rng::seed_from_u64(seed));
+        let position = |index: usize| rows.iter().position(|row| *row == index);
+        self.ui.browser.selected_visible = focused.and_then(position);
+        self.ui.browser.loaded_visible = loaded.and_then(position);
+        self.ui.browser.selection_anchor_visible = anchor.and_then(position);
+        self.ui.browser.visible = VisibleRows::List(rows);
     }
 
     pub(crate) fn selected_row_index(&mut self) -> Option<usize> {
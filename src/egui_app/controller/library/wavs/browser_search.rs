@@ -57,13 +57,18 @@ impl EguiController {
             folder_negated.as_ref(),
             root_mode,
         );
-        let folder_accepts = |relative_path: &Path| {
-            crate::egui_app::controller::library::source_folders::folder_filter_accepts(
-                relative_path,
-                folder_selection.as_ref(),
-                folder_negated.as_ref(),
-                root_mode,
-            )
+        let silent_paths = self.silent_paths_for_filter();
+        let path_accepts = |relative_path: &Path| {
+            let silent_ok = silent_paths
+                .as_ref()
+                .is_none_or(|paths| paths.contains(relative_path));
+            silent_ok
+                && crate::egui_app::controller::library::source_folders::folder_filter_accepts(
+                    relative_path,
+                    folder_selection.as_ref(),
+                    folder_negated.as_ref(),
+                    root_mode,
+                )
         };
         let sort_mode = self.ui.browser.sort;
         if let Some(similar) = self.ui.browser.similar_query.clone() {
@@ -72,7 +77,7 @@ impl EguiController {
                 let Some(entry) = self.wav_entry(index) else {
                     continue;
                 };
-                if filter_accepts(entry.tag) && folder_accepts(&entry.relative_path) {
+                if filter_accepts(entry.tag) && path_accepts(&entry.relative_path) {
                     visible.push(index);
                 }
             }
@@ -84,7 +89,9 @@ impl EguiController {
                     let mut score_lookup = vec![None; self.wav_entries_len()];
                     for (&index, &score) in similar.indices.iter().zip(similar.scores.iter()) {
                         if index < score_lookup.len() {
-                            score_lookup[index] = Some(score);
+                            // Negate scores so outlier queries list the least similar rows first.
+                            let rank = if similar.least_similar_first { -score } else { score };
+                            score_lookup[index] = Some(rank);
                         }
                     }
                     visible.sort_by(|a, b| {
@@ -103,7 +110,7 @@ impl EguiController {
                     });
                     if let Some(anchor) = similar.anchor_index {
                         if let Some(entry) = self.wav_entry(anchor) {
-                            if filter_accepts(entry.tag) && folder_accepts(&entry.relative_path) {
+                            if filter_accepts(entry.tag) && path_accepts(&entry.relative_path) {
                                 if let Some(pos) = visible.iter().position(|i| *i == anchor) {
                                     visible.remove(pos);
                                 }
@@ -118,6 +125,9 @@ impl EguiController {
                 SampleBrowserSort::PlaybackAgeDesc => {
                     sort_visible_by_playback_age(self, &mut visible, false);
                 }
+                SampleBrowserSort::RatingDesc => {
+                    sort_visible_by_stars(self, &mut visible);
+                }
             }
             let selected_visible =
                 focused_index.and_then(|idx| visible.iter().position(|i| *i == idx));
@@ -131,6 +141,7 @@ impl EguiController {
         }
         let Some(query) = self.active_search_query().map(str::to_string) else {
             if !has_folder_filters
+                && silent_paths.is_none()
                 && self.ui.browser.filter == TriageFlagFilter::All
                 && rating_filter_empty
                 && self.ui.browser.similar_query.is_none()
@@ -146,7 +157,7 @@ impl EguiController {
             let mut visible = Vec::new();
             let mut playback_scratch = Vec::new();
             let _ = self.for_each_wav_entry(|index, entry| {
-                if filter_accepts(entry.tag) && folder_accepts(&entry.relative_path) {
+                if filter_accepts(entry.tag) && path_accepts(&entry.relative_path) {
                     if matches!(
                         sort_mode,
                         SampleBrowserSort::PlaybackAgeAsc | SampleBrowserSort::PlaybackAgeDesc
@@ -172,6 +183,9 @@ impl EguiController {
                 });
                 visible = playback_scratch.into_iter().map(|(index, _)| index).collect();
             }
+            if sort_mode == SampleBrowserSort::RatingDesc {
+                sort_visible_by_stars(self, &mut visible);
+            }
             let selected_visible =
                 focused_index.and_then(|idx| visible.iter().position(|i| *i == idx));
             let loaded_visible =
@@ -188,7 +202,7 @@ impl EguiController {
         scratch.clear();
         scratch.reserve(self.wav_entries_len().min(1024));
         let _ = self.for_each_wav_entry(|index, entry| {
-            if !filter_accepts(entry.tag) || !folder_accepts(&entry.relative_path) {
+            if !filter_accepts(entry.tag) || !path_accepts(&entry.relative_path) {
                 return;
             }
             if let Some(score) = scores.get(index).and_then(|s| *s) {
@@ -213,6 +227,8 @@ impl EguiController {
         ) {
             let ascending = sort_mode == SampleBrowserSort::PlaybackAgeAsc;
             sort_visible_by_playback_age(self, &mut visible, ascending);
+        } else if sort_mode == SampleBrowserSort::RatingDesc {
+            sort_visible_by_stars(self, &mut visible);
         }
         let selected_visible = focused_index.and_then(|idx| visible.iter().position(|i| *i == idx));
         let loaded_visible = loaded_index.and_then(|idx| visible.iter().position(|i| *i == idx));
@@ -349,6 +365,7 @@ impl EguiController {
         let root_mode = self
             .root_folder_filter_mode_for_filter()
             .unwrap_or_default();
+        let silent_paths = self.silent_paths_for_filter();
 
         self.ui.browser.search_busy = true;
         self.runtime.jobs.send_search_job(crate::egui_app::controller::jobs::SearchJob {
@@ -362,8 +379,17 @@ impl EguiController {
             folder_selection,
             folder_negated,
             root_mode,
+            silent_paths,
         });
     }
+
+    /// Paths analysis flagged as silent, when the silent-only filter is active.
+    fn silent_paths_for_filter(&self) -> Option<std::collections::HashSet<std::path::PathBuf>> {
+        self.ui
+            .browser
+            .silent_only
+            .then(|| self.silent_sample_paths().into_iter().collect())
+    }
 }
 
 pub(crate) fn set_browser_filter(controller: &mut EguiController, filter: TriageFlagFilter) {
@@ -402,6 +428,14 @@ pub(crate) fn set_browser_rating_filter(
     }
 }
 
+/// Toggle the silent-only browser filter.
+pub(crate) fn set_browser_silent_only(controller: &mut EguiController, silent_only: bool) {
+    if controller.ui.browser.silent_only != silent_only {
+        controller.ui.browser.silent_only = silent_only;
+        controller.rebuild_browser_lists();
+    }
+}
+
 /// Clear all browser rating filters.
 pub(crate) fn clear_browser_rating_filter(controller: &mut EguiController) {
     if controller.ui.browser.rating_filter.is_empty() {
@@ -412,7 +446,8 @@ pub(crate) fn clear_browser_rating_filter(controller: &mut EguiController) {
 }
 
 pub(crate) fn set_browser_sort(controller: &mut EguiController, sort: SampleBrowserSort) {
-    if controller.ui.browser.sort != sort {
+    if controller.ui.browser.sort != sort || controller.ui.browser.shuffle_seed.is_some() {
+        controller.ui.browser.shuffle_seed = None;
         controller.ui.browser.sort = sort;
         if sort != SampleBrowserSort::Similarity {
             controller.ui.browser.similarity_sort_follow_loaded = false;
@@ -460,3 +495,17 @@ fn sort_visible_by_playback_age(
         order.then_with(|| a.cmp(b))
     });
 }
+
+fn sort_visible_by_stars(controller: &mut EguiController, visible: &mut Vec<usize>) {
+    visible.sort_by(|a, b| {
+        let a_key = controller
+            .wav_entry(*a)
+            .and_then(|entry| entry.stars)
+            .unwrap_or(0);
+        let b_key = controller
+            .wav_entry(*b)
+            .and_then(|entry| entry.stars)
+            .unwrap_or(0);
+        b_key.cmp(&a_key).then_with(|| a.cmp(b))
+    });
+}
@@ -16,6 +16,7 @@ struct CompactSearchEntry {
     relative_path: Box<str>,
     tag: Rating,
     last_played_at: Option<i64>,
+    stars: Option<u8>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -262,6 +263,7 @@ fn process_search_job(
                             relative_path: relative_path.into_boxed_str(),
                             tag: e.tag,
                             last_played_at: e.last_played_at,
+                            stars: e.stars,
                         }
                     })
                     .collect();
@@ -289,14 +291,19 @@ fn process_search_job(
         triage_ok && rating_ok
     };
 
-    let folder_accepts = |entry: &CompactSearchEntry| {
+    let path_accepts = |entry: &CompactSearchEntry| {
         let path = std::path::Path::new(entry.relative_path.as_ref());
-        crate::egui_app::controller::library::source_folders::folder_filter_accepts(
-            path,
-            job.folder_selection.as_ref(),
-            job.folder_negated.as_ref(),
-            job.root_mode,
-        )
+        let silent_ok = job
+            .silent_paths
+            .as_ref()
+            .is_none_or(|paths| paths.contains(path));
+        silent_ok
+            && crate::egui_app::controller::library::source_folders::folder_filter_accepts(
+                path,
+                job.folder_selection.as_ref(),
+                job.folder_negated.as_ref(),
+                job.root_mode,
+            )
     };
 
     let mut scores = vec![None; entries.len()];
@@ -313,7 +320,7 @@ fn process_search_job(
     if let Some(similar) = &job.similar_query {
         for index in similar.indices.iter().copied() {
             if let Some(entry) = entries.get(index) {
-                if filter_accepts(entry.tag) && folder_accepts(entry) {
+                if filter_accepts(entry.tag) && path_accepts(entry) {
                     visible.push(index);
                 }
             }
@@ -324,7 +331,9 @@ fn process_search_job(
                 let mut score_lookup = vec![None; entries.len()];
                 for (&index, &score) in similar.indices.iter().zip(similar.scores.iter()) {
                     if index < score_lookup.len() {
-                        score_lookup[index] = Some(score);
+                        // Negate scores so outlier queries list the least similar rows first.
+                        let rank = if similar.least_similar_first { -score } else { score };
+                        score_lookup[index] = Some(rank);
                     }
                 }
                 visible.sort_by(|a: &usize, b: &usize| {
@@ -344,7 +353,7 @@ fn process_search_job(
 
                 if let Some(anchor) = similar.anchor_index {
                     if let Some(entry) = entries.get(anchor) {
-                        if filter_accepts(entry.tag) && folder_accepts(entry) {
+                        if filter_accepts(entry.tag) && path_accepts(entry) {
                             if let Some(pos) = visible.iter().position(|i| *i == anchor) {
                                 visible.remove(pos);
                             }
@@ -359,6 +368,9 @@ fn process_search_job(
             SampleBrowserSort::PlaybackAgeDesc => {
                 sort_visible_by_playback_age(entries, &mut visible, false);
             }
+            SampleBrowserSort::RatingDesc => {
+                sort_visible_by_stars(entries, &mut visible);
+            }
             SampleBrowserSort::ListOrder => {
                 visible.sort_unstable();
             }
@@ -379,7 +391,7 @@ fn process_search_job(
             neutral.push(index);
         }
 
-        if job.similar_query.is_none() && filter_accepts(entry.tag) && folder_accepts(entry) {
+        if job.similar_query.is_none() && filter_accepts(entry.tag) && path_accepts(entry) {
             if has_query {
                 if let Some(score) = scores[index] {
                     scratch.push((index, score));
@@ -407,6 +419,7 @@ fn process_search_job(
         && job.similar_query.is_none()
         && job.sort == SampleBrowserSort::ListOrder
         && job.rating_filter.is_empty()
+        && job.silent_paths.is_none()
     {
         return SearchResult {
             source_id: job.source_id,
@@ -429,6 +442,9 @@ fn process_search_job(
             SampleBrowserSort::PlaybackAgeDesc => {
                 sort_visible_by_playback_age(entries, &mut visible, false);
             }
+            SampleBrowserSort::RatingDesc => {
+                sort_visible_by_stars(entries, &mut visible);
+            }
             _ => {}
         }
     }
@@ -479,6 +495,14 @@ fn sort_visible_by_playback_age(
     });
 }
 
+fn sort_visible_by_stars(entries: &[CompactSearchEntry], visible: &mut Vec<usize>) {
+    visible.sort_by(|a, b| {
+        let a_key = entries.get(*a).and_then(|entry| entry.stars).unwrap_or(0);
+        let b_key = entries.get(*b).and_then(|entry| entry.stars).unwrap_or(0);
+        b_key.cmp(&a_key).then_with(|| a.cmp(b))
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -500,6 +524,7 @@ mod tests {
                 looped: false,
                 missing: false,
                 last_played_at: None,
+                stars: None,
             },
             WavEntry {
                 relative_path: std::path::PathBuf::from("kits/drums/snare.wav"),
@@ -510,6 +535,7 @@ mod tests {
                 looped: false,
                 missing: false,
                 last_played_at: None,
+                stars: None,
             },
         ];
 
@@ -523,6 +549,7 @@ mod tests {
                     relative_path: relative_path.into_boxed_str(),
                     tag: e.tag,
                     last_played_at: e.last_played_at,
+                    stars: e.stars,
                 }
             })
             .collect();
@@ -607,6 +634,7 @@ mod tests {
             folder_selection: None,
             folder_negated: None,
             root_mode: crate::egui_app::state::RootFolderFilterMode::AllDescendants,
+            silent_paths: None,
         }
     }
 }
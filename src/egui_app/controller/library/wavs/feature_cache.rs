@@ -2,6 +2,7 @@ use super::*;
 use crate::egui_app::controller::controller_state::{
     AnalysisJobStatus, FeatureCache, FeatureStatus,
 };
+use crate::sample_sources::path_text::normalize_path_separators;
 use rusqlite::params;
 use std::collections::HashMap;
 use std::path::Path;
@@ -54,11 +55,11 @@ impl EguiController {
         let Some(cache) = self.ui_cache.browser.features.get_mut(source_id) else {
             return;
         };
-        let normalized = relative_path.to_string_lossy().replace('\\', "/");
+        let normalized = normalize_path_separators(relative_path);
         let Some(index) = self
             .wav_entries
             .lookup
-            .get(Path::new(&normalized))
+            .get(&normalized)
             .copied()
         else {
             return;
@@ -211,11 +212,11 @@ impl EguiController {
         let Some(cache) = self.ui_cache.browser.features.get_mut(source_id) else {
             return;
         };
-        let normalized = relative_path.to_string_lossy().replace('\\', "/");
+        let normalized = normalize_path_separators(relative_path);
         let Some(index) = self
             .wav_entries
             .lookup
-            .get(Path::new(&normalized))
+            .get(&normalized)
             .copied()
         else {
             return;
@@ -275,6 +275,49 @@ pub(crate) fn set_sample_tag_for_source(
     Ok(())
 }
 
+pub(crate) fn set_sample_tags_for_source(
+    controller: &mut EguiController,
+    source: &SampleSource,
+    updates: &[(PathBuf, Rating)],
+) -> Result<(), String> {
+    if updates.is_empty() {
+        return Ok(());
+    }
+    let db = controller.database_for(source).map_err(|err| {
+        warn!(source_id = %source.id, error = %err, "triage tag: database unavailable");
+        err.to_string()
+    })?;
+    db.set_tags_batch(updates).map_err(|err| {
+        warn!(
+            source_id = %source.id,
+            count = updates.len(),
+            error = %err,
+            "triage tag: db batch update failed"
+        );
+        format!("Failed to update tags: {err}")
+    })?;
+    let mut updated_active = false;
+    for (path, tag) in updates {
+        if let Some(index) = controller.wav_index_for_path(path) {
+            let _ = controller.ensure_wav_page_loaded(index);
+            if let Some(entry) = controller.wav_entries.entry_mut(index) {
+                entry.tag = *tag;
+                updated_active = true;
+            }
+        }
+        if let Some(cache) = controller.cache.wav.entries.get_mut(&source.id)
+            && let Some(index) = cache.lookup.get(path).copied()
+            && let Some(entry) = cache.entry_mut(index)
+        {
+            entry.tag = *tag;
+        }
+    }
+    if updated_active {
+        controller.rebuild_browser_lists();
+    }
+    Ok(())
+}
+
 pub(crate) fn set_sample_looped_for_source(
     controller: &mut EguiController,
     source: &SampleSource,
@@ -337,3 +380,45 @@ pub(crate) fn set_sample_looped_for_source(
     }
     Ok(())
 }
+
+pub(crate) fn set_sample_stars_for_source(
+    controller: &mut EguiController,
+    source: &SampleSource,
+    path: &Path,
+    stars: Option<u8>,
+) -> Result<(), String> {
+    let stars = stars.map(|stars| stars.clamp(1, crate::sample_sources::MAX_STARS));
+    let db = controller.database_for(source).map_err(|err| {
+        warn!(source_id = %source.id, error = %err, "star rating: database unavailable");
+        err.to_string()
+    })?;
+    db.set_stars(path, stars).map_err(|err| {
+        warn!(
+            source_id = %source.id,
+            path = %path.display(),
+            error = %err,
+            "star rating: db set_stars failed"
+        );
+        format!("Failed to save rating: {err}")
+    })?;
+    let mut updated_active = false;
+    if let Some(index) = controller.wav_index_for_path(path) {
+        let _ = controller.ensure_wav_page_loaded(index);
+        if let Some(entry) = controller.wav_entries.entry_mut(index) {
+            entry.stars = stars;
+            updated_active = true;
+        }
+    }
+    if let Some(cache) = controller.cache.wav.entries.get_mut(&source.id)
+        && let Some(index) = cache.lookup.get(path).copied()
+        && let Some(entry) = cache.entry_mut(index)
+    {
+        entry.stars = stars;
+    }
+    if updated_active
+        && controller.ui.browser.sort == crate::egui_app::state::SampleBrowserSort::RatingDesc
+    {
+        controller.rebuild_browser_lists();
+    }
+    Ok(())
+}
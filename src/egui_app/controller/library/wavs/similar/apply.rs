@@ -43,6 +43,7 @@ mod tests {
             indices: vec![0],
             scores: vec![0.5],
             anchor_index: Some(2),
+            least_similar_first: false,
         };
         apply_similarity_query(&mut controller, query);
         let applied = controller.ui.browser.similar_query.as_ref().unwrap();
@@ -3,11 +3,10 @@ use crate::egui_app::state::FocusedSimilarity;
 use crate::egui_app::view_model;
 
 mod apply;
+mod outliers;
 mod query;
 mod resolve;
 
-const DEFAULT_SIMILAR_COUNT: usize = 40;
-const SIMILAR_RE_RANK_CANDIDATES: usize = 200;
 const EMBED_WEIGHT: f32 = 0.8;
 const DSP_WEIGHT: f32 = 0.2;
 const DUPLICATE_SCORE_THRESHOLD: f32 = 0.995;
@@ -61,6 +60,66 @@ pub(crate) fn find_similar_for_sample_id(
     )
 }
 
+pub(crate) fn find_similar_for_sample_ids(
+    controller: &mut EguiController,
+    sample_ids: &[String],
+) -> Result<(), String> {
+    let query = query::build_similarity_query_for_sample_ids(controller, sample_ids)?;
+    apply::apply_similarity_query(controller, query);
+    Ok(())
+}
+
+pub(crate) use outliers::rank_source_outliers;
+
+pub(crate) fn find_outliers_for_selected_source(
+    controller: &mut EguiController,
+) -> Result<(), String> {
+    if controller.runtime.jobs.outlier_scan_in_progress() {
+        return Err("Already searching for acoustically unique samples".to_string());
+    }
+    let source = controller
+        .current_source()
+        .ok_or_else(|| "No active source selected".to_string())?;
+    controller
+        .runtime
+        .jobs
+        .begin_outlier_scan(crate::egui_app::controller::jobs::OutlierScanJob {
+            source_id: source.id,
+            source_root: source.root,
+        });
+    controller.set_status(
+        "Searching for acoustically unique samples…".to_string(),
+        StatusTone::Busy,
+    );
+    Ok(())
+}
+
+pub(crate) fn apply_outlier_scan_result(
+    controller: &mut EguiController,
+    message: crate::egui_app::controller::jobs::OutlierScanResult,
+) {
+    controller.runtime.jobs.clear_outlier_scan();
+    if controller.selection_state.ctx.selected_source.as_ref() != Some(&message.source_id) {
+        return;
+    }
+    let query = message
+        .result
+        .and_then(|ranked| outliers::build_outlier_query(controller, &message.source_id, ranked));
+    match query {
+        Ok(query) => {
+            let count = query.indices.len();
+            apply::apply_similarity_query(controller, query);
+            controller.set_status(
+                format!("Found {count} acoustically unique samples"),
+                StatusTone::Info,
+            );
+        }
+        Err(err) => {
+            controller.set_status(format!("Find outliers failed: {err}"), StatusTone::Error);
+        }
+    }
+}
+
 pub(crate) fn clear_similar_filter(controller: &mut EguiController) {
     apply::clear_similar_filter(controller);
 }
@@ -164,8 +223,40 @@ pub(crate) fn refresh_similarity_sort_for_loaded(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::egui_app::controller::jobs::OutlierScanResult;
+    use crate::egui_app::controller::test_support::{
+        prepare_with_source_and_wav_entries, sample_entry,
+    };
     use std::path::PathBuf;
 
+    #[test]
+    fn outlier_scan_result_lists_least_similar_rows_first() {
+        let (mut controller, source) = prepare_with_source_and_wav_entries(vec![
+            sample_entry("common.wav", crate::sample_sources::Rating::NEUTRAL),
+            sample_entry("unique.wav", crate::sample_sources::Rating::NEUTRAL),
+        ]);
+        let ranked = vec![
+            (format!("{}::unique.wav", source.id.as_str()), 0.6),
+            (format!("{}::common.wav", source.id.as_str()), 0.1),
+        ];
+
+        apply_outlier_scan_result(
+            &mut controller,
+            OutlierScanResult {
+                source_id: source.id.clone(),
+                result: Ok(ranked),
+            },
+        );
+
+        let query = controller.ui.browser.similar_query.as_ref().expect("query");
+        assert!(query.least_similar_first);
+        assert!((query.scores[0] - 0.4).abs() < 1.0e-6);
+        assert!((query.scores[1] - 0.9).abs() < 1.0e-6);
+        let unique = controller.wav_index_for_path(Path::new("unique.wav"));
+        assert_eq!(controller.visible_browser_index(0), unique);
+        assert!(!controller.runtime.jobs.outlier_scan_in_progress());
+    }
+
     #[test]
     fn focused_similarity_from_resolved_skips_anchor() {
         let resolved = resolve::ResolvedSimilarity {
@@ -0,0 +1,154 @@
+use super::*;
+use crate::analysis::ann_index::SimilarNeighbor;
+use crate::egui_app::state::SimilarQuery;
+use rusqlite::params;
+
+/// Neighbours averaged per sample when scoring how atypical it is.
+const OUTLIER_NEIGHBOURS: usize = 8;
+
+/// Rank a source's analyzed samples by mean neighbour distance, most atypical first.
+///
+/// Runs one ANN query per sample, so callers run it on a background job.
+pub(crate) fn rank_source_outliers(
+    source_id: &SourceId,
+    source_root: &Path,
+) -> Result<Vec<(String, f32)>, String> {
+    let conn = super::analysis_jobs::open_source_db(source_root)?;
+    let sample_ids = load_source_embedding_ids(&conn, source_id)?;
+    if sample_ids.len() < 2 {
+        return Err("Need at least two analyzed samples to find outliers".to_string());
+    }
+    rank_outliers(&sample_ids, |sample_id| {
+        crate::analysis::ann_index::find_similar(&conn, sample_id, OUTLIER_NEIGHBOURS)
+    })
+}
+
+/// Build a least-similar-first browser query from ranked outliers.
+///
+/// Mean cosine distances are converted to neighbour similarities so `scores` keeps
+/// the same meaning as every other similarity query.
+pub(crate) fn build_outlier_query(
+    controller: &mut EguiController,
+    source_id: &SourceId,
+    ranked: Vec<(String, f32)>,
+) -> Result<SimilarQuery, String> {
+    let result_count = controller.similar_result_count();
+    let mut indices = Vec::new();
+    let mut scores = Vec::new();
+    for (sample_id, mean_distance) in ranked {
+        let (_, relative_path) = super::analysis_jobs::parse_sample_id(&sample_id)?;
+        if let Some(index) = controller.wav_index_for_path(&relative_path) {
+            indices.push(index);
+            scores.push(similarity_from_distance(mean_distance));
+            if indices.len() >= result_count {
+                break;
+            }
+        }
+    }
+    if indices.is_empty() {
+        return Err("No analyzed samples found in the current source".to_string());
+    }
+    Ok(SimilarQuery {
+        sample_id: format!("outliers::{}", source_id.as_str()),
+        label: "Acoustically unique".to_string(),
+        indices,
+        scores,
+        anchor_index: None,
+        least_similar_first: true,
+    })
+}
+
+/// Convert an ANN cosine distance into a cosine similarity.
+fn similarity_from_distance(distance: f32) -> f32 {
+    (1.0 - distance).clamp(-1.0, 1.0)
+}
+
+fn load_source_embedding_ids(
+    conn: &rusqlite::Connection,
+    source_id: &SourceId,
+) -> Result<Vec<String>, String> {
+    let mut stmt = conn
+        .prepare("SELECT sample_id FROM embeddings WHERE model_id = ?1 ORDER BY sample_id")
+        .map_err(|err| format!("Load embeddings failed: {err}"))?;
+    let rows = stmt
+        .query_map(params![crate::analysis::similarity::SIMILARITY_MODEL_ID], |row| {
+            row.get::<_, String>(0)
+        })
+        .map_err(|err| format!("Load embeddings failed: {err}"))?;
+    let mut sample_ids = Vec::new();
+    for row in rows {
+        let sample_id = row.map_err(|err| format!("Load embeddings failed: {err}"))?;
+        let (candidate_source, _) = super::analysis_jobs::parse_sample_id(&sample_id)?;
+        if candidate_source.as_str() == source_id.as_str() {
+            sample_ids.push(sample_id);
+        }
+    }
+    Ok(sample_ids)
+}
+
+/// Rank samples by mean distance to their nearest neighbours, most atypical first.
+fn rank_outliers(
+    sample_ids: &[String],
+    mut nearest: impl FnMut(&str) -> Result<Vec<SimilarNeighbor>, String>,
+) -> Result<Vec<(String, f32)>, String> {
+    let mut ranked = Vec::with_capacity(sample_ids.len());
+    for sample_id in sample_ids {
+        let neighbours: Vec<f32> = nearest(sample_id)?
+            .into_iter()
+            .filter(|neighbour| {
+                neighbour.sample_id != *sample_id && neighbour.distance.is_finite()
+            })
+            .map(|neighbour| neighbour.distance)
+            .collect();
+        if neighbours.is_empty() {
+            continue;
+        }
+        let mean = neighbours.iter().sum::<f32>() / neighbours.len() as f32;
+        ranked.push((sample_id.clone(), mean));
+    }
+    ranked.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.0.cmp(&b.0))
+    });
+    Ok(ranked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::resolve::{cosine_similarity, normalize_l2};
+    use super::*;
+
+    #[test]
+    fn distinct_embedding_ranks_as_top_outlier() {
+        let embeddings: Vec<(String, Vec<f32>)> = vec![
+            ("s::kick1.wav".to_string(), normalize_l2(vec![1.0, 0.05, 0.0])),
+            ("s::kick2.wav".to_string(), normalize_l2(vec![1.0, 0.0, 0.05])),
+            ("s::kick3.wav".to_string(), normalize_l2(vec![0.95, 0.05, 0.05])),
+            ("s::kick4.wav".to_string(), normalize_l2(vec![1.0, 0.02, 0.02])),
+            ("s::noise.wav".to_string(), normalize_l2(vec![0.0, 0.1, 1.0])),
+        ];
+        let sample_ids: Vec<String> = embeddings.iter().map(|(id, _)| id.clone()).collect();
+        let brute_force = |query: &str| {
+            let (_, query_vec) = embeddings.iter().find(|(id, _)| id == query).unwrap();
+            let mut neighbours: Vec<SimilarNeighbor> = embeddings
+                .iter()
+                .filter(|(id, _)| id != query)
+                .map(|(id, vec)| SimilarNeighbor {
+                    sample_id: id.clone(),
+                    distance: 1.0 - cosine_similarity(query_vec, vec),
+                })
+                .collect();
+            neighbours.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap());
+            neighbours.truncate(2);
+            Ok(neighbours)
+        };
+
+        let ranked = rank_outliers(&sample_ids, brute_force).unwrap();
+
+        assert_eq!(ranked.len(), 5);
+        assert_eq!(ranked[0].0, "s::noise.wav");
+        assert!(ranked[0].1 > ranked[1].1 * 5.0);
+        assert!(similarity_from_distance(ranked[0].1) < similarity_from_distance(ranked[1].1));
+    }
+}
@@ -1,12 +1,13 @@
 use super::resolve::{
-    ResolvedSimilarity, cosine_similarity, load_embedding_for_sample, load_light_dsp_for_sample,
-    normalize_l2, open_source_db_for_id, rerank_with_dsp,
+    ResolvedSimilarity, cosine_similarity, exclude_seen, hidden_seen_sample_ids,
+    load_embedding_for_sample, load_light_dsp_for_sample, normalize_l2, open_source_db_for_id,
+    rerank_with_dsp,
 };
 use super::*;
 use crate::egui_app::state::SimilarQuery;
 use crate::egui_app::view_model;
 use rusqlite::params;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub(crate) fn build_similar_query_for_sample_id(
     controller: &mut EguiController,
@@ -57,7 +58,7 @@ pub(crate) fn build_similarity_query_for_loaded_sample(
     })?;
     let mut stmt = conn
         .prepare(
-            "SELECT embeddings.sample_id, embeddings.vec, features.vec_blob
+            "SELECT embeddings.sample_id, embeddings.dtype, embeddings.vec, features.vec_blob
              FROM embeddings
              LEFT JOIN features ON features.sample_id = embeddings.sample_id
              WHERE embeddings.model_id = ?1",
@@ -73,12 +74,15 @@ pub(crate) fn build_similarity_query_for_loaded_sample(
         let candidate_id: String = row
             .get(0)
             .map_err(|err| format!("Load embeddings failed: {err}"))?;
-        let blob: Vec<u8> = row
+        let dtype: String = row
             .get(1)
             .map_err(|err| format!("Load embeddings failed: {err}"))?;
-        let features_blob: Option<Vec<u8>> = row
+        let blob: Vec<u8> = row
             .get(2)
             .map_err(|err| format!("Load embeddings failed: {err}"))?;
+        let features_blob: Option<Vec<u8>> = row
+            .get(3)
+            .map_err(|err| format!("Load embeddings failed: {err}"))?;
         let (candidate_source, relative_path) =
             super::analysis_jobs::parse_sample_id(&candidate_id)?;
         if candidate_source.as_str() != source_id.as_str() {
@@ -87,8 +91,7 @@ pub(crate) fn build_similarity_query_for_loaded_sample(
         let Some(index) = path_lookup.get(&relative_path).copied() else {
             continue;
         };
-        let candidate =
-            crate::analysis::decode_f32_le_blob(&blob).map_err(|err| err.to_string())?;
+        let candidate = crate::analysis::vector::decode_embedding_blob(&blob, &dtype)?;
         let embed_sim = cosine_similarity(&query_embedding, &candidate).clamp(-1.0, 1.0);
         let dsp_sim = query_dsp.as_deref().and_then(|query_dsp| {
             features_blob
@@ -126,6 +129,7 @@ pub(crate) fn build_similarity_query_for_loaded_sample(
         indices,
         scores,
         anchor_index,
+        least_similar_first: false,
     })
 }
 
@@ -143,12 +147,14 @@ pub(crate) fn build_similarity_query_for_audio_path(
     let embedding = crate::analysis::similarity::embedding_from_features(&features)?;
     let query_dsp = crate::analysis::light_dsp_from_features_v1(&features).map(normalize_l2);
     let conn = open_source_db_for_id(controller, &source_id)?;
+    let result_count = controller.similar_result_count();
     let neighbours = crate::analysis::ann_index::find_similar_for_embedding(
         &conn,
         &embedding,
-        SIMILAR_RE_RANK_CANDIDATES,
+        controller.similar_rerank_candidates(),
     )?;
     let ranked = rerank_with_dsp(&conn, neighbours, Some(&embedding), query_dsp.as_deref())?;
+    let ranked = exclude_seen(ranked, hidden_seen_sample_ids(controller), None);
 
     let mut indices = Vec::new();
     let mut scores = Vec::new();
@@ -161,7 +167,7 @@ pub(crate) fn build_similarity_query_for_audio_path(
         if let Some(index) = controller.wav_index_for_path(&relative_path) {
             indices.push(index);
             scores.push(score);
-            if indices.len() >= DEFAULT_SIMILAR_COUNT {
+            if indices.len() >= result_count {
                 break;
             }
         }
@@ -180,9 +186,130 @@ pub(crate) fn build_similarity_query_for_audio_path(
         indices,
         scores,
         anchor_index: None,
+        least_similar_first: false,
     })
 }
 
+pub(crate) fn build_similarity_query_for_sample_ids(
+    controller: &mut EguiController,
+    sample_ids: &[String],
+) -> Result<SimilarQuery, String> {
+    let first = sample_ids
+        .first()
+        .ok_or_else(|| "Select samples to find similar".to_string())?;
+    let (source_id, first_path) = super::analysis_jobs::parse_sample_id(first)?;
+    for sample_id in sample_ids {
+        let (candidate_source, _) = super::analysis_jobs::parse_sample_id(sample_id)?;
+        if candidate_source != source_id {
+            return Err("Pick anchors from a single source to find similar".to_string());
+        }
+    }
+    let source_id = SourceId::from_string(source_id);
+    if controller.selection_state.ctx.selected_source.as_ref() != Some(&source_id) {
+        controller.select_source(Some(source_id.clone()));
+    }
+    let conn = open_source_db_for_id(controller, &source_id)?;
+    let result_count = controller.similar_result_count();
+    let ranked =
+        rank_for_anchor_centroid(&conn, sample_ids, controller.similar_rerank_candidates())?;
+    let ranked = exclude_seen(ranked, hidden_seen_sample_ids(controller), None);
+    let mut indices = Vec::new();
+    let mut scores = Vec::new();
+    for (candidate_id, score) in ranked {
+        let (candidate_source, relative_path) =
+            super::analysis_jobs::parse_sample_id(&candidate_id)?;
+        if candidate_source.as_str() != source_id.as_str() {
+            continue;
+        }
+        if let Some(index) = controller.wav_index_for_path(&relative_path) {
+            indices.push(index);
+            scores.push(score);
+            if indices.len() >= result_count {
+                break;
+            }
+        }
+    }
+    if indices.is_empty() {
+        return Err("No similar samples found in the current source".to_string());
+    }
+    let label = if sample_ids.len() == 1 {
+        view_model::sample_display_label(&first_path)
+    } else {
+        format!("Like {} samples", sample_ids.len())
+    };
+    Ok(SimilarQuery {
+        sample_id: first.clone(),
+        label,
+        indices,
+        scores,
+        anchor_index: None,
+        least_similar_first: false,
+    })
+}
+
+/// Average and re-normalize anchor embeddings into a single query vector.
+fn average_embeddings(embeddings: &[Vec<f32>]) -> Option<Vec<f32>> {
+    let dim = embeddings.first()?.len();
+    let mut sum = vec![0.0_f32; dim];
+    for embedding in embeddings {
+        if embedding.len() != dim {
+            return None;
+        }
+        for (acc, value) in sum.iter_mut().zip(embedding) {
+            *acc += value;
+        }
+    }
+    let centroid = normalize_l2(sum);
+    centroid.iter().any(|value| *value != 0.0).then_some(centroid)
+}
+
+/// Search once around the centroid of the anchors and rank candidates, excluding the anchors.
+fn rank_for_anchor_centroid(
+    conn: &rusqlite::Connection,
+    sample_ids: &[String],
+    candidates: usize,
+) -> Result<Vec<(String, f32)>, String> {
+    let mut embeddings = Vec::with_capacity(sample_ids.len());
+    let mut dsp_vectors = Vec::with_capacity(sample_ids.len());
+    for sample_id in sample_ids {
+        let embedding = load_embedding_for_sample(conn, sample_id)?
+            .ok_or_else(|| format!("Similarity data missing for {sample_id}"))?;
+        embeddings.push(embedding);
+        if let Some(dsp) = load_light_dsp_for_sample(conn, sample_id)? {
+            dsp_vectors.push(dsp);
+        }
+    }
+    let query = average_embeddings(&embeddings)
+        .ok_or_else(|| "Anchor embeddings cancel out".to_string())?;
+    // Only blend DSP similarity when every anchor contributed a DSP vector.
+    let query_dsp = if dsp_vectors.len() == sample_ids.len() {
+        average_embeddings(&dsp_vectors)
+    } else {
+        None
+    };
+    let neighbours = crate::analysis::ann_index::find_similar_for_embedding(
+        conn,
+        &query,
+        candidates + sample_ids.len(),
+    )?;
+    rank_neighbours_for_query(conn, neighbours, &query, query_dsp.as_deref(), sample_ids)
+}
+
+fn rank_neighbours_for_query(
+    conn: &rusqlite::Connection,
+    neighbours: Vec<crate::analysis::ann_index::SimilarNeighbor>,
+    query: &[f32],
+    query_dsp: Option<&[f32]>,
+    anchors: &[String],
+) -> Result<Vec<(String, f32)>, String> {
+    let anchors: HashSet<&str> = anchors.iter().map(String::as_str).collect();
+    let neighbours = neighbours
+        .into_iter()
+        .filter(|neighbour| !anchors.contains(neighbour.sample_id.as_str()))
+        .collect();
+    rerank_with_dsp(conn, neighbours, Some(query), query_dsp)
+}
+
 fn build_similar_query_from_resolved(
     controller: &mut EguiController,
     resolved: ResolvedSimilarity,
@@ -195,6 +322,7 @@ fn build_similar_query_from_resolved(
         indices: resolved.indices,
         scores: resolved.scores,
         anchor_index: resolve_anchor_index(controller, &resolved.relative_path, anchor_override),
+        least_similar_first: false,
     }
 }
 
@@ -213,6 +341,67 @@ mod tests {
         prepare_with_source_and_wav_entries, sample_entry,
     };
 
+    fn unit(dim: usize, axes: &[(usize, f32)]) -> Vec<f32> {
+        let mut values = vec![0.0; dim];
+        for (axis, weight) in axes {
+            values[*axis] = *weight;
+        }
+        normalize_l2(values)
+    }
+
+    #[test]
+    fn averaged_anchors_rank_centroid_neighbour_first() {
+        let dim = crate::analysis::similarity::SIMILARITY_DIM;
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE embeddings (
+                sample_id TEXT PRIMARY KEY,
+                model_id TEXT NOT NULL,
+                dtype TEXT NOT NULL DEFAULT 'f32',
+                vec BLOB NOT NULL
+            );",
+        )
+        .unwrap();
+        let samples = [
+            ("s::a.wav", unit(dim, &[(0, 1.0)])),
+            ("s::b.wav", unit(dim, &[(1, 1.0)])),
+            ("s::c.wav", unit(dim, &[(2, 1.0)])),
+            ("s::center.wav", unit(dim, &[(0, 1.0), (1, 1.0), (2, 1.0), (3, 0.2)])),
+            ("s::edge.wav", unit(dim, &[(0, 1.0), (3, 0.1)])),
+            ("s::far.wav", unit(dim, &[(4, 1.0)])),
+        ];
+        for (sample_id, embedding) in &samples {
+            conn.execute(
+                "INSERT INTO embeddings (sample_id, model_id, vec) VALUES (?1, ?2, ?3)",
+                params![
+                    sample_id,
+                    crate::analysis::similarity::SIMILARITY_MODEL_ID,
+                    crate::analysis::vector::encode_f32_le_blob(embedding)
+                ],
+            )
+            .unwrap();
+        }
+        let anchors: Vec<String> = ["s::a.wav", "s::b.wav", "s::c.wav"]
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+        let embeddings: Vec<Vec<f32>> = samples[..3].iter().map(|(_, e)| e.clone()).collect();
+        let query = average_embeddings(&embeddings).expect("centroid");
+        let neighbours = samples
+            .iter()
+            .map(|(sample_id, _)| crate::analysis::ann_index::SimilarNeighbor {
+                sample_id: sample_id.to_string(),
+                distance: 0.0,
+            })
+            .collect();
+
+        let ranked = rank_neighbours_for_query(&conn, neighbours, &query, None, &anchors).unwrap();
+
+        let order: Vec<&str> = ranked.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(order, vec!["s::center.wav", "s::edge.wav", "s::far.wav"]);
+        assert!(ranked[0].1 > 0.95);
+    }
+
     #[test]
     fn resolve_anchor_index_prefers_override() {
         let (mut controller, _source) =
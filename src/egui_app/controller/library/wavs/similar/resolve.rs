@@ -1,5 +1,7 @@
 use super::*;
+use crate::sample_sources::path_text::encode_path_text;
 use rusqlite::{OptionalExtension, params};
+use std::collections::HashSet;
 
 pub(crate) struct ResolvedSimilarity {
     pub sample_id: String,
@@ -74,8 +76,12 @@ pub(crate) fn resolve_similarity_for_sample_id(
             }
         }
     }
-    let neighbours =
-        crate::analysis::ann_index::find_similar(&conn, sample_id, SIMILAR_RE_RANK_CANDIDATES)?;
+    let result_count = controller.similar_result_count();
+    let neighbours = crate::analysis::ann_index::find_similar(
+        &conn,
+        sample_id,
+        controller.similar_rerank_candidates(),
+    )?;
     let query_embedding = load_embedding_for_sample(&conn, sample_id)?;
     let query_dsp = load_light_dsp_for_sample(&conn, sample_id)?;
     let ranked = rerank_with_dsp(
@@ -84,8 +90,13 @@ pub(crate) fn resolve_similarity_for_sample_id(
         query_embedding.as_deref(),
         query_dsp.as_deref(),
     )?;
+    let ranked = if score_cutoff.is_none() {
+        exclude_seen(ranked, hidden_seen_sample_ids(controller), Some(sample_id))
+    } else {
+        ranked
+    };
     let (indices, scores) =
-        filter_ranked_candidates(&conn, ranked, &source_id, score_cutoff, |path| {
+        filter_ranked_candidates(&conn, ranked, &source_id, score_cutoff, result_count, |path| {
             controller.wav_index_for_path(path)
         })?;
     Ok(ResolvedSimilarity {
@@ -146,6 +157,30 @@ pub(crate) fn rerank_with_dsp(
     Ok(scored)
 }
 
+/// Auditioned sample ids to exclude from similar results, when "hide seen" is enabled.
+pub(crate) fn hidden_seen_sample_ids(controller: &EguiController) -> Option<&HashSet<String>> {
+    controller
+        .ui
+        .browser
+        .similar_hide_seen
+        .then_some(&controller.audio.auditioned_sample_ids)
+}
+
+/// Drop ranked candidates found in `seen`, always keeping the query `anchor` itself.
+pub(crate) fn exclude_seen(
+    ranked: Vec<(String, f32)>,
+    seen: Option<&HashSet<String>>,
+    anchor: Option<&str>,
+) -> Vec<(String, f32)> {
+    let Some(seen) = seen.filter(|seen| !seen.is_empty()) else {
+        return ranked;
+    };
+    ranked
+        .into_iter()
+        .filter(|(sample_id, _)| anchor == Some(sample_id.as_str()) || !seen.contains(sample_id))
+        .collect()
+}
+
 pub(crate) fn load_light_dsp_for_sample(
     conn: &rusqlite::Connection,
     sample_id: &str,
@@ -192,18 +227,18 @@ pub(crate) fn load_embedding_for_sample(
     conn: &rusqlite::Connection,
     sample_id: &str,
 ) -> Result<Option<Vec<f32>>, String> {
-    let blob: Option<Vec<u8>> = conn
+    let row: Option<(String, Vec<u8>)> = conn
         .query_row(
-            "SELECT vec FROM embeddings WHERE sample_id = ?1 AND model_id = ?2",
+            "SELECT dtype, vec FROM embeddings WHERE sample_id = ?1 AND model_id = ?2",
             params![sample_id, crate::analysis::similarity::SIMILARITY_MODEL_ID],
-            |row| row.get(0),
+            |row| Ok((row.get(0)?, row.get(1)?)),
         )
         .optional()
         .map_err(|err| format!("Load embedding failed: {err}"))?;
-    let Some(blob) = blob else {
+    let Some((dtype, blob)) = row else {
         return Ok(None);
     };
-    crate::analysis::decode_f32_le_blob(&blob).map(Some)
+    crate::analysis::vector::decode_embedding_blob(&blob, &dtype).map(Some)
 }
 
 pub(crate) fn normalize_l2(mut values: Vec<f32>) -> Vec<f32> {
@@ -241,6 +276,7 @@ fn filter_ranked_candidates(
     ranked: impl IntoIterator<Item = (String, f32)>,
     source_id: &SourceId,
     score_cutoff: Option<f32>,
+    limit: usize,
     mut resolve_index: impl FnMut(&Path) -> Option<usize>,
 ) -> Result<(Vec<usize>, Vec<f32>), String> {
     let mut indices = Vec::new();
@@ -267,7 +303,7 @@ fn filter_ranked_candidates(
         if let Some(index) = resolve_index(&relative_path) {
             indices.push(index);
             scores.push(score);
-            if indices.len() >= DEFAULT_SIMILAR_COUNT {
+            if indices.len() >= limit {
                 break;
             }
         }
@@ -316,7 +352,7 @@ fn maybe_enqueue_full_analysis(
         return Ok(());
     }
     let (source_id, relative_path) = super::analysis_jobs::parse_sample_id(sample_id)?;
-    let relative_path = relative_path.to_string_lossy().replace('\\', "/");
+    let relative_path = encode_path_text(&relative_path);
     let created_at = now_epoch_seconds();
     conn.execute(
         "INSERT INTO analysis_jobs (sample_id, source_id, relative_path, job_type, content_hash, status, attempts, created_at)
@@ -328,7 +364,8 @@ fn maybe_enqueue_full_analysis(
             status = 'pending',
             attempts = 0,
             created_at = excluded.created_at,
-            last_error = NULL",
+            last_error = NULL,
+            retry_at = NULL",
         params![
             sample_id,
             source_id,
@@ -521,6 +558,26 @@ mod tests {
         assert!(scores.is_empty());
     }
 
+    #[test]
+    fn exclude_seen_drops_auditioned_neighbours_but_keeps_anchor() {
+        let ranked = vec![
+            ("s::anchor.wav".to_string(), 1.0),
+            ("s::heard.wav".to_string(), 0.9),
+            ("s::fresh.wav".to_string(), 0.8),
+        ];
+        let seen: HashSet<String> = ["s::anchor.wav", "s::heard.wav"]
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+
+        let kept = exclude_seen(ranked.clone(), Some(&seen), Some("s::anchor.wav"));
+        let ids: Vec<&str> = kept.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["s::anchor.wav", "s::fresh.wav"]);
+
+        let unfiltered = exclude_seen(ranked.clone(), None, None);
+        assert_eq!(unfiltered, ranked);
+    }
+
     #[test]
     fn resolve_sample_id_for_visible_row_errors_on_empty_visible_rows() {
         let (mut controller, _source) = dummy_controller();
@@ -536,4 +593,38 @@ mod tests {
         let err = resolve_sample_id_for_visible_row(&mut controller, 0).unwrap_err();
         assert_eq!(err, "Sample entry missing");
     }
+
+    fn ranked_candidates(count: usize) -> Vec<(String, f32)> {
+        (0..count)
+            .map(|i| (format!("s::{i}.wav"), 1.0 - i as f32 * 0.001))
+            .collect()
+    }
+
+    #[test]
+    fn larger_result_count_returns_up_to_that_many_candidates() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        let source_id = SourceId::from_string("s");
+        let resolve = |path: &Path| -> Option<usize> {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse().ok())
+        };
+
+        let (indices, _) =
+            filter_ranked_candidates(&conn, ranked_candidates(150), &source_id, None, 40, resolve)
+                .unwrap();
+        assert_eq!(indices.len(), 40);
+
+        let (indices, scores) =
+            filter_ranked_candidates(&conn, ranked_candidates(150), &source_id, None, 100, resolve)
+                .unwrap();
+        assert_eq!(indices.len(), 100);
+        assert_eq!(scores.len(), 100);
+        assert_eq!(indices, (0..100).collect::<Vec<_>>());
+
+        let (indices, _) =
+            filter_ranked_candidates(&conn, ranked_candidates(60), &source_id, None, 100, resolve)
+                .unwrap();
+        assert_eq!(indices.len(), 60);
+    }
 }
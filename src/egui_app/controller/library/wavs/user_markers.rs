@@ -0,0 +1,130 @@
+use super::*;
+use crate::egui_app::controller::undo;
+use std::path::Path;
+
+/// Markers closer than this (normalized) are treated as the same point.
+const MARKER_MERGE_EPSILON: f32 = 1e-4;
+/// Furthest (normalized) distance from the cursor at which a marker can be removed.
+const MARKER_REMOVE_RADIUS: f32 = 0.01;
+/// Cap on the removal radius as a fraction of the visible view, so zoomed-in clicks
+/// never reach markers that are off-screen.
+const MARKER_REMOVE_VIEW_FRACTION: f32 = 0.03;
+
+impl EguiController {
+    /// Drop a user marker at `position` on the loaded sample and persist it.
+    pub(crate) fn add_marker(&mut self, position: f32) -> Result<(), String> {
+        if !position.is_finite() {
+            return Err("Invalid marker position".into());
+        }
+        let position = position.clamp(0.0, 1.0);
+        let before = self.ui.waveform.user_markers.clone();
+        if before
+            .iter()
+            .any(|marker| (marker - position).abs() < MARKER_MERGE_EPSILON)
+        {
+            return Ok(());
+        }
+        let mut after = before.clone();
+        after.push(position);
+        after.sort_by(f32::total_cmp);
+        self.commit_user_markers("Add marker", before, after)
+    }
+
+    /// Remove the user marker nearest to `position` if one lies within the removal radius,
+    /// returning the removed position.
+    pub(crate) fn remove_nearest_marker(&mut self, position: f32) -> Result<Option<f32>, String> {
+        let before = self.ui.waveform.user_markers.clone();
+        let Some(nearest) = crate::waveform::transients::nearest_transient(&before, position)
+            .filter(|marker| (marker - position).abs() <= self.marker_remove_radius())
+        else {
+            return Ok(None);
+        };
+        let after: Vec<f32> = before
+            .iter()
+            .copied()
+            .filter(|marker| *marker != nearest)
+            .collect();
+        self.commit_user_markers("Remove marker", before, after)?;
+        Ok(Some(nearest))
+    }
+
+    fn marker_remove_radius(&self) -> f32 {
+        let view_width = self.ui.waveform.view.width();
+        if view_width.is_finite() && view_width > 0.0 {
+            MARKER_REMOVE_RADIUS.min((view_width * MARKER_REMOVE_VIEW_FRACTION as f64) as f32)
+        } else {
+            MARKER_REMOVE_RADIUS
+        }
+    }
+
+    pub(crate) fn load_user_markers(&mut self, source: &SampleSource, relative_path: &Path) {
+        let markers = match self.database_for(source) {
+            Ok(db) => db.user_markers_for_path(relative_path).unwrap_or_else(|err| {
+                tracing::warn!(
+                    "Failed to load markers for {}: {err}",
+                    relative_path.display()
+                );
+                Vec::new()
+            }),
+            Err(err) => {
+                tracing::warn!("Failed to access database for markers: {err}");
+                Vec::new()
+            }
+        };
+        self.ui.waveform.user_markers = markers;
+    }
+
+    fn commit_user_markers(
+        &mut self,
+        label: &'static str,
+        before: Vec<f32>,
+        after: Vec<f32>,
+    ) -> Result<(), String> {
+        let audio = self
+            .sample_view
+            .wav
+            .loaded_audio
+            .as_ref()
+            .ok_or_else(|| "Load a sample before placing markers".to_string())?;
+        let source = SampleSource {
+            id: audio.source_id.clone(),
+            root: audio.root.clone(),
+        };
+        let relative_path = audio.relative_path.clone();
+        self.store_user_markers(&source, &relative_path, &after)?;
+        let undo_target = (source.clone(), relative_path.clone());
+        let redo_target = (source, relative_path);
+        self.push_undo_entry(undo::UndoEntry::<EguiController>::new(
+            label,
+            move |controller: &mut EguiController| {
+                controller.store_user_markers(&undo_target.0, &undo_target.1, &before)?;
+                Ok(undo::UndoExecution::Applied)
+            },
+            move |controller: &mut EguiController| {
+                controller.store_user_markers(&redo_target.0, &redo_target.1, &after)?;
+                Ok(undo::UndoExecution::Applied)
+            },
+        ));
+        Ok(())
+    }
+
+    fn store_user_markers(
+        &mut self,
+        source: &SampleSource,
+        relative_path: &Path,
+        markers: &[f32],
+    ) -> Result<(), String> {
+        let db = self
+            .database_for(source)
+            .map_err(|err| format!("Database unavailable: {err}"))?;
+        db.set_user_markers(relative_path, markers)
+            .map_err(|err| format!("Failed to save markers: {err}"))?;
+        let is_loaded = self.sample_view.wav.loaded_audio.as_ref().is_some_and(|audio| {
+            audio.source_id == source.id && audio.relative_path == relative_path
+        });
+        if is_loaded {
+            self.ui.waveform.user_markers = markers.to_vec();
+        }
+        Ok(())
+    }
+}
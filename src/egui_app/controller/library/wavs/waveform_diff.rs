@@ -0,0 +1,107 @@
+use super::*;
+use crate::egui_app::controller::jobs::{WaveformDiffJob, WaveformDiffResult};
+use crate::waveform::WaveformRenderer;
+use crate::waveform::diff::difference_waveform;
+use std::fs;
+
+impl EguiController {
+    /// Toggle rendering the loaded sample as its difference from the pre-edit audio.
+    ///
+    /// The pre-edit file is read and decoded on a background thread.
+    pub fn toggle_waveform_ab_diff(&mut self) {
+        if self.runtime.jobs.cancel_waveform_diff() {
+            self.set_status("Cancelled version comparison", StatusTone::Info);
+            return;
+        }
+        if self.sample_view.waveform.difference.is_some() {
+            self.clear_waveform_ab_diff();
+            return;
+        }
+        match self.waveform_ab_diff_job() {
+            Ok(job) => {
+                self.runtime.jobs.begin_waveform_diff(job);
+                self.set_status("Comparing with the pre-edit version…", StatusTone::Busy);
+            }
+            Err(err) => self.set_status(err, StatusTone::Warning),
+        }
+    }
+
+    /// Return to the normal waveform render if the A-B difference view is active.
+    pub(crate) fn clear_waveform_ab_diff(&mut self) {
+        self.runtime.jobs.cancel_waveform_diff();
+        if self.sample_view.waveform.difference.take().is_none() {
+            return;
+        }
+        self.ui.waveform.ab_diff_enabled = false;
+        self.sample_view.waveform.render_meta = None;
+        self.refresh_waveform_image();
+    }
+
+    /// Show a finished A-B difference if it still matches the loaded waveform.
+    pub(crate) fn apply_waveform_diff_result(&mut self, message: WaveformDiffResult) {
+        if !self.runtime.jobs.finish_waveform_diff(message.current_token) {
+            return;
+        }
+        let still_loaded = self
+            .sample_view
+            .waveform
+            .decoded
+            .as_ref()
+            .is_some_and(|decoded| decoded.cache_token == message.current_token);
+        if !still_loaded {
+            return;
+        }
+        match message.result {
+            Ok(difference) => {
+                self.sample_view.waveform.difference = Some(difference);
+                self.ui.waveform.ab_diff_enabled = true;
+                self.sample_view.waveform.render_meta = None;
+                self.refresh_waveform_image();
+                self.set_status("Showing difference from the pre-edit version", StatusTone::Info);
+            }
+            Err(err) => self.set_status(err, StatusTone::Warning),
+        }
+    }
+
+    fn waveform_ab_diff_job(&self) -> Result<WaveformDiffJob, String> {
+        let loaded = self
+            .sample_view
+            .wav
+            .loaded_audio
+            .as_ref()
+            .ok_or_else(|| "Load a sample to compare versions".to_string())?;
+        let backup = self
+            .history
+            .last_edit_backup
+            .as_ref()
+            .filter(|backup| {
+                backup.source_id == loaded.source_id && backup.relative_path == loaded.relative_path
+            })
+            .ok_or_else(|| "No earlier version of this sample to compare".to_string())?;
+        let current = self
+            .sample_view
+            .waveform
+            .decoded
+            .clone()
+            .ok_or_else(|| "Waveform not decoded yet".to_string())?;
+        Ok(WaveformDiffJob {
+            renderer: self.sample_view.renderer.clone(),
+            before_path: backup.before.clone(),
+            current,
+        })
+    }
+}
+
+/// Read and decode the pre-edit file, then diff it against `current`.
+pub(crate) fn load_waveform_ab_diff(
+    renderer: &WaveformRenderer,
+    before_path: &Path,
+    current: &DecodedWaveform,
+) -> Result<DecodedWaveform, String> {
+    let bytes = fs::read(before_path)
+        .map_err(|err| format!("Failed to read pre-edit audio: {err}"))?;
+    let before = renderer
+        .decode_from_bytes(&crate::wav_sanitize::sanitize_wav_bytes(bytes))
+        .map_err(|err| err.to_string())?;
+    difference_waveform(&before, current)
+}
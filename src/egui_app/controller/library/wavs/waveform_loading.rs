@@ -3,6 +3,11 @@ use crate::egui_app::controller::playback::audio_samples::{
     decode_samples_from_bytes, wav_bytes_from_samples,
 };
 use crate::egui_app::state::WaveformView;
+use crate::sample_sources::config::LoadSelectionMode;
+use crate::selection::SelectionRange;
+
+/// Minimum number of full-scale samples before a load reports clipping.
+const CLIPPING_WARNING_MIN_SAMPLES: usize = 4;
 
 impl EguiController {
     pub(crate) fn load_waveform_for_selection(
@@ -56,6 +61,7 @@ impl EguiController {
         )?;
         let duration_seconds = decoded.duration_seconds;
         let sample_rate = decoded.sample_rate;
+        let clipped_samples = decoded.clipped_sample_count();
         let cache_key = CacheKey::new(&source.id, relative_path);
         if !stretched {
             self.audio
@@ -72,8 +78,7 @@ impl EguiController {
             None,
         )?;
         self.maybe_trigger_pending_playback();
-        let message = Self::loaded_status_text(relative_path, duration_seconds, sample_rate);
-        self.set_status(message, StatusTone::Info);
+        self.set_loaded_status(relative_path, duration_seconds, sample_rate, clipped_samples);
         self.refresh_similarity_sort_for_loaded_sample();
         Ok(())
     }
@@ -86,7 +91,7 @@ impl EguiController {
         bytes: Vec<u8>,
         intent: AudioLoadIntent,
         preserve_selections: bool,
-        transients: Option<Vec<f32>>,
+        transients: Option<LoadedTransients>,
     ) -> Result<(), String> {
         let duration_seconds = decoded.duration_seconds;
         let sample_rate = decoded.sample_rate;
@@ -109,6 +114,10 @@ impl EguiController {
             sample_rate,
             bytes,
         )?;
+        if !preserve_selections {
+            self.apply_load_selection_mode();
+        }
+        self.load_user_markers(source, relative_path);
         if matches!(intent, AudioLoadIntent::Selection) {
             self.apply_loaded_sample_bpm(source, relative_path);
             self.apply_loaded_sample_loop_marker(source, relative_path);
@@ -189,6 +198,17 @@ impl EguiController {
         self.selection_state.edit_range.clear();
     }
 
+    fn apply_load_selection_mode(&mut self) {
+        match self.ui.controls.load_selection_mode {
+            LoadSelectionMode::None => {}
+            LoadSelectionMode::Full => {
+                let range = SelectionRange::new(0.0, 1.0);
+                self.selection_state.range.set_range(Some(range));
+                self.apply_selection(Some(range));
+            }
+        }
+    }
+
     pub(crate) fn loaded_status_text(
         relative_path: &Path,
         duration_seconds: f32,
@@ -202,6 +222,25 @@ impl EguiController {
         )
     }
 
+    /// Report a finished load, warning when the sample contains clipped audio.
+    pub(crate) fn set_loaded_status(
+        &mut self,
+        relative_path: &Path,
+        duration_seconds: f32,
+        sample_rate: u32,
+        clipped_samples: usize,
+    ) {
+        let message = Self::loaded_status_text(relative_path, duration_seconds, sample_rate);
+        if clipped_samples >= CLIPPING_WARNING_MIN_SAMPLES {
+            self.set_status(
+                format!("{message}; clipping detected ({clipped_samples} samples at full scale)"),
+                StatusTone::Warning,
+            );
+        } else {
+            self.set_status(message, StatusTone::Info);
+        }
+    }
+
     fn loaded_audio_for(
         &self,
         source: &SampleSource,
@@ -437,6 +476,8 @@ impl EguiController {
         self.sample_view.wav.loaded_audio = None;
         self.sample_view.waveform.decoded = None;
         self.ui.waveform.image = None;
+        self.ui.waveform.cue_markers.clear();
+        self.ui.waveform.user_markers.clear();
         self.ui.waveform.playhead = PlayheadState::default();
         self.ui.waveform.selection = None;
         self.ui.waveform.selection_duration = None;
@@ -2,12 +2,15 @@ use crate::egui_app::controller::playback::audio_cache::FileMetadata;
 use super::*;
 use crate::egui_app::state::WaveformView;
 use crate::waveform::DecodedWaveform;
+use crate::waveform::transients::SensitivityParams;
 use std::fs;
 use std::path::Path;
 
 const MIN_VIEW_WIDTH_BASE: f64 = 1e-9;
 const MIN_SAMPLES_PER_PIXEL: f32 = 1.0;
 pub(crate) const DEFAULT_TRANSIENT_SENSITIVITY: f32 = 0.6;
+const MIN_VERTICAL_ZOOM: f32 = 1.0;
+const MAX_VERTICAL_ZOOM: f32 = 32.0;
 
 fn min_view_width_for_frames(frame_count: usize, width_px: u32) -> f64 {
     if frame_count == 0 {
@@ -29,6 +32,8 @@ pub(crate) struct WaveformRenderMeta {
     pub channels: u16,
     /// Optional edit-fade preview range used to invalidate cached renders.
     pub edit_fade: Option<crate::selection::SelectionRange>,
+    /// Display-only amplitude magnification applied to the render.
+    pub vertical_zoom: f32,
 }
 
 impl WaveformRenderMeta {
@@ -49,6 +54,7 @@ impl WaveformRenderMeta {
             && (self.view_start - other.view_start).abs() < eps
             && (self.view_end - other.view_end).abs() < eps
             && edit_fade_matches(self.edit_fade, other.edit_fade, fade_eps)
+            && (self.vertical_zoom - other.vertical_zoom).abs() < f32::EPSILON
     }
 }
 
@@ -100,7 +106,7 @@ impl EguiController {
     pub(crate) fn apply_waveform_image(
         &mut self,
         decoded: DecodedWaveform,
-        transients: Option<Vec<f32>>,
+        transients: Option<LoadedTransients>,
     ) {
         if self.sample_view.waveform.decoded.as_ref().is_some_and(|d| d.cache_token == decoded.cache_token) {
             // Content matches, no need to invalidate the current render or transients.
@@ -112,7 +118,10 @@ impl EguiController {
         // Force a rerender whenever decoded samples change, even if the view metadata is
         // identical to the previous render.
         self.sample_view.waveform.render_meta = None;
+        self.ui.waveform.cue_markers = decoded.cue_marker_positions();
         self.sample_view.waveform.decoded = Some(decoded);
+        self.sample_view.waveform.difference = None;
+        self.ui.waveform.ab_diff_enabled = false;
         
         // Reset view to show full waveform when loading new audio
         self.ui.waveform.view = WaveformView {
@@ -120,9 +129,13 @@ impl EguiController {
             end: 1.0,
         };
         
-        if let Some(transients) = transients {
-            self.ui.waveform.transients = transients;
+        if let Some(transients) = transients
+            && transients.tuning == self.ui.waveform.transient_tuning
+        {
+            self.ui.waveform.transients = transients.markers;
             self.ui.waveform.transient_cache_token = Some(token);
+            self.sample_view.waveform.transient_novelty =
+                transients.novelty.map(|novelty| (token, novelty));
         } else {
             self.refresh_waveform_transients();
         }
@@ -141,7 +154,8 @@ impl EguiController {
     }
 
     pub(crate) fn refresh_waveform_image(&mut self) {
-        let Some(decoded) = self.sample_view.waveform.decoded.as_ref() else {
+        let waveform = &self.sample_view.waveform;
+        let Some(decoded) = waveform.difference.as_ref().or(waveform.decoded.as_ref()) else {
             return;
         };
         let [width, height] = self.sample_view.waveform.size;
@@ -179,6 +193,7 @@ impl EguiController {
                 .waveform
                 .edit_selection
                 .filter(|selection| selection.has_edit_effects()),
+            vertical_zoom: self.ui.waveform.vertical_zoom,
         };
         if self
             .sample_view
@@ -200,6 +215,7 @@ impl EguiController {
                 effective_width,
                 height,
                 desired_meta.edit_fade,
+                desired_meta.vertical_zoom,
             );
         let (view_start, view_end) = self
             .sample_view
@@ -227,6 +243,24 @@ impl EguiController {
         self.sample_view.waveform.render_meta = Some(desired_meta);
     }
 
+    /// Set the display-only vertical zoom for the waveform and rerender.
+    pub(crate) fn set_waveform_vertical_zoom(&mut self, zoom: f32) {
+        if !zoom.is_finite() {
+            return;
+        }
+        let zoom = zoom.clamp(MIN_VERTICAL_ZOOM, MAX_VERTICAL_ZOOM);
+        if (self.ui.waveform.vertical_zoom - zoom).abs() < f32::EPSILON {
+            return;
+        }
+        self.ui.waveform.vertical_zoom = zoom;
+        self.refresh_waveform_image();
+    }
+
+    /// Reset the waveform vertical zoom to unscaled amplitudes.
+    pub(crate) fn reset_waveform_vertical_zoom(&mut self) {
+        self.set_waveform_vertical_zoom(1.0);
+    }
+
     pub(crate) fn refresh_waveform_transients(&mut self) {
         let Some(decoded) = self.sample_view.waveform.decoded.as_ref() else {
             self.ui.waveform.transients.clear();
@@ -236,9 +270,64 @@ impl EguiController {
         if self.ui.waveform.transient_cache_token == Some(decoded.cache_token) {
             return;
         }
-        self.ui.waveform.transients =
-            crate::waveform::transients::detect_transients(decoded, DEFAULT_TRANSIENT_SENSITIVITY);
-        self.ui.waveform.transient_cache_token = Some(decoded.cache_token);
+        let token = decoded.cache_token;
+        let max_markers = self
+            .settings
+            .controls
+            .transient_max_markers
+            .map(|cap| cap as usize);
+        let Some(tuning) = self.ui.waveform.transient_tuning else {
+            self.ui.waveform.transients = crate::waveform::transients::detect_transients_with_cap(
+                decoded,
+                DEFAULT_TRANSIENT_SENSITIVITY,
+                max_markers,
+            );
+            self.ui.waveform.transient_cache_token = Some(token);
+            return;
+        };
+        let duration_seconds = decoded.duration_seconds;
+        let cached = &mut self.sample_view.waveform.transient_novelty;
+        if cached.as_ref().is_none_or(|(cached_token, _)| *cached_token != token) {
+            *cached = crate::waveform::transients::compute_transient_novelty(decoded)
+                .map(|novelty| (token, novelty));
+        }
+        self.ui.waveform.transients = match cached.as_ref() {
+            Some((_, novelty)) => crate::waveform::transients::pick_transients_with_tuning(
+                novelty,
+                tuning,
+                duration_seconds,
+                max_markers,
+            ),
+            None => Vec::new(),
+        };
+        self.ui.waveform.transient_cache_token = Some(token);
+    }
+
+    /// Thresholds currently used for transient markers, custom or automatic.
+    pub(crate) fn transient_tuning(&self) -> SensitivityParams {
+        self.ui
+            .waveform
+            .transient_tuning
+            .unwrap_or_else(|| SensitivityParams::from_sensitivity(DEFAULT_TRANSIENT_SENSITIVITY))
+    }
+
+    /// Re-pick transients for the loaded sample with custom thresholds, reusing its novelty.
+    pub(crate) fn retune_waveform_transients(&mut self, tuning: SensitivityParams) {
+        if self.ui.waveform.transient_tuning == Some(tuning) {
+            return;
+        }
+        self.ui.waveform.transient_tuning = Some(tuning);
+        self.ui.waveform.transient_cache_token = None;
+        self.refresh_waveform_transients();
+    }
+
+    /// Drop custom transient thresholds and return to automatic detection.
+    pub(crate) fn reset_transient_tuning(&mut self) {
+        if self.ui.waveform.transient_tuning.take().is_none() {
+            return;
+        }
+        self.ui.waveform.transient_cache_token = None;
+        self.refresh_waveform_transients();
     }
 
     pub(crate) fn read_waveform_bytes(
@@ -6,8 +6,12 @@ pub(crate) fn clear_waveform_view(controller: &mut EguiController) {
     controller.ui.waveform.notice = None;
     controller.ui.waveform.loading = None;
     controller.ui.waveform.transients.clear();
+    controller.ui.waveform.cue_markers.clear();
+    controller.ui.waveform.user_markers.clear();
     controller.ui.waveform.transient_cache_token = None;
     controller.sample_view.waveform.decoded = None;
+    controller.sample_view.waveform.difference = None;
+    controller.ui.waveform.ab_diff_enabled = false;
     controller.ui.waveform.playhead = PlayheadState::default();
     controller.ui.waveform.last_start_marker = None;
     controller.ui.waveform.cursor = None;
@@ -132,6 +132,7 @@ mod tests {
             duration_seconds: 1.0,
             sample_rate: 44_100,
             channels: 1,
+            cue_markers: Vec::new(),
         }
     }
 
@@ -1,5 +1,7 @@
 use super::*;
+use crate::egui_app::controller::library::wavs::waveform_rendering::DEFAULT_TRANSIENT_SENSITIVITY;
 use crate::egui_app::controller::playback::audio_cache::FileMetadata;
+use crate::waveform::transients::{SensitivityParams, TransientNovelty};
 use crate::waveform::{DecodedWaveform, WaveformRenderer};
 use std::{
     fs,
@@ -21,6 +23,9 @@ pub(crate) struct AudioLoadJob {
     pub root: PathBuf,
     pub relative_path: PathBuf,
     pub stretch_ratio: Option<f64>,
+    pub transient_max_markers: Option<usize>,
+    /// Custom transient thresholds; `None` uses automatic detection.
+    pub transient_tuning: Option<SensitivityParams>,
 }
 
 #[derive(Debug)]
@@ -28,10 +33,19 @@ pub(crate) struct AudioLoadOutcome {
     pub decoded: DecodedWaveform,
     pub bytes: Vec<u8>,
     pub metadata: FileMetadata,
-    pub transients: Vec<f32>,
+    pub transients: LoadedTransients,
     pub stretched: bool,
 }
 
+/// Transient markers picked on the loader thread, plus the novelty curve for retuning.
+#[derive(Debug)]
+pub(crate) struct LoadedTransients {
+    pub markers: Vec<f32>,
+    pub novelty: Option<TransientNovelty>,
+    /// Thresholds `markers` were picked with; `None` for automatic detection.
+    pub tuning: Option<SensitivityParams>,
+}
+
 #[derive(Debug)]
 pub(crate) enum AudioLoadError {
     Missing(String),
@@ -168,10 +182,28 @@ fn load_audio(
         }
     }
 
-    let transients = crate::waveform::transients::detect_transients(
-        &decoded,
-        crate::egui_app::controller::library::wavs::waveform_rendering::DEFAULT_TRANSIENT_SENSITIVITY,
-    );
+    // Novelty is the expensive part of transient detection, so it is computed here even for
+    // custom tuning and handed back for the UI to re-pick from.
+    let novelty = crate::waveform::transients::compute_transient_novelty(&decoded);
+    let tuning = job
+        .transient_tuning
+        .unwrap_or_else(|| SensitivityParams::from_sensitivity(DEFAULT_TRANSIENT_SENSITIVITY));
+    let markers = novelty
+        .as_ref()
+        .map(|novelty| {
+            crate::waveform::transients::pick_transients_with_tuning(
+                novelty,
+                tuning,
+                decoded.duration_seconds,
+                job.transient_max_markers,
+            )
+        })
+        .unwrap_or_default();
+    let transients = LoadedTransients {
+        markers,
+        novelty,
+        tuning: job.transient_tuning,
+    };
 
     Ok(AudioLoadOutcome {
         decoded,
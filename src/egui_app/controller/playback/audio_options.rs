@@ -321,6 +321,16 @@ impl EguiController {
         self.apply_audio_selection();
     }
 
+    /// Update the output resampling quality and rebuild the audio stream.
+    pub fn set_audio_resample_quality(&mut self, quality: crate::audio::ResampleQuality) {
+        if self.settings.audio_output.resample_quality == quality {
+            return;
+        }
+        self.settings.audio_output.resample_quality = quality;
+        self.ui.audio.selected.resample_quality = quality;
+        self.apply_audio_selection();
+    }
+
     /// Apply current audio config to the player and persist config.
     pub(crate) fn apply_audio_selection(&mut self) {
         self.ui.audio.selected = self.settings.audio_output.clone();
@@ -349,17 +359,28 @@ impl EguiController {
 
     pub(crate) fn rebuild_audio_player(&mut self) -> Result<(), String> {
         let loaded_audio = self.sample_view.wav.loaded_audio.clone();
+        let was_playing = self.is_playing();
+        let was_looping = self.ui.waveform.loop_enabled;
+        let playhead_position = self.ui.waveform.playhead.position;
         self.audio.player = None;
         let Some(player_rc) = self.ensure_player()? else {
             self.ui.audio.applied = None;
             return Err("Audio unavailable".into());
         };
+        let has_audio = loaded_audio.is_some();
         if let Some(audio) = loaded_audio {
             let mut player = player_rc.borrow_mut();
             player.stop();
             player.set_audio(audio.bytes.clone(), audio.duration_seconds);
         }
         self.update_audio_output_status();
+        if was_playing && has_audio {
+            // Hot-swap: carry on from where the old stream left off.
+            let start = playhead_position
+                .is_finite()
+                .then(|| playhead_position.clamp(0.0, 1.0));
+            self.play_audio(was_looping, start)?;
+        }
         Ok(())
     }
 
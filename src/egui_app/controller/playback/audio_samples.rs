@@ -72,6 +72,28 @@ pub(crate) fn write_wav(
         .map_err(|err| format!("Failed to finalize clip: {err}"))
 }
 
+/// Write a float WAV carrying `markers` (frame offsets) as a `cue ` chunk.
+pub(crate) fn write_wav_with_markers(
+    target: &Path,
+    samples: &[f32],
+    sample_rate: u32,
+    channels: u16,
+    markers: &[u32],
+) -> Result<(), String> {
+    if markers.is_empty() {
+        return write_wav(target, samples, sample_rate, channels);
+    }
+    if let Some(parent) = target.parent()
+        && !parent.exists()
+    {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("Failed to create folder {}: {err}", parent.display()))?;
+    }
+    let mut bytes = wav_bytes_from_samples(samples, sample_rate, channels)?;
+    crate::wav_sanitize::append_cue_chunk(&mut bytes, markers);
+    fs::write(target, bytes).map_err(|err| format!("Failed to write clip: {err}"))
+}
+
 /// Encode interleaved f32 samples into WAV bytes for in-memory playback.
 pub(crate) fn wav_bytes_from_samples(
     samples: &[f32],
@@ -123,7 +145,7 @@ fn decode_samples(
     }
 }
 
-fn frame_bounds(total_frames: usize, bounds: SelectionRange) -> (usize, usize) {
+pub(crate) fn frame_bounds(total_frames: usize, bounds: SelectionRange) -> (usize, usize) {
     let start_frame = ((bounds.start() * total_frames as f32).floor() as usize)
         .min(total_frames.saturating_sub(1));
     let mut end_frame = ((bounds.end() * total_frames as f32).ceil() as usize).min(total_frames);
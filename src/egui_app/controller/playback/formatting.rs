@@ -1,3 +1,39 @@
+/// Playhead location expressed as a fraction, a frame index and a timestamp.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlaybackPosition {
+    /// Position across the loaded sample, from 0.0 to 1.0.
+    pub normalized: f32,
+    /// Frame index at the sample's native rate.
+    pub frame: u64,
+    /// Seconds from the start of the sample.
+    pub seconds: f32,
+}
+
+/// Resolve a normalized position against a sample's duration and rate.
+pub(crate) fn playback_position_at(
+    normalized: f32,
+    duration_seconds: f32,
+    sample_rate: u32,
+) -> PlaybackPosition {
+    let normalized = if normalized.is_finite() {
+        normalized.clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let duration_seconds = if duration_seconds.is_finite() {
+        duration_seconds.max(0.0)
+    } else {
+        0.0
+    };
+    let seconds = duration_seconds * normalized;
+    let frame = (seconds as f64 * sample_rate as f64).round() as u64;
+    PlaybackPosition {
+        normalized,
+        frame,
+        seconds,
+    }
+}
+
 pub(crate) fn format_selection_duration(seconds: f32) -> String {
     if !seconds.is_finite() || seconds <= 0.0 {
         return "0 ms".to_string();
@@ -44,6 +80,14 @@ mod tests {
         assert_eq!(format_timestamp_hms_ms(59.9995), "00:01:00:000");
     }
 
+    #[test]
+    fn playback_position_clamps_out_of_range_input() {
+        let position = playback_position_at(1.5, 2.0, 44_100);
+        assert_eq!(position.normalized, 1.0);
+        assert_eq!(position.frame, 88_200);
+        assert_eq!(playback_position_at(f32::NAN, 2.0, 44_100).frame, 0);
+    }
+
     #[test]
     fn format_timestamp_handles_hours() {
         assert_eq!(format_timestamp_hms_ms(3_661.789), "01:01:01:789");
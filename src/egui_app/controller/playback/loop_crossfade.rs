@@ -2,10 +2,12 @@ use crate::egui_app::controller::jobs::UndoFileJob;
 use crate::egui_app::controller::library::wav_io::{
     file_metadata, read_samples_for_normalization,
 };
+use crate::egui_app::controller::state::audio::LoopCrossfadePreview;
 use crate::egui_app::controller::undo;
 use super::*;
 use crate::egui_app::state::{LoopCrossfadePrompt, LoopCrossfadeSettings, LoopCrossfadeUnit};
 use hound::SampleFormat;
+use std::io::Cursor;
 use std::path::{Path, PathBuf};
 
 impl EguiController {
@@ -29,6 +31,7 @@ impl EguiController {
             return Ok(());
         };
         self.ui.loop_crossfade_prompt = None;
+        self.stop_loop_crossfade_preview();
         let source = loop_crossfade_source(self, &prompt.source_id)?;
         let absolute_path = source.root.join(&prompt.relative_path);
         let was_playing = self.is_playing();
@@ -65,6 +68,76 @@ impl EguiController {
     /// Clear any pending loop crossfade prompt.
     pub fn clear_loop_crossfade_prompt(&mut self) {
         self.ui.loop_crossfade_prompt = None;
+        self.stop_loop_crossfade_preview();
+    }
+
+    /// Audition the pending loop crossfade in a loop without writing anything to disk.
+    ///
+    /// The crossfaded audio is rendered in memory and handed to the player only; the
+    /// original file and the loaded waveform stay untouched.
+    pub fn preview_loop_crossfade_prompt(&mut self) -> Result<(), String> {
+        let Some(prompt) = self.ui.loop_crossfade_prompt.clone() else {
+            return Ok(());
+        };
+        let source = loop_crossfade_source(self, &prompt.source_id)?;
+        let absolute_path = source.root.join(&prompt.relative_path);
+        let (mut samples, spec) = read_samples_for_normalization(&absolute_path)?;
+        let (channels, total_frames) = loop_crossfade_layout(&samples, spec.channels)?;
+        let sample_rate = spec.sample_rate.max(1);
+        let fade_frames = loop_crossfade_frames(&prompt.settings, sample_rate, total_frames)?;
+        apply_loop_crossfade(&mut samples, channels, total_frames, fade_frames)?;
+        let bytes = loop_crossfade_wav_bytes(&samples, loop_crossfade_spec(&spec))?;
+        let duration = total_frames as f32 / sample_rate as f32;
+        self.audio.loop_crossfade_preview = Some(LoopCrossfadePreview {
+            source_id: prompt.source_id,
+            relative_path: prompt.relative_path,
+            settings: prompt.settings,
+            fade_frames,
+        });
+        let player = match self.ensure_player() {
+            Ok(player) => player,
+            Err(err) => {
+                self.set_status(err, StatusTone::Warning);
+                return Ok(());
+            }
+        };
+        if let Some(player) = player {
+            let mut player = player.borrow_mut();
+            player.set_audio(bytes, duration);
+            if let Err(err) = player.play_range(0.0, 1.0, true) {
+                self.audio.loop_crossfade_preview = None;
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether a loop crossfade preview is currently active.
+    pub fn loop_crossfade_preview_active(&self) -> bool {
+        self.audio.loop_crossfade_preview.is_some()
+    }
+
+    /// Settings used to render the active loop crossfade preview, if any.
+    pub fn loop_crossfade_preview_settings(&self) -> Option<&LoopCrossfadeSettings> {
+        self.audio
+            .loop_crossfade_preview
+            .as_ref()
+            .map(|preview| &preview.settings)
+    }
+
+    /// Stop any loop crossfade preview and hand the loaded sample back to the player.
+    pub fn stop_loop_crossfade_preview(&mut self) {
+        if self.audio.loop_crossfade_preview.take().is_none() {
+            return;
+        }
+        let Some(player) = self.audio.player.clone() else {
+            return;
+        };
+        let mut player = player.borrow_mut();
+        player.stop();
+        if let Some(audio) = self.sample_view.wav.loaded_audio.as_ref() {
+            player.set_audio(audio.bytes.clone(), audio.duration_seconds);
+        }
     }
 
     /// Apply a loop crossfade copy for a single sample path.
@@ -283,6 +356,23 @@ fn write_loop_crossfade_wav(
         .map_err(|err| format!("Failed to finalize wav: {err}"))
 }
 
+fn loop_crossfade_wav_bytes(samples: &[f32], spec: hound::WavSpec) -> Result<Vec<u8>, String> {
+    let mut cursor = Cursor::new(Vec::new());
+    {
+        let mut writer = hound::WavWriter::new(&mut cursor, spec)
+            .map_err(|err| format!("Failed to encode preview: {err}"))?;
+        for sample in samples {
+            writer
+                .write_sample(*sample)
+                .map_err(|err| format!("Failed to encode preview: {err}"))?;
+        }
+        writer
+            .finalize()
+            .map_err(|err| format!("Failed to encode preview: {err}"))?;
+    }
+    Ok(cursor.into_inner())
+}
+
 fn register_loop_crossfade_entry(
     controller: &mut EguiController,
     source: &SampleSource,
@@ -309,6 +399,7 @@ fn register_loop_crossfade_entry(
             looped: false,
             missing: false,
             last_played_at: None,
+            stars: None,
         },
     );
     controller.enqueue_similarity_for_new_sample(source, relative_path, file_size, modified_ns);
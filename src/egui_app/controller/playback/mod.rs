@@ -17,13 +17,16 @@ mod formatting;
 mod player;
 mod playhead_trail;
 mod random_nav;
+mod selection_statistics;
 mod tagging;
 mod transport;
 
 #[cfg(test)]
 mod audio_options_tests;
 
-use formatting::{format_selection_duration, format_timestamp_hms_ms};
+use formatting::{format_selection_duration, format_timestamp_hms_ms, playback_position_at};
+pub use formatting::PlaybackPosition;
+pub(crate) use selection_statistics::{SelectionStatistics, SelectionStatisticsCache};
 use tracing::warn;
 
 #[cfg(test)]
@@ -214,6 +217,11 @@ impl EguiController {
         let root = audio.root.clone();
         let relative_path = audio.relative_path.clone();
         let played_at = now_epoch_seconds();
+        let sample_id = crate::egui_app::controller::library::analysis_jobs::build_sample_id(
+            source_id.as_str(),
+            &relative_path,
+        );
+        self.audio.auditioned_sample_ids.insert(sample_id);
 
         self.audio.pending_age_update = Some(PendingAgeUpdate {
             source_id: source_id.clone(),
@@ -327,6 +335,13 @@ impl EguiController {
         player::update_waveform_hover_time(self, position);
     }
 
+    /// Report the playhead as a normalized fraction, frame index and seconds.
+    ///
+    /// Returns `None` when no audio is loaded.
+    pub fn playback_position(&self) -> Option<PlaybackPosition> {
+        player::playback_position(self)
+    }
+
     #[allow(dead_code)]
     pub(crate) fn selection_duration_label(&self, range: SelectionRange) -> Option<String> {
         player::selection_duration_label(self, range)
@@ -336,6 +351,15 @@ impl EguiController {
         player::apply_volume(self, volume);
     }
 
+    /// Solo a frequency band on playback without touching the file; `None` clears it.
+    pub fn set_solo_band(&mut self, band: Option<crate::audio::SoloBand>) {
+        player::apply_solo_band(self, band);
+    }
+
+    pub(crate) fn sync_metronome(&mut self) {
+        player::sync_metronome(self);
+    }
+
     pub(crate) fn ensure_player(
         &mut self,
     ) -> Result<Option<Rc<RefCell<AudioPlayer>>>, String> {
@@ -351,6 +375,16 @@ impl EguiController {
         tagging::tag_selected(self, target);
     }
 
+    /// Record tag changes as one undo entry that restores each sample's previous tag.
+    pub(crate) fn push_tag_undo_entry(
+        &mut self,
+        label: &str,
+        previous: Vec<(SourceId, PathBuf, crate::sample_sources::Rating)>,
+        target: crate::sample_sources::Rating,
+    ) {
+        tagging::push_tag_undo_entry(self, label, previous, target);
+    }
+
     /// Move selection within the current sample browser list by an offset and play.
     pub fn nudge_selection(&mut self, offset: isize) {
         browser_nav::nudge_selection(self, offset);
@@ -428,6 +462,26 @@ mod tests {
         assert_eq!(label.as_deref(), Some("2.00 s"));
     }
 
+    #[test]
+    fn playback_position_reports_seconds_and_frame_from_playhead() {
+        let (mut controller, source) = test_support::dummy_controller();
+        controller.sample_view.wav.loaded_audio = Some(LoadedAudio {
+            source_id: source.id.clone(),
+            root: source.root.clone(),
+            relative_path: PathBuf::from("clip.wav"),
+            bytes: Vec::new(),
+            duration_seconds: 4.0,
+            sample_rate: 48_000,
+        });
+        controller.ui.waveform.playhead.position = 0.5;
+
+        let position = controller.playback_position().expect("loaded audio");
+
+        assert!((position.normalized - 0.5).abs() < 1e-6);
+        assert!((position.seconds - 2.0).abs() < 1e-4);
+        assert_eq!(position.frame, 96_000);
+    }
+
     #[test]
     fn selection_duration_label_is_absent_without_audio() {
         let (controller, _) = test_support::dummy_controller();
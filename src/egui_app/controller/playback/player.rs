@@ -115,6 +115,7 @@ pub(crate) fn play_audio(
         player.borrow_mut().play_range(start, span_end, false)?;
     }
     controller.ui.waveform.playhead.active_span_end = Some(span_end.clamp(0.0, 1.0));
+    controller.ui.waveform.playhead.loop_cycles = 0;
     controller.ui.waveform.playhead.visible = true;
     controller.ui.waveform.playhead.position = start;
     super::playhead_trail::start_or_seek_trail(
@@ -187,7 +188,13 @@ pub(crate) fn tick_playhead(controller: &mut EguiController) {
     let is_playing = player_ref.is_playing();
     let progress = player_ref.progress();
     let is_looping = player_ref.is_looping();
+    let completed_loops = player_ref.completed_loops();
     drop(player_ref);
+    if let Some(loops) = completed_loops
+        && record_loop_cycles(controller, loops)
+    {
+        return;
+    }
     update_playhead_from_progress(controller, progress, is_looping, is_playing);
     if !is_playing && controller.sample_view.waveform.decoded.is_none() {
         hide_waveform_playhead(controller);
@@ -204,11 +211,15 @@ pub(crate) fn update_playhead_from_progress(
         let playhead = &mut controller.ui.waveform.playhead;
         let progress = smooth_progress_after_seek(&mut playhead.recent_seek, progress);
         controller.ui.waveform.playhead.position = progress;
+        let trail_length = controller.ui.controls.playhead_trail_duration();
+        let trail_fade = controller.ui.controls.playhead_trail_fade();
         super::playhead_trail::tick_playhead_trail(
             &mut controller.ui.waveform.playhead,
             progress,
             is_looping,
             is_playing,
+            trail_length,
+            trail_fade,
         );
         if playhead_completed_span(controller, progress, is_looping) {
             hide_waveform_playhead(controller);
@@ -220,6 +231,24 @@ pub(crate) fn update_playhead_from_progress(
     }
 }
 
+/// Record loop cycles reported by the player and stop once the configured cap is reached.
+fn record_loop_cycles(controller: &mut EguiController, completed_loops: u32) -> bool {
+    let playhead = &mut controller.ui.waveform.playhead;
+    if completed_loops <= playhead.loop_cycles {
+        return false;
+    }
+    playhead.loop_cycles = completed_loops;
+    let cycles = completed_loops;
+    let Some(limit) = controller.ui.waveform.loop_cycle_limit else {
+        return false;
+    };
+    if cycles < limit || !controller.stop_playback_if_active() {
+        return false;
+    }
+    controller.set_status(format!("Stopped after {cycles} loops"), StatusTone::Info);
+    true
+}
+
 fn playhead_completed_span(controller: &EguiController, progress: f32, is_looping: bool) -> bool {
     if is_looping {
         return false;
@@ -351,6 +380,15 @@ pub(crate) fn update_waveform_hover_time(controller: &mut EguiController, positi
     }
 }
 
+pub(crate) fn playback_position(controller: &EguiController) -> Option<PlaybackPosition> {
+    let audio = controller.sample_view.wav.loaded_audio.as_ref()?;
+    Some(playback_position_at(
+        controller.ui.waveform.playhead.position,
+        audio.duration_seconds,
+        audio.sample_rate,
+    ))
+}
+
 pub(crate) fn selection_duration_label(
     controller: &EguiController,
     range: SelectionRange,
@@ -368,6 +406,29 @@ pub(crate) fn apply_volume(controller: &mut EguiController, volume: f32) {
     }
 }
 
+pub(crate) fn apply_solo_band(
+    controller: &mut EguiController,
+    band: Option<crate::audio::SoloBand>,
+) {
+    controller.ui.waveform.solo_band = band;
+    if let Some(player) = controller.audio.player.as_ref() {
+        player.borrow().set_solo_band(band);
+    }
+}
+
+fn metronome_bpm(controller: &EguiController) -> Option<f32> {
+    let waveform = &controller.ui.waveform;
+    waveform.metronome_enabled.then_some(waveform.bpm_value).flatten()
+}
+
+/// Push the metronome tempo to the player, silencing it when disabled or without a BPM.
+pub(crate) fn sync_metronome(controller: &mut EguiController) {
+    let bpm = metronome_bpm(controller);
+    if let Some(player) = controller.audio.player.as_ref() {
+        player.borrow().set_metronome_bpm(bpm);
+    }
+}
+
 pub(crate) fn ensure_player(
     controller: &mut EguiController,
 ) -> Result<Option<Rc<RefCell<AudioPlayer>>>, String> {
@@ -375,6 +436,8 @@ pub(crate) fn ensure_player(
         let mut created = AudioPlayer::from_config(&controller.settings.audio_output)
             .map_err(|err| format!("Audio init failed: {err}"))?;
         created.set_volume(controller.ui.volume);
+        created.set_solo_band(controller.ui.waveform.solo_band);
+        created.set_metronome_bpm(metronome_bpm(controller));
         created.set_anti_clip_settings(
             controller.settings.controls.anti_clip_fade_enabled,
             controller.settings.controls.anti_clip_fade_ms,
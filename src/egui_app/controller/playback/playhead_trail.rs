@@ -1,8 +1,6 @@
 use crate::egui_app::state::{FadingPlayheadTrail, PlayheadState, PlayheadTrailSample};
 use std::time::{Duration, Instant};
 
-const TRAIL_DURATION: Duration = Duration::from_millis(1250);
-const TRAIL_FADE: Duration = Duration::from_millis(450);
 const MAX_TRAIL_SAMPLES: usize = 384;
 const MAX_FADING_TRAILS: usize = 2;
 const POSITION_EPS: f32 = 0.0005;
@@ -43,16 +41,27 @@ pub(crate) fn stash_active_trail(playhead: &mut PlayheadState) {
     }
 }
 
+/// Advance the playhead trail, keeping at most `trail_length` of history.
+///
+/// A zero `trail_length` disables the trail entirely; detached trails are dropped
+/// once they are older than `trail_fade`.
 pub(crate) fn tick_playhead_trail(
     playhead: &mut PlayheadState,
     position: f32,
     _is_looping: bool,
     is_playing: bool,
+    trail_length: Duration,
+    trail_fade: Duration,
 ) {
+    if trail_length.is_zero() {
+        playhead.trail.clear();
+        playhead.fading_trails.clear();
+        return;
+    }
     let now = Instant::now();
     playhead
         .fading_trails
-        .retain(|trail| now.saturating_duration_since(trail.started_at) < TRAIL_FADE);
+        .retain(|trail| now.saturating_duration_since(trail.started_at) < trail_fade);
 
     if !is_playing {
         if !playhead.trail.is_empty() {
@@ -97,7 +106,7 @@ pub(crate) fn tick_playhead_trail(
     }
 
     while let Some(front) = playhead.trail.front() {
-        if now.saturating_duration_since(front.time) > TRAIL_DURATION {
+        if now.saturating_duration_since(front.time) > trail_length {
             playhead.trail.pop_front();
         } else {
             break;
@@ -114,6 +123,20 @@ mod tests {
     use crate::egui_app::state::{PlayheadState, PlayheadTrailSample};
     use std::time::{Duration, Instant};
 
+    const LENGTH: Duration = Duration::from_millis(1250);
+    const FADE: Duration = Duration::from_millis(450);
+
+    fn playhead_with_history(now: Instant) -> PlayheadState {
+        let mut playhead = PlayheadState::default();
+        for step in (1..=10u64).rev() {
+            playhead.trail.push_back(PlayheadTrailSample {
+                position: 0.5 - step as f32 * 0.01,
+                time: now - Duration::from_millis(step * 100),
+            });
+        }
+        playhead
+    }
+
     #[test]
     fn tick_playhead_trail_clamps_tiny_backwards_jitter() {
         let mut playhead = PlayheadState::default();
@@ -122,7 +145,7 @@ mod tests {
             time: Instant::now() - Duration::from_secs(1),
         });
 
-        tick_playhead_trail(&mut playhead, 0.4999, false, true);
+        tick_playhead_trail(&mut playhead, 0.4999, false, true, LENGTH, FADE);
 
         assert!(playhead.trail.len() >= 1);
         let last = playhead.trail.back().unwrap();
@@ -137,10 +160,40 @@ mod tests {
             time: Instant::now() - Duration::from_millis(50),
         });
 
-        tick_playhead_trail(&mut playhead, 0.30, false, true);
+        tick_playhead_trail(&mut playhead, 0.30, false, true, LENGTH, FADE);
 
         assert!(playhead.fading_trails.is_empty());
         assert!(playhead.trail.len() >= 2);
         assert!((playhead.trail.back().unwrap().position - 0.30).abs() < 1e-6);
     }
+
+    #[test]
+    fn shorter_trail_length_retains_fewer_points() {
+        let now = Instant::now();
+        let mut long = playhead_with_history(now);
+        let mut short = playhead_with_history(now);
+
+        tick_playhead_trail(&mut long, 0.5, false, true, LENGTH, FADE);
+        tick_playhead_trail(
+            &mut short,
+            0.5,
+            false,
+            true,
+            Duration::from_millis(350),
+            FADE,
+        );
+
+        assert!(short.trail.len() < long.trail.len());
+        assert!(short.trail.len() >= 2);
+    }
+
+    #[test]
+    fn zero_trail_length_disables_trail() {
+        let mut playhead = playhead_with_history(Instant::now());
+
+        tick_playhead_trail(&mut playhead, 0.5, false, true, Duration::ZERO, FADE);
+
+        assert!(playhead.trail.is_empty());
+        assert!(playhead.fading_trails.is_empty());
+    }
 }
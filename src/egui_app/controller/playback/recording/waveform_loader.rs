@@ -328,6 +328,7 @@ impl RecordingWaveformState {
                 duration_seconds,
                 sample_rate: self.sample_rate,
                 channels: self.channels,
+                cue_markers: Vec::new(),
             },
             RecordingWaveformMode::Peaks {
                 bucket_size_frames,
@@ -360,6 +361,7 @@ impl RecordingWaveformState {
                     duration_seconds,
                     sample_rate: self.sample_rate,
                     channels: self.channels,
+                    cue_markers: Vec::new(),
                 }
             }
         }
@@ -850,6 +852,7 @@ fn empty_recording_waveform(sample_rate: u32, channels: u16) -> DecodedWaveform
         duration_seconds: 0.0,
         sample_rate,
         channels,
+        cue_markers: Vec::new(),
     }
 }
 
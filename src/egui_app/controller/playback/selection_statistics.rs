@@ -0,0 +1,142 @@
+use super::audio_samples::{crop_samples, frame_bounds};
+use crate::waveform::{DecodedWaveform, WaveformPeaks};
+use super::*;
+use crate::analysis::time_domain;
+
+/// Level and length readout for the active waveform selection.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SelectionStatistics {
+    /// Largest absolute sample value across all channels.
+    pub(crate) peak: f32,
+    /// Root-mean-square level across all channels.
+    pub(crate) rms: f32,
+    /// Ungated BS.1770 loudness.
+    pub(crate) lufs: f32,
+    /// Length of the selection in seconds at the file's sample rate.
+    pub(crate) duration_seconds: f32,
+}
+
+/// Statistics computed for one selection of one decoded buffer.
+pub(crate) struct SelectionStatisticsCache {
+    cache_token: u64,
+    range: SelectionRange,
+    statistics: SelectionStatistics,
+}
+
+impl EguiController {
+    /// Measure peak, RMS, loudness and duration of the loaded audio under the selection.
+    ///
+    /// Results are cached until the selection or the decoded audio changes.
+    pub(crate) fn selection_statistics(&mut self) -> Option<SelectionStatistics> {
+        let range = self.ui.waveform.selection?;
+        let cache_token = self.sample_view.waveform.decoded.as_ref()?.cache_token;
+        if let Some(cache) = self.sample_view.waveform.selection_statistics.as_ref()
+            && cache.cache_token == cache_token
+            && cache.range == range
+        {
+            return Some(cache.statistics);
+        }
+        let statistics = self.measure_selection(range)?;
+        self.sample_view.waveform.selection_statistics = Some(SelectionStatisticsCache {
+            cache_token,
+            range,
+            statistics,
+        });
+        Some(statistics)
+    }
+
+    fn measure_selection(&self, range: SelectionRange) -> Option<SelectionStatistics> {
+        let decoded = self.sample_view.waveform.decoded.as_ref()?;
+        if decoded.samples.is_empty() {
+            return measure_long_selection(decoded, range);
+        }
+        let samples = crop_samples(&decoded.samples, decoded.channels, range).ok()?;
+        let channels = decoded.channel_count();
+        Some(SelectionStatistics {
+            peak: time_domain::peak(&samples),
+            rms: time_domain::rms(&samples),
+            lufs: time_domain::integrated_loudness_lufs(&samples, channels, decoded.sample_rate),
+            duration_seconds: time_domain::duration_seconds(
+                samples.len() / channels,
+                decoded.sample_rate,
+            ),
+        })
+    }
+}
+
+/// Measure a selection of a long file from its in-memory peaks and analysis stream.
+///
+/// Long files keep no full-resolution samples, so the peak comes from the min/max
+/// buckets and RMS/loudness from the decimated mono analysis samples.
+fn measure_long_selection(
+    decoded: &DecodedWaveform,
+    range: SelectionRange,
+) -> Option<SelectionStatistics> {
+    let peaks = decoded.peaks.as_deref()?;
+    let total_frames = decoded.frame_count();
+    if total_frames == 0 || decoded.analysis_samples.is_empty() {
+        return None;
+    }
+    let (start_frame, end_frame) = frame_bounds(total_frames, range);
+    let stride = decoded.analysis_stride.max(1);
+    let analysis_end = end_frame.div_ceil(stride).min(decoded.analysis_samples.len());
+    let analysis_start = (start_frame / stride).min(analysis_end.saturating_sub(1));
+    let analysis = &decoded.analysis_samples[analysis_start..analysis_end];
+    Some(SelectionStatistics {
+        peak: bucket_peak(peaks, start_frame, end_frame),
+        rms: time_domain::rms(analysis),
+        lufs: time_domain::integrated_loudness_lufs(analysis, 1, decoded.analysis_sample_rate),
+        duration_seconds: time_domain::duration_seconds(
+            end_frame - start_frame,
+            decoded.sample_rate,
+        ),
+    })
+}
+
+fn bucket_peak(peaks: &WaveformPeaks, start_frame: usize, end_frame: usize) -> f32 {
+    let bucket = peaks.bucket_size_frames.max(1);
+    let end = end_frame.div_ceil(bucket).min(peaks.mono.len());
+    let start = (start_frame / bucket).min(end);
+    let buckets = [Some(&peaks.mono), peaks.left.as_ref(), peaks.right.as_ref()];
+    buckets
+        .into_iter()
+        .flatten()
+        .flat_map(|channel| channel.get(start..end.min(channel.len())).unwrap_or(&[]))
+        .map(|(min, max)| min.abs().max(max.abs()))
+        .fold(0.0, f32::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn long_file_selection_uses_peaks_and_analysis_samples() {
+        let decoded = DecodedWaveform {
+            cache_token: 1,
+            samples: Arc::from(Vec::<f32>::new()),
+            analysis_samples: Arc::from(vec![0.1, 0.1, 0.5, 0.5, 0.5, 0.5, 0.1, 0.1]),
+            analysis_sample_rate: 100,
+            analysis_stride: 10,
+            peaks: Some(Arc::new(WaveformPeaks {
+                total_frames: 80,
+                channels: 1,
+                bucket_size_frames: 20,
+                mono: vec![(-0.1, 0.1), (-0.8, 0.5), (-0.2, 0.3), (-0.9, 0.9)],
+                left: None,
+                right: None,
+            })),
+            duration_seconds: 0.08,
+            sample_rate: 1_000,
+            channels: 1,
+            cue_markers: Vec::new(),
+        };
+
+        let stats = measure_long_selection(&decoded, SelectionRange::new(0.25, 0.75)).unwrap();
+
+        assert!((stats.peak - 0.8).abs() < 1e-6);
+        assert!((stats.rms - 0.5).abs() < 1e-6);
+        assert!((stats.duration_seconds - 0.04).abs() < 1e-4);
+    }
+}
@@ -47,57 +47,20 @@ pub(crate) fn tag_selected(controller: &mut EguiController, target: crate::sampl
             Err(err) => last_error = Some(err),
         }
     }
-    if !applied.is_empty() {
-        let label = if target == crate::sample_sources::Rating::KEEP_1 {
-            "Tag keep"
-        } else if target == crate::sample_sources::Rating::TRASH_3 {
-            "Tag trash"
-        } else if target == crate::sample_sources::Rating::NEUTRAL {
-            "Tag neutral"
-        } else {
-            "Tag sample"
-        };
-        let redo_updates: Vec<(SourceId, PathBuf, crate::sample_sources::Rating)> = applied
-            .iter()
-            .map(|(source_id, path, _)| (source_id.clone(), path.clone(), target))
-            .collect();
-        let refocus_path_undo = refocus_path.clone();
-        controller.push_undo_entry(super::undo::UndoEntry::<EguiController>::new(
-            label,
-            move |controller: &mut EguiController| {
-                for (source_id, path, tag) in applied.iter() {
-                    let source = controller
-                        .library
-                        .sources
-                        .iter()
-                        .find(|s| &s.id == source_id)
-                        .cloned()
-                        .ok_or_else(|| "Source not available".to_string())?;
-                    controller.set_sample_tag_for_source(&source, path, *tag, false)?;
-                }
-                if let Some(path) = refocus_path_undo.as_deref() {
-                    controller.selection_state.suppress_autoplay_once = true;
-                    if let Some(row) = controller.visible_row_for_path(path) {
-                        controller.focus_browser_row_only(row);
-                    }
-                }
-                Ok(super::undo::UndoExecution::Applied)
-            },
-            move |controller: &mut EguiController| {
-                for (source_id, path, tag) in redo_updates.iter() {
-                    let source = controller
-                        .library
-                        .sources
-                        .iter()
-                        .find(|s| &s.id == source_id)
-                        .cloned()
-                        .ok_or_else(|| "Source not available".to_string())?;
-                    controller.set_sample_tag_for_source(&source, path, *tag, false)?;
-                }
-                Ok(super::undo::UndoExecution::Applied)
-            },
-        ));
-    }
+    let label = if target == crate::sample_sources::Rating::KEEP_1 {
+        "Tag keep"
+    } else if target == crate::sample_sources::Rating::TRASH_3 {
+        "Tag trash"
+    } else if target == crate::sample_sources::Rating::NEUTRAL {
+        "Tag neutral"
+    } else {
+        "Tag sample"
+    };
+    let changes = applied
+        .into_iter()
+        .map(|(source_id, path, before)| (source_id, path, before, target))
+        .collect();
+    push_tag_changes_undo_entry(controller, label, changes, refocus_path);
     controller.refocus_after_filtered_removal(primary_row);
     if let Some(err) = last_error {
         controller.set_status(err, StatusTone::Error);
@@ -153,12 +116,7 @@ pub(crate) fn adjust_selected_rating(controller: &mut EguiController, delta: i8)
     controller.focus_browser_context();
     controller.ui.browser.autoscroll = true;
     let mut last_error = None;
-
-    // Use a HashMap to store previous values to allow per-item untagging if needed
-    // However, the standard pattern in tagging.rs is to store (SourceId, Path, Rating).
-    let mut previous_values: Vec<(SourceId, PathBuf, crate::sample_sources::Rating)> = Vec::new();
-    let mut applied_updates: Vec<(SourceId, PathBuf, crate::sample_sources::Rating)> = Vec::new();
-    
+    let mut changes: Vec<TagChange> = Vec::new();
     let mut contexts = Vec::with_capacity(rows.len());
     let mut seen = std::collections::HashSet::new();
     for row in rows {
@@ -175,88 +133,40 @@ pub(crate) fn adjust_selected_rating(controller: &mut EguiController, delta: i8)
     for ctx in contexts {
         let current_rating = ctx.entry.tag;
         let mut new_val = current_rating.val() + delta;
-
-        // If we were rated and hit 0, skip it
+        // Rated samples step over neutral instead of landing on it.
         if current_rating.val() != 0 && new_val == 0 {
             new_val += delta;
         }
-
-        let new_val = new_val.clamp(-3, 3);
-        let target = crate::sample_sources::Rating::new(new_val);
-
-        if target != current_rating {
-             let before = (
+        let target = crate::sample_sources::Rating::new(new_val.clamp(-3, 3));
+        if target == current_rating {
+            continue;
+        }
+        match controller.set_sample_tag_for_source(
+            &ctx.source,
+            &ctx.entry.relative_path,
+            target,
+            true,
+        ) {
+            Ok(()) => changes.push((
                 ctx.source.id.clone(),
                 ctx.entry.relative_path.clone(),
                 current_rating,
-            );
-            
-            match controller.set_sample_tag_for_source(
-                &ctx.source,
-                &ctx.entry.relative_path,
                 target,
-                true,
-            ) {
-                Ok(()) => {
-                    previous_values.push(before);
-                    applied_updates.push((ctx.source.id.clone(), ctx.entry.relative_path.clone(), target));
-                }
-                Err(err) => last_error = Some(err),
-            }
+            )),
+            Err(err) => last_error = Some(err),
         }
     }
-
-    if !applied_updates.is_empty() {
-        let label = if delta > 0 { "Increase rating" } else { "Decrease rating" };
-        
-        // Capture for closures
-        let redo_updates = applied_updates.clone();
-        let undo_values = previous_values;
-        let refocus_path_undo = refocus_path.clone();
-
-        controller.push_undo_entry(super::undo::UndoEntry::<EguiController>::new(
-            label,
-            move |controller: &mut EguiController| {
-                for (source_id, path, tag) in undo_values.iter() {
-                     let source = controller
-                        .library
-                        .sources
-                        .iter()
-                        .find(|s| &s.id == source_id)
-                        .cloned()
-                        .ok_or_else(|| "Source not available".to_string())?;
-                    controller.set_sample_tag_for_source(&source, path, *tag, false)?;
-                }
-                if let Some(path) = refocus_path_undo.as_deref() {
-                     controller.selection_state.suppress_autoplay_once = true;
-                     if let Some(row) = controller.visible_row_for_path(path) {
-                         controller.focus_browser_row_only(row);
-                     }
-                }
-                Ok(super::undo::UndoExecution::Applied)
-            },
-            move |controller: &mut EguiController| {
-                 for (source_id, path, tag) in redo_updates.iter() {
-                    let source = controller
-                        .library
-                        .sources
-                        .iter()
-                        .find(|s| &s.id == source_id)
-                        .cloned()
-                        .ok_or_else(|| "Source not available".to_string())?;
-                    controller.set_sample_tag_for_source(&source, path, *tag, false)?;
-                }
-                Ok(super::undo::UndoExecution::Applied)
-            },
-        ));
-    }
+    let label = if delta > 0 {
+        "Increase rating"
+    } else {
+        "Decrease rating"
+    };
+    push_tag_changes_undo_entry(controller, label, changes, refocus_path);
     controller.refocus_after_filtered_removal(primary_row);
     if let Some(err) = last_error {
         controller.set_status(err, StatusTone::Error);
     }
 
-
-
     if controller.settings.controls.advance_after_rating
         && controller.ui.browser.selected_visible == Some(primary_row)
     {
@@ -270,3 +180,64 @@ pub(crate) fn adjust_selected_rating(controller: &mut EguiController, delta: i8)
         }
     }
 }
+
+/// A sample's tag before and after a change: `(source, path, before, after)`.
+type TagChange = (
+    SourceId,
+    PathBuf,
+    crate::sample_sources::Rating,
+    crate::sample_sources::Rating,
+);
+
+pub(crate) fn push_tag_undo_entry(
+    controller: &mut EguiController,
+    label: &str,
+    previous: Vec<(SourceId, PathBuf, crate::sample_sources::Rating)>,
+    target: crate::sample_sources::Rating,
+) {
+    let changes = previous
+        .into_iter()
+        .map(|(source_id, path, before)| (source_id, path, before, target))
+        .collect();
+    push_tag_changes_undo_entry(controller, label, changes, None);
+}
+
+/// Record per-sample tag changes as one undo entry, refocusing `refocus_path` on undo.
+fn push_tag_changes_undo_entry(
+    controller: &mut EguiController,
+    label: &str,
+    changes: Vec<TagChange>,
+    refocus_path: Option<PathBuf>,
+) {
+    if changes.is_empty() {
+        return;
+    }
+    let redo_changes = changes.clone();
+    controller.push_undo_entry(super::undo::UndoEntry::<EguiController>::new(
+        label,
+        move |controller: &mut EguiController| {
+            for (source_id, path, before, _) in changes.iter() {
+                let source = controller
+                    .find_source_by_id(source_id)
+                    .ok_or_else(|| "Source not available".to_string())?;
+                controller.set_sample_tag_for_source(&source, path, *before, false)?;
+            }
+            if let Some(path) = refocus_path.as_deref() {
+                controller.selection_state.suppress_autoplay_once = true;
+                if let Some(row) = controller.visible_row_for_path(path) {
+                    controller.focus_browser_row_only(row);
+                }
+            }
+            Ok(super::undo::UndoExecution::Applied)
+        },
+        move |controller: &mut EguiController| {
+            for (source_id, path, _, after) in redo_changes.iter() {
+                let source = controller
+                    .find_source_by_id(source_id)
+                    .ok_or_else(|| "Source not available".to_string())?;
+                controller.set_sample_tag_for_source(&source, path, *after, false)?;
+            }
+            Ok(super::undo::UndoExecution::Applied)
+        },
+    ));
+}
@@ -1,6 +1,8 @@
 use super::*;
 use crate::egui_app::state::FocusContext;
+use crate::egui_app::controller::state::audio::SeekRequest;
 use crate::selection::SelectionEdge;
+use std::time::Duration;
 
 const TRANSIENT_SNAP_RADIUS: f32 = 0.01;
 const SELECTION_START_SNAP_RADIUS: f32 = 0.01;
@@ -10,6 +12,7 @@ const SELECTION_START_SNAP_SECONDS: f32 = 0.1;
 pub(crate) fn start_selection_drag(controller: &mut EguiController, position: f32) {
     controller.selection_state.bpm_scale_beats = None;
     controller.begin_selection_undo("Selection");
+    controller.begin_interaction_pause();
     let start = snap_selection_start(controller, position)
         .or_else(|| snap_to_transient(controller, position))
         .unwrap_or(position);
@@ -19,6 +22,7 @@ pub(crate) fn start_selection_drag(controller: &mut EguiController, position: f3
 
 pub(crate) fn start_edit_selection_drag(controller: &mut EguiController, position: f32) {
     let _ = controller.commit_edit_selection_fades();
+    controller.begin_interaction_pause();
     let start = snap_to_transient(controller, position).unwrap_or(position);
     let range = controller.selection_state.edit_range.begin_new(start);
     controller.apply_edit_selection(Some(range));
@@ -33,6 +37,7 @@ pub(crate) fn start_selection_edge_drag(
         return false;
     }
     controller.begin_selection_undo("Selection");
+    controller.begin_interaction_pause();
     controller.selection_state.bpm_scale_beats = if bpm_scale {
         selection_scale_beats(controller)
     } else {
@@ -93,6 +98,7 @@ pub(crate) fn finish_selection_drag(controller: &mut EguiController) {
     controller.selection_state.bpm_scale_beats = None;
     clear_too_small_bpm_selection(controller);
     controller.commit_selection_undo();
+    controller.end_interaction_pause();
     let is_playing = controller
         .audio
         .player
@@ -124,6 +130,7 @@ pub(crate) fn finish_selection_drag(controller: &mut EguiController) {
 
 pub(crate) fn finish_edit_selection_drag(controller: &mut EguiController) {
     controller.selection_state.edit_range.finish_drag();
+    controller.end_interaction_pause();
 }
 
 pub(crate) fn set_selection_range(controller: &mut EguiController, range: SelectionRange) {
@@ -288,10 +295,31 @@ pub(crate) fn toggle_loop(controller: &mut EguiController) {
     }
 }
 
+fn seek_request(controller: &EguiController, position: f32) -> SeekRequest {
+    let controls = &controller.settings.controls;
+    let crossfade = controls
+        .seek_crossfade_enabled
+        .then(|| Duration::from_secs_f32(controls.seek_crossfade_ms.max(0.0) / 1000.0));
+    SeekRequest {
+        position,
+        crossfade,
+    }
+}
+
 pub(crate) fn seek_to(controller: &mut EguiController, position: f32) {
     let looped = controller.ui.waveform.loop_enabled;
-    record_play_start(controller, position);
-    if let Err(err) = controller.play_audio(looped, Some(position)) {
+    let request = seek_request(controller, position);
+    #[cfg(test)]
+    {
+        controller.audio.last_seek_request = Some(request);
+    }
+    if player::is_playing(controller)
+        && let Some(player) = controller.audio.player.as_ref()
+    {
+        player.borrow_mut().set_seek_crossfade(request.crossfade);
+    }
+    record_play_start(controller, request.position);
+    if let Err(err) = controller.play_audio(looped, Some(request.position)) {
         controller.set_status(err, StatusTone::Error);
     }
 }
@@ -11,6 +11,7 @@ use std::time::{Duration, Instant};
 
 const COMMAND_POLL_INTERVAL: Duration = Duration::from_millis(200);
 const SOURCE_WATCH_DEBOUNCE: Duration = Duration::from_millis(400);
+const MAX_TARGETED_PATHS: usize = 512;
 
 /// Input used to configure which source roots are actively watched.
 #[derive(Clone, Debug)]
@@ -41,6 +42,11 @@ pub(crate) enum SourceWatchCommand {
 #[derive(Debug, Clone)]
 pub(crate) struct SourceWatchEvent {
     pub(crate) source_id: SourceId,
+    /// Audio file paths reported as created, modified, or removed since the last event.
+    pub(crate) changed_paths: Vec<PathBuf>,
+    /// True when the changes cannot be described by file paths alone (directories,
+    /// unspecific events, or too many paths), so the whole source must be rescanned.
+    pub(crate) needs_full_scan: bool,
 }
 
 /// Join handle and command sender for the source watcher thread.
@@ -118,17 +124,20 @@ fn run_source_watcher(command_rx: Receiver<SourceWatchCommand>, message_tx: JobM
             if !event_triggers_sync(&event) {
                 continue;
             }
-            let mut impacted = HashSet::new();
+            let unspecific = matches!(event.kind, EventKind::Any);
             for path in &event.paths {
                 if !path_is_candidate(path) {
                     continue;
                 }
-                if let Some(source_id) = select_source_for_path(&sources, path) {
-                    impacted.insert(source_id);
-                }
-            }
-            for source_id in impacted {
-                update_pending_watch(&mut pending, source_id, Instant::now());
+                let Some(source_id) = select_source_for_path(&sources, path) else {
+                    continue;
+                };
+                let change = if unspecific || !is_supported_audio(path) {
+                    None
+                } else {
+                    Some(path.clone())
+                };
+                update_pending_watch(&mut pending, source_id, Instant::now(), change);
             }
         }
 
@@ -138,23 +147,49 @@ fn run_source_watcher(command_rx: Receiver<SourceWatchCommand>, message_tx: JobM
             SOURCE_WATCH_DEBOUNCE,
             scan_in_progress,
         );
-        for source_id in ready {
-            let _ = message_tx.send(JobMessage::SourceWatch(SourceWatchEvent { source_id }));
+        for event in ready {
+            let _ = message_tx.send(JobMessage::SourceWatch(event));
         }
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 struct PendingSourceWatch {
     last_event: Instant,
+    paths: HashSet<PathBuf>,
+    needs_full_scan: bool,
 }
 
+/// Record a change for a source, restarting its debounce window.
+/// `None` marks a change that can only be resolved by a full rescan.
 fn update_pending_watch(
     pending: &mut HashMap<SourceId, PendingSourceWatch>,
     source_id: SourceId,
     now: Instant,
+    change: Option<PathBuf>,
 ) {
-    pending.insert(source_id, PendingSourceWatch { last_event: now });
+    let entry = pending
+        .entry(source_id)
+        .or_insert_with(|| PendingSourceWatch {
+            last_event: now,
+            paths: HashSet::new(),
+            needs_full_scan: false,
+        });
+    entry.last_event = now;
+    match change {
+        Some(path) if !entry.needs_full_scan => {
+            entry.paths.insert(path);
+            if entry.paths.len() > MAX_TARGETED_PATHS {
+                entry.needs_full_scan = true;
+                entry.paths.clear();
+            }
+        }
+        Some(_) => {}
+        None => {
+            entry.needs_full_scan = true;
+            entry.paths.clear();
+        }
+    }
 }
 
 fn drain_ready_sources(
@@ -162,7 +197,7 @@ fn drain_ready_sources(
     now: Instant,
     debounce: Duration,
     scan_in_progress: bool,
-) -> Vec<SourceId> {
+) -> Vec<SourceWatchEvent> {
     if scan_in_progress {
         return Vec::new();
     }
@@ -173,10 +208,19 @@ fn drain_ready_sources(
                 .then(|| source_id.clone())
         })
         .collect();
-    for source_id in &ready {
-        pending.remove(source_id);
-    }
     ready
+        .into_iter()
+        .filter_map(|source_id| {
+            let entry = pending.remove(&source_id)?;
+            let mut changed_paths: Vec<PathBuf> = entry.paths.into_iter().collect();
+            changed_paths.sort();
+            Some(SourceWatchEvent {
+                source_id,
+                changed_paths,
+                needs_full_scan: entry.needs_full_scan,
+            })
+        })
+        .collect()
 }
 
 fn prune_pending_sources(
@@ -301,7 +345,7 @@ mod tests {
         let mut pending = HashMap::new();
         let source_id = SourceId::from_string("a");
         let start = Instant::now();
-        update_pending_watch(&mut pending, source_id.clone(), start);
+        update_pending_watch(&mut pending, source_id.clone(), start, None);
         assert!(drain_ready_sources(
             &mut pending,
             start + Duration::from_millis(200),
@@ -315,7 +359,69 @@ mod tests {
             Duration::from_millis(400),
             false,
         );
-        assert_eq!(ready, vec![source_id]);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].source_id, source_id);
+        assert!(ready[0].needs_full_scan);
+    }
+
+    #[test]
+    fn pending_watch_collects_paths_across_debounced_events() {
+        let mut pending = HashMap::new();
+        let source_id = SourceId::from_string("a");
+        let start = Instant::now();
+        let kick = PathBuf::from("/music/kick.wav");
+        let snare = PathBuf::from("/music/snare.wav");
+        update_pending_watch(&mut pending, source_id.clone(), start, Some(kick.clone()));
+        update_pending_watch(
+            &mut pending,
+            source_id.clone(),
+            start + Duration::from_millis(300),
+            Some(snare.clone()),
+        );
+        update_pending_watch(
+            &mut pending,
+            source_id.clone(),
+            start + Duration::from_millis(350),
+            Some(kick.clone()),
+        );
+        assert!(drain_ready_sources(
+            &mut pending,
+            start + Duration::from_millis(500),
+            Duration::from_millis(400),
+            false
+        )
+        .is_empty());
+        let ready = drain_ready_sources(
+            &mut pending,
+            start + Duration::from_millis(800),
+            Duration::from_millis(400),
+            false,
+        );
+        assert_eq!(ready.len(), 1);
+        assert!(!ready[0].needs_full_scan);
+        assert_eq!(ready[0].changed_paths, vec![kick, snare]);
+    }
+
+    #[test]
+    fn pending_watch_falls_back_to_full_scan_for_directories() {
+        let mut pending = HashMap::new();
+        let source_id = SourceId::from_string("a");
+        let start = Instant::now();
+        update_pending_watch(
+            &mut pending,
+            source_id.clone(),
+            start,
+            Some(PathBuf::from("/music/kick.wav")),
+        );
+        update_pending_watch(&mut pending, source_id, start, None);
+        let ready = drain_ready_sources(
+            &mut pending,
+            start + Duration::from_millis(500),
+            Duration::from_millis(400),
+            false,
+        );
+        assert!(ready[0].needs_full_scan);
+        assert!(ready[0].changed_paths.is_empty());
     }
 
     #[test]
@@ -323,7 +429,7 @@ mod tests {
         let mut pending = HashMap::new();
         let source_id = SourceId::from_string("a");
         let start = Instant::now();
-        update_pending_watch(&mut pending, source_id, start);
+        update_pending_watch(&mut pending, source_id, start, None);
         let ready = drain_ready_sources(
             &mut pending,
             start + Duration::from_millis(500),
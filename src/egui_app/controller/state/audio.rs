@@ -2,12 +2,14 @@
 
 use crate::audio::AudioPlayer;
 use crate::egui_app::controller::playback::audio_cache::AudioCache;
+use crate::egui_app::state::LoopCrossfadeSettings;
 use crate::sample_sources::SourceId;
 use crate::audio::{AudioRecorder, InputMonitor};
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::rc::Rc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 pub(crate) struct ControllerAudioState {
     pub(crate) player: Option<Rc<RefCell<AudioPlayer>>>,
@@ -17,6 +19,12 @@ pub(crate) struct ControllerAudioState {
     pub(crate) recording_target: Option<RecordingTarget>,
     pub(crate) input_monitor: Option<InputMonitor>,
     pub(crate) pending_age_update: Option<PendingAgeUpdate>,
+    pub(crate) loop_crossfade_preview: Option<LoopCrossfadePreview>,
+    /// Sample ids played during this session, used to hide already-heard similar results.
+    pub(crate) auditioned_sample_ids: HashSet<String>,
+    #[cfg(test)]
+    /// Most recent seek issued through the transport, for crossfade tests.
+    pub(crate) last_seek_request: Option<SeekRequest>,
 }
 
 impl ControllerAudioState {
@@ -33,10 +41,31 @@ impl ControllerAudioState {
             recording_target: None,
             input_monitor: None,
             pending_age_update: None,
+            loop_crossfade_preview: None,
+            auditioned_sample_ids: HashSet::new(),
+            #[cfg(test)]
+            last_seek_request: None,
         }
     }
 }
 
+/// Playback restart requested by a seek, with the crossfade window to blend over.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SeekRequest {
+    pub(crate) position: f32,
+    /// `None` jumps straight to the new position.
+    pub(crate) crossfade: Option<Duration>,
+}
+
+/// Loop crossfade currently auditioned from memory instead of the file on disk.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LoopCrossfadePreview {
+    pub(crate) source_id: SourceId,
+    pub(crate) relative_path: PathBuf,
+    pub(crate) settings: LoopCrossfadeSettings,
+    pub(crate) fade_frames: usize,
+}
+
 #[derive(Clone)]
 pub(crate) struct PendingAgeUpdate {
     pub(crate) source_id: SourceId,
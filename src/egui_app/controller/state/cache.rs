@@ -3,7 +3,8 @@
 use super::super::{
     SampleSource, SourceDatabase, SourceDbError, SourceId, WavEntry,
 };
-use crate::egui_app::controller::library::{source_folders, wavs};
+use crate::egui_app::controller::library::{analysis_jobs, source_folders, wavs};
+use crate::sample_sources::path_text::normalize_path_separators;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
@@ -69,10 +70,52 @@ pub(crate) struct BrowserCacheState {
     pub(crate) analysis_failures:
         HashMap<SourceId, HashMap<PathBuf, String>>,
     pub(crate) analysis_failures_pending: HashSet<SourceId>,
+    pub(crate) analysis_warnings: HashMap<SourceId, HashMap<PathBuf, String>>,
+    pub(crate) silent_samples: HashMap<SourceId, HashSet<PathBuf>>,
+    pub(crate) decode_outcomes: HashMap<SourceId, analysis_jobs::DecodeOutcomeCounts>,
+    pub(crate) decode_outcomes_pending: HashSet<SourceId>,
     pub(crate) search: wavs::BrowserSearchCache,
     pub(crate) features: HashMap<SourceId, FeatureCache>,
     pub(crate) bpm_values: HashMap<SourceId, HashMap<PathBuf, Option<f32>>>,
     pub(crate) durations: HashMap<SourceId, HashMap<PathBuf, f32>>,
+    pub(crate) class_predictions: ClassPredictionCache,
+}
+
+/// Predicted class shown as a chip next to a browser row.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ClassPrediction {
+    pub(crate) label: String,
+    pub(crate) confidence: f32,
+}
+
+/// Classifier predictions keyed by sample id and classifier model version.
+///
+/// Samples without a stored embedding cache `None` so rows aren't re-queried every frame.
+#[derive(Default)]
+pub(crate) struct ClassPredictionCache {
+    entries: HashMap<(String, u64), Option<ClassPrediction>>,
+}
+
+impl ClassPredictionCache {
+    /// Return the cached prediction, running `compute` only on the first lookup.
+    pub(crate) fn get_or_compute(
+        &mut self,
+        sample_id: &str,
+        model_version: u64,
+        compute: impl FnOnce() -> Option<ClassPrediction>,
+    ) -> Option<ClassPrediction> {
+        let key = (sample_id.to_string(), model_version);
+        if let Some(cached) = self.entries.get(&key) {
+            return cached.clone();
+        }
+        let prediction = compute();
+        self.entries.insert(key, prediction.clone());
+        prediction
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -116,9 +159,14 @@ impl ControllerUiCacheState {
                 labels: HashMap::new(),
                 analysis_failures: HashMap::new(),
                 analysis_failures_pending: HashSet::new(),
+                analysis_warnings: HashMap::new(),
+                silent_samples: HashMap::new(),
+                decode_outcomes: HashMap::new(),
+                decode_outcomes_pending: HashSet::new(),
                 search: wavs::BrowserSearchCache::default(),
                 features: HashMap::new(),
                 bpm_values: HashMap::new(),
+                class_predictions: ClassPredictionCache::default(),
                 durations: HashMap::new(),
             },
             folders: FolderBrowsersState {
@@ -190,8 +238,8 @@ impl WavEntriesState {
         path: &Path,
         entry: WavEntry,
     ) -> bool {
-        let normalized = path.to_string_lossy().replace('\\', "/");
-        let Some(index) = self.lookup.get(Path::new(&normalized)).copied() else {
+        let normalized = normalize_path_separators(path);
+        let Some(index) = self.lookup.get(&normalized).copied() else {
             return false;
         };
         let Some(slot) = self.entry_mut(index) else {
@@ -202,8 +250,7 @@ impl WavEntriesState {
     }
 
     pub(crate) fn insert_lookup(&mut self, path: PathBuf, index: usize) {
-        let normalized = path.to_string_lossy().replace('\\', "/");
-        self.lookup.insert(PathBuf::from(normalized), index);
+        self.lookup.insert(normalize_path_separators(&path), index);
     }
 }
 
@@ -245,6 +292,7 @@ mod tests {
             looped: false,
             missing: false,
             last_played_at: None,
+            stars: None,
         }]);
         
         let new_entry = WavEntry {
@@ -256,6 +304,7 @@ mod tests {
             looped: false,
             missing: false,
             last_played_at: None,
+            stars: None,
         };
         
         // Update using backslash path
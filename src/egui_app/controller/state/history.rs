@@ -11,6 +11,8 @@ pub(crate) struct ControllerHistoryState {
     pub(crate) pending_undo: Option<undo::DeferredUndo<super::super::EguiController>>,
     pub(crate) random_history: RandomHistoryState,
     pub(crate) focus_history: FocusHistoryState,
+    /// Pre-edit snapshot of the most recent in-place audio edit.
+    pub(crate) last_edit_backup: Option<EditBackupRef>,
 }
 
 impl ControllerHistoryState {
@@ -20,10 +22,19 @@ impl ControllerHistoryState {
             pending_undo: None,
             random_history: RandomHistoryState::new(),
             focus_history: FocusHistoryState::new(),
+            last_edit_backup: None,
         }
     }
 }
 
+/// Location of the pre-edit copy kept by an undo entry for an overwritten sample.
+#[derive(Clone)]
+pub(crate) struct EditBackupRef {
+    pub(crate) source_id: SourceId,
+    pub(crate) relative_path: PathBuf,
+    pub(crate) before: PathBuf,
+}
+
 #[derive(Clone)]
 pub(crate) struct RandomHistoryEntry {
     pub(crate) source_id: SourceId,
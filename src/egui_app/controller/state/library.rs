@@ -1,6 +1,7 @@
 //! Library state for sources and missing entries.
 
 use super::super::{SampleSource, SourceId};
+use crate::analysis::centroid_classifier::NearestCentroidModel;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
@@ -27,6 +28,12 @@ impl MissingState {
 pub(crate) struct LibraryState {
     pub(crate) sources: Vec<SampleSource>,
     pub(crate) missing: MissingState,
+    /// True when the library database is read-only and source edits are disabled.
+    pub(crate) read_only: bool,
+    /// Imported embedding classifier used for tag suggestions.
+    pub(crate) classifier: Option<NearestCentroidModel>,
+    /// Bumped whenever `classifier` changes so cached predictions go stale.
+    pub(crate) classifier_version: u64,
 }
 
 impl LibraryState {
@@ -34,6 +41,9 @@ impl LibraryState {
         Self {
             sources: Vec::new(),
             missing: MissingState::new(),
+            read_only: false,
+            classifier: None,
+            classifier_version: 0,
         }
     }
 }
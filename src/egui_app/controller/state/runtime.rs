@@ -17,6 +17,7 @@ pub(crate) struct ControllerRuntimeState {
     pub(crate) similarity_prep_last_attempt: Option<Instant>,
     pub(crate) similarity_prep_force_full_analysis_next: bool,
     pub(crate) auto_sync_last_by_source: HashMap<SourceId, Instant>,
+    pub(crate) launch: crate::egui_app::launch::LaunchOptions,
     #[cfg(test)]
     pub(crate) progress_cancel_after: Option<usize>,
     #[cfg(test)]
@@ -38,6 +39,7 @@ impl ControllerRuntimeState {
             similarity_prep_last_attempt: None,
             similarity_prep_force_full_analysis_next: false,
             auto_sync_last_by_source: HashMap::new(),
+            launch: crate::egui_app::launch::LaunchOptions::default(),
             #[cfg(test)]
             progress_cancel_after: None,
             #[cfg(test)]
@@ -52,6 +54,8 @@ pub(crate) struct PerformanceGovernorState {
     pub(crate) last_frame_at: Option<Instant>,
     pub(crate) last_worker_count: Option<u32>,
     pub(crate) idle_worker_override: Option<u32>,
+    pub(crate) interaction_active: bool,
+    pub(crate) interaction_resume_at: Option<Instant>,
 }
 
 impl PerformanceGovernorState {
@@ -62,6 +66,8 @@ impl PerformanceGovernorState {
             last_frame_at: None,
             last_worker_count: None,
             idle_worker_override: None,
+            interaction_active: false,
+            interaction_resume_at: None,
         }
     }
 }
@@ -74,6 +80,8 @@ pub(crate) struct SimilarityPrepState {
     pub(crate) scan_completed_at: Option<i64>,
     pub(crate) skip_backfill: bool,
     pub(crate) force_full_analysis: bool,
+    /// Embeddings computed so far and the total expected, as `(completed, total)`.
+    pub(crate) embedding_progress: Option<(usize, usize)>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -1,8 +1,10 @@
 //! Selection and waveform view state for the controller.
 
 use crate::egui_app::controller::library::wavs;
+use crate::egui_app::controller::playback::SelectionStatisticsCache;
 use crate::sample_sources::{SampleSource, SourceId};
 use crate::selection::SelectionRange;
+use crate::waveform::transients::TransientNovelty;
 use crate::waveform::{DecodedWaveform, WaveformRenderer};
 use super::audio::LoadedAudio;
 use crate::selection::SelectionState;
@@ -39,7 +41,10 @@ impl ControllerSampleViewState {
             waveform: WaveformState {
                 size: [waveform_width, waveform_height],
                 decoded: None,
+                difference: None,
                 render_meta: None,
+                selection_statistics: None,
+                transient_novelty: None,
             },
             waveform_slide: None,
             wav: WavSelectionState::new(),
@@ -114,5 +119,11 @@ impl ControllerSelectionState {
 pub(crate) struct WaveformState {
     pub(crate) size: [u32; 2],
     pub(crate) decoded: Option<DecodedWaveform>,
+    /// Current audio minus its pre-edit version, rendered instead of `decoded` when set.
+    pub(crate) difference: Option<DecodedWaveform>,
     pub(crate) render_meta: Option<wavs::WaveformRenderMeta>,
+    /// Last selection readout, reused while the selection and audio are unchanged.
+    pub(crate) selection_statistics: Option<SelectionStatisticsCache>,
+    /// Novelty curve for the decoded audio, keyed by its cache token, reused when retuning.
+    pub(crate) transient_novelty: Option<(u64, TransientNovelty)>,
 }
@@ -15,7 +15,9 @@ pub(crate) struct AppSettingsState {
     pub(crate) audio_input: AudioInputConfig,
     pub(crate) controls: crate::sample_sources::config::InteractionOptions,
     pub(crate) trash_folder: Option<PathBuf>,
+    pub(crate) trash_strategy: crate::sample_sources::config::TrashStrategy,
     pub(crate) drop_targets: Vec<DropTargetConfig>,
+    pub(crate) layout: crate::sample_sources::config::WindowLayout,
 }
 
 impl AppSettingsState {
@@ -31,7 +33,9 @@ impl AppSettingsState {
             audio_input: AudioInputConfig::default(),
             controls: crate::sample_sources::config::InteractionOptions::default(),
             trash_folder: None,
+            trash_strategy: crate::sample_sources::config::TrashStrategy::default(),
             drop_targets: Vec::new(),
+            layout: crate::sample_sources::config::WindowLayout::default(),
         }
     }
 }
@@ -27,6 +27,7 @@ pub(super) fn sample_entry(name: &str, tag: crate::sample_sources::Rating) -> Wa
         looped: false,
         missing: false,
         last_played_at: None,
+        stars: None,
     }
 }
 
@@ -1,9 +1,10 @@
 use super::super::test_support::{
-    load_waveform_selection, prepare_with_source_and_wav_entries, sample_entry,
+    dummy_controller, load_waveform_selection, prepare_with_source_and_wav_entries, sample_entry,
 };
 use crate::selection::SelectionRange;
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::Duration;
 
 #[test]
 fn mute_selection_resumes_playback_when_playing() {
@@ -116,3 +117,21 @@ fn mute_selection_preserves_selection_and_loop_range() {
     // but we can check the playhead's active span end in UI state.
     assert_eq!(controller.ui.waveform.playhead.active_span_end, Some(0.4), "Playhead active span should match loop end");
 }
+
+#[test]
+fn seek_crossfade_setting_controls_seek_request_window() {
+    let (mut controller, _source) = dummy_controller();
+    controller.set_seek_crossfade_enabled(true);
+    controller.set_seek_crossfade_ms(40.0);
+
+    controller.seek_to(0.25);
+    let request = controller.audio.last_seek_request.expect("seek recorded");
+    assert!((request.position - 0.25).abs() < 1e-6);
+    let window = request.crossfade.expect("crossfade window");
+    assert!(window.abs_diff(Duration::from_millis(40)) < Duration::from_micros(1));
+
+    controller.set_seek_crossfade_enabled(false);
+    controller.seek_to(0.75);
+    let request = controller.audio.last_seek_request.expect("seek recorded");
+    assert_eq!(request.crossfade, None);
+}
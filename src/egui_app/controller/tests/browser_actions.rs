@@ -4,7 +4,7 @@ use super::super::test_support::{
 };
 use crate::sample_sources::Rating;
 use super::super::*;
-use super::common::visible_indices;
+use super::common::{max_sample_amplitude, visible_indices};
 use crate::egui_app::controller::ui::hotkeys;
 use crate::egui_app::state::FocusContext;
 use hound::WavReader;
@@ -70,6 +70,22 @@ fn f_hotkey_focuses_loaded_sample_in_browser() {
     assert_eq!(controller.ui.browser.selected_visible, Some(1));
 }
 
+#[test]
+fn reveal_hotkey_targets_focused_browser_sample() {
+    let (mut controller, _source) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("gone.wav", Rating::NEUTRAL)]);
+    controller.focus_browser_row_only(0);
+    controller.ui.focus.set_context(FocusContext::SampleBrowser);
+
+    let action = hotkeys::iter_actions()
+        .find(|action| action.command() == hotkeys::HotkeyCommand::RevealFocusedSample)
+        .expect("missing reveal sample hotkey");
+    controller.handle_hotkey(action, FocusContext::SampleBrowser);
+
+    assert!(controller.ui.status.text.contains("File missing"));
+    assert!(controller.ui.status.text.contains("gone.wav"));
+}
+
 #[test]
 fn x_key_toggle_respects_focus() {
     let (mut controller, _source) = prepare_with_source_and_wav_entries(vec![
@@ -128,6 +144,36 @@ fn tag_actions_apply_to_all_selected_rows() {
     assert_eq!(controller.wav_entry(1).unwrap().tag, crate::sample_sources::Rating::KEEP_1);
 }
 
+#[test]
+fn map_selection_tag_action_tags_every_selected_point() {
+    let (mut controller, source) = prepare_with_source_and_wav_entries(vec![
+        sample_entry("one.wav", Rating::NEUTRAL),
+        sample_entry("two.wav", Rating::NEUTRAL),
+        sample_entry("three.wav", Rating::NEUTRAL),
+    ]);
+    controller.ui.map.selected_sample_ids = ["one.wav", "three.wav"]
+        .iter()
+        .map(|name| {
+            crate::egui_app::controller::library::analysis_jobs::build_sample_id(
+                source.id.as_str(),
+                Path::new(name),
+            )
+        })
+        .collect();
+
+    let tagged = controller.tag_map_selection(Rating::TRASH_3).unwrap();
+
+    assert_eq!(tagged, 2);
+    assert_eq!(controller.ui.status.text, "Tagged 2 map samples as Trash (-3)");
+    let mut tag_of = |name: &str| {
+        let index = controller.wav_index_for_path(Path::new(name)).unwrap();
+        controller.wav_entry(index).unwrap().tag
+    };
+    assert_eq!(tag_of("one.wav"), Rating::TRASH_3);
+    assert_eq!(tag_of("two.wav"), Rating::NEUTRAL);
+    assert_eq!(tag_of("three.wav"), Rating::TRASH_3);
+}
+
 #[test]
 fn delete_actions_apply_to_all_selected_rows() {
     let (mut controller, source) = prepare_with_source_and_wav_entries(vec![
@@ -198,6 +244,29 @@ fn normalize_actions_apply_to_all_selected_rows() {
     assert!(entries.iter().all(|e| e.file_size > 0));
 }
 
+#[test]
+fn matching_peak_levels_brings_selected_rows_to_the_same_peak() {
+    let (mut controller, source) = prepare_with_source_and_wav_entries(vec![
+        sample_entry("quiet.wav", crate::sample_sources::Rating::NEUTRAL),
+        sample_entry("loud.wav", crate::sample_sources::Rating::NEUTRAL),
+    ]);
+    write_test_wav(&source.root.join("quiet.wav"), &[0.0, 0.1, -0.2, 0.05]);
+    write_test_wav(&source.root.join("loud.wav"), &[0.0, 0.6, -0.3, 0.4]);
+
+    controller.focus_browser_row_only(0);
+    controller.toggle_browser_row_selection(1);
+    let rows = controller.action_rows_from_primary(0);
+
+    controller
+        .match_browser_sample_levels(&rows, LevelMatchMode::Peak)
+        .unwrap();
+
+    let quiet = max_sample_amplitude(&source.root.join("quiet.wav"));
+    let loud = max_sample_amplitude(&source.root.join("loud.wav"));
+    assert!((quiet - loud).abs() < 1e-4);
+    assert!((quiet - 1.0).abs() < 1e-4);
+}
+
 #[test]
 fn selection_persists_when_nudging_focus() {
     let (mut controller, _source) = prepare_with_source_and_wav_entries(vec![
@@ -358,3 +358,33 @@ fn browser_selection_is_retained_when_waveform_focused() {
     let selected_visible = controller.ui.browser.selected_visible;
     assert_eq!(selected_visible, visible_row);
 }
+
+#[test]
+fn browser_row_summary_reports_probed_stream_properties() {
+    let (mut controller, source) = dummy_controller();
+    controller.library.sources.push(source.clone());
+    controller.selection_state.ctx.selected_source = Some(source.id.clone());
+    let db = controller.database_for(&source).unwrap();
+    let mut batch = db.write_batch().unwrap();
+    batch.upsert_file(Path::new("loop.flac"), 10, 1).unwrap();
+    batch
+        .set_audio_metadata(
+            Path::new("loop.flac"),
+            &crate::sample_sources::AudioMetadata {
+                duration_seconds: Some(1.5),
+                sample_rate: Some(48_000),
+                channels: Some(2),
+            },
+        )
+        .unwrap();
+    batch.upsert_file(Path::new("raw.wav"), 10, 1).unwrap();
+    batch.commit().unwrap();
+
+    assert_eq!(
+        controller
+            .audio_metadata_summary_for_path(Path::new("loop.flac"))
+            .as_deref(),
+        Some("48.0 kHz · stereo · 1.50 s")
+    );
+    assert_eq!(controller.audio_metadata_summary_for_path(Path::new("raw.wav")), None);
+}
@@ -60,6 +60,7 @@ fn find_similar_from_map_switches_to_browser_list() {
         indices: vec![0],
         scores: vec![1.0],
         anchor_index: Some(0),
+        least_similar_first: false,
     });
     let action = hotkeys::iter_actions()
         .find(|a| a.id == "find-similar")
@@ -456,3 +457,59 @@ fn random_sample_navigation_avoids_repeats() {
         .expect("path");
     assert!(played.contains(&path), "Should repeat after all were played");
 }
+
+#[test]
+fn seeded_shuffle_is_reproducible_per_seed() {
+    let (mut controller, source) = dummy_controller();
+    controller.library.sources.push(source.clone());
+    controller.set_wav_entries_for_tests(
+        (0..12)
+            .map(|i| sample_entry(&format!("{i}.wav"), crate::sample_sources::Rating::NEUTRAL))
+            .collect(),
+    );
+    controller.rebuild_wav_lookup();
+    controller.rebuild_browser_lists();
+    let original = visible_indices(&controller);
+
+    controller.shuffle_visible_with_seed(7);
+    let first = visible_indices(&controller);
+    controller.rebuild_browser_lists();
+    controller.shuffle_visible_with_seed(7);
+    let repeat = visible_indices(&controller);
+    controller.rebuild_browser_lists();
+    controller.shuffle_visible_with_seed(8);
+    let other = visible_indices(&controller);
+
+    assert_eq!(first, repeat);
+    assert_ne!(first, other);
+    let mut sorted = first.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, original);
+}
+
+#[test]
+fn seeded_shuffle_survives_rebuilds_until_cleared() {
+    let (mut controller, source) = dummy_controller();
+    controller.library.sources.push(source.clone());
+    controller.set_wav_entries_for_tests(
+        (0..12)
+            .map(|i| sample_entry(&format!("{i}.wav"), crate::sample_sources::Rating::NEUTRAL))
+            .collect(),
+    );
+    controller.rebuild_wav_lookup();
+    controller.rebuild_browser_lists();
+    let original = visible_indices(&controller);
+
+    controller.shuffle_visible_with_seed(7);
+    let shuffled = visible_indices(&controller);
+    controller.rebuild_browser_lists();
+
+    assert_ne!(shuffled, original);
+    assert_eq!(visible_indices(&controller), shuffled);
+    assert_eq!(controller.browser_shuffle_seed(), Some(7));
+
+    controller.clear_browser_shuffle();
+
+    assert_eq!(controller.browser_shuffle_seed(), None);
+    assert_eq!(visible_indices(&controller), original);
+}
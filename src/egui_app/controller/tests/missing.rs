@@ -19,6 +19,7 @@ fn selecting_missing_sample_sets_waveform_notice() {
         looped: false,
         missing: true,
         last_played_at: None,
+        stars: None,
     }]);
     controller.rebuild_wav_lookup();
     controller.rebuild_browser_lists();
@@ -51,6 +52,7 @@ fn read_failure_marks_sample_missing() {
         looped: false,
         missing: false,
         last_played_at: None,
+        stars: None,
     }]);
     controller.rebuild_wav_lookup();
     controller.rebuild_browser_lists();
@@ -108,6 +110,7 @@ fn apply_wav_entries_updates_missing_lookup() {
             looped: false,
             missing: false,
             last_played_at: None,
+            stars: None,
         },
         WavEntry {
             relative_path: PathBuf::from("gone.wav"),
@@ -118,6 +121,7 @@ fn apply_wav_entries_updates_missing_lookup() {
             looped: false,
             missing: true,
             last_played_at: None,
+            stars: None,
         },
     ];
 
@@ -239,6 +243,7 @@ fn mark_missing_updates_cache_db_and_missing_set_when_inactive() {
             looped: false,
             missing: false,
             last_played_at: None,
+            stars: None,
         }],
     );
     controller
@@ -310,3 +315,26 @@ fn mark_missing_updates_db_and_missing_set_without_cache() {
             .is_some_and(|set| set.contains(&PathBuf::from("ghost.wav")))
     );
 }
+
+#[test]
+fn source_summaries_apply_to_rows_and_survive_refresh() {
+    let (mut controller, source) = dummy_controller();
+    controller.library.sources.push(source.clone());
+    controller.refresh_sources_ui();
+    let coverage = crate::sample_sources::AnalysisCoverage {
+        total: 4,
+        analyzed: 3,
+        embedded: 2,
+    };
+
+    controller.apply_source_summary(crate::egui_app::controller::jobs::SourceSummaryResult {
+        source_id: source.id.clone(),
+        coverage: Some(coverage),
+        last_analyzed_at: Some(42),
+    });
+    controller.refresh_sources_ui();
+
+    let row = &controller.ui.sources.rows[0];
+    assert_eq!(row.coverage, Some(coverage));
+    assert_eq!(row.last_analyzed_at, Some(42));
+}
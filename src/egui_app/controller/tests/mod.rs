@@ -16,11 +16,14 @@ mod playback_loop;
 mod recording;
 mod selection_bpm_scale;
 mod selection_undo;
+mod similarity_prep;
 mod transient_options;
 mod trash;
 mod waveform;
 mod waveform_nav_cursor;
 mod waveform_nav_render;
+mod window_layout;
 mod audio_action_playback;
 mod edit_selection_no_snap;
 mod rating_logic;
+mod user_markers;
@@ -4,6 +4,7 @@ use crate::egui_app::controller::library::analysis_jobs;
 use crate::selection::SelectionRange;
 use rusqlite::params;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tempfile::tempdir;
 
 fn setup_looping_controller(selection: SelectionRange) -> Option<EguiController> {
@@ -270,6 +271,7 @@ fn loading_non_looped_sample_disables_loop_playback() {
         looped: false,
         missing: false,
         last_played_at: None,
+        stars: None,
     }]);
     controller.rebuild_wav_lookup();
     controller.rebuild_browser_lists();
@@ -305,6 +307,7 @@ fn loading_non_looped_sample_preserves_loop_when_locked() {
         looped: false,
         missing: false,
         last_played_at: None,
+        stars: None,
     }]);
     controller.rebuild_wav_lookup();
     controller.rebuild_browser_lists();
@@ -320,3 +323,95 @@ fn loading_non_looped_sample_preserves_loop_when_locked() {
         .expect("pending playback to be queued");
     assert!(pending.looped);
 }
+
+#[test]
+fn loop_crossfade_preview_sets_playback_params_without_touching_file() {
+    let (mut controller, source) = dummy_controller();
+    controller.library.sources.push(source.clone());
+    let wav_path = source.root.join("preview_loop.wav");
+    let samples: Vec<f32> = (0..64).map(|i| (i as f32 / 64.0) - 0.5).collect();
+    write_test_wav(&wav_path, &samples);
+    let before = std::fs::read(&wav_path).unwrap();
+    let settings = crate::egui_app::state::LoopCrossfadeSettings {
+        depth_ms: 10,
+        depth_samples: 4,
+        unit: crate::egui_app::state::LoopCrossfadeUnit::Samples,
+    };
+    controller.ui.loop_crossfade_prompt = Some(crate::egui_app::state::LoopCrossfadePrompt {
+        source_id: source.id.clone(),
+        relative_path: PathBuf::from("preview_loop.wav"),
+        settings: settings.clone(),
+    });
+
+    // Without an output device the preview is still recorded and reported as a status.
+    controller
+        .preview_loop_crossfade_prompt()
+        .expect("preview should start");
+
+    let preview = controller
+        .audio
+        .loop_crossfade_preview
+        .clone()
+        .expect("preview should be active");
+    assert_eq!(preview.fade_frames, 4);
+    assert_eq!(preview.settings, settings);
+    assert_eq!(preview.relative_path, PathBuf::from("preview_loop.wav"));
+    assert_eq!(std::fs::read(&wav_path).unwrap(), before);
+    assert_eq!(std::fs::read_dir(&source.root).unwrap().count(), 1);
+
+    controller.clear_loop_crossfade_prompt();
+    assert!(!controller.loop_crossfade_preview_active());
+    assert_eq!(std::fs::read(&wav_path).unwrap(), before);
+}
+
+/// Move the player clock just past the end of loop cycle `cycles` and tick the playhead.
+fn advance_player_past_cycle(controller: &mut EguiController, cycles: u32) {
+    let player = controller.audio.player.clone().expect("player should be set");
+    {
+        let mut player = player.borrow_mut();
+        player.set_elapsed_for_tests(Some(Duration::ZERO));
+        let remaining = player.remaining_loop_duration().expect("player should loop");
+        let (start, end) = player.play_span().expect("play span should be set");
+        let span = Duration::from_secs_f32(end - start);
+        let elapsed = remaining + span * (cycles - 1) + Duration::from_millis(10);
+        player.set_elapsed_for_tests(Some(elapsed));
+    }
+    controller.tick_playhead();
+}
+
+#[test]
+fn loop_wrap_increments_cycle_counter() {
+    let selection = SelectionRange::new(0.2, 0.8);
+    let Some(mut controller) = setup_looping_controller(selection) else {
+        return;
+    };
+
+    controller.update_playhead_from_progress(Some(0.7), true);
+    controller.update_playhead_from_progress(Some(0.25), true);
+    assert_eq!(controller.ui.waveform.playhead.loop_cycles, 0);
+
+    advance_player_past_cycle(&mut controller, 1);
+    assert_eq!(controller.ui.waveform.playhead.loop_cycles, 1);
+    advance_player_past_cycle(&mut controller, 2);
+    assert_eq!(controller.ui.waveform.playhead.loop_cycles, 2);
+}
+
+#[test]
+fn loop_cycle_limit_stops_playback_at_cap() {
+    let selection = SelectionRange::new(0.2, 0.8);
+    let Some(mut controller) = setup_looping_controller(selection) else {
+        return;
+    };
+    controller.set_loop_cycle_limit(Some(2));
+    assert_eq!(controller.ui.waveform.playhead.loop_cycles, 0);
+
+    advance_player_past_cycle(&mut controller, 1);
+    assert_eq!(controller.ui.waveform.playhead.loop_cycles, 1);
+    assert!(controller.is_playing());
+
+    advance_player_past_cycle(&mut controller, 2);
+
+    assert_eq!(controller.ui.waveform.playhead.loop_cycles, 2);
+    assert!(!controller.is_playing());
+    assert!(!controller.ui.waveform.playhead.visible);
+}
@@ -1,6 +1,10 @@
-use super::super::test_support::{dummy_controller, sample_entry};
+use super::super::test_support::{
+    dummy_controller, prepare_with_source_and_wav_entries, sample_entry,
+};
+use super::common::visible_indices;
+use crate::egui_app::state::SampleBrowserSort;
 use crate::sample_sources::Rating;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 
 
@@ -92,3 +96,101 @@ fn advance_after_rating_respects_random_navigation() {
     let selected_path = controller.sample_view.wav.selected_wav.as_ref().expect("selection");
     assert_eq!(selected_path, &PathBuf::from("c.wav"));
 }
+
+#[test]
+fn star_rating_persists_and_sorts_highest_first() {
+    let (mut controller, source) = prepare_with_source_and_wav_entries(vec![
+        sample_entry("a.wav", Rating::NEUTRAL),
+        sample_entry("b.wav", Rating::KEEP_1),
+        sample_entry("c.wav", Rating::TRASH_1),
+        sample_entry("d.wav", Rating::NEUTRAL),
+    ]);
+    controller
+        .set_sample_stars_for_source(&source, Path::new("a.wav"), Some(2))
+        .unwrap();
+    controller
+        .set_sample_stars_for_source(&source, Path::new("c.wav"), Some(5))
+        .unwrap();
+    controller
+        .set_sample_stars_for_source(&source, Path::new("d.wav"), Some(4))
+        .unwrap();
+
+    let db = controller.database_for(&source).unwrap();
+    assert_eq!(db.stars_for_path(Path::new("c.wav")).unwrap(), Some(5));
+    assert_eq!(db.stars_for_path(Path::new("b.wav")).unwrap(), None);
+    assert_eq!(db.tag_for_path(Path::new("c.wav")).unwrap(), Some(Rating::TRASH_1));
+
+    controller.set_browser_sort(SampleBrowserSort::RatingDesc);
+    let order: Vec<PathBuf> = visible_indices(&controller)
+        .into_iter()
+        .filter_map(|index| controller.wav_entries.entry(index))
+        .map(|entry| entry.relative_path.clone())
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            PathBuf::from("c.wav"),
+            PathBuf::from("d.wav"),
+            PathBuf::from("a.wav"),
+            PathBuf::from("b.wav"),
+        ]
+    );
+}
+
+#[test]
+fn reset_tags_under_folder_only_touches_that_folder() {
+    let (mut controller, source) = prepare_with_source_and_wav_entries(vec![
+        sample_entry("drums/kick.wav", Rating::KEEP_1),
+        sample_entry("drums/fills/roll.wav", Rating::TRASH_3),
+        sample_entry("drumsets/snare.wav", Rating::KEEP_3),
+        sample_entry("bass/sub.wav", Rating::TRASH_1),
+    ]);
+
+    let reset = controller
+        .reset_tags_under_folder(&source, Path::new("drums"))
+        .unwrap();
+
+    assert_eq!(reset, 2);
+    let db = controller.database_for(&source).unwrap();
+    let tag = |path: &str| db.tag_for_path(Path::new(path)).unwrap();
+    assert_eq!(tag("drums/kick.wav"), Some(Rating::NEUTRAL));
+    assert_eq!(tag("drums/fills/roll.wav"), Some(Rating::NEUTRAL));
+    assert_eq!(tag("drumsets/snare.wav"), Some(Rating::KEEP_3));
+    assert_eq!(tag("bass/sub.wav"), Some(Rating::TRASH_1));
+
+    controller.undo();
+    assert_eq!(tag("drums/kick.wav"), Some(Rating::KEEP_1));
+    assert_eq!(tag("drums/fills/roll.wav"), Some(Rating::TRASH_3));
+}
+
+#[test]
+fn undo_restores_tag_before_keep() {
+    let (mut controller, source) = prepare_with_source_and_wav_entries(vec![
+        sample_entry("a.wav", Rating::TRASH_1),
+        sample_entry("b.wav", Rating::NEUTRAL),
+    ]);
+
+    controller.tag_browser_sample(0, Rating::KEEP_1).unwrap();
+    let db = controller.database_for(&source).unwrap();
+    assert_eq!(db.tag_for_path(Path::new("a.wav")).unwrap(), Some(Rating::KEEP_1));
+
+    controller.undo();
+    assert_eq!(db.tag_for_path(Path::new("a.wav")).unwrap(), Some(Rating::TRASH_1));
+    assert_eq!(controller.wav_entries.entry(0).unwrap().tag, Rating::TRASH_1);
+}
+
+#[test]
+fn undo_restores_rating_before_adjust() {
+    let (mut controller, source) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("a.wav", Rating::KEEP_1)]);
+    controller.sample_view.wav.selected_wav = Some(PathBuf::from("a.wav"));
+
+    controller.adjust_selected_rating(1);
+    let db = controller.database_for(&source).unwrap();
+    assert_eq!(db.tag_for_path(Path::new("a.wav")).unwrap(), Some(Rating::new(2)));
+
+    controller.undo();
+    assert_eq!(db.tag_for_path(Path::new("a.wav")).unwrap(), Some(Rating::KEEP_1));
+    controller.redo();
+    assert_eq!(db.tag_for_path(Path::new("a.wav")).unwrap(), Some(Rating::new(2)));
+}
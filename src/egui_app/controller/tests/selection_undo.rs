@@ -32,3 +32,32 @@ fn undo_restores_cleared_selection() {
     controller.undo();
     assert_eq!(controller.ui.waveform.selection, Some(selection));
 }
+
+#[test]
+fn selection_drag_pauses_analysis_and_schedules_resume() {
+    let (mut controller, _source) = dummy_controller();
+    controller.settings.analysis.pause_analysis_during_interaction = true;
+
+    controller.start_selection_drag(0.2);
+    assert!(controller.runtime.analysis.claiming_paused());
+    assert!(controller.runtime.performance.interaction_active);
+
+    controller.update_selection_drag(0.6, false);
+    controller.finish_selection_drag();
+    assert!(!controller.runtime.performance.interaction_active);
+    assert!(controller.runtime.performance.interaction_resume_at.is_some());
+    assert!(controller.runtime.analysis.claiming_paused());
+}
+
+#[test]
+fn interaction_pause_is_released_when_drag_ends_without_finish() {
+    let (mut controller, _source) = dummy_controller();
+    controller.settings.analysis.pause_analysis_during_interaction = true;
+
+    controller.start_selection_drag(0.2);
+    controller.selection_state.range.clear();
+    controller.update_performance_governor(false);
+
+    assert!(!controller.runtime.performance.interaction_active);
+    assert!(controller.runtime.performance.interaction_resume_at.is_some());
+}
@@ -0,0 +1,51 @@
+use super::super::test_support::dummy_controller;
+use super::super::*;
+use crate::app_dirs::ConfigBaseGuard;
+use crate::egui_app::controller::library::analysis_jobs;
+use rusqlite::params;
+use tempfile::tempdir;
+
+#[test]
+fn embedding_stage_reports_backfill_job_counts() {
+    let (mut controller, source) = dummy_controller();
+    controller.library.sources.push(source.clone());
+    let conn = analysis_jobs::open_source_db(&source.root).unwrap();
+    for (index, status) in ["done", "done", "failed", "pending", "running"].iter().enumerate() {
+        conn.execute(
+            "INSERT INTO analysis_jobs (sample_id, source_id, job_type, status, attempts, created_at)
+             VALUES (?1, ?2, 'embedding_backfill_v1', ?3, 0, 0)",
+            params![format!("{}::s{index}.wav", source.id.as_str()), source.id.as_str(), status],
+        )
+        .unwrap();
+    }
+    controller.runtime.similarity_prep = Some(SimilarityPrepState {
+        source_id: source.id.clone(),
+        stage: SimilarityPrepStage::AwaitEmbeddings,
+        umap_version: "v1".to_string(),
+        scan_completed_at: None,
+        skip_backfill: false,
+        force_full_analysis: false,
+        embedding_progress: None,
+    });
+
+    controller.refresh_similarity_prep_progress();
+
+    assert_eq!(controller.similarity_prep_embedding_progress(), Some((3, 5)));
+}
+
+#[test]
+fn quantized_storage_setting_applies_to_every_source() {
+    let config_dir = tempdir().unwrap();
+    let _guard = ConfigBaseGuard::set(config_dir.path().to_path_buf());
+    let (mut controller, source) = dummy_controller();
+    let other_dir = tempdir().unwrap();
+    let other = SampleSource::new(other_dir.path().to_path_buf());
+    controller.library.sources.extend([source.clone(), other.clone()]);
+
+    controller.set_quantized_embeddings_enabled(true);
+
+    for source in [&source, &other] {
+        let conn = analysis_jobs::open_source_db(&source.root).unwrap();
+        assert!(analysis_jobs::quantized_embedding_storage(&conn).unwrap());
+    }
+}
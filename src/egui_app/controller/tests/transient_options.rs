@@ -1,4 +1,8 @@
 use super::super::test_support::dummy_controller;
+use crate::egui_app::controller::LoadedTransients;
+use crate::selection::SelectionRange;
+use crate::waveform::DecodedWaveform;
+use crate::waveform::transients::SensitivityParams;
 
 #[test]
 fn transient_snap_restores_after_marker_toggle() {
@@ -19,3 +23,113 @@ fn transient_snap_restores_after_marker_toggle() {
     assert!(controller.ui.waveform.transient_markers_enabled);
     assert!(controller.ui.waveform.transient_snap_enabled);
 }
+
+#[test]
+fn extend_selection_moves_end_to_next_transient() {
+    let (mut controller, _source) = dummy_controller();
+    controller.sample_view.waveform.decoded = Some(DecodedWaveform {
+        cache_token: 1,
+        samples: std::sync::Arc::from(vec![0.0; 1_000]),
+        analysis_samples: std::sync::Arc::from(Vec::new()),
+        analysis_sample_rate: 0,
+        analysis_stride: 1,
+        peaks: None,
+        duration_seconds: 1.0,
+        sample_rate: 1_000,
+        channels: 1,
+        cue_markers: Vec::new(),
+    });
+    controller.ui.waveform.transients = vec![0.3, 0.55, 0.8];
+    let selection = SelectionRange::new(0.1, 0.4);
+    controller.selection_state.range.set_range(Some(selection));
+    controller.apply_selection(Some(selection));
+
+    controller.extend_selection_to_next_transient();
+
+    let extended = controller.ui.waveform.selection.expect("selection");
+    assert!((extended.start() - 0.1).abs() < 1e-6);
+    assert!((extended.end() - 0.55).abs() < 1e-6);
+}
+
+#[test]
+fn custom_tuning_with_larger_min_gap_reduces_transient_markers() {
+    let (mut controller, _source) = dummy_controller();
+    let mut samples = vec![0.0f32; 48_000];
+    for spike in 1..10 {
+        samples[spike * 4_800] = 1.0;
+    }
+    controller.sample_view.waveform.decoded = Some(DecodedWaveform {
+        cache_token: 7,
+        samples: std::sync::Arc::from(samples),
+        analysis_samples: std::sync::Arc::from(Vec::new()),
+        analysis_sample_rate: 0,
+        analysis_stride: 1,
+        peaks: None,
+        duration_seconds: 1.0,
+        sample_rate: 48_000,
+        channels: 1,
+        cue_markers: Vec::new(),
+    });
+    let tight = SensitivityParams::from_sensitivity(1.0);
+    controller.retune_waveform_transients(tight);
+    let tight_count = controller.ui.waveform.transients.len();
+    assert!(tight_count > 3);
+
+    controller.retune_waveform_transients(SensitivityParams {
+        min_gap_seconds: 0.25,
+        ..tight
+    });
+
+    assert!(controller.ui.waveform.transients.len() < tight_count);
+    assert_eq!(
+        controller
+            .sample_view
+            .waveform
+            .transient_novelty
+            .as_ref()
+            .map(|(token, _)| *token),
+        Some(7)
+    );
+}
+
+#[test]
+fn loader_transients_picked_with_current_tuning_are_used_as_is() {
+    let (mut controller, _source) = dummy_controller();
+    let tuning = SensitivityParams::from_sensitivity(1.0);
+    controller.ui.waveform.transient_tuning = Some(tuning);
+    let decoded = |cache_token| DecodedWaveform {
+        cache_token,
+        samples: std::sync::Arc::from(vec![0.0; 1_000]),
+        analysis_samples: std::sync::Arc::from(Vec::new()),
+        analysis_sample_rate: 0,
+        analysis_stride: 1,
+        peaks: None,
+        duration_seconds: 1.0,
+        sample_rate: 1_000,
+        channels: 1,
+        cue_markers: Vec::new(),
+    };
+
+    controller.apply_waveform_image(
+        decoded(11),
+        Some(LoadedTransients {
+            markers: vec![0.25, 0.5],
+            novelty: None,
+            tuning: Some(tuning),
+        }),
+    );
+    assert_eq!(controller.ui.waveform.transients, vec![0.25, 0.5]);
+    assert_eq!(controller.ui.waveform.transient_cache_token, Some(11));
+
+    // Markers picked with stale thresholds are recomputed for the current tuning.
+    controller.apply_waveform_image(
+        decoded(12),
+        Some(LoadedTransients {
+            markers: vec![0.25, 0.5],
+            novelty: None,
+            tuning: None,
+        }),
+    );
+    assert!(controller.ui.waveform.transients.is_empty());
+    assert_eq!(controller.ui.waveform.transient_cache_token, Some(12));
+}
@@ -90,6 +90,39 @@ fn moving_trashed_samples_can_cancel_midway() -> Result<(), String> {
     Ok(())
 }
 
+#[test]
+fn moving_trashed_samples_with_same_name_keeps_both() {
+    let temp = tempdir().unwrap();
+    let trash_root = temp.path().join("trash");
+    let (mut controller, first) = dummy_controller();
+    let second_root = temp.path().join("second");
+    std::fs::create_dir_all(&second_root).unwrap();
+    let second = crate::sample_sources::SampleSource::new(second_root);
+    controller.library.sources.push(first.clone());
+    controller.library.sources.push(second.clone());
+    controller.settings.trash_folder = Some(trash_root.clone());
+    controller.ui.trash_folder = Some(trash_root.clone());
+
+    for (source, samples) in [(&first, [0.1, -0.1]), (&second, [0.3, -0.3])] {
+        write_test_wav(&source.root.join("kick.wav"), &samples);
+        let db = controller.database_for(source).unwrap();
+        db.upsert_file(Path::new("kick.wav"), 4, 1).unwrap();
+        db.set_tag(Path::new("kick.wav"), crate::sample_sources::Rating::TRASH_3)
+            .unwrap();
+    }
+
+    controller.move_all_trashed_to_folder();
+
+    let original = trash_root.join("kick.wav");
+    let renamed = trash_root.join("kick_1.wav");
+    assert!(original.is_file());
+    assert!(renamed.is_file());
+    assert_ne!(std::fs::read(&original).unwrap(), std::fs::read(&renamed).unwrap());
+    assert!(!first.root.join("kick.wav").exists());
+    assert!(!second.root.join("kick.wav").exists());
+    assert!(controller.ui.status.text.contains("1 renamed"));
+}
+
 #[test]
 fn taking_out_trash_deletes_files() {
     let temp = tempdir().unwrap();
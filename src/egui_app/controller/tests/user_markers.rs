@@ -0,0 +1,69 @@
+use super::super::test_support::{prepare_with_source_and_wav_entries, sample_entry, write_test_wav};
+use crate::egui_app::controller::EguiController;
+use crate::sample_sources::{Rating, SampleSource};
+use std::path::Path;
+
+fn controller_with_loaded_markers_wav() -> (EguiController, SampleSource) {
+    let (mut controller, source) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("markers.wav", Rating::NEUTRAL)]);
+    write_test_wav(&source.root.join("markers.wav"), &[0.1, 0.2, 0.3, 0.4]);
+    controller
+        .database_for(&source)
+        .unwrap()
+        .upsert_file(Path::new("markers.wav"), 1, 1)
+        .unwrap();
+    controller
+        .load_waveform_for_selection(&source, Path::new("markers.wav"))
+        .unwrap();
+    (controller, source)
+}
+
+#[test]
+fn removing_nearest_marker_keeps_the_farther_one() {
+    let (mut controller, source) = controller_with_loaded_markers_wav();
+
+    controller.add_marker(0.2).unwrap();
+    controller.add_marker(0.7).unwrap();
+    let removed = controller.remove_nearest_marker(0.695).unwrap();
+
+    assert_eq!(removed, Some(0.7));
+    assert_eq!(controller.ui.waveform.user_markers, vec![0.2]);
+    let stored = controller
+        .database_for(&source)
+        .unwrap()
+        .user_markers_for_path(Path::new("markers.wav"))
+        .unwrap();
+    assert_eq!(stored, vec![0.2]);
+}
+
+#[test]
+fn removing_marker_far_from_cursor_does_nothing() {
+    let (mut controller, _source) = controller_with_loaded_markers_wav();
+    controller.add_marker(0.7).unwrap();
+
+    assert_eq!(controller.remove_nearest_marker(0.3).unwrap(), None);
+    assert_eq!(controller.ui.waveform.user_markers, vec![0.7]);
+}
+
+#[test]
+fn marker_removal_undoes_and_redoes() {
+    let (mut controller, source) = controller_with_loaded_markers_wav();
+    controller.add_marker(0.2).unwrap();
+    controller.add_marker(0.7).unwrap();
+    controller.remove_nearest_marker(0.2).unwrap();
+    let stored = |controller: &mut EguiController| {
+        controller
+            .database_for(&source)
+            .unwrap()
+            .user_markers_for_path(Path::new("markers.wav"))
+            .unwrap()
+    };
+
+    controller.undo();
+    assert_eq!(controller.ui.waveform.user_markers, vec![0.2, 0.7]);
+    assert_eq!(stored(&mut controller), vec![0.2, 0.7]);
+
+    controller.redo();
+    assert_eq!(controller.ui.waveform.user_markers, vec![0.7]);
+    assert_eq!(stored(&mut controller), vec![0.7]);
+}
@@ -1,10 +1,13 @@
 use crate::egui_app::controller::library::selection_edits::SelectionEditRequest;
 use super::super::test_support::{
-    load_waveform_selection, prepare_with_source_and_wav_entries, sample_entry, write_test_wav,
+    dummy_controller, load_waveform_selection, prepare_with_source_and_wav_entries, sample_entry,
+    write_test_wav,
 };
 use super::super::*;
 use super::common::max_sample_amplitude;
-use crate::egui_app::state::{DestructiveSelectionEdit, FocusContext, WaveformView};
+use crate::egui_app::state::{DestructiveSelectionEdit, FocusContext, StatusAction, WaveformView};
+use crate::sample_sources::config::LoadSelectionMode;
+use crate::waveform::WaveformChannelView;
 use hound::WavReader;
 use std::cell::RefCell;
 use std::mem;
@@ -46,6 +49,42 @@ fn removing_selected_source_clears_waveform_view() {
     assert!(controller.sample_view.wav.loaded_wav.is_none());
 }
 
+#[test]
+fn full_load_selection_mode_selects_whole_sample_on_load() {
+    let (mut controller, source) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("full.wav", crate::sample_sources::Rating::NEUTRAL)]);
+    write_test_wav(&source.root.join("full.wav"), &[0.0, 0.25, -0.25, 0.5]);
+    controller.set_load_selection_mode(LoadSelectionMode::Full);
+
+    controller
+        .load_waveform_for_selection(&source, Path::new("full.wav"))
+        .unwrap();
+
+    assert_eq!(
+        controller.ui.waveform.selection,
+        Some(SelectionRange::new(0.0, 1.0))
+    );
+    assert_eq!(
+        controller.selection_state.range.range(),
+        Some(SelectionRange::new(0.0, 1.0))
+    );
+}
+
+#[test]
+fn none_load_selection_mode_leaves_no_selection_on_load() {
+    let (mut controller, source) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("none.wav", crate::sample_sources::Rating::NEUTRAL)]);
+    write_test_wav(&source.root.join("none.wav"), &[0.0, 0.25, -0.25, 0.5]);
+    controller.set_load_selection_mode(LoadSelectionMode::None);
+
+    controller
+        .load_waveform_for_selection(&source, Path::new("none.wav"))
+        .unwrap();
+
+    assert!(controller.ui.waveform.selection.is_none());
+    assert!(controller.selection_state.range.range().is_none());
+}
+
 #[test]
 fn switching_sources_resets_waveform_state() {
     let (mut controller, first) =
@@ -70,6 +109,39 @@ fn switching_sources_resets_waveform_state() {
     assert!(controller.sample_view.wav.loaded_audio.is_none());
 }
 
+#[test]
+fn switching_sources_restores_map_view_per_source() {
+    let (mut controller, first) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("a.wav", crate::sample_sources::Rating::NEUTRAL)]);
+    let second_dir = tempdir().unwrap();
+    let second_root = second_dir.path().join("second");
+    std::fs::create_dir_all(&second_root).unwrap();
+    mem::forget(second_dir);
+    let second = SampleSource::new(second_root);
+    controller.library.sources.push(second.clone());
+
+    controller.ui.map.zoom = 3.5;
+    controller.ui.map.pan = egui::vec2(40.0, -12.0);
+    controller.ui.map.selected_sample_id = Some("first::a.wav".to_string());
+
+    controller.select_source(Some(second.id.clone()));
+    assert_eq!(controller.ui.map.zoom, 1.0);
+    assert_eq!(controller.ui.map.pan, egui::Vec2::ZERO);
+    assert!(controller.ui.map.selected_sample_id.is_none());
+    controller.ui.map.zoom = 0.5;
+
+    controller.select_source(Some(first.id.clone()));
+    assert_eq!(controller.ui.map.zoom, 3.5);
+    assert_eq!(controller.ui.map.pan, egui::vec2(40.0, -12.0));
+    assert_eq!(
+        controller.ui.map.selected_sample_id.as_deref(),
+        Some("first::a.wav")
+    );
+
+    controller.select_source(Some(second.id.clone()));
+    assert_eq!(controller.ui.map.zoom, 0.5);
+}
+
 #[test]
 fn pruning_missing_selection_clears_waveform_view() {
     let (mut controller, source) =
@@ -115,6 +187,82 @@ fn cropping_selection_overwrites_file() {
     assert_eq!(controller.ui.status.badge_label, "Info");
 }
 
+#[test]
+fn constant_selection_reports_matching_peak_and_rms() {
+    let (mut controller, source) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("level.wav", crate::sample_sources::Rating::NEUTRAL)]);
+    load_waveform_selection(
+        &mut controller,
+        &source,
+        "level.wav",
+        &[0.1, -0.9, 0.5, 0.5, 0.5, 0.5, 0.2, -0.7],
+        SelectionRange::new(0.25, 0.75),
+    );
+
+    let stats = controller.selection_statistics().unwrap();
+
+    assert!((stats.peak - 0.5).abs() < 1e-4);
+    assert!((stats.rms - 0.5).abs() < 1e-4);
+    assert!((stats.duration_seconds - 0.5).abs() < 1e-4);
+}
+
+#[test]
+fn destructive_edit_status_offers_undo_of_pushed_entry() {
+    let (mut controller, source) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("undo.wav", crate::sample_sources::Rating::NEUTRAL)]);
+    load_waveform_selection(
+        &mut controller,
+        &source,
+        "undo.wav",
+        &[0.1, 0.2, 0.3, 0.4],
+        SelectionRange::new(0.25, 0.75),
+    );
+
+    controller.crop_waveform_selection().unwrap();
+
+    let (entry_id, label) = controller.history.undo_stack.last_undo().unwrap();
+    let label = label.to_string();
+    assert_eq!(
+        controller.ui.status.action,
+        Some(StatusAction::Undo { entry_id, label })
+    );
+
+    controller.undo();
+
+    assert!(controller.ui.status.action.is_none());
+}
+
+#[test]
+fn status_undo_is_withdrawn_once_another_edit_is_pushed() {
+    let (mut controller, source) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("undo.wav", crate::sample_sources::Rating::NEUTRAL)]);
+    load_waveform_selection(
+        &mut controller,
+        &source,
+        "undo.wav",
+        &[0.1, 0.2, 0.3, 0.4],
+        SelectionRange::new(0.25, 0.75),
+    );
+    controller.crop_waveform_selection().unwrap();
+    let action = controller.ui.status.action.clone();
+    assert!(controller.status_undo_label().is_some());
+
+    controller.push_undo_entry(super::super::undo::UndoEntry::new(
+        "Later edit",
+        |_: &mut EguiController| Ok(super::super::undo::UndoExecution::Applied),
+        |_: &mut EguiController| Ok(super::super::undo::UndoExecution::Applied),
+    ));
+    controller.ui.status.action = action;
+
+    assert_eq!(controller.status_undo_label(), None);
+    controller.undo_status_action();
+    assert_eq!(
+        controller.history.undo_stack.last_undo().map(|(_, label)| label),
+        Some("Later edit")
+    );
+    assert!(controller.ui.status.action.is_none());
+}
+
 #[test]
 fn align_waveform_start_uses_hover_cursor() {
     let (mut controller, source) =
@@ -220,6 +368,33 @@ fn destructive_edit_request_prompts_without_yolo_mode() {
     assert_eq!(samples.len(), 4);
 }
 
+#[test]
+fn loading_clipped_sample_warns_while_clean_sample_does_not() {
+    let (mut controller, source) = prepare_with_source_and_wav_entries(vec![
+        sample_entry("clipped.wav", crate::sample_sources::Rating::NEUTRAL),
+        sample_entry("clean.wav", crate::sample_sources::Rating::NEUTRAL),
+    ]);
+    write_test_wav(
+        &source.root.join("clipped.wav"),
+        &[0.1, 1.0, -1.0, 1.0, 0.2, -1.0, 1.0, 0.0],
+    );
+    write_test_wav(
+        &source.root.join("clean.wav"),
+        &[0.1, 0.5, -0.5, 0.9, 0.2, -0.9, 0.3, 0.0],
+    );
+
+    controller
+        .load_waveform_for_selection(&source, Path::new("clipped.wav"))
+        .unwrap();
+    assert!(controller.ui.status.text.contains("clipping detected (5 samples"));
+
+    controller
+        .load_waveform_for_selection(&source, Path::new("clean.wav"))
+        .unwrap();
+    assert!(!controller.ui.status.text.contains("clipping"));
+    assert!(controller.ui.status.text.starts_with("Loaded clean.wav"));
+}
+
 #[test]
 fn yolo_mode_applies_destructive_edit_immediately() {
     let (mut controller, source) =
@@ -247,6 +422,75 @@ fn yolo_mode_applies_destructive_edit_immediately() {
     assert_eq!(samples, vec![0.2, 0.3]);
 }
 
+#[test]
+fn per_edit_confirmation_settings_choose_prompt_or_apply() {
+    let (mut controller, source) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("per_edit.wav", crate::sample_sources::Rating::NEUTRAL)]);
+    let wav_path = load_waveform_selection(
+        &mut controller,
+        &source,
+        "per_edit.wav",
+        &[0.5, 0.5, 0.5, 0.5],
+        SelectionRange::new(0.0, 1.0),
+    );
+    controller.set_destructive_edit_confirmation(DestructiveSelectionEdit::CropSelection, true);
+    controller.set_destructive_edit_confirmation(DestructiveSelectionEdit::MuteSelection, false);
+
+    let crop = controller
+        .request_destructive_selection_edit(DestructiveSelectionEdit::CropSelection)
+        .unwrap();
+
+    assert!(matches!(crop, SelectionEditRequest::Prompted));
+    assert_eq!(
+        controller.ui.waveform.pending_destructive.as_ref().map(|prompt| prompt.edit),
+        Some(DestructiveSelectionEdit::CropSelection)
+    );
+
+    let mute = controller
+        .request_destructive_selection_edit(DestructiveSelectionEdit::MuteSelection)
+        .unwrap();
+
+    assert!(matches!(mute, SelectionEditRequest::Applied));
+    assert!(controller.ui.waveform.pending_destructive.is_none());
+    assert!(!controller.ui.controls.destructive_confirmations.mute);
+    let samples: Vec<f32> = hound::WavReader::open(&wav_path)
+        .unwrap()
+        .samples::<f32>()
+        .map(|s| s.unwrap())
+        .collect();
+    assert!(samples.iter().all(|sample| sample.abs() < 1e-6));
+}
+
+#[test]
+fn clipboard_paste_waits_for_confirmation() {
+    let (mut controller, source) =
+        prepare_with_source_and_wav_entries(vec![sample_entry("paste.wav", crate::sample_sources::Rating::NEUTRAL)]);
+    let wav_path = load_waveform_selection(
+        &mut controller,
+        &source,
+        "paste.wav",
+        &[0.1, 0.2, 0.3, 0.4],
+        SelectionRange::new(0.25, 0.75),
+    );
+    controller.set_destructive_yolo_mode(false);
+
+    let outcome = controller
+        .request_destructive_selection_edit(DestructiveSelectionEdit::PasteClipboard)
+        .unwrap();
+
+    assert!(matches!(outcome, SelectionEditRequest::Prompted));
+    assert_eq!(
+        controller.ui.waveform.pending_destructive.as_ref().map(|prompt| prompt.edit),
+        Some(DestructiveSelectionEdit::PasteClipboard)
+    );
+    let samples: Vec<f32> = hound::WavReader::open(&wav_path)
+        .unwrap()
+        .samples::<f32>()
+        .map(|s| s.unwrap())
+        .collect();
+    assert_eq!(samples, vec![0.1, 0.2, 0.3, 0.4]);
+}
+
 #[test]
 fn confirming_pending_destructive_edit_clears_prompt() {
     let (mut controller, source) =
@@ -274,6 +518,22 @@ fn confirming_pending_destructive_edit_clears_prompt() {
     assert_eq!(samples, vec![0.0, 0.3]);
 }
 
+#[test]
+fn channel_view_hotkey_toggles_between_mono_and_split_stereo() {
+    let (mut controller, _source) = dummy_controller();
+    controller.ui.waveform.channel_view = WaveformChannelView::Mono;
+    controller.settings.controls.waveform_channel_view = WaveformChannelView::Mono;
+    let action = hotkeys::iter_actions()
+        .find(|action| action.id == "toggle-channel-view")
+        .unwrap();
+
+    controller.handle_hotkey(action, FocusContext::Waveform);
+    assert_eq!(controller.ui.waveform.channel_view, WaveformChannelView::SplitStereo);
+
+    controller.handle_hotkey(action, FocusContext::Waveform);
+    assert_eq!(controller.ui.waveform.channel_view, WaveformChannelView::Mono);
+}
+
 #[test]
 fn t_hotkey_prompts_trim_selection_in_waveform_focus() {
     let (mut controller, source) = prepare_with_source_and_wav_entries(vec![sample_entry(
@@ -532,3 +792,46 @@ fn shift_c_hotkey_crops_selection_to_new_sample() {
         .collect();
     assert_eq!(cropped_samples, vec![0.1, 0.2]);
 }
+
+#[test]
+fn slicing_at_two_markers_writes_three_files_covering_the_sample() {
+    let (mut controller, source) = prepare_with_source_and_wav_entries(vec![sample_entry(
+        "loop.wav",
+        crate::sample_sources::Rating::NEUTRAL,
+    )]);
+    let samples = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7];
+    load_waveform_selection(
+        &mut controller,
+        &source,
+        "loop.wav",
+        &samples,
+        SelectionRange::new(0.0, 1.0),
+    );
+    controller.ui.waveform.user_markers = vec![0.25, 0.75];
+
+    let slices = controller.slice_loaded_sample_at_markers().unwrap();
+
+    assert_eq!(
+        slices,
+        vec![
+            PathBuf::from("loop_slice001.wav"),
+            PathBuf::from("loop_slice002.wav"),
+            PathBuf::from("loop_slice003.wav"),
+        ]
+    );
+    let joined: Vec<f32> = slices
+        .iter()
+        .flat_map(|slice| {
+            WavReader::open(source.root.join(slice))
+                .unwrap()
+                .samples::<f32>()
+                .map(|s| s.unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    assert_eq!(joined, samples.to_vec());
+    let db = controller.database_for(&source).unwrap();
+    for slice in &slices {
+        assert!(db.tag_for_path(slice).unwrap().is_some());
+    }
+}
@@ -46,6 +46,7 @@ fn mouse_zoom_prefers_pointer_over_playhead() {
         duration_seconds: 1.0,
         sample_rate: 48_000,
         channels: 1,
+        cue_markers: Vec::new(),
     });
     controller.ui.waveform.playhead.position = 0.1;
     controller.ui.waveform.playhead.visible = true;
@@ -183,6 +184,7 @@ fn play_from_cursor_prefers_cursor_position() {
         duration_seconds: 1.0,
         sample_rate: 48_000,
         channels: 1,
+        cue_markers: Vec::new(),
     });
     controller.ui.waveform.cursor = Some(0.33);
     controller.ui.waveform.cursor_last_navigation_at = Some(Instant::now());
@@ -217,6 +219,7 @@ fn play_from_cursor_ignores_hover_cursor_when_replaying() {
         duration_seconds: 1.0,
         sample_rate: 48_000,
         channels: 1,
+        cue_markers: Vec::new(),
     });
     controller.ui.waveform.cursor = Some(0.33);
     controller.ui.waveform.cursor_last_navigation_at =
@@ -251,6 +254,7 @@ fn cursor_alpha_fades_before_reset() {
         duration_seconds: 1.0,
         sample_rate: 48_000,
         channels: 1,
+        cue_markers: Vec::new(),
     });
     controller.ui.waveform.cursor = Some(0.4);
     controller.ui.waveform.cursor_last_navigation_at =
@@ -276,6 +280,7 @@ fn cursor_alpha_resets_after_idle_timeout() {
         duration_seconds: 1.0,
         sample_rate: 48_000,
         channels: 1,
+        cue_markers: Vec::new(),
     });
     controller.ui.waveform.cursor = Some(0.4);
     controller.ui.waveform.cursor_last_navigation_at =
@@ -301,6 +306,7 @@ fn cursor_does_not_fade_when_waveform_focused() {
         duration_seconds: 1.0,
         sample_rate: 48_000,
         channels: 1,
+        cue_markers: Vec::new(),
     });
     controller.ui.waveform.cursor = Some(0.4);
     controller.ui.waveform.cursor_last_navigation_at =
@@ -25,6 +25,7 @@ fn waveform_refresh_respects_view_slice_and_caps_width() {
         duration_seconds: 1.0,
         sample_rate: 48_000,
         channels: 1,
+        cue_markers: Vec::new(),
     });
     controller.sample_view.waveform.render_meta = None;
     controller.refresh_waveform_image();
@@ -61,6 +62,7 @@ fn waveform_render_meta_rejects_small_shifts_when_zoomed_in() {
         channel_view: crate::waveform::WaveformChannelView::Mono,
         channels: 2,
         edit_fade: None,
+        vertical_zoom: 1.0,
     };
     let shifted = wavs::WaveformRenderMeta {
         view_start: 0.10095,
@@ -81,6 +83,7 @@ fn waveform_render_meta_allows_small_shifts_on_full_view() {
         channel_view: crate::waveform::WaveformChannelView::Mono,
         channels: 1,
         edit_fade: None,
+        vertical_zoom: 1.0,
     };
     let minor_shift = wavs::WaveformRenderMeta {
         view_start: 0.0005,
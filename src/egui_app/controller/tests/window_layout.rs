@@ -0,0 +1,21 @@
+use super::super::*;
+use crate::app_dirs::ConfigBaseGuard;
+use tempfile::tempdir;
+
+#[test]
+fn saved_layout_restores_split_ratios_after_reload() {
+    let temp = tempdir().unwrap();
+    let _guard = ConfigBaseGuard::set(temp.path().to_path_buf());
+    let mut controller = EguiController::new(WaveformRenderer::new(10, 10), None);
+    controller.set_sources_panel_splits(Some(0.35), Some(0.15));
+    controller.set_map_window_size([720.0, 480.0]);
+
+    let cfg = crate::sample_sources::config::load_or_default().unwrap();
+    let mut reloaded = EguiController::new(WaveformRenderer::new(10, 10), None);
+    reloaded.apply_window_layout(cfg.core.layout);
+
+    assert_eq!(reloaded.ui.sources.sources_split_ratio, Some(0.35));
+    assert_eq!(reloaded.ui.sources.drop_targets.split_ratio, Some(0.15));
+    assert_eq!(reloaded.ui.map.window_size, Some([720.0, 480.0]));
+    assert!(!reloaded.ui.map.open);
+}
@@ -24,6 +24,27 @@ impl EguiController {
         }
     }
 
+    /// Copy the absolute paths of the selected (or focused) samples as newline-separated text.
+    pub fn copy_sample_paths_as_text(&mut self) {
+        match self.selected_sample_paths() {
+            Ok(paths) if paths.is_empty() => {
+                self.set_status("Select a sample to copy its path", StatusTone::Warning);
+            }
+            Ok(paths) => match crate::external_clipboard::copy_text(&paths_as_text(&paths)) {
+                Ok(()) => {
+                    let label = if paths.len() == 1 {
+                        "Copied path to clipboard".to_string()
+                    } else {
+                        format!("Copied {} paths to clipboard", paths.len())
+                    };
+                    self.set_status(label, StatusTone::Info);
+                }
+                Err(err) => self.set_status(err, StatusTone::Error),
+            },
+            Err(err) => self.set_status(err, StatusTone::Error),
+        }
+    }
+
     /// Copy the status log text to the system clipboard.
     pub fn copy_status_log_to_clipboard(&mut self) {
         let text = self.ui.status.log_text();
@@ -143,6 +164,14 @@ fn clipboard_copy_label(paths: &[PathBuf]) -> String {
     }
 }
 
+fn paths_as_text(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,6 +217,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn selected_samples_format_as_absolute_path_lines_in_order() {
+        let temp = tempdir().unwrap();
+        let source_root = temp.path().join("source");
+        std::fs::create_dir_all(&source_root).unwrap();
+        write_test_wav(&source_root.join("b.wav"), &[0.1, 0.2]);
+        write_test_wav(&source_root.join("a.wav"), &[0.1, 0.2]);
+
+        let renderer = crate::waveform::WaveformRenderer::new(8, 8);
+        let mut controller = EguiController::new(renderer, None);
+        let source = SampleSource::new(source_root.clone());
+        controller.library.sources.push(source.clone());
+        controller.selection_state.ctx.selected_source = Some(source.id.clone());
+        controller.ui.browser.selected_paths = vec![PathBuf::from("b.wav"), PathBuf::from("a.wav")];
+
+        let text = paths_as_text(&controller.selected_sample_paths().unwrap());
+
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                source_root.join("b.wav").display().to_string(),
+                source_root.join("a.wav").display().to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn copy_flash_paths_prefers_browser_selection() {
         let renderer = crate::waveform::WaveformRenderer::new(8, 8);
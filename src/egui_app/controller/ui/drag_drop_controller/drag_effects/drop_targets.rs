@@ -82,6 +82,9 @@ impl DragDropController<'_> {
         let last_played_at = self
             .sample_last_played_for(&source, &relative_path)
             .unwrap_or(None);
+        let stars = self
+            .sample_stars_for(&source, &relative_path)
+            .unwrap_or(None);
         if copy_requested {
             match copy_sample_to_target(
                 self,
@@ -92,6 +95,7 @@ impl DragDropController<'_> {
                 tag,
                 looped,
                 last_played_at,
+                stars,
             ) {
                 Ok(path) => {
                     self.set_status(
@@ -136,6 +140,7 @@ impl DragDropController<'_> {
             tag,
             looped,
             last_played_at,
+            stars,
         ) {
             let _ = super::source_moves::move_sample_file(&destination_absolute, &absolute);
             self.set_status(err, StatusTone::Error);
@@ -156,6 +161,7 @@ impl DragDropController<'_> {
             looped,
             missing: false,
             last_played_at,
+            stars,
         };
         self.insert_cached_entry(&target.source, new_entry);
         self.set_status(
@@ -210,6 +216,7 @@ fn copy_sample_to_target(
     tag: Rating,
     looped: bool,
     last_played_at: Option<i64>,
+    stars: Option<u8>,
 ) -> Result<PathBuf, String> {
     let destination_relative =
         copy_destination_relative(target, target_folder, file_name)?;
@@ -243,6 +250,7 @@ fn copy_sample_to_target(
         tag,
         looped,
         last_played_at,
+        stars,
     ) {
         let _ = std::fs::remove_file(&destination_absolute);
         return Err(err);
@@ -256,6 +264,7 @@ fn copy_sample_to_target(
         looped,
         missing: false,
         last_played_at,
+        stars,
     };
     controller.insert_cached_entry(target, new_entry);
     Ok(destination_relative)
@@ -192,6 +192,7 @@ impl DragDropController<'_> {
                 looped: entry.looped,
                 missing: false,
                 last_played_at: entry.last_played_at,
+                stars: entry.stars,
             };
             let new_entry = WavEntry {
                 relative_path: entry.new_relative.clone(),
@@ -202,6 +203,7 @@ impl DragDropController<'_> {
                 looped: entry.looped,
                 missing: false,
                 last_played_at: entry.last_played_at,
+                stars: entry.stars,
             };
             updates.push((old_entry, new_entry));
         }
@@ -369,6 +371,7 @@ impl DragDropController<'_> {
                 looped: entry.looped,
                 missing: false,
                 last_played_at: entry.last_played_at,
+                stars: entry.stars,
             };
             let new_entry = WavEntry {
                 relative_path: entry.new_relative.clone(),
@@ -379,6 +382,7 @@ impl DragDropController<'_> {
                 looped: entry.looped,
                 missing: false,
                 last_played_at: entry.last_played_at,
+                stars: entry.stars,
             };
             updates.push((old_entry, new_entry));
         }
@@ -516,6 +520,15 @@ fn run_folder_sample_move_task(
                 continue;
             }
         };
+        let stars = match db.stars_for_path(&request.relative_path) {
+            Ok(value) => value,
+            Err(err) => {
+                errors.push(format!("Failed to read database: {err}"));
+                completed += 1;
+                report_progress(sender, completed, detail);
+                continue;
+            }
+        };
         let op_id = file_ops_journal::new_op_id();
         let staged_relative = match file_ops_journal::staged_relative_for_target(
             &request.target_relative,
@@ -639,6 +652,14 @@ fn run_folder_sample_move_task(
                 continue;
             }
         }
+        if let Err(err) = batch.set_stars(&request.target_relative, stars) {
+            rollback_folder_move_to_source(&mut errors, &staged_absolute, &absolute);
+            remove_folder_move_journal_entry(&mut errors, &db, &op_id);
+            errors.push(format!("Failed to copy star rating: {err}"));
+            completed += 1;
+            report_progress(sender, completed, detail);
+            continue;
+        }
         if let Err(err) = batch.commit() {
             rollback_folder_move_to_source(&mut errors, &staged_absolute, &absolute);
             remove_folder_move_journal_entry(&mut errors, &db, &op_id);
@@ -680,6 +701,7 @@ fn run_folder_sample_move_task(
             tag,
             looped,
             last_played_at,
+            stars,
         });
         completed += 1;
         report_progress(sender, completed, detail);
@@ -953,6 +975,19 @@ fn run_folder_move_task(
                     };
                 }
             }
+            if let Err(err) = batch.set_stars(&updated_path, entry.stars) {
+                let _ = std::fs::rename(&absolute_new, &absolute_old);
+                errors.push(format!("Failed to copy star rating: {err}"));
+                return FolderMoveResult {
+                    source_id: request.source_id,
+                    old_folder: request.folder,
+                    new_folder: new_relative,
+                    folder_moved: false,
+                    moved,
+                    errors,
+                    cancelled,
+                };
+            }
             updates.push(FolderEntryMove {
                 old_relative: entry.relative_path.clone(),
                 new_relative: updated_path,
@@ -961,6 +996,7 @@ fn run_folder_move_task(
                 tag: entry.tag,
                 looped: entry.looped,
                 last_played_at: entry.last_played_at,
+                stars: entry.stars,
             });
         }
         if let Err(err) = batch.commit() {
@@ -1027,6 +1063,7 @@ mod tests {
         batch
             .set_last_played_at(Path::new("one.wav"), 42)
             .unwrap();
+        batch.set_stars(Path::new("one.wav"), Some(4)).unwrap();
         batch.commit().unwrap();
 
         let request = FolderSampleMoveRequest {
@@ -1063,6 +1100,10 @@ mod tests {
                 .unwrap(),
             Some(42)
         );
+        assert_eq!(
+            db.stars_for_path(Path::new("folder/one.wav")).unwrap(),
+            Some(4)
+        );
     }
 
     #[test]
@@ -11,25 +11,14 @@ impl DragDropController<'_> {
         relative_path: PathBuf,
         triage_target: Option<TriageFlagColumn>,
     ) {
-        if let Some(column) = triage_target {
-            self.selection_state.suppress_autoplay_once = true;
-            let target_tag = match column {
-                TriageFlagColumn::Trash => Rating::TRASH_1,
-                TriageFlagColumn::Neutral => Rating::NEUTRAL,
-                TriageFlagColumn::Keep => Rating::KEEP_1,
-            };
-            if let Some(source) = self
-                .library
-                .sources
-                .iter()
-                .find(|s| s.id == source_id)
-                .cloned()
-            {
-                let _ = self.set_sample_tag_for_source(&source, &relative_path, target_tag, false);
-            } else {
-                let _ = self.set_sample_tag(&relative_path, column);
-            }
-        }
+        let Some(column) = triage_target else {
+            return;
+        };
+        let previous = self
+            .apply_sample_drop_tag(source_id, relative_path, column)
+            .into_iter()
+            .collect();
+        self.push_tag_undo_entry("Tag sample", previous, triage_column_tag(column));
     }
 
     pub(crate) fn handle_samples_drop(
@@ -37,12 +26,50 @@ impl DragDropController<'_> {
         samples: &[DragSample],
         triage_target: Option<TriageFlagColumn>,
     ) {
-        for sample in samples {
-            self.handle_sample_drop(
-                sample.source_id.clone(),
-                sample.relative_path.clone(),
-                triage_target,
-            );
-        }
+        let Some(column) = triage_target else {
+            return;
+        };
+        let previous = samples
+            .iter()
+            .filter_map(|sample| {
+                self.apply_sample_drop_tag(
+                    sample.source_id.clone(),
+                    sample.relative_path.clone(),
+                    column,
+                )
+            })
+            .collect();
+        self.push_tag_undo_entry("Tag samples", previous, triage_column_tag(column));
+    }
+
+    /// Tag a dropped sample and return its previous tag when it can be undone.
+    fn apply_sample_drop_tag(
+        &mut self,
+        source_id: SourceId,
+        relative_path: PathBuf,
+        column: TriageFlagColumn,
+    ) -> Option<(SourceId, PathBuf, Rating)> {
+        self.selection_state.suppress_autoplay_once = true;
+        let target_tag = triage_column_tag(column);
+        let Some(source) = self.find_source_by_id(&source_id) else {
+            let _ = self.set_sample_tag(&relative_path, column);
+            return None;
+        };
+        let previous = self
+            .database_for(&source)
+            .ok()
+            .and_then(|db| db.tag_for_path(&relative_path).ok().flatten());
+        self.set_sample_tag_for_source(&source, &relative_path, target_tag, false).ok()?;
+        previous
+            .filter(|tag| *tag != target_tag)
+            .map(|tag| (source_id, relative_path, tag))
+    }
+}
+
+fn triage_column_tag(column: TriageFlagColumn) -> Rating {
+    match column {
+        TriageFlagColumn::Trash => Rating::TRASH_1,
+        TriageFlagColumn::Neutral => Rating::NEUTRAL,
+        TriageFlagColumn::Keep => Rating::KEEP_1,
     }
 }
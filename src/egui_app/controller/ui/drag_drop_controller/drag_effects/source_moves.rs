@@ -163,6 +163,7 @@ impl DragDropController<'_> {
                     looped: entry.looped,
                     missing: false,
                     last_played_at: entry.last_played_at,
+                    stars: entry.stars,
                 },
             );
             moved_sources.insert(source.id.clone());
@@ -221,6 +222,7 @@ impl DragDropController<'_> {
         tag: Rating,
         looped: bool,
         last_played_at: Option<i64>,
+        stars: Option<u8>,
     ) -> Result<(), String> {
         let db = self
             .database_for(source)
@@ -235,6 +237,8 @@ impl DragDropController<'_> {
             db.set_last_played_at(relative_path, last_played_at)
                 .map_err(|err| format!("Failed to copy playback age: {err}"))?;
         }
+        db.set_stars(relative_path, stars)
+            .map_err(|err| format!("Failed to copy star rating: {err}"))?;
         Ok(())
     }
 
@@ -422,6 +426,15 @@ fn run_source_move_task(
                 continue;
             }
         };
+        let stars = match source_db.stars_for_path(&request.relative_path) {
+            Ok(value) => value,
+            Err(err) => {
+                errors.push(format!("Failed to read database: {err}"));
+                completed += 1;
+                report_progress(sender, completed, detail);
+                continue;
+            }
+        };
         let op_id = file_ops_journal::new_op_id();
         let staged_relative = match file_ops_journal::staged_relative_for_target(&target_relative, &op_id) {
             Ok(path) => path,
@@ -534,6 +547,14 @@ fn run_source_move_task(
                 continue;
             }
         }
+        if let Err(err) = batch.set_stars(&target_relative, stars) {
+            rollback_move_to_source(&mut errors, &staged_absolute, &absolute);
+            remove_move_journal_entry(&mut errors, &target_db, &op_id);
+            errors.push(format!("Failed to copy star rating: {err}"));
+            completed += 1;
+            report_progress(sender, completed, detail);
+            continue;
+        }
         if let Err(err) = batch.commit() {
             rollback_move_to_source(&mut errors, &staged_absolute, &absolute);
             remove_move_journal_entry(&mut errors, &target_db, &op_id);
@@ -583,6 +604,7 @@ fn run_source_move_task(
             tag,
             looped,
             last_played_at,
+            stars,
         });
         completed += 1;
         report_progress(sender, completed, detail);
@@ -148,7 +148,7 @@ impl EguiController {
     }
 
     fn diagnostics_block(&self) -> String {
-        let version = env!("CARGO_PKG_VERSION");
+        let build = crate::build_info();
         let build_type = if cfg!(debug_assertions) {
             "debug"
         } else {
@@ -160,8 +160,21 @@ impl EguiController {
             .ok()
             .map(|p| p.display().to_string())
             .unwrap_or_else(|| "n/a".to_string());
+        let build_lines: String = build
+            .summary_lines()
+            .iter()
+            .map(|line| format!("- {line}\n"))
+            .collect();
+        let gpu_line = self
+            .ui
+            .feedback_issue
+            .gpu_info
+            .as_ref()
+            .filter(|_| self.ui.feedback_issue.include_gpu_info)
+            .map(|info| format!("\n- {}", gpu_summary_line(info)))
+            .unwrap_or_default();
         format!(
-            "---\n\nDiagnostics\n- App version: {version}\n- OS: {os} ({arch})\n- Build: {build_type}\n- Logs: {logs}"
+            "---\n\nDiagnostics\n{build_lines}- OS: {os} ({arch})\n- Build: {build_type}\n- Logs: {logs}{gpu_line}"
         )
     }
 
@@ -192,3 +205,60 @@ impl EguiController {
         self.runtime.jobs.begin_issue_token_load();
     }
 }
+
+/// Collapse adapter details into one line, dropping device ids and serial-like tokens.
+pub(crate) fn gpu_summary_line(info: &crate::egui_app::state::GpuAdapterInfo) -> String {
+    format!(
+        "GPU: {} ({}) | backend: {} | driver: {}",
+        redact_gpu_field(&info.name),
+        redact_gpu_field(&info.vendor),
+        redact_gpu_field(&info.backend),
+        redact_gpu_field(&info.driver),
+    )
+}
+
+fn redact_gpu_field(value: &str) -> String {
+    let redacted: Vec<String> = value
+        .split_whitespace()
+        .map(|token| {
+            let core = token.trim_matches(|c: char| !c.is_ascii_alphanumeric());
+            let is_hex_id = core.len() > 2
+                && core.get(..2).is_some_and(|prefix| prefix.eq_ignore_ascii_case("0x"));
+            let is_serial = core.len() >= 8 && core.chars().all(|c| c.is_ascii_hexdigit());
+            let is_path = token.contains('\\') || token.starts_with('/');
+            if is_hex_id || is_serial || is_path {
+                "[redacted]".to_string()
+            } else {
+                token.to_string()
+            }
+        })
+        .collect();
+    if redacted.is_empty() {
+        "unknown".to_string()
+    } else {
+        redacted.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gpu_summary_line;
+    use crate::egui_app::state::GpuAdapterInfo;
+
+    #[test]
+    fn gpu_summary_is_single_redacted_line() {
+        let info = GpuAdapterInfo {
+            name: "AMD Radeon RX 6800 (0x73bf)\nserial 1a2b3c4d5e6f".to_string(),
+            vendor: "ATI Technologies Inc.".to_string(),
+            backend: "OpenGL".to_string(),
+            driver: "4.6.0 Compatibility Profile 23.10.2".to_string(),
+        };
+        let line = gpu_summary_line(&info);
+        assert!(!line.contains('\n'));
+        assert!(!line.contains("0x73bf"));
+        assert!(!line.contains("1a2b3c4d5e6f"));
+        assert!(line.contains("AMD Radeon RX 6800"));
+        assert!(line.contains("backend: OpenGL"));
+        assert!(line.contains("driver: 4.6.0 Compatibility Profile 23.10.2"));
+    }
+}
@@ -27,6 +27,7 @@ impl EguiController {
                 self.drag_drop().apply_folder_move_result(result);
             }
             FileOpResult::UndoFile(result) => self.apply_undo_file_result(result),
+            FileOpResult::FeatureExport(result) => self.apply_feature_export_result(result),
         }
     }
 
@@ -132,6 +133,7 @@ impl EguiController {
         let label = pending.entry.label.clone();
         let direction = pending.direction;
         let job = pending.job.clone();
+        let file_count = job.file_count();
         let title = match direction {
             UndoDirection::Undo => format!("Undoing {label}"),
             UndoDirection::Redo => format!("Redoing {label}"),
@@ -141,7 +143,7 @@ impl EguiController {
         self.show_status_progress(
             crate::egui_app::state::ProgressTaskKind::FileOps,
             title,
-            1,
+            file_count,
             true,
         );
         let (tx, rx) = std::sync::mpsc::channel();
@@ -155,6 +157,11 @@ impl EguiController {
 
     fn apply_undo_file_outcome(&mut self, outcome: &UndoFileOutcome) {
         match outcome {
+            UndoFileOutcome::Batch(outcomes) => {
+                for outcome in outcomes {
+                    self.apply_undo_file_outcome(outcome);
+                }
+            }
             UndoFileOutcome::Overwrite {
                 source_id,
                 relative_path,
@@ -163,6 +170,7 @@ impl EguiController {
                 tag,
                 looped,
                 last_played_at,
+                stars,
             } => {
                 let Some(source) = self
                     .library
@@ -183,6 +191,7 @@ impl EguiController {
                     looped: *looped,
                     missing: false,
                     last_played_at: *last_played_at,
+                    stars: *stars,
                 };
                 self.update_cached_entry(&source, relative_path, entry);
                 self.refresh_waveform_for_sample(&source, relative_path);
@@ -211,6 +220,7 @@ impl EguiController {
                 tag,
                 looped,
                 last_played_at,
+                stars,
             } => {
                 let Some(source) = self
                     .library
@@ -233,6 +243,7 @@ impl EguiController {
                         looped: *looped,
                         missing: false,
                         last_played_at: *last_played_at,
+                        stars: *stars,
                     },
                 );
                 self.refresh_waveform_for_sample(&source, relative_path);
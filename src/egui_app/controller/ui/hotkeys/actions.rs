@@ -80,6 +80,13 @@ pub(crate) const HOTKEY_ACTIONS: &[HotkeyAction] = &[
         scope: HotkeyScope::Focus(FocusContext::SampleBrowser),
         command: HotkeyCommand::RenameFocusedSample,
     },
+    HotkeyAction {
+        id: "reveal-sample",
+        label: "Open in file explorer",
+        gesture: HotkeyGesture::new(Key::O),
+        scope: HotkeyScope::Focus(FocusContext::SampleBrowser),
+        command: HotkeyCommand::RevealFocusedSample,
+    },
     HotkeyAction {
         id: "new-folder",
         label: "New folder",
@@ -101,6 +108,21 @@ pub(crate) const HOTKEY_ACTIONS: &[HotkeyAction] = &[
         scope: HotkeyScope::Focus(FocusContext::SampleBrowser),
         command: HotkeyCommand::FocusBrowserSearch,
     },
+    HotkeyAction {
+        id: "clear-browser-search",
+        label: "Clear sample search",
+        gesture: HotkeyGesture {
+            first: KeyPress {
+                key: Key::F,
+                command: true,
+                shift: true,
+                alt: false,
+            },
+            chord: None,
+        },
+        scope: HotkeyScope::Focus(FocusContext::SampleBrowser),
+        command: HotkeyCommand::ClearBrowserSearch,
+    },
     HotkeyAction {
         id: "focus-loaded-sample",
         label: "Focus loaded sample",
@@ -164,6 +186,13 @@ pub(crate) const HOTKEY_ACTIONS: &[HotkeyAction] = &[
         scope: HotkeyScope::Focus(FocusContext::Waveform),
         command: HotkeyCommand::CropSelectionNewSample,
     },
+    HotkeyAction {
+        id: "slice-at-markers",
+        label: "Slice sample at markers",
+        gesture: HotkeyGesture::with_shift(Key::M),
+        scope: HotkeyScope::Focus(FocusContext::Waveform),
+        command: HotkeyCommand::SliceAtMarkers,
+    },
     HotkeyAction {
         id: "save-selection-to-browser",
         label: "Save selection/slices to browser",
@@ -347,6 +376,13 @@ pub(crate) const HOTKEY_ACTIONS: &[HotkeyAction] = &[
         scope: HotkeyScope::Focus(FocusContext::Waveform),
         command: HotkeyCommand::ToggleTransientMarkers,
     },
+    HotkeyAction {
+        id: "toggle-channel-view",
+        label: "Toggle mono/split stereo view",
+        gesture: HotkeyGesture::new(Key::V),
+        scope: HotkeyScope::Focus(FocusContext::Waveform),
+        command: HotkeyCommand::ToggleChannelView,
+    },
     HotkeyAction {
         id: "reverse-selection",
         label: "Reverse selection",
@@ -438,4 +474,11 @@ pub(crate) const HOTKEY_ACTIONS: &[HotkeyAction] = &[
         scope: HotkeyScope::Focus(FocusContext::Waveform),
         command: HotkeyCommand::NudgeSelectionRight,
     },
+    HotkeyAction {
+        id: "extend-selection-to-next-transient",
+        label: "Extend selection to next transient",
+        gesture: HotkeyGesture::new(Key::E),
+        scope: HotkeyScope::Focus(FocusContext::Waveform),
+        command: HotkeyCommand::ExtendSelectionToNextTransient,
+    },
 ];
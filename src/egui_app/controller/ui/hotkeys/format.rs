@@ -57,6 +57,8 @@ fn key_label(key: Key) -> &'static str {
         egui::Key::L => "L",
         egui::Key::P => "P",
         egui::Key::F => "F",
+        egui::Key::E => "E",
+        egui::Key::O => "O",
         egui::Key::F1 => "F1",
         egui::Key::OpenBracket => "[",
         egui::Key::CloseBracket => "]",
@@ -113,9 +113,11 @@ pub(crate) enum HotkeyCommand {
     DeleteFocusedFolder,
     RenameFocusedFolder,
     RenameFocusedSample,
+    RevealFocusedSample,
     CreateFolder,
     FocusFolderSearch,
     FocusBrowserSearch,
+    ClearBrowserSearch,
     FindSimilarFocusedSample,
     ToggleOverlay,
     ToggleLoop,
@@ -148,10 +150,12 @@ pub(crate) enum HotkeyCommand {
     DeleteSliceMarkers,
     ToggleBpmSnap,
     ToggleTransientMarkers,
+    ToggleChannelView,
     NormalizeWaveform,
     AlignWaveformStartToMarker,
     CropSelection,
     CropSelectionNewSample,
+    SliceAtMarkers,
     SaveSelectionToBrowser,
     OpenFeedbackIssuePrompt,
     CopyStatusLog,
@@ -163,6 +167,7 @@ pub(crate) enum HotkeyCommand {
     SlideSelectionRight,
     NudgeSelectionLeft,
     NudgeSelectionRight,
+    ExtendSelectionToNextTransient,
 }
 
 /// Hotkey metadata surfaced to the UI.
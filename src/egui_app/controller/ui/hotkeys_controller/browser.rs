@@ -28,6 +28,10 @@ pub(crate) fn handle_browser_command(
             controller.start_browser_rename();
             true
         }
+        HotkeyCommand::RevealFocusedSample => {
+            controller.reveal_focused_sample_in_file_manager();
+            true
+        }
         HotkeyCommand::FocusBrowserSearch => {
             if matches!(controller.ui.browser.active_tab, SampleBrowserTab::Map) {
                 controller.ui.map.focus_selected_requested = true;
@@ -36,6 +40,10 @@ pub(crate) fn handle_browser_command(
             }
             true
         }
+        HotkeyCommand::ClearBrowserSearch => {
+            controller.clear_browser_search();
+            true
+        }
         HotkeyCommand::FindSimilarFocusedSample => {
             if matches!(controller.ui.browser.active_tab, SampleBrowserTab::Map) {
                 controller.ui.browser.active_tab = SampleBrowserTab::List;
@@ -241,4 +241,21 @@ mod tests {
         assert!(!controller.ui.browser.search_focus_requested);
     }
 
+    #[test]
+    fn clear_browser_search_hotkey_restores_unfiltered_rows() {
+        let (mut controller, _source) = prepare_with_source_and_wav_entries(vec![
+            sample_entry("kick.wav", Rating::NEUTRAL),
+            sample_entry("snare.wav", Rating::NEUTRAL),
+            sample_entry("hat.wav", Rating::NEUTRAL),
+        ]);
+        controller.set_browser_search("snr");
+        assert_eq!(controller.visible_browser_len(), 1);
+
+        let action = action_for(HotkeyCommand::ClearBrowserSearch);
+        controller.handle_hotkey(action, FocusContext::SampleBrowser);
+
+        assert!(controller.ui.browser.search_query.is_empty());
+        assert_eq!(controller.visible_browser_len(), 3);
+    }
+
 }
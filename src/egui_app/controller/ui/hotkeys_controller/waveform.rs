@@ -3,6 +3,7 @@ use crate::egui_app::controller::StatusTone;
 use crate::egui_app::controller::ui::hotkeys::HotkeyCommand;
 use crate::egui_app::state::DestructiveSelectionEdit;
 use crate::sample_sources::WavEntry;
+use crate::waveform::WaveformChannelView;
 
 pub(crate) fn handle_waveform_command(
     controller: &mut HotkeysController<'_>,
@@ -30,6 +31,12 @@ pub(crate) fn handle_waveform_command(
             }
             true
         }
+        HotkeyCommand::SliceAtMarkers => {
+            if let Err(err) = controller.slice_loaded_sample_at_markers() {
+                controller.set_status(err, StatusTone::Error);
+            }
+            true
+        }
         HotkeyCommand::SaveSelectionToBrowser => {
             match controller.commit_edit_selection_fades() {
                 Ok(true) => return true,
@@ -106,6 +113,10 @@ pub(crate) fn handle_waveform_command(
             controller.toggle_transient_markers();
             true
         }
+        HotkeyCommand::ToggleChannelView => {
+            controller.toggle_channel_view();
+            true
+        }
         HotkeyCommand::ZoomInSelection => {
             controller.waveform().zoom_to_selection();
             true
@@ -126,6 +137,10 @@ pub(crate) fn handle_waveform_command(
             controller.waveform().nudge_selection_range(1, true);
             true
         }
+        HotkeyCommand::ExtendSelectionToNextTransient => {
+            controller.waveform().extend_selection_to_next_transient();
+            true
+        }
         HotkeyCommand::ZoomOutSelection => {
             controller.waveform().zoom_out_full();
             true
@@ -157,6 +172,14 @@ impl HotkeysController<'_> {
         self.set_transient_markers_enabled(enabled);
     }
 
+    fn toggle_channel_view(&mut self) {
+        let view = match self.ui.waveform.channel_view {
+            WaveformChannelView::Mono => WaveformChannelView::SplitStereo,
+            WaveformChannelView::SplitStereo => WaveformChannelView::Mono,
+        };
+        self.set_waveform_channel_view(view);
+    }
+
     fn normalize_waveform_selection_or_sample(&mut self) {
         if self
             .ui
@@ -202,6 +225,7 @@ impl HotkeysController<'_> {
             .sample_last_played_for(&source, &relative_path)
             .unwrap_or(None);
         let looped = self.sample_looped_for(&source, &relative_path).unwrap_or(false);
+        let stars = self.sample_stars_for(&source, &relative_path).unwrap_or(None);
         let updated = WavEntry {
             relative_path: relative_path.clone(),
             file_size,
@@ -211,6 +235,7 @@ impl HotkeysController<'_> {
             looped,
             missing: false,
             last_played_at,
+            stars,
         };
         self.update_cached_entry(&source, &relative_path, updated);
         if self.selection_state.ctx.selected_source.as_ref() == Some(&source.id) {
@@ -317,6 +342,7 @@ impl HotkeysController<'_> {
                 looped: false,
                 missing: false,
                 last_played_at: None,
+                stars: None,
             },
             absolute_path,
         };
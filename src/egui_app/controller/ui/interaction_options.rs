@@ -9,6 +9,10 @@ const MIN_WHEEL_ZOOM_SPEED: f32 = 0.1;
 const MAX_WHEEL_ZOOM_SPEED: f32 = 20.0;
 const MIN_ANTI_CLIP_FADE_MS: f32 = 0.0;
 const MAX_ANTI_CLIP_FADE_MS: f32 = 20.0;
+const MIN_SEEK_CROSSFADE_MS: f32 = 1.0;
+const MAX_SEEK_CROSSFADE_MS: f32 = 250.0;
+const MAX_PLAYHEAD_TRAIL_SECONDS: f32 = 5.0;
+const MAX_PLAYHEAD_TRAIL_FADE_SECONDS: f32 = 2.0;
 
 pub(crate) fn clamp_scroll_speed(speed: f32) -> f32 {
     speed.clamp(MIN_SCROLL_SPEED, MAX_SCROLL_SPEED)
@@ -22,6 +26,27 @@ pub(crate) fn clamp_anti_clip_fade_ms(fade_ms: f32) -> f32 {
     fade_ms.clamp(MIN_ANTI_CLIP_FADE_MS, MAX_ANTI_CLIP_FADE_MS)
 }
 
+pub(crate) fn clamp_seek_crossfade_ms(fade_ms: f32) -> f32 {
+    if !fade_ms.is_finite() {
+        return MIN_SEEK_CROSSFADE_MS;
+    }
+    fade_ms.clamp(MIN_SEEK_CROSSFADE_MS, MAX_SEEK_CROSSFADE_MS)
+}
+
+pub(crate) fn clamp_playhead_trail_seconds(seconds: f32) -> f32 {
+    if !seconds.is_finite() {
+        return 0.0;
+    }
+    seconds.clamp(0.0, MAX_PLAYHEAD_TRAIL_SECONDS)
+}
+
+pub(crate) fn clamp_playhead_trail_fade_seconds(seconds: f32) -> f32 {
+    if !seconds.is_finite() {
+        return 0.0;
+    }
+    seconds.clamp(0.0, MAX_PLAYHEAD_TRAIL_FADE_SECONDS)
+}
+
 fn clamp_wheel_zoom_speed(speed: f32) -> f32 {
     speed.clamp(MIN_WHEEL_ZOOM_SPEED, MAX_WHEEL_ZOOM_SPEED)
 }
@@ -113,6 +138,67 @@ impl EguiController {
         self.persist_controls();
     }
 
+    /// Toggle and persist crossfading when seeking during playback.
+    pub fn set_seek_crossfade_enabled(&mut self, enabled: bool) {
+        if self.settings.controls.seek_crossfade_enabled == enabled {
+            return;
+        }
+        self.settings.controls.seek_crossfade_enabled = enabled;
+        self.ui.controls.seek_crossfade_enabled = enabled;
+        self.persist_controls();
+    }
+
+    /// Set and persist the seek crossfade window in milliseconds.
+    pub fn set_seek_crossfade_ms(&mut self, fade_ms: f32) {
+        let clamped = clamp_seek_crossfade_ms(fade_ms);
+        if (self.settings.controls.seek_crossfade_ms - clamped).abs() < f32::EPSILON {
+            return;
+        }
+        self.settings.controls.seek_crossfade_ms = clamped;
+        self.ui.controls.seek_crossfade_ms = clamped;
+        self.persist_controls();
+    }
+
+    /// Set and persist the playhead trail length in seconds (0 disables the trail).
+    pub fn set_playhead_trail_seconds(&mut self, seconds: f32) {
+        let clamped = clamp_playhead_trail_seconds(seconds);
+        if (self.settings.controls.playhead_trail_seconds - clamped).abs() < f32::EPSILON {
+            return;
+        }
+        self.settings.controls.playhead_trail_seconds = clamped;
+        self.ui.controls.playhead_trail_seconds = clamped;
+        if clamped <= 0.0 {
+            let playhead = &mut self.ui.waveform.playhead;
+            playhead.trail.clear();
+            playhead.fading_trails.clear();
+        }
+        self.persist_controls();
+    }
+
+    /// Set and persist the fade-out time for detached playhead trails.
+    pub fn set_playhead_trail_fade_seconds(&mut self, seconds: f32) {
+        let clamped = clamp_playhead_trail_fade_seconds(seconds);
+        if (self.settings.controls.playhead_trail_fade_seconds - clamped).abs() < f32::EPSILON {
+            return;
+        }
+        self.settings.controls.playhead_trail_fade_seconds = clamped;
+        self.ui.controls.playhead_trail_fade_seconds = clamped;
+        self.persist_controls();
+    }
+
+    /// Set and persist the selection applied when a sample finishes loading.
+    pub fn set_load_selection_mode(
+        &mut self,
+        mode: crate::sample_sources::config::LoadSelectionMode,
+    ) {
+        if self.settings.controls.load_selection_mode == mode {
+            return;
+        }
+        self.settings.controls.load_selection_mode = mode;
+        self.ui.controls.load_selection_mode = mode;
+        self.persist_controls();
+    }
+
     /// Toggle and persist auto edge fades for new samples exported from selections.
     pub fn set_auto_edge_fades_on_selection_exports(&mut self, enabled: bool) {
         if self.settings.controls.auto_edge_fades_on_selection_exports == enabled {
@@ -123,6 +209,16 @@ impl EguiController {
         self.persist_controls();
     }
 
+    /// Toggle and persist writing waveform markers into samples exported from selections.
+    pub fn set_markers_on_selection_exports(&mut self, enabled: bool) {
+        if self.settings.controls.markers_on_selection_exports == enabled {
+            return;
+        }
+        self.settings.controls.markers_on_selection_exports = enabled;
+        self.ui.controls.markers_on_selection_exports = enabled;
+        self.persist_controls();
+    }
+
     /// Toggle and persist destructive "yolo mode" (skip confirmation prompts).
     pub fn set_destructive_yolo_mode(&mut self, enabled: bool) {
         if self.settings.controls.destructive_yolo_mode == enabled {
@@ -133,6 +229,21 @@ impl EguiController {
         self.persist_controls();
     }
 
+    /// Set and persist whether a destructive edit asks for confirmation.
+    pub fn set_destructive_edit_confirmation(
+        &mut self,
+        edit: crate::egui_app::state::DestructiveSelectionEdit,
+        required: bool,
+    ) {
+        let confirmations = &mut self.settings.controls.destructive_confirmations;
+        if edit.requires_confirmation(confirmations) == required {
+            return;
+        }
+        edit.set_requires_confirmation(confirmations, required);
+        self.ui.controls.destructive_confirmations = *confirmations;
+        self.persist_controls();
+    }
+
     /// Toggle and persist input monitoring during recording.
     pub fn set_input_monitoring_enabled(&mut self, enabled: bool) {
         if self.settings.controls.input_monitoring_enabled == enabled {
@@ -245,6 +356,17 @@ impl EguiController {
         self.persist_controls();
     }
 
+    /// Set and persist how many loop cycles play before looped playback stops.
+    pub fn set_loop_cycle_limit(&mut self, limit: Option<u32>) {
+        let limit = limit.filter(|limit| *limit > 0);
+        if self.settings.controls.loop_cycle_limit == limit {
+            return;
+        }
+        self.settings.controls.loop_cycle_limit = limit;
+        self.ui.waveform.loop_cycle_limit = limit;
+        self.persist_controls();
+    }
+
     /// Update and persist the BPM snap value for waveform snapping and stretching.
     ///
     /// When stretch is enabled and a sample is loaded, the waveform reloads to
@@ -272,6 +394,7 @@ impl EguiController {
         self.settings.controls.bpm_value = value;
         self.ui.waveform.bpm_value = Some(value);
         self.persist_controls();
+        self.sync_metronome();
         if self.ui.waveform.bpm_stretch_enabled
             && !self.selection_state.range.is_dragging()
             && !self.selection_state.edit_range.is_dragging()
@@ -305,6 +428,17 @@ impl EguiController {
         }
     }
 
+    /// Enable/disable the beat metronome click on playback and persist the setting.
+    pub fn set_metronome_enabled(&mut self, enabled: bool) {
+        if self.settings.controls.metronome_enabled == enabled {
+            return;
+        }
+        self.settings.controls.metronome_enabled = enabled;
+        self.ui.waveform.metronome_enabled = enabled;
+        self.persist_controls();
+        self.sync_metronome();
+    }
+
     /// Enable/disable transient snapping and persist the setting.
     pub fn set_transient_snap_enabled(&mut self, enabled: bool) {
         if self.settings.controls.transient_snap_enabled == enabled {
@@ -333,6 +467,32 @@ impl EguiController {
         self.persist_controls();
     }
 
+    /// Current hard cap on transient markers, if any.
+    pub fn transient_max_markers(&self) -> Option<u32> {
+        self.settings.controls.transient_max_markers
+    }
+
+    /// Set the hard cap on transient markers (`None` for automatic) and re-detect them.
+    pub fn set_transient_max_markers(&mut self, max_markers: Option<u32>) {
+        let max_markers = max_markers.map(|cap| cap.max(1));
+        if self.settings.controls.transient_max_markers == max_markers {
+            return;
+        }
+        self.settings.controls.transient_max_markers = max_markers;
+        self.ui.waveform.transient_cache_token = None;
+        self.refresh_waveform_transients();
+        self.persist_controls();
+    }
+
+    /// Detected transient markers per second for the loaded waveform.
+    pub fn transient_onset_density(&self) -> Option<f32> {
+        let decoded = self.sample_view.waveform.decoded.as_ref()?;
+        Some(crate::waveform::transients::onset_density(
+            self.ui.waveform.transients.len(),
+            decoded.duration_seconds,
+        ))
+    }
+
     /// Enable/disable normalized audition playback and persist the setting.
     pub fn set_normalized_audition_enabled(&mut self, enabled: bool) {
         if self.settings.controls.normalized_audition_enabled == enabled {
@@ -353,6 +513,16 @@ impl EguiController {
         self.persist_controls();
     }
 
+    /// Toggle and persist the high-contrast colour theme.
+    pub fn set_high_contrast_theme(&mut self, enabled: bool) {
+        if self.settings.controls.high_contrast_theme == enabled {
+            return;
+        }
+        self.settings.controls.high_contrast_theme = enabled;
+        self.ui.controls.high_contrast_theme = enabled;
+        self.persist_controls();
+    }
+
     /// Toggle and persist auto-advance after rating/tagging.
     pub fn set_advance_after_rating(&mut self, enabled: bool) {
         if self.settings.controls.advance_after_rating == enabled {
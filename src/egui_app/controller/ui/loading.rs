@@ -193,15 +193,50 @@ impl EguiController {
         let source = source.clone();
         std::thread::spawn(move || {
             let result = analysis_jobs::failed_samples_for_source(&source);
+            let warnings = analysis_jobs::analysis_warnings_for_source(&source).unwrap_or_default();
+            let silent = analysis_jobs::silent_samples_for_source(&source).unwrap_or_default();
             let _ = tx.send(super::jobs::JobMessage::AnalysisFailuresLoaded(
                 super::jobs::AnalysisFailuresResult {
                     source_id: source.id.clone(),
                     result,
+                    warnings,
+                    silent,
                 },
             ));
         });
     }
 
+    /// Reload decode outcome counts for the selected source in the background.
+    pub(crate) fn refresh_decode_outcome_counts(&mut self) {
+        let Some(source) = self.current_source() else {
+            return;
+        };
+        if !self
+            .ui_cache
+            .browser
+            .decode_outcomes_pending
+            .insert(source.id.clone())
+        {
+            return;
+        }
+        let tx = self.runtime.jobs.message_sender();
+        std::thread::spawn(move || {
+            let result = analysis_jobs::decode_outcome_counts_for_source(&source);
+            let _ = tx.send(super::jobs::JobMessage::DecodeOutcomeCountsLoaded(
+                super::jobs::DecodeOutcomeCountsResult {
+                    source_id: source.id.clone(),
+                    result,
+                },
+            ));
+        });
+    }
+
+    /// Return the last loaded decode outcome counts for the selected source.
+    pub(crate) fn decode_outcome_counts(&self) -> Option<analysis_jobs::DecodeOutcomeCounts> {
+        let source_id = self.selection_state.ctx.selected_source.as_ref()?;
+        self.ui_cache.browser.decode_outcomes.get(source_id).copied()
+    }
+
     pub(crate) fn invalidate_wav_entries_for_source(&mut self, source: &SampleSource) {
         self.cache.wav.entries.remove(&source.id);
         if self.selection_state.ctx.selected_source.as_ref() == Some(&source.id) {
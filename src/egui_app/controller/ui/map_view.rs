@@ -3,6 +3,7 @@ use crate::egui_app::controller::library::analysis_jobs;
 use rusqlite::types::Value;
 use rusqlite::{Connection, OptionalExtension, params, params_from_iter};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 pub(crate) struct UmapBounds {
     pub min_x: f32,
@@ -21,11 +22,118 @@ pub(crate) struct UmapPoint {
 impl EguiController {
     /// Open the map view panel.
     pub fn open_map(&mut self) {
+        if self.runtime.launch.disable_map {
+            self.set_status("Map is disabled in safe mode", StatusTone::Info);
+            return;
+        }
         self.ui.map.open = true;
+        self.set_map_open_persisted(true);
+    }
+
+    /// Whether the map is unavailable for this session (safe mode).
+    pub fn map_disabled(&self) -> bool {
+        self.runtime.launch.disable_map
+    }
+
+    /// Apply launch options such as safe mode before the first frame.
+    pub fn apply_launch_options(&mut self, options: crate::egui_app::launch::LaunchOptions) {
+        self.runtime.launch = options;
+        if options.disable_map {
+            self.ui.map.open = false;
+        }
+        if options.safe_mode {
+            self.set_status("Safe mode: map disabled", StatusTone::Warning);
+        }
+    }
+
+    /// Apply a rating to every sample in the map multi-selection.
+    ///
+    /// Returns the number of samples tagged.
+    pub fn tag_map_selection(
+        &mut self,
+        tag: crate::sample_sources::Rating,
+    ) -> Result<usize, String> {
+        let sample_ids = self.ui.map.selected_sample_ids.clone();
+        if sample_ids.is_empty() {
+            return Err("Select points on the map first".to_string());
+        }
+        let mut last_error = None;
+        let mut groups: Vec<(SampleSource, Vec<PathBuf>)> = Vec::new();
+        for sample_id in &sample_ids {
+            let parsed = analysis_jobs::parse_sample_id(sample_id).and_then(|(source_id, path)| {
+                self.library
+                    .sources
+                    .iter()
+                    .find(|source| source.id.as_str() == source_id)
+                    .cloned()
+                    .map(|source| (source, path))
+                    .ok_or_else(|| format!("Unknown source for sample_id: {sample_id}"))
+            });
+            match parsed {
+                Ok((source, path)) => {
+                    match groups.iter_mut().find(|(known, _)| known.id == source.id) {
+                        Some((_, paths)) => paths.push(path),
+                        None => groups.push((source, vec![path])),
+                    }
+                }
+                Err(err) => last_error = Some(err),
+            }
+        }
+        let mut tagged = 0;
+        let mut previous = Vec::new();
+        for (source, paths) in groups {
+            let db = match self.database_for(&source) {
+                Ok(db) => db,
+                Err(err) => {
+                    last_error = Some(err.to_string());
+                    continue;
+                }
+            };
+            let mut updates = Vec::with_capacity(paths.len());
+            let mut before = Vec::with_capacity(paths.len());
+            for path in paths {
+                match db.tag_for_path(&path) {
+                    Ok(Some(old_tag)) => {
+                        before.push((source.id.clone(), path.clone(), old_tag));
+                        updates.push((path, tag));
+                    }
+                    Ok(None) => {
+                        last_error = Some(format!("Sample not found: {}", path.display()));
+                    }
+                    Err(err) => last_error = Some(err.to_string()),
+                }
+            }
+            match self.set_sample_tags_for_source(&source, &updates) {
+                Ok(()) => {
+                    tagged += updates.len();
+                    previous.extend(before);
+                }
+                Err(err) => last_error = Some(err),
+            }
+        }
+        self.push_tag_undo_entry("Tag map samples", previous, tag);
+        if let Some(err) = last_error {
+            self.set_status(
+                format!("Tagged {tagged} of {} map samples: {err}", sample_ids.len()),
+                StatusTone::Warning,
+            );
+            if tagged == 0 {
+                return Err(err);
+            }
+        } else {
+            self.set_status(
+                format!("Tagged {tagged} map samples as {}", tag.label()),
+                StatusTone::Info,
+            );
+        }
+        Ok(tagged)
     }
 
     /// Enqueue a UMAP layout build for the selected source.
     pub fn build_umap_layout(&mut self, model_id: &str, umap_version: &str) {
+        if self.runtime.launch.disable_map {
+            return;
+        }
         if self.runtime.jobs.umap_build_in_progress() {
             self.set_status_message(StatusMessage::TsneBuildAlreadyRunning);
             return;
@@ -18,3 +18,4 @@ pub(crate) mod os_explorer;
 pub(crate) mod status_message;
 pub(crate) mod waveform_controller;
 pub(crate) mod waveform_slide;
+pub(crate) mod window_layout;
@@ -1,9 +1,6 @@
-use std::path::Path;
-
-#[cfg(any(test, target_os = "windows"))]
 use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 
-#[cfg(any(test, target_os = "windows"))]
 fn windows_explorer_target(path: &Path) -> OsString {
     let rendered = path.to_string_lossy();
     if rendered.contains('/') {
@@ -13,51 +10,91 @@ fn windows_explorer_target(path: &Path) -> OsString {
     }
 }
 
-#[cfg(any(test, target_os = "windows"))]
 fn windows_explorer_select_args(path: &Path) -> [OsString; 2] {
     [OsString::from("/select,"), windows_explorer_target(path)]
 }
 
+/// Desktop platform whose file manager conventions apply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FileManagerPlatform {
+    Windows,
+    MacOs,
+    Other,
+}
+
+impl FileManagerPlatform {
+    pub(crate) fn current() -> Self {
+        if cfg!(target_os = "windows") {
+            Self::Windows
+        } else if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// How to ask the platform file manager to reveal a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum RevealCommand {
+    /// Launch a file manager that selects the file itself.
+    Spawn {
+        program: &'static str,
+        args: Vec<OsString>,
+        label: &'static str,
+    },
+    /// Open the containing folder with the desktop's default handler.
+    OpenFolder(PathBuf),
+}
+
+/// Map a file path to the command that reveals it on `platform`.
+pub(crate) fn reveal_command(
+    platform: FileManagerPlatform,
+    path: &Path,
+) -> Result<RevealCommand, String> {
+    match platform {
+        FileManagerPlatform::Windows => Ok(RevealCommand::Spawn {
+            program: "explorer.exe",
+            args: windows_explorer_select_args(path).into(),
+            label: "Explorer",
+        }),
+        FileManagerPlatform::MacOs => Ok(RevealCommand::Spawn {
+            program: "open",
+            args: vec![OsString::from("-R"), path.as_os_str().to_owned()],
+            label: "Finder",
+        }),
+        FileManagerPlatform::Other => path
+            .parent()
+            .map(|parent| RevealCommand::OpenFolder(parent.to_path_buf()))
+            .ok_or_else(|| "Unable to resolve parent directory".to_string()),
+    }
+}
+
 pub(crate) fn reveal_in_file_explorer(path: &Path) -> Result<(), String> {
     if !path.exists() {
         return Err(format!("File not found: {}", path.display()));
     }
-    #[cfg(target_os = "windows")]
-    {
-        let status = std::process::Command::new("explorer.exe")
-            .args(windows_explorer_select_args(path))
-            .status()
-            .map_err(|err| format!("Failed to launch explorer: {err}"))?;
-        if status.success() {
-            return Ok(());
+    match reveal_command(FileManagerPlatform::current(), path)? {
+        RevealCommand::Spawn {
+            program,
+            args,
+            label,
+        } => {
+            let status = std::process::Command::new(program)
+                .args(args)
+                .status()
+                .map_err(|err| format!("Failed to launch {label}: {err}"))?;
+            if status.success() {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{label} exited unsuccessfully for {}",
+                    path.display()
+                ))
+            }
         }
-        return Err(format!(
-            "Explorer exited unsuccessfully for {}",
-            path.display()
-        ));
-    }
-    #[cfg(target_os = "macos")]
-    {
-        let status = std::process::Command::new("open")
-            .arg("-R")
-            .arg(path)
-            .status()
-            .map_err(|err| format!("Failed to launch Finder: {err}"))?;
-        if status.success() {
-            return Ok(());
-        }
-        return Err(format!(
-            "Finder exited unsuccessfully for {}",
-            path.display()
-        ));
-    }
-    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
-    {
-        let parent = path
-            .parent()
-            .ok_or_else(|| "Unable to resolve parent directory".to_string())?;
-        open::that(parent)
-            .map_err(|err| format!("Could not open folder {}: {err}", parent.display()))
+        RevealCommand::OpenFolder(parent) => open::that(&parent)
+            .map_err(|err| format!("Could not open folder {}: {err}", parent.display())),
     }
 }
 
@@ -102,6 +139,57 @@ pub(crate) fn open_folder_in_file_explorer(path: &Path) -> Result<(), String> {
     }
 }
 
+const WINDOWS_RECYCLE_SCRIPT: &str = "Add-Type -AssemblyName Microsoft.VisualBasic; \
+     [Microsoft.VisualBasic.FileIO.FileSystem]::DeleteFile($env:SEMPAL_TRASH_PATH, \
+     'OnlyErrorDialogs', 'SendToRecycleBin')";
+
+/// Send a file to the platform recycle bin so it can still be restored from there.
+pub(crate) fn send_to_os_trash(path: &Path) -> Result<(), String> {
+    if !path.is_file() {
+        return Err(format!("File not found: {}", path.display()));
+    }
+    let mut command = match FileManagerPlatform::current() {
+        FileManagerPlatform::Windows => {
+            let mut command = std::process::Command::new("powershell.exe");
+            command
+                .args(["-NoProfile", "-NonInteractive", "-Command", WINDOWS_RECYCLE_SCRIPT])
+                .env("SEMPAL_TRASH_PATH", path);
+            command
+        }
+        FileManagerPlatform::MacOs => {
+            let mut command = std::process::Command::new("osascript");
+            command
+                .args([
+                    "-e",
+                    "on run argv",
+                    "-e",
+                    "tell application \"Finder\" to delete (POSIX file (item 1 of argv))",
+                    "-e",
+                    "end run",
+                ])
+                .arg(path);
+            command
+        }
+        FileManagerPlatform::Other => {
+            let mut command = std::process::Command::new("gio");
+            command.arg("trash").arg(path);
+            command
+        }
+    };
+    let output = command
+        .output()
+        .map_err(|err| format!("Failed to launch system trash: {err}"))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "System trash failed for {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +215,31 @@ mod tests {
         let path = Path::new("C:/samples");
         assert_eq!(windows_explorer_target(path), OsStr::new("C:\\samples"));
     }
+
+    #[test]
+    fn reveal_command_maps_path_to_platform_arguments() {
+        let path = Path::new("/samples/drums/kick.wav");
+        let RevealCommand::Spawn { program, args, .. } =
+            reveal_command(FileManagerPlatform::MacOs, path).unwrap()
+        else {
+            panic!("expected Finder to be spawned");
+        };
+        assert_eq!(program, "open");
+        assert_eq!(args, vec![OsString::from("-R"), path.as_os_str().to_owned()]);
+
+        let RevealCommand::Spawn { program, args, .. } =
+            reveal_command(FileManagerPlatform::Windows, Path::new("C:/samples/kick.wav"))
+                .unwrap()
+        else {
+            panic!("expected Explorer to be spawned");
+        };
+        assert_eq!(program, "explorer.exe");
+        assert_eq!(args[0], OsStr::new("/select,"));
+        assert_eq!(args[1], OsStr::new("C:\\samples\\kick.wav"));
+
+        assert_eq!(
+            reveal_command(FileManagerPlatform::Other, path).unwrap(),
+            RevealCommand::OpenFolder(PathBuf::from("/samples/drums"))
+        );
+    }
 }
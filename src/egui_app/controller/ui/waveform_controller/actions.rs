@@ -13,6 +13,7 @@ pub(crate) trait WaveformActions {
     );
     fn nudge_selection_range(&mut self, steps: isize, fine: bool);
     fn slide_selection_range(&mut self, steps: isize);
+    fn extend_selection_to_next_transient(&mut self);
     fn scroll_waveform_view(&mut self, center: f64);
 }
 
@@ -124,6 +125,35 @@ impl WaveformActions for WaveformController<'_> {
         self.push_selection_undo("Selection", before, Some(range));
     }
 
+    fn extend_selection_to_next_transient(&mut self) {
+        if !self.waveform_ready() {
+            return;
+        }
+        let Some(selection) = self
+            .selection_state
+            .range
+            .range()
+            .or(self.ui.waveform.selection)
+        else {
+            self.set_status("Create a selection first", StatusTone::Info);
+            return;
+        };
+        let Some(marker) = crate::waveform::transients::next_transient_after(
+            &self.ui.waveform.transients,
+            selection.end(),
+        ) else {
+            self.set_status("No transient after the selection", StatusTone::Info);
+            return;
+        };
+        let before = Some(selection);
+        let range = SelectionRange::new(selection.start(), marker).with_gain(selection.gain());
+        self.selection_state.range.set_range(Some(range));
+        self.apply_selection(Some(range));
+        self.ensure_selection_visible_in_view(range);
+        self.refresh_loop_after_selection_change(range);
+        self.push_selection_undo("Selection", before, Some(range));
+    }
+
     fn scroll_waveform_view(&mut self, center: f64) {
         let view = self.ui.waveform.view;  // Use actual view, not display_view
         let width = view.width();
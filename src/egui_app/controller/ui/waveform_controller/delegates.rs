@@ -37,6 +37,10 @@ impl EguiController {
         self.waveform().slide_selection_range(steps);
     }
 
+    pub(crate) fn extend_selection_to_next_transient(&mut self) {
+        self.waveform().extend_selection_to_next_transient();
+    }
+
     pub(crate) fn waveform_ready(&self) -> bool {
         self.sample_view.waveform.decoded.is_some()
     }
@@ -45,6 +49,19 @@ impl EguiController {
         self.waveform().set_waveform_cursor(position);
     }
 
+    /// Move the cursor to the transient marker nearest `position`.
+    ///
+    /// Returns false when no transients are available to snap to.
+    pub(crate) fn snap_waveform_cursor_to_transient(&mut self, position: f32) -> bool {
+        let Some(marker) =
+            crate::waveform::transients::nearest_transient(&self.ui.waveform.transients, position)
+        else {
+            return false;
+        };
+        self.set_waveform_cursor(marker);
+        true
+    }
+
     pub(crate) fn set_waveform_cursor_from_hover(&mut self, position: f32) {
         self.waveform().set_waveform_cursor_from_hover(position);
     }
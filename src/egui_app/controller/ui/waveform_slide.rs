@@ -201,6 +201,7 @@ impl EguiController {
             duration_seconds,
             sample_rate: sample_rate.max(1),
             channels,
+            cue_markers: Vec::new(),
         });
         self.sample_view.waveform.render_meta = None;
         self.ui.waveform.transient_cache_token = None;
@@ -230,11 +231,11 @@ impl EguiController {
             .map_err(|err| format!("Failed to sync database entry: {err}"))?;
         db.set_tag(&state.relative_path, tag)
             .map_err(|err| format!("Failed to sync tag: {err}"))?;
-        let (last_played_at, looped) = self
+        let (last_played_at, looped, stars) = self
             .wav_index_for_path(&state.relative_path)
             .and_then(|idx| self.wav_entry(idx))
-            .map(|entry| (entry.last_played_at, entry.looped))
-            .unwrap_or((None, false));
+            .map(|entry| (entry.last_played_at, entry.looped, entry.stars))
+            .unwrap_or((None, false, None));
         let entry = WavEntry {
             relative_path: state.relative_path.clone(),
             file_size,
@@ -244,9 +245,11 @@ impl EguiController {
             looped,
             missing: false,
             last_played_at,
+            stars,
         };
         self.update_cached_entry(&state.source, &state.relative_path, entry);
         self.refresh_waveform_for_sample(&state.source, &state.relative_path);
+        self.remember_edit_backup(&state.source.id, &state.relative_path, &backup);
         self.push_undo_entry(self.selection_edit_undo_entry(
             format!("Circular slide {}", state.relative_path.display()),
             state.source.id.clone(),
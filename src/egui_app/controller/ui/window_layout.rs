@@ -0,0 +1,62 @@
+use super::*;
+use crate::sample_sources::config::clamp_split_ratio;
+
+/// Minimum size change in points before a map window resize is persisted.
+const MAP_SIZE_EPSILON: f32 = 1.0;
+
+impl EguiController {
+    /// Persist the sources panel split as shares of the panel height.
+    pub fn set_sources_panel_splits(&mut self, sources: Option<f32>, drop_targets: Option<f32>) {
+        let sources = sources.and_then(clamp_split_ratio);
+        let drop_targets = drop_targets.and_then(clamp_split_ratio);
+        self.ui.sources.sources_split_ratio = sources;
+        self.ui.sources.drop_targets.split_ratio = drop_targets;
+        if self.settings.layout.sources_split == sources
+            && self.settings.layout.drop_targets_split == drop_targets
+        {
+            return;
+        }
+        self.settings.layout.sources_split = sources;
+        self.settings.layout.drop_targets_split = drop_targets;
+        self.persist_layout();
+    }
+
+    /// Persist the map window size after the user resizes it.
+    pub fn set_map_window_size(&mut self, size: [f32; 2]) {
+        if size.iter().any(|edge| !edge.is_finite() || *edge <= 0.0) {
+            return;
+        }
+        let unchanged = self.settings.layout.map_size.is_some_and(|stored| {
+            stored
+                .iter()
+                .zip(size)
+                .all(|(stored, edge)| (stored - edge).abs() < MAP_SIZE_EPSILON)
+        });
+        self.ui.map.window_size = Some(size);
+        if unchanged {
+            return;
+        }
+        self.settings.layout.map_size = Some(size);
+        self.persist_layout();
+    }
+
+    /// Close the map window and remember it stays closed next session.
+    pub fn close_map(&mut self) {
+        self.ui.map.open = false;
+        self.set_map_open_persisted(false);
+    }
+
+    pub(crate) fn set_map_open_persisted(&mut self, open: bool) {
+        if self.settings.layout.map_open == open {
+            return;
+        }
+        self.settings.layout.map_open = open;
+        self.persist_layout();
+    }
+
+    fn persist_layout(&mut self) {
+        if let Err(err) = self.persist_config("Failed to save window layout") {
+            self.set_status(err, StatusTone::Warning);
+        }
+    }
+}
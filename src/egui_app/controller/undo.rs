@@ -2,6 +2,7 @@ use std::{
     collections::VecDeque,
     fs,
     path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
 };
 use super::jobs::UndoFileJob;
 use uuid::Uuid;
@@ -46,7 +47,11 @@ pub(crate) enum UndoOutcome<T> {
     Deferred(DeferredUndo<T>),
 }
 
+/// Source of unique undo entry ids, so status actions can tell entries with equal labels apart.
+static NEXT_UNDO_ENTRY_ID: AtomicU64 = AtomicU64::new(1);
+
 pub(crate) struct UndoEntry<T> {
+    pub(crate) id: u64,
     pub(crate) label: String,
     pub(crate) undo: Box<dyn Fn(&mut T) -> UndoResult>,
     pub(crate) redo: Box<dyn Fn(&mut T) -> UndoResult>,
@@ -60,6 +65,7 @@ impl<T> UndoEntry<T> {
         redo: impl Fn(&mut T) -> UndoResult + 'static,
     ) -> Self {
         Self {
+            id: NEXT_UNDO_ENTRY_ID.fetch_add(1, Ordering::Relaxed),
             label: label.into(),
             undo: Box::new(undo),
             redo: Box::new(redo),
@@ -96,6 +102,13 @@ impl<T> UndoStack<T> {
         }
     }
 
+    /// Id and label of the entry that the next undo would revert, if any.
+    pub(crate) fn last_undo(&self) -> Option<(u64, &str)> {
+        self.undo
+            .back()
+            .map(|entry| (entry.id, entry.label.as_str()))
+    }
+
     pub(crate) fn undo(&mut self, target: &mut T) -> Result<UndoOutcome<T>, String> {
         let Some(entry) = self.undo.pop_back() else {
             return Ok(UndoOutcome::Empty);
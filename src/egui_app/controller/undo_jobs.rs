@@ -22,6 +22,48 @@ pub(crate) fn run_undo_file_job(
         };
     }
     let result = match job {
+        UndoFileJob::Batch(jobs) => run_batch(jobs, sender),
+        job => {
+            let result = run_job_step(job);
+            if let Some(tx) = sender {
+                let _ = tx.send(FileOpMessage::Progress {
+                    completed: 1,
+                    detail: None,
+                });
+            }
+            result
+        }
+    };
+    UndoFileOpResult {
+        result,
+        cancelled: false,
+    }
+}
+
+/// Run grouped jobs in order, stopping at the first failure.
+///
+/// Cancellation is only honoured before the first step so a group is never left half-applied
+/// on purpose; every step copies from a backup, so retrying after a failure is safe.
+fn run_batch(
+    jobs: Vec<UndoFileJob>,
+    sender: Option<&Sender<FileOpMessage>>,
+) -> Result<UndoFileOutcome, String> {
+    let mut outcomes = Vec::with_capacity(jobs.len());
+    for (index, job) in jobs.into_iter().enumerate() {
+        outcomes.push(run_job_step(job)?);
+        if let Some(tx) = sender {
+            let _ = tx.send(FileOpMessage::Progress {
+                completed: index + 1,
+                detail: None,
+            });
+        }
+    }
+    Ok(UndoFileOutcome::Batch(outcomes))
+}
+
+fn run_job_step(job: UndoFileJob) -> Result<UndoFileOutcome, String> {
+    match job {
+        UndoFileJob::Batch(jobs) => run_batch(jobs, None),
         UndoFileJob::Overwrite {
             source_id,
             source_root,
@@ -30,15 +72,8 @@ pub(crate) fn run_undo_file_job(
             backup_path,
         } => {
             if let Some(parent) = absolute_path.parent() {
-                if let Err(err) = std::fs::create_dir_all(parent) {
-                    return UndoFileOpResult {
-                        result: Err(format!(
-                            "Failed to create folder {}: {err}",
-                            parent.display()
-                        )),
-                        cancelled: false,
-                    };
-                }
+                std::fs::create_dir_all(parent)
+                    .map_err(|err| format!("Failed to create folder {}: {err}", parent.display()))?;
             }
             std::fs::copy(&backup_path, &absolute_path)
                 .map_err(|err| format!("Failed to restore audio: {err}"))
@@ -57,6 +92,9 @@ pub(crate) fn run_undo_file_job(
                     let last_played_at = db
                         .last_played_at_for_path(&relative_path)
                         .map_err(|err| format!("Failed to read database: {err}"))?;
+                    let stars = db
+                        .stars_for_path(&relative_path)
+                        .map_err(|err| format!("Failed to read database: {err}"))?;
                     Ok(UndoFileOutcome::Overwrite {
                         source_id,
                         relative_path,
@@ -65,6 +103,7 @@ pub(crate) fn run_undo_file_job(
                         tag,
                         looped,
                         last_played_at,
+                        stars,
                     })
                 })
         }
@@ -74,15 +113,8 @@ pub(crate) fn run_undo_file_job(
             relative_path,
             absolute_path,
         } => {
-            let db = match SourceDatabase::open(&source_root) {
-                Ok(db) => db,
-                Err(err) => {
-                    return UndoFileOpResult {
-                        result: Err(format!("Database unavailable: {err}")),
-                        cancelled: false,
-                    };
-                }
-            };
+            let db = SourceDatabase::open(&source_root)
+                .map_err(|err| format!("Database unavailable: {err}"))?;
             let _ = std::fs::remove_file(&absolute_path);
             let _ = db.remove_file(&relative_path);
             Ok(UndoFileOutcome::Removed {
@@ -99,15 +131,8 @@ pub(crate) fn run_undo_file_job(
             tag,
         } => {
             if let Some(parent) = absolute_path.parent() {
-                if let Err(err) = std::fs::create_dir_all(parent) {
-                    return UndoFileOpResult {
-                        result: Err(format!(
-                            "Failed to create folder {}: {err}",
-                            parent.display()
-                        )),
-                        cancelled: false,
-                    };
-                }
+                std::fs::create_dir_all(parent)
+                    .map_err(|err| format!("Failed to create folder {}: {err}", parent.display()))?;
             }
             std::fs::copy(&backup_path, &absolute_path)
                 .map_err(|err| format!("Failed to restore audio: {err}"))
@@ -127,18 +152,9 @@ pub(crate) fn run_undo_file_job(
                         tag,
                         looped: false,
                         last_played_at: None,
+                        stars: None,
                     })
                 })
         }
-    };
-    if let Some(tx) = sender {
-        let _ = tx.send(FileOpMessage::Progress {
-            completed: 1,
-            detail: None,
-        });
-    }
-    UndoFileOpResult {
-        result,
-        cancelled: false,
     }
 }
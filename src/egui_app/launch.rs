@@ -0,0 +1,83 @@
+//! Command-line and environment launch options for the desktop app.
+
+use std::ffi::OsStr;
+
+/// Command-line flag that starts the app in safe mode.
+pub const SAFE_MODE_FLAG: &str = "--safe-mode";
+/// Environment variable that starts the app in safe mode when set to a truthy value.
+pub const SAFE_MODE_ENV: &str = "SEMPAL_SAFE_MODE";
+
+/// Startup options resolved from the command line and environment.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LaunchOptions {
+    /// Whether safe mode was requested.
+    pub safe_mode: bool,
+    /// Keep the sample map (and its layout builds) unavailable.
+    pub disable_map: bool,
+}
+
+impl LaunchOptions {
+    /// Options for a safe-mode launch with the GPU-heavy map turned off.
+    pub fn safe_mode() -> Self {
+        Self {
+            safe_mode: true,
+            disable_map: true,
+        }
+    }
+
+    /// Resolve options from process arguments (without the program name) and the env value.
+    pub fn from_args<I, S>(args: I, safe_mode_env: Option<&str>) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let flag = args.into_iter().any(|arg| arg.as_ref() == SAFE_MODE_FLAG);
+        let env = safe_mode_env.is_some_and(env_value_enabled);
+        if flag || env {
+            Self::safe_mode()
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Resolve options for the running process.
+    pub fn from_env() -> Self {
+        let env = std::env::var(SAFE_MODE_ENV).ok();
+        Self::from_args(std::env::args_os().skip(1), env.as_deref())
+    }
+}
+
+fn env_value_enabled(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_mode_flag_disables_map() {
+        let options = LaunchOptions::from_args(["--log", SAFE_MODE_FLAG], None);
+        assert!(options.safe_mode);
+        assert!(options.disable_map);
+    }
+
+    #[test]
+    fn safe_mode_env_is_honored_and_defaults_are_off() {
+        assert_eq!(
+            LaunchOptions::from_args(Vec::<&str>::new(), Some("1")),
+            LaunchOptions::safe_mode()
+        );
+        assert_eq!(
+            LaunchOptions::from_args(Vec::<&str>::new(), Some("0")),
+            LaunchOptions::default()
+        );
+        assert_eq!(
+            LaunchOptions::from_args(["--log"], None),
+            LaunchOptions::default()
+        );
+    }
+}
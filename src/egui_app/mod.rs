@@ -1,4 +1,5 @@
 pub mod controller;
+pub mod launch;
 pub mod state;
 pub mod ui;
 pub mod view_model;
@@ -39,12 +39,18 @@ pub struct SampleBrowserState {
     pub search_focus_requested: bool,
     /// When enabled, Up/Down jump through random samples instead of list order.
     pub random_navigation_mode: bool,
+    /// Seed of the active reproducible shuffle, re-applied whenever the list is rebuilt.
+    pub shuffle_seed: Option<u64>,
     /// Sorting mode for the sample browser list.
     pub sort: SampleBrowserSort,
     /// True when similarity sorting should follow the loaded sample.
     pub similarity_sort_follow_loaded: bool,
     /// Optional similar-sounds filter scoped to the current source.
     pub similar_query: Option<SimilarQuery>,
+    /// Hide samples already auditioned this session from find-similar results.
+    pub similar_hide_seen: bool,
+    /// Only show samples that analysis flagged as silent.
+    pub silent_only: bool,
     /// Near-duplicate highlight set for the focused sample.
     pub focused_similarity: Option<FocusedSimilarity>,
     /// Pending inline action for the sample browser rows.
@@ -81,9 +87,12 @@ impl Default for SampleBrowserState {
             search_query: String::new(),
             search_focus_requested: false,
             random_navigation_mode: false,
+            shuffle_seed: None,
             sort: SampleBrowserSort::ListOrder,
             similarity_sort_follow_loaded: false,
             similar_query: None,
+            similar_hide_seen: false,
+            silent_only: false,
             focused_similarity: None,
             pending_action: None,
             rename_focus_requested: false,
@@ -108,6 +117,8 @@ pub struct SimilarQuery {
     pub scores: Vec<f32>,
     /// Optional anchor index in the visible list.
     pub anchor_index: Option<usize>,
+    /// Sort rows with the lowest scores first, e.g. for outlier queries.
+    pub least_similar_first: bool,
 }
 
 impl SimilarQuery {
@@ -243,6 +254,8 @@ pub enum SampleBrowserSort {
     PlaybackAgeAsc,
     /// Sort by playback age descending.
     PlaybackAgeDesc,
+    /// Sort by star rating, highest first; unrated samples last.
+    RatingDesc,
 }
 
 /// Pending inline action for the sample browser.
@@ -1,4 +1,5 @@
 use crate::waveform::WaveformChannelView;
+use std::time::Duration;
 
 /// Interaction tuning surfaced in the UI.
 #[derive(Clone, Debug)]
@@ -15,10 +16,18 @@ pub struct InteractionOptionsState {
     pub anti_clip_fade_enabled: bool,
     /// Anti-clip fade duration in milliseconds.
     pub anti_clip_fade_ms: f32,
+    /// Whether seeks during playback crossfade instead of jumping.
+    pub seek_crossfade_enabled: bool,
+    /// Seek crossfade window in milliseconds.
+    pub seek_crossfade_ms: f32,
     /// Auto-apply short edge fades when exporting new samples from selections.
     pub auto_edge_fades_on_selection_exports: bool,
+    /// Write waveform markers as cue points when exporting new samples from selections.
+    pub markers_on_selection_exports: bool,
     /// Allow destructive edits without confirmation.
     pub destructive_yolo_mode: bool,
+    /// Which destructive edits ask for confirmation when yolo mode is off.
+    pub destructive_confirmations: crate::sample_sources::config::DestructiveConfirmations,
     /// Default waveform channel view.
     pub waveform_channel_view: WaveformChannelView,
     /// Whether input monitoring is enabled.
@@ -27,6 +36,14 @@ pub struct InteractionOptionsState {
     pub advance_after_rating: bool,
     /// Tooltip detail level.
     pub tooltip_mode: crate::sample_sources::config::TooltipMode,
+    /// Playhead trail length in seconds (0 disables the trail).
+    pub playhead_trail_seconds: f32,
+    /// Fade-out time in seconds for detached playhead trails.
+    pub playhead_trail_fade_seconds: f32,
+    /// Selection applied to a freshly loaded sample.
+    pub load_selection_mode: crate::sample_sources::config::LoadSelectionMode,
+    /// Whether the high-contrast colour theme is active.
+    pub high_contrast_theme: bool,
 }
 
 impl Default for InteractionOptionsState {
@@ -38,16 +55,36 @@ impl Default for InteractionOptionsState {
             keyboard_zoom_factor: 0.9,
             anti_clip_fade_enabled: true,
             anti_clip_fade_ms: 2.0,
+            seek_crossfade_enabled: false,
+            seek_crossfade_ms: 30.0,
             auto_edge_fades_on_selection_exports: true,
+            markers_on_selection_exports: false,
             destructive_yolo_mode: false,
+            destructive_confirmations: Default::default(),
             waveform_channel_view: WaveformChannelView::Mono,
             input_monitoring_enabled: true,
             advance_after_rating: true,
             tooltip_mode: crate::sample_sources::config::TooltipMode::Regular,
+            playhead_trail_seconds: 1.25,
+            playhead_trail_fade_seconds: 0.45,
+            load_selection_mode: crate::sample_sources::config::LoadSelectionMode::None,
+            high_contrast_theme: false,
         }
     }
 }
 
+impl InteractionOptionsState {
+    /// Configured playhead trail length.
+    pub fn playhead_trail_duration(&self) -> Duration {
+        Duration::from_secs_f32(self.playhead_trail_seconds.max(0.0))
+    }
+
+    /// Configured fade-out time for detached playhead trails.
+    pub fn playhead_trail_fade(&self) -> Duration {
+        Duration::from_secs_f32(self.playhead_trail_fade_seconds.max(0.0))
+    }
+}
+
 /// Destructive selection edits that overwrite audio on disk.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DestructiveSelectionEdit {
@@ -69,6 +106,8 @@ pub enum DestructiveSelectionEdit {
     NormalizeSelection,
     /// Attempt to remove clicks in the selection.
     ClickRemoval,
+    /// Replace the selection with audio from the system clipboard.
+    PasteClipboard,
 }
 
 /// Confirmation prompt content for destructive edits.
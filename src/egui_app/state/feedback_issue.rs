@@ -54,4 +54,21 @@ pub struct FeedbackIssueUiState {
     pub last_error: Option<String>,
     /// URL of the last created issue.
     pub last_success_url: Option<String>,
+    /// Graphics adapter details captured from the render context.
+    pub gpu_info: Option<GpuAdapterInfo>,
+    /// Whether to attach the redacted GPU summary to submitted issues.
+    pub include_gpu_info: bool,
+}
+
+/// Graphics adapter details reported by the render backend.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GpuAdapterInfo {
+    /// Adapter/renderer name.
+    pub name: String,
+    /// Adapter vendor.
+    pub vendor: String,
+    /// Rendering backend (e.g. "OpenGL").
+    pub backend: String,
+    /// Driver or API version string.
+    pub driver: String,
 }
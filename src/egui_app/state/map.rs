@@ -1,12 +1,16 @@
+use crate::sample_sources::SourceId;
 use eframe::egui::{Pos2, Vec2};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 /// UI state for the map view and its caches.
 #[derive(Clone, Debug)]
 pub struct MapUiState {
     /// Whether the map panel is open.
     pub open: bool,
+    /// Persisted map window size as `[width, height]`, if the user resized it.
+    pub window_size: Option<[f32; 2]>,
     /// Current pan offset in screen space.
     pub pan: Vec2,
     /// Current zoom level.
@@ -39,8 +43,16 @@ pub struct MapUiState {
     pub similarity_anchor_point: Option<(f32, f32)>,
     /// Sample id currently selected in the map.
     pub selected_sample_id: Option<String>,
+    /// Sample ids in the map multi-selection (Ctrl-click or Shift-drag lasso).
+    pub selected_sample_ids: Vec<String>,
+    /// Screen-space anchor of an in-progress lasso drag.
+    pub lasso_start: Option<Pos2>,
     /// Active hover target for paint operations.
     pub paint_hover_active_id: Option<String>,
+    /// Hovered sample waiting out the hover-play debounce, with when it was first hovered.
+    pub hover_play_pending: Option<(String, Instant)>,
+    /// Sample most recently played by hover-play.
+    pub hover_play_last_id: Option<String>,
     /// Selected UMAP version string.
     pub umap_version: String,
     /// Whether to overlay cluster information.
@@ -67,12 +79,15 @@ pub struct MapUiState {
     pub last_render_mode: MapRenderMode,
     /// Whether the map data is out of date.
     pub outdated: bool,
+    /// Viewports remembered for sources the user switched away from.
+    pub saved_views: HashMap<SourceId, MapViewSnapshot>,
 }
 
 impl Default for MapUiState {
     fn default() -> Self {
         Self {
             open: false,
+            window_size: None,
             pan: Vec2::ZERO,
             zoom: 1.0,
             last_drag_pos: None,
@@ -89,7 +104,11 @@ impl Default for MapUiState {
             similarity_anchor_sample_id: None,
             similarity_anchor_point: None,
             selected_sample_id: None,
+            selected_sample_ids: Vec::new(),
+            lasso_start: None,
             paint_hover_active_id: None,
+            hover_play_pending: None,
+            hover_play_last_id: None,
             umap_version: "v1".to_string(),
             cluster_overlay: true,
             cluster_hide_noise: true,
@@ -103,10 +122,50 @@ impl Default for MapUiState {
             last_points_rendered: 0,
             last_render_mode: MapRenderMode::Points,
             outdated: false,
+            saved_views: HashMap::new(),
         }
     }
 }
 
+impl MapUiState {
+    /// Remember the current zoom, pan and selected sample for `source_id`.
+    pub fn save_view(&mut self, source_id: SourceId) {
+        self.saved_views.insert(
+            source_id,
+            MapViewSnapshot {
+                pan: self.pan,
+                zoom: self.zoom,
+                selected_sample_id: self.selected_sample_id.clone(),
+            },
+        );
+    }
+
+    /// Restore the viewport saved for `source_id`, falling back to the default view.
+    pub fn restore_view(&mut self, source_id: Option<&SourceId>) {
+        let snapshot = source_id.and_then(|id| self.saved_views.get(id)).cloned();
+        let snapshot = snapshot.unwrap_or(MapViewSnapshot {
+            pan: Vec2::ZERO,
+            zoom: 1.0,
+            selected_sample_id: None,
+        });
+        self.pan = snapshot.pan;
+        self.zoom = snapshot.zoom;
+        self.selected_sample_id = snapshot.selected_sample_id;
+        self.last_drag_pos = None;
+    }
+}
+
+/// Map viewport remembered per source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MapViewSnapshot {
+    /// Pan offset in screen space.
+    pub pan: Vec2,
+    /// Zoom level.
+    pub zoom: f32,
+    /// Sample id selected in the map.
+    pub selected_sample_id: Option<String>,
+}
+
 /// Bounds covering all points in a layout.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MapBounds {
@@ -62,6 +62,8 @@ pub struct UiState {
     pub controls: InteractionOptionsState,
     /// Pending loop crossfade prompt state.
     pub loop_crossfade_prompt: Option<LoopCrossfadePrompt>,
+    /// Whether the About window is open.
+    pub about_open: bool,
     /// Master output volume (0.0-1.0).
     pub volume: f32,
     /// Release update status / notification state.
@@ -88,6 +90,7 @@ impl Default for UiState {
             map: MapUiState::default(),
             controls: InteractionOptionsState::default(),
             loop_crossfade_prompt: None,
+            about_open: false,
             volume: 1.0,
             update: UpdateUiState::default(),
             loaded_wav: None,
@@ -16,7 +16,7 @@ pub enum ProgressTaskKind {
     FileOps,
 }
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// UI state for the progress overlay and its counters.
 #[derive(Clone, Debug, Default)]
@@ -43,6 +43,10 @@ pub struct ProgressOverlayState {
     pub last_update_at: Option<Instant>,
     /// Last time progress advanced.
     pub last_progress_at: Option<Instant>,
+    /// When the task driving the overlay started.
+    pub started_at: Option<Instant>,
+    /// Estimated seconds remaining, derived from the completion rate so far.
+    pub eta_secs: Option<f64>,
     /// Optional analysis progress snapshot.
     pub analysis: Option<AnalysisProgressSnapshot>,
 }
@@ -120,6 +124,8 @@ impl ProgressOverlayState {
             cancel_requested: false,
             last_update_at: Some(now),
             last_progress_at: Some(now),
+            started_at: Some(now),
+            eta_secs: None,
             analysis: None,
         }
     }
@@ -142,7 +148,16 @@ impl ProgressOverlayState {
         }
         self.total = total;
         self.completed = completed;
-        self.last_update_at = Some(Instant::now());
+        let now = Instant::now();
+        self.eta_secs = self.started_at.and_then(|started| {
+            estimate_remaining_secs(completed, total, now.saturating_duration_since(started))
+        });
+        self.last_update_at = Some(now);
+    }
+
+    /// Human-readable remaining-time estimate, e.g. `~2m remaining`.
+    pub fn eta_label(&self) -> Option<String> {
+        self.eta_secs.map(format_remaining)
     }
 
     /// Update the analysis progress snapshot.
@@ -161,9 +176,39 @@ impl ProgressOverlayState {
     }
 }
 
+/// Estimate remaining seconds from the average completion rate over `elapsed`.
+///
+/// Returns `None` until at least one item has completed or when the total is unknown.
+pub fn estimate_remaining_secs(completed: usize, total: usize, elapsed: Duration) -> Option<f64> {
+    if total == 0 || completed == 0 || elapsed.is_zero() {
+        return None;
+    }
+    let rate = completed as f64 / elapsed.as_secs_f64();
+    let remaining = total.saturating_sub(completed) as f64;
+    Some(remaining / rate)
+}
+
+fn format_remaining(secs: f64) -> String {
+    let secs = secs.max(0.0).round() as u64;
+    if secs < 60 {
+        return format!("~{secs}s remaining");
+    }
+    // Round to whole minutes before choosing the unit so 59m30s+ reads as an hour.
+    let minutes = (secs + 30) / 60;
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("~{minutes}m remaining"),
+        (hours, 0) => format!("~{hours}h remaining"),
+        (hours, minutes) => format!("~{hours}h {minutes:02}m remaining"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{ProgressOverlayState, ProgressTaskKind, RunningJobSnapshot};
+    use super::{
+        ProgressOverlayState, ProgressTaskKind, RunningJobSnapshot, estimate_remaining_secs,
+        format_remaining,
+    };
+    use std::time::Duration;
 
     #[test]
     fn progress_fraction_handles_zero_total() {
@@ -193,4 +238,29 @@ mod tests {
             RunningJobSnapshot::from_heartbeat("job".to_string(), Some(18), Some(5), Some(20));
         assert!(!snapshot.possibly_stalled);
     }
+
+    #[test]
+    fn steady_rate_yields_expected_remaining_estimate() {
+        // 10 items in 20 seconds is 0.5 items/sec; 30 remaining items take 60 seconds.
+        let eta = estimate_remaining_secs(10, 40, Duration::from_secs(20)).unwrap();
+        assert!((eta - 60.0).abs() < 1e-9);
+        assert_eq!(estimate_remaining_secs(40, 40, Duration::from_secs(20)), Some(0.0));
+    }
+
+    #[test]
+    fn remaining_estimate_requires_progress() {
+        assert_eq!(estimate_remaining_secs(0, 40, Duration::from_secs(20)), None);
+        assert_eq!(estimate_remaining_secs(5, 0, Duration::from_secs(20)), None);
+        assert_eq!(estimate_remaining_secs(5, 40, Duration::ZERO), None);
+    }
+
+    #[test]
+    fn remaining_label_rounds_to_readable_units() {
+        assert_eq!(format_remaining(42.4), "~42s remaining");
+        assert_eq!(format_remaining(125.0), "~2m remaining");
+        assert_eq!(format_remaining(3_900.0), "~1h 05m remaining");
+        assert_eq!(format_remaining(3_599.0), "~1h remaining");
+        assert_eq!(format_remaining(3_569.0), "~59m remaining");
+        assert_eq!(format_remaining(7_170.0), "~2h remaining");
+    }
 }
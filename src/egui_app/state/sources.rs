@@ -1,4 +1,4 @@
-use crate::sample_sources::SourceId;
+use crate::sample_sources::{AnalysisCoverage, SourceId};
 use std::path::PathBuf;
 
 /// Sidebar list of sample sources.
@@ -16,6 +16,8 @@ pub struct SourcePanelState {
     pub sources_height_override: Option<f32>,
     /// Cached list height at the start of a sources resize drag for stable deltas.
     pub sources_resize_origin_height: Option<f32>,
+    /// Persisted share of the panel height used for the sources list when no override is set.
+    pub sources_split_ratio: Option<f32>,
     /// Folder browser sub-state.
     pub folders: FolderBrowserUiState,
     /// Drop target sub-state.
@@ -33,6 +35,10 @@ pub struct SourceRowView {
     pub path: String,
     /// Whether the source is missing on disk.
     pub missing: bool,
+    /// Analysis and embedding coverage, when the source database could be read.
+    pub coverage: Option<AnalysisCoverage>,
+    /// Unix time when the source's analysis queue last drained.
+    pub last_analyzed_at: Option<i64>,
 }
 
 /// UI state for browsing folders within the active source.
@@ -143,6 +149,8 @@ pub struct DropTargetsUiState {
     pub height_override: Option<f32>,
     /// Cached height at the start of a resize drag for stable deltas.
     pub resize_origin_height: Option<f32>,
+    /// Persisted share of the panel height used for drop targets when no override is set.
+    pub split_ratio: Option<f32>,
     /// Cached header height for the drop targets section.
     pub header_height: f32,
 }
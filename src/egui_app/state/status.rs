@@ -12,6 +12,20 @@ pub struct StatusBarState {
     pub badge_color: Color32,
     /// Rolling status log entries.
     pub log: Vec<String>,
+    /// Optional action offered alongside the current message.
+    pub action: Option<StatusAction>,
+}
+
+/// Quick action rendered next to a status message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StatusAction {
+    /// Revert the undo entry that produced the message.
+    Undo {
+        /// Id of the undo entry the action reverts.
+        entry_id: u64,
+        /// Label of the undo entry the action reverts.
+        label: String,
+    },
 }
 
 impl StatusBarState {
@@ -22,6 +36,7 @@ impl StatusBarState {
             badge_label: "Idle".into(),
             badge_color: style::status_badge_color(style::StatusTone::Idle),
             log: Vec::new(),
+            action: None,
         }
     }
 
@@ -45,20 +45,36 @@ pub struct WaveformState {
     pub bpm_value: Option<f32>,
     /// Cached transient positions (normalized 0-1) for the loaded waveform.
     pub transients: Vec<f32>,
+    /// Markers embedded in the loaded file (normalized 0-1), e.g. WAV cue points.
+    pub cue_markers: Vec<f32>,
+    /// User-placed markers (normalized 0-1) for the loaded sample, persisted per sample.
+    pub user_markers: Vec<f32>,
     /// When true, transient markers are rendered on the waveform.
     pub transient_markers_enabled: bool,
     /// When true, selection drags snap to nearby transient markers (disabled while hidden).
     pub transient_snap_enabled: bool,
     /// Cache token for the waveform transients.
     pub transient_cache_token: Option<u64>,
+    /// Custom peak-picking thresholds overriding automatic transient detection.
+    pub transient_tuning: Option<crate::waveform::transients::SensitivityParams>,
     /// Current visible viewport within the waveform (0.0-1.0 normalized).
     pub view: WaveformView,
+    /// Display-only amplitude magnification (1.0 = unscaled).
+    pub vertical_zoom: f32,
+    /// When true, the waveform shows the difference against the pre-edit audio.
+    pub ab_diff_enabled: bool,
     /// Whether looped playback is enabled.
     pub loop_enabled: bool,
     /// When true, loop playback state is locked against auto-updates.
     pub loop_lock_enabled: bool,
+    /// Stop looped playback after this many cycles (`None` loops forever).
+    pub loop_cycle_limit: Option<u32>,
     /// Whether to normalize audition playback.
     pub normalized_audition_enabled: bool,
+    /// Frequency band soloed on playback for auditioning (`None` plays the full range).
+    pub solo_band: Option<crate::audio::SoloBand>,
+    /// When true, playback mixes a click on every beat of the BPM value.
+    pub metronome_enabled: bool,
     /// Optional notice text displayed near the waveform.
     pub notice: Option<String>,
     /// Optional path for the sample currently loading to drive UI affordances.
@@ -102,13 +118,21 @@ impl Default for WaveformState {
             bpm_input: "142".to_string(),
             bpm_value: Some(142.0),
             transients: Vec::new(),
+            cue_markers: Vec::new(),
+            user_markers: Vec::new(),
             transient_markers_enabled: true,
             transient_snap_enabled: false,
             transient_cache_token: None,
+            transient_tuning: None,
             view: WaveformView::default(),
+            vertical_zoom: 1.0,
+            ab_diff_enabled: false,
             loop_enabled: false,
             loop_lock_enabled: false,
+            loop_cycle_limit: None,
             normalized_audition_enabled: false,
+            solo_band: None,
+            metronome_enabled: false,
             notice: None,
             loading: None,
             pending_destructive: None,
@@ -183,6 +207,8 @@ pub struct PlayheadState {
     pub trail: VecDeque<PlayheadTrailSample>,
     /// Previous trails that are fading out after a discontinuity (seek/loop/stop).
     pub fading_trails: Vec<FadingPlayheadTrail>,
+    /// Loop cycles completed since looped playback last started.
+    pub loop_cycles: u32,
 }
 
 impl Default for PlayheadState {
@@ -194,6 +220,7 @@ impl Default for PlayheadState {
             recent_seek: None,
             trail: VecDeque::new(),
             fading_trails: Vec::new(),
+            loop_cycles: 0,
         }
     }
 }
@@ -1,5 +1,6 @@
 //! egui renderer for the application UI.
 
+mod about_window;
 mod chrome;
 mod drag_overlay;
 mod drag_targets;
@@ -37,6 +38,7 @@ pub const DEFAULT_VIEWPORT_SIZE: [f32; 2] = [960.0, 560.0];
 /// Minimum viewport size for the app window.
 pub const MIN_VIEWPORT_SIZE: [f32; 2] = [640.0, 400.0];
 
+use crate::egui_app::launch::LaunchOptions;
 use crate::{audio::AudioPlayer, egui_app::controller::EguiController, waveform::WaveformRenderer};
 use eframe::egui::{self, TextureHandle};
 
@@ -105,6 +107,15 @@ impl EguiApp {
     pub fn new(
         renderer: WaveformRenderer,
         player: Option<std::rc::Rc<std::cell::RefCell<AudioPlayer>>>,
+    ) -> Result<Self, String> {
+        Self::new_with_launch_options(renderer, player, LaunchOptions::default())
+    }
+
+    /// Create a new egui app honoring command-line launch options such as safe mode.
+    pub fn new_with_launch_options(
+        renderer: WaveformRenderer,
+        player: Option<std::rc::Rc<std::cell::RefCell<AudioPlayer>>>,
+        launch: LaunchOptions,
     ) -> Result<Self, String> {
         let cfg = crate::sample_sources::config::load_or_default()
             .map_err(|err| format!("Failed to load config: {err}"))?;
@@ -116,6 +127,7 @@ impl EguiApp {
         controller
             .apply_configuration(cfg)
             .map_err(|err| format!("Failed to load config: {err}"))?;
+        controller.apply_launch_options(launch);
         controller.select_first_source();
         Ok(Self {
             controller,
@@ -0,0 +1,50 @@
+use super::EguiApp;
+use super::style;
+use eframe::egui::{self, Align2, RichText};
+
+impl EguiApp {
+    /// Render the About window with version and build details.
+    pub(super) fn render_about_window(&mut self, ctx: &egui::Context) {
+        if !self.controller.ui.about_open {
+            return;
+        }
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.controller.ui.about_open = false;
+            return;
+        }
+        let info = crate::build_info();
+        let palette = style::palette();
+        let mut open = true;
+        egui::Window::new("About Sempal")
+            .anchor(Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .collapsible(false)
+            .resizable(false)
+            .default_width(420.0)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                egui::Grid::new("about_build_info")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        let rows = [
+                            ("Version", info.version),
+                            ("Git commit", info.short_sha()),
+                            ("Analysis version", info.analysis_version),
+                            ("Embedding model", info.embedding_model_id),
+                        ];
+                        for (label, value) in rows {
+                            ui.label(RichText::new(label).color(palette.text_muted));
+                            ui.label(RichText::new(value).monospace().color(palette.text_primary));
+                            ui.end_row();
+                        }
+                    });
+                ui.add_space(8.0);
+                if ui.button("Copy build info").clicked() {
+                    ui.ctx().copy_text(info.summary_lines().join("\n"));
+                }
+            });
+        if !open {
+            self.controller.ui.about_open = false;
+        }
+    }
+}
@@ -67,9 +67,73 @@ impl EguiApp {
                 .set_analysis_worker_count(workers.max(0) as u32);
         }
 
+        ui.add_space(ui.spacing().item_spacing.y);
+        ui.label(RichText::new("Claim batch size (0 = auto):").color(palette.text_muted));
+        let mut claim_batch = self.controller.analysis_claim_batch() as i64;
+        let drag = egui::DragValue::new(&mut claim_batch).range(0..=1024);
+        let response = helpers::tooltip(
+            ui.add(drag),
+            "Claim Batch Size",
+            "How many analysis jobs each decoder leases from a source database per round. Larger batches mean fewer database transactions and higher throughput; smaller batches reduce lock contention with other writers. Auto uses 64 or SEMPAL_ANALYSIS_CLAIM_BATCH.",
+            tooltip_mode,
+        );
+        if response.changed() {
+            self.controller
+                .set_analysis_claim_batch(claim_batch.max(0) as u32);
+        }
+
+        ui.add_space(ui.spacing().item_spacing.y);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Decode outcomes (current source):").color(palette.text_muted));
+            if ui.small_button("Refresh").clicked() {
+                self.controller.refresh_decode_outcome_counts();
+            }
+        });
+        match self.controller.decode_outcome_counts() {
+            Some(counts) => {
+                ui.label(format!(
+                    "Decoded {} · Skipped {} · Failed {} · Not needed {}",
+                    counts.decoded, counts.skipped, counts.failed, counts.not_needed
+                ));
+            }
+            None => {
+                ui.label(RichText::new("Not loaded").color(palette.text_muted));
+            }
+        }
+
         ui.add_space(ui.spacing().item_spacing.y);
         ui.separator();
         section_label(ui, "Similarity embeddings");
         ui.label(RichText::new("Backend: CPU (DSP)").color(palette.text_muted));
+
+        ui.add_space(ui.spacing().item_spacing.y);
+        ui.separator();
+        section_label(ui, "Classifier");
+        let classifier_label = match self.controller.classifier() {
+            Some(model) => format!("Loaded: {} classes", model.classes.len()),
+            None => "No classifier loaded".to_string(),
+        };
+        ui.label(RichText::new(classifier_label).color(palette.text_muted));
+        ui.horizontal(|ui| {
+            let import = helpers::tooltip(
+                ui.button("Import…"),
+                "Import Classifier",
+                "Load a classifier model JSON file. The file is validated before it replaces the current model.",
+                tooltip_mode,
+            );
+            if import.clicked() {
+                self.controller.import_classifier_via_dialog();
+            }
+            let has_model = self.controller.classifier().is_some();
+            let export = helpers::tooltip(
+                ui.add_enabled(has_model, egui::Button::new("Export…")),
+                "Export Classifier",
+                "Save the loaded classifier model to a JSON file.",
+                tooltip_mode,
+            );
+            if export.clicked() {
+                self.controller.export_classifier_via_dialog();
+            }
+        });
     }
 }
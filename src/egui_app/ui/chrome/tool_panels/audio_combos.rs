@@ -1,5 +1,6 @@
 use eframe::egui;
 
+use crate::audio::ResampleQuality;
 use crate::egui_app::ui::EguiApp;
 
 impl EguiApp {
@@ -215,4 +216,23 @@ impl EguiApp {
                 }
             });
     }
+
+    pub(super) fn render_audio_resample_combo(&mut self, ui: &mut egui::Ui) {
+        let selected = self.controller.ui.audio.selected.resample_quality;
+        egui::ComboBox::from_id_salt("audio_resample_combo")
+            .width(220.0)
+            .selected_text(selected.label())
+            .show_ui(ui, |ui| {
+                for option in [ResampleQuality::Linear, ResampleQuality::Sinc] {
+                    if ui
+                        .selectable_label(selected == option, option.label())
+                        .clicked()
+                    {
+                        self.controller.set_audio_resample_quality(option);
+                    }
+                }
+            })
+            .response
+            .on_hover_text("Used when a sample's rate differs from the device rate");
+    }
 }
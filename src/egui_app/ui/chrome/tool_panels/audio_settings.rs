@@ -1,8 +1,10 @@
 use eframe::egui::{self, RichText, SliderClamping};
 
 use super::section_label;
+use crate::egui_app::state::DestructiveSelectionEdit;
 use crate::egui_app::ui::EguiApp;
 use crate::egui_app::ui::style;
+use crate::sample_sources::config::LoadSelectionMode;
 
 impl EguiApp {
     pub(in crate::egui_app::ui) fn render_audio_settings_window(&mut self, ctx: &egui::Context) {
@@ -51,6 +53,7 @@ impl EguiApp {
                 self.render_audio_device_combo(ui);
                 self.render_audio_sample_rate_combo(ui);
                 self.render_audio_buffer_combo(ui);
+                self.render_audio_resample_combo(ui);
                 if let Some(applied) = &self.controller.ui.audio.applied {
                     let buffer = applied
                         .buffer_size_frames
@@ -75,6 +78,12 @@ impl EguiApp {
                     );
                 }
                 ui.separator();
+                section_label(ui, "Appearance");
+                let mut high_contrast = self.controller.ui.controls.high_contrast_theme;
+                if ui.checkbox(&mut high_contrast, "High-contrast theme").changed() {
+                    self.controller.set_high_contrast_theme(high_contrast);
+                }
+                ui.separator();
                 section_label(ui, "Waveform & Zoom");
                 let mut invert_scroll = self.controller.ui.controls.invert_waveform_scroll;
                 if ui
@@ -110,6 +119,20 @@ impl EguiApp {
                 if ui.add(keyboard_slider).changed() {
                     self.controller.set_keyboard_zoom_factor(keyboard_zoom);
                 }
+                let mut select_full = matches!(
+                    self.controller.ui.controls.load_selection_mode,
+                    LoadSelectionMode::Full
+                );
+                if ui
+                    .checkbox(&mut select_full, "Select whole sample on load")
+                    .changed()
+                {
+                    self.controller.set_load_selection_mode(if select_full {
+                        LoadSelectionMode::Full
+                    } else {
+                        LoadSelectionMode::None
+                    });
+                }
                 ui.add_space(6.0);
                 ui.separator();
                 section_label(ui, "Playback");
@@ -124,6 +147,40 @@ impl EguiApp {
                 if ui.add_enabled(anti_clip_enabled, anti_clip_slider).changed() {
                     self.controller.set_anti_clip_fade_ms(anti_clip_fade_ms);
                 }
+                let mut seek_crossfade = self.controller.ui.controls.seek_crossfade_enabled;
+                if ui.checkbox(&mut seek_crossfade, "Crossfade on seek").changed() {
+                    self.controller.set_seek_crossfade_enabled(seek_crossfade);
+                }
+                let mut seek_crossfade_ms = self.controller.ui.controls.seek_crossfade_ms;
+                let seek_crossfade_slider = egui::Slider::new(&mut seek_crossfade_ms, 1.0..=250.0)
+                    .text("Crossfade length")
+                    .suffix(" ms");
+                if ui.add_enabled(seek_crossfade, seek_crossfade_slider).changed() {
+                    self.controller.set_seek_crossfade_ms(seek_crossfade_ms);
+                }
+                let mut trail_seconds = self.controller.ui.controls.playhead_trail_seconds;
+                let trail_slider = egui::Slider::new(&mut trail_seconds, 0.0..=5.0)
+                    .text("Playhead trail")
+                    .suffix(" s")
+                    .clamping(SliderClamping::Always);
+                if ui
+                    .add(trail_slider)
+                    .on_hover_text("Set to 0 to disable the trail")
+                    .changed()
+                {
+                    self.controller.set_playhead_trail_seconds(trail_seconds);
+                }
+                let mut trail_fade = self.controller.ui.controls.playhead_trail_fade_seconds;
+                let trail_fade_slider = egui::Slider::new(&mut trail_fade, 0.0..=2.0)
+                    .text("Trail fade")
+                    .suffix(" s")
+                    .clamping(SliderClamping::Always);
+                if ui
+                    .add_enabled(trail_seconds > 0.0, trail_fade_slider)
+                    .changed()
+                {
+                    self.controller.set_playhead_trail_fade_seconds(trail_fade);
+                }
                 ui.add_space(6.0);
                 let mut yolo_mode = self.controller.ui.controls.destructive_yolo_mode;
                 let yolo_label = RichText::new(
@@ -139,6 +196,29 @@ impl EguiApp {
                     )
                     .color(style::status_badge_color(style::StatusTone::Warning)),
                 );
+                ui.add_enabled_ui(!yolo_mode, |ui| {
+                    ui.label("Confirm before:");
+                    ui.horizontal_wrapped(|ui| {
+                        let confirmations = self.controller.ui.controls.destructive_confirmations;
+                        for (edit, label) in [
+                            (DestructiveSelectionEdit::CropSelection, "Crop"),
+                            (DestructiveSelectionEdit::TrimSelection, "Trim"),
+                            (DestructiveSelectionEdit::ReverseSelection, "Reverse"),
+                            (DestructiveSelectionEdit::FadeLeftToRight, "Fade"),
+                            (DestructiveSelectionEdit::ShortEdgeFades, "Edge fades"),
+                            (DestructiveSelectionEdit::MuteSelection, "Mute"),
+                            (DestructiveSelectionEdit::NormalizeSelection, "Normalize"),
+                            (DestructiveSelectionEdit::ClickRemoval, "Click removal"),
+                            (DestructiveSelectionEdit::PasteClipboard, "Paste"),
+                        ] {
+                            let mut required = edit.requires_confirmation(&confirmations);
+                            if ui.checkbox(&mut required, label).changed() {
+                                self.controller
+                                    .set_destructive_edit_confirmation(edit, required);
+                            }
+                        }
+                    });
+                });
                 let mut advance_after_rating = self.controller.ui.controls.advance_after_rating;
                 if ui
                     .checkbox(&mut advance_after_rating, "Advance to next sample after rating")
@@ -2,6 +2,7 @@ use eframe::egui::{self, RichText, SliderClamping};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use super::super::EguiApp;
+use crate::sample_sources::config::TrashStrategy;
 use super::super::style;
 use super::buttons;
 
@@ -34,6 +35,20 @@ impl EguiApp {
                 self.controller.open_trash_folder();
                 close_menu = true;
             }
+            let current_strategy = self.controller.trash_strategy();
+            let mut strategy = current_strategy;
+            ui.menu_button("Trash strategy", |ui| {
+                for option in [
+                    TrashStrategy::MoveToFolder,
+                    TrashStrategy::OsTrash,
+                    TrashStrategy::DeletePermanently,
+                ] {
+                    ui.radio_value(&mut strategy, option, option.label());
+                }
+            });
+            if strategy != current_strategy {
+                self.controller.set_trash_strategy(strategy);
+            }
             if ui
                 .add(buttons::action_button("Open config folder"))
                 .clicked()
@@ -53,10 +68,18 @@ impl EguiApp {
             ui.separator();
             self.render_analysis_options_menu(ui);
             ui.separator();
-            if ui
-                .add(buttons::action_button("Move trashed samples to folder"))
-                .clicked()
-            {
+            let dispose_button = match self.controller.trash_strategy() {
+                TrashStrategy::MoveToFolder => {
+                    buttons::action_button("Move trashed samples to folder")
+                }
+                TrashStrategy::OsTrash => {
+                    buttons::action_button("Send trashed samples to system trash")
+                }
+                TrashStrategy::DeletePermanently => {
+                    buttons::destructive_button("Delete trashed samples")
+                }
+            };
+            if ui.add(dispose_button).clicked() {
                 self.controller.move_all_trashed_to_folder();
                 close_menu = true;
             }
@@ -72,6 +95,21 @@ impl EguiApp {
             }
         });
         ui.add_space(10.0);
+        if let Some(label) = self.controller.status_undo_label().map(str::to_string) {
+            if ui
+                .add(buttons::action_button("Undo"))
+                .on_hover_text(format!("Undo {label}"))
+                .clicked()
+            {
+                self.controller.undo_status_action();
+            }
+            ui.add_space(10.0);
+        }
+        if self.controller.library_read_only() {
+            ui.label(RichText::new("Read-only library").color(style::warning_soft_text()))
+                .on_hover_text("The library database is not writable; source edits are disabled.");
+            ui.add_space(10.0);
+        }
         const APP_VERSION: &str = concat!("v", env!("CARGO_PKG_VERSION"));
         match self.controller.ui.update.status {
             crate::egui_app::state::UpdateStatus::Checking => {
@@ -135,6 +173,7 @@ impl EguiApp {
         }
         if self.controller.ui.progress.visible {
             ui.add_space(10.0);
+            let throughput = self.controller.analysis_throughput();
             let progress = &self.controller.ui.progress;
             let fraction = progress.fraction();
             let mut bar = egui::ProgressBar::new(fraction)
@@ -159,6 +198,9 @@ impl EguiApp {
                 if let Some(detail) = progress.detail.as_deref() {
                     ui.label(detail);
                 }
+                if let Some(eta) = progress.eta_label() {
+                    ui.label(eta);
+                }
                 if let Some(snapshot) = progress.analysis.as_ref() {
                     ui.add_space(4.0);
                     let jobs_fraction = progress.fraction();
@@ -171,6 +213,9 @@ impl EguiApp {
                         "Queue: {} pending • {} running • {} failed",
                         snapshot.pending, snapshot.running, snapshot.failed
                     ));
+                    if throughput.samples_completed > 0 {
+                        ui.label(throughput.summary());
+                    }
                     ui.add(
                         egui::ProgressBar::new(jobs_fraction)
                             .desired_width(180.0)
@@ -298,6 +298,19 @@ impl EguiApp {
                 .lock_focus(true),
         );
 
+        ui.add_space(6.0);
+        let gpu_summary = state
+            .gpu_info
+            .as_ref()
+            .map(crate::egui_app::controller::gpu_summary_line);
+        ui.add_enabled_ui(!submitting && gpu_summary.is_some(), |ui| {
+            let checkbox = ui.checkbox(&mut state.include_gpu_info, "Include GPU info");
+            match gpu_summary.as_deref() {
+                Some(summary) => checkbox.on_hover_text(summary),
+                None => checkbox.on_disabled_hover_text("GPU details unavailable"),
+            };
+        });
+
         ui.add_space(10.0);
         let mut action = FeedbackSubmitAction::None;
         ui.horizontal(|ui| {
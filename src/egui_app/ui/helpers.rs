@@ -249,6 +249,12 @@ const LONG_BADGE_GAP: f32 = 6.0;
 const BPM_BADGE_PADDING_X: f32 = 6.0;
 const BPM_BADGE_PADDING_Y: f32 = 2.0;
 const BPM_BADGE_GAP: f32 = 6.0;
+const CLASS_CHIP_PADDING_X: f32 = 5.0;
+const CLASS_CHIP_PADDING_Y: f32 = 1.0;
+const CLASS_CHIP_GAP: f32 = 6.0;
+const COVERAGE_BADGE_PADDING_X: f32 = 5.0;
+const COVERAGE_BADGE_PADDING_Y: f32 = 1.0;
+const COVERAGE_BADGE_GAP: f32 = 6.0;
 
 /// Return the horizontal space needed for the loop badge, including the gap.
 pub(super) fn loop_badge_space(ui: &Ui) -> f32 {
@@ -287,6 +293,30 @@ pub(super) fn bpm_badge_space(ui: &Ui, label: &str) -> f32 {
     BPM_BADGE_GAP + text_width + BPM_BADGE_PADDING_X * 2.0
 }
 
+pub(super) fn class_chip_space(ui: &Ui, label: &str) -> f32 {
+    let font_id = TextStyle::Small.resolve(ui.style());
+    let text_width = ui
+        .ctx()
+        .fonts_mut(|fonts| {
+            fonts.layout_no_wrap(label.to_string(), font_id, style::class_chip_text())
+        })
+        .size()
+        .x;
+    CLASS_CHIP_GAP + text_width + CLASS_CHIP_PADDING_X * 2.0
+}
+
+pub(super) fn coverage_badge_space(ui: &Ui, label: &str) -> f32 {
+    let font_id = TextStyle::Small.resolve(ui.style());
+    let text_width = ui
+        .ctx()
+        .fonts_mut(|fonts| {
+            fonts.layout_no_wrap(label.to_string(), font_id, style::coverage_badge_text())
+        })
+        .size()
+        .x;
+    COVERAGE_BADGE_GAP + text_width + COVERAGE_BADGE_PADDING_X * 2.0
+}
+
 pub(super) struct ListRow<'a> {
     pub label: &'a str,
     pub row_width: f32,
@@ -301,6 +331,8 @@ pub(super) struct ListRow<'a> {
     pub looped: bool,
     pub long_sample: bool,
     pub bpm_label: Option<&'a str>,
+    pub class_label: Option<&'a str>,
+    pub coverage_label: Option<&'a str>,
 }
 
 pub(super) fn render_list_row(ui: &mut Ui, row: ListRow<'_>) -> egui::Response {
@@ -481,6 +513,64 @@ pub(super) fn render_list_row(ui: &mut Ui, row: ListRow<'_>) -> egui::Response {
         );
         trailing_x = badge_rect.right();
     }
+    if let Some(label) = row.class_label {
+        let chip_font = TextStyle::Small.resolve(ui.style());
+        let chip_galley = ui.ctx().fonts_mut(|fonts| {
+            fonts.layout_no_wrap(label.to_string(), chip_font.clone(), style::class_chip_text())
+        });
+        let chip_min = egui::pos2(
+            trailing_x + CLASS_CHIP_GAP,
+            rect.center().y - chip_galley.size().y * 0.5 - CLASS_CHIP_PADDING_Y,
+        );
+        let chip_rect = egui::Rect::from_min_size(
+            chip_min,
+            egui::vec2(
+                chip_galley.size().x + CLASS_CHIP_PADDING_X * 2.0,
+                chip_galley.size().y + CLASS_CHIP_PADDING_Y * 2.0,
+            ),
+        );
+        ui.painter()
+            .rect_filled(chip_rect, chip_rect.height() * 0.5, style::class_chip_fill());
+        ui.painter().text(
+            chip_rect.center(),
+            Align2::CENTER_CENTER,
+            label,
+            chip_font,
+            style::class_chip_text(),
+        );
+        trailing_x = chip_rect.right();
+    }
+    if let Some(label) = row.coverage_label {
+        let badge_font = TextStyle::Small.resolve(ui.style());
+        let badge_galley = ui.ctx().fonts_mut(|fonts| {
+            fonts.layout_no_wrap(
+                label.to_string(),
+                badge_font.clone(),
+                style::coverage_badge_text(),
+            )
+        });
+        let badge_min = egui::pos2(
+            trailing_x + COVERAGE_BADGE_GAP,
+            rect.center().y - badge_galley.size().y * 0.5 - COVERAGE_BADGE_PADDING_Y,
+        );
+        let badge_rect = egui::Rect::from_min_size(
+            badge_min,
+            egui::vec2(
+                badge_galley.size().x + COVERAGE_BADGE_PADDING_X * 2.0,
+                badge_galley.size().y + COVERAGE_BADGE_PADDING_Y * 2.0,
+            ),
+        );
+        ui.painter()
+            .rect_filled(badge_rect, 0.0, style::coverage_badge_fill());
+        ui.painter().text(
+            badge_rect.center(),
+            Align2::CENTER_CENTER,
+            label,
+            badge_font,
+            style::coverage_badge_text(),
+        );
+        trailing_x = badge_rect.right();
+    }
     if let Some(rating) = row.rating {
         if !rating.is_neutral() {
             let count = rating.val().abs();
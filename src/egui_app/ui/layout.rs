@@ -10,9 +10,15 @@ use eframe::egui::{TopBottomPanel, Ui, UiBuilder};
 
 impl EguiApp {
     pub(super) fn apply_visuals(&mut self, ctx: &egui::Context) {
-        if self.visuals_set {
+        let theme = if self.controller.ui.controls.high_contrast_theme {
+            style::Theme::HighContrast
+        } else {
+            style::Theme::Standard
+        };
+        if self.visuals_set && style::theme() == theme {
             return;
         }
+        style::set_theme(theme);
         let mut visuals = egui::Visuals::dark();
         style::apply_visuals(&mut visuals);
         ctx.set_visuals(visuals);
@@ -143,9 +149,13 @@ impl EguiApp {
                                 self.controller.ui.update.status,
                                 crate::egui_app::state::UpdateStatus::UpdateAvailable
                             ) {
-                                ui.label(
+                                let version_btn = egui::Button::new(
                                     egui::RichText::new(APP_VERSION).color(palette.text_muted),
-                                );
+                                )
+                                .frame(false);
+                                if ui.add(version_btn).on_hover_text("About Sempal").clicked() {
+                                    self.controller.ui.about_open = true;
+                                }
                                 ui.add_space(8.0);
                                 if ui
                                     .add(crate::egui_app::ui::chrome::buttons::action_button(
@@ -257,6 +267,7 @@ impl EguiApp {
         progress_overlay::render_progress_overlay(ctx, &mut self.controller.ui.progress);
         self.render_feedback_issue_prompt(ctx);
         self.render_loop_crossfade_prompt(ctx);
+        self.render_about_window(ctx);
         self.render_map_window(ctx);
         if hotkey_overlay_visible && !self.modal_overlay_blocks_overlays() {
             if input.escape {
@@ -10,6 +10,8 @@ impl EguiApp {
         let mut open = true;
         let mut apply = false;
         let mut close_prompt = false;
+        let mut toggle_preview = false;
+        let previewing = self.controller.loop_crossfade_preview_active();
         let Some(prompt) = self.controller.ui.loop_crossfade_prompt.as_mut() else {
             return;
         };
@@ -26,8 +28,18 @@ impl EguiApp {
             .auto_sized()
             .open(&mut open)
             .show(ctx, |ui| {
-                render_loop_crossfade_body(ui, prompt, &mut apply, &mut close_prompt);
+                render_loop_crossfade_body(
+                    ui,
+                    prompt,
+                    previewing,
+                    &mut apply,
+                    &mut close_prompt,
+                    &mut toggle_preview,
+                );
             });
+        let settings_changed = previewing
+            && self.controller.ui.loop_crossfade_prompt.as_ref().map(|p| &p.settings)
+                != self.controller.loop_crossfade_preview_settings();
         if apply {
             if let Err(err) = self.controller.apply_loop_crossfade_prompt() {
                 self.controller.set_status(err, style::StatusTone::Error);
@@ -36,6 +48,14 @@ impl EguiApp {
         }
         if close_prompt || !open {
             self.controller.clear_loop_crossfade_prompt();
+            return;
+        }
+        if toggle_preview && previewing {
+            self.controller.stop_loop_crossfade_preview();
+        } else if toggle_preview || settings_changed {
+            if let Err(err) = self.controller.preview_loop_crossfade_prompt() {
+                self.controller.set_status(err, style::StatusTone::Error);
+            }
         }
     }
 }
@@ -43,8 +63,10 @@ impl EguiApp {
 fn render_loop_crossfade_body(
     ui: &mut egui::Ui,
     prompt: &mut crate::egui_app::state::LoopCrossfadePrompt,
+    previewing: bool,
     apply: &mut bool,
     close_prompt: &mut bool,
+    toggle_preview: &mut bool,
 ) {
     let palette = style::palette();
     ui.set_min_width(320.0);
@@ -97,6 +119,14 @@ fn render_loop_crossfade_body(
         if ui.button("Cancel").clicked() {
             *close_prompt = true;
         }
+        let preview_label = if previewing { "Stop preview" } else { "Preview" };
+        if ui
+            .button(preview_label)
+            .on_hover_text("Loop the crossfaded audio without writing a file")
+            .clicked()
+        {
+            *toggle_preview = true;
+        }
         let apply_btn = egui::Button::new(RichText::new("Apply").color(palette.text_primary));
         if ui.add(apply_btn).clicked() {
             *apply = true;
@@ -6,6 +6,7 @@ pub(crate) fn render_empty_state(
     rect: egui::Rect,
     palette: &style::Palette,
     busy: bool,
+    progress: Option<(usize, usize)>,
 ) -> bool {
     let mut build_clicked = false;
     let pulse = if busy {
@@ -37,9 +38,19 @@ pub(crate) fn render_empty_state(
                     RichText::new("This can take a minute for new sources.")
                         .color(palette.text_muted),
                 );
-                let center = rect.center();
-                ui.painter()
-                    .circle_filled(center + egui::vec2(0.0, 50.0), 8.0, pulse_color);
+                if let Some((completed, total)) = progress {
+                    ui.add_space(8.0);
+                    let fraction = completed as f32 / total.max(1) as f32;
+                    ui.add(
+                        egui::ProgressBar::new(fraction)
+                            .desired_width((rect.width() * 0.5).clamp(160.0, 360.0))
+                            .text(format!("Embeddings {completed}/{total}")),
+                    );
+                } else {
+                    let center = rect.center();
+                    ui.painter()
+                        .circle_filled(center + egui::vec2(0.0, 50.0), 8.0, pulse_color);
+                }
             } else {
                 ui.label(
                     RichText::new("No map layout yet.")
@@ -1,6 +1,9 @@
 use super::map_view::map_render;
 use eframe::egui;
 
+/// Screen-space radius within which the cursor snaps to a map point.
+pub(crate) const HOVER_HIT_RADIUS_PX: f32 = 6.0;
+
 pub(crate) fn find_hover_point(
     points: &[crate::egui_app::state::MapPoint],
     rect: egui::Rect,
@@ -17,7 +20,7 @@ pub(crate) fn find_hover_point(
     for point in points {
         let pos = map_render::map_to_screen(point.x, point.y, rect, center, scale, pan);
         let dist_sq = pos.distance_sq(pointer);
-        if dist_sq > 36.0 {
+        if dist_sq > HOVER_HIT_RADIUS_PX * HOVER_HIT_RADIUS_PX {
             continue;
         }
         match best {
@@ -29,3 +32,51 @@ pub(crate) fn find_hover_point(
     }
     best.map(|(point, pos, _)| (point, pos))
 }
+
+pub(crate) fn points_in_screen_rect(
+    points: &[crate::egui_app::state::MapPoint],
+    rect: egui::Rect,
+    center: egui::Pos2,
+    scale: f32,
+    pan: egui::Vec2,
+    selection: egui::Rect,
+) -> Vec<String> {
+    points
+        .iter()
+        .filter(|point| {
+            let pos = map_render::map_to_screen(point.x, point.y, rect, center, scale, pan);
+            rect.contains(pos) && selection.contains(pos)
+        })
+        .map(|point| point.sample_id.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::egui_app::state::MapPoint;
+
+    fn point(sample_id: &str, x: f32, y: f32) -> MapPoint {
+        MapPoint {
+            sample_id: sample_id.to_string(),
+            x,
+            y,
+            cluster_id: None,
+        }
+    }
+
+    #[test]
+    fn hover_resolves_nearest_point_within_radius_only() {
+        let rect = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(200.0, 200.0));
+        let center = egui::Pos2::ZERO;
+        let points = [point("far", 4.0, 0.0), point("near", 1.0, 0.0)];
+        let origin = map_render::map_to_screen(0.0, 0.0, rect, center, 1.0, egui::Vec2::ZERO);
+
+        let hit = find_hover_point(&points, rect, center, 1.0, egui::Vec2::ZERO, Some(origin));
+        assert_eq!(hit.map(|(point, _)| point.sample_id), Some("near".to_string()));
+
+        let beyond = origin + egui::vec2(0.0, HOVER_HIT_RADIUS_PX + 1.0);
+        let miss = find_hover_point(&points, rect, center, 1.0, egui::Vec2::ZERO, Some(beyond));
+        assert!(miss.is_none());
+    }
+}
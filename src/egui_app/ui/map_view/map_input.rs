@@ -2,6 +2,10 @@ use super::EguiApp;
 use super::map_interactions;
 use super::style;
 use eframe::egui::{self};
+use std::time::{Duration, Instant};
+
+/// How long the cursor must rest on a point before hover-play starts it.
+const HOVER_PLAY_DEBOUNCE: Duration = Duration::from_millis(120);
 
 pub(super) fn handle_zoom(app: &mut EguiApp, ui: &egui::Ui, response: &egui::Response) {
     let scroll_delta = ui.input(|i| i.smooth_scroll_delta.y);
@@ -107,6 +111,51 @@ pub(super) fn resolve_hover(
     hovered
 }
 
+/// While Alt is held, play whichever sample the cursor rests on, debounced per point.
+pub(super) fn handle_hover_play(
+    app: &mut EguiApp,
+    ui: &egui::Ui,
+    hovered: Option<&(crate::egui_app::state::MapPoint, egui::Pos2)>,
+) {
+    let held = ui.input(|i| i.modifiers.alt);
+    let map = &mut app.controller.ui.map;
+    if !held {
+        map.hover_play_pending = None;
+        map.hover_play_last_id = None;
+        return;
+    }
+    let Some((point, _)) = hovered else {
+        map.hover_play_pending = None;
+        return;
+    };
+    if map.hover_play_last_id.as_deref() == Some(point.sample_id.as_str()) {
+        return;
+    }
+    let now = Instant::now();
+    let since = match &map.hover_play_pending {
+        Some((id, since)) if id == &point.sample_id => *since,
+        _ => {
+            map.hover_play_pending = Some((point.sample_id.clone(), now));
+            ui.ctx().request_repaint_after(HOVER_PLAY_DEBOUNCE);
+            return;
+        }
+    };
+    let waited = now.saturating_duration_since(since);
+    if waited < HOVER_PLAY_DEBOUNCE {
+        ui.ctx().request_repaint_after(HOVER_PLAY_DEBOUNCE - waited);
+        return;
+    }
+    map.hover_play_pending = None;
+    map.hover_play_last_id = Some(point.sample_id.clone());
+    if let Err(err) = app.controller.preview_sample_by_id(&point.sample_id) {
+        app.controller
+            .set_status(format!("Preview failed: {err}"), style::StatusTone::Error);
+    } else if let Err(err) = app.controller.play_audio(false, None) {
+        app.controller
+            .set_status(format!("Playback failed: {err}"), style::StatusTone::Error);
+    }
+}
+
 pub(super) fn handle_paint_hover(
     app: &mut EguiApp,
     ui: &egui::Ui,
@@ -155,3 +204,93 @@ pub(super) fn handle_click(
         }
     }
 }
+
+pub(super) fn toggle_multi_selection(
+    app: &mut EguiApp,
+    hovered: Option<&(crate::egui_app::state::MapPoint, egui::Pos2)>,
+) {
+    let Some((point, _)) = hovered else {
+        return;
+    };
+    let selection = &mut app.controller.ui.map.selected_sample_ids;
+    if let Some(index) = selection.iter().position(|id| id == &point.sample_id) {
+        selection.remove(index);
+    } else {
+        selection.push(point.sample_id.clone());
+    }
+}
+
+/// Track a Shift-drag lasso and replace the multi-selection with the points inside it.
+pub(super) fn handle_lasso(
+    app: &mut EguiApp,
+    painter: &egui::Painter,
+    response: &egui::Response,
+    pointer: Option<egui::Pos2>,
+    rect: egui::Rect,
+    center: egui::Pos2,
+    scale: f32,
+) -> bool {
+    let Some(start) = app.controller.ui.map.lasso_start else {
+        return false;
+    };
+    let Some(current) = pointer.or_else(|| response.hover_pos()) else {
+        app.controller.ui.map.lasso_start = None;
+        return false;
+    };
+    let lasso = egui::Rect::from_two_pos(start, current);
+    if response.drag_stopped() {
+        app.controller.ui.map.lasso_start = None;
+        app.controller.ui.map.selected_sample_ids = map_interactions::points_in_screen_rect(
+            &app.controller.ui.map.cached_filtered_points,
+            rect,
+            center,
+            scale,
+            app.controller.ui.map.pan,
+            lasso,
+        );
+        return true;
+    }
+    let accent = style::palette().accent_copper;
+    painter.rect_filled(lasso, 0.0, style::with_alpha(accent, 30));
+    painter.rect_stroke(
+        lasso,
+        0.0,
+        egui::Stroke::new(1.0, accent),
+        egui::StrokeKind::Inside,
+    );
+    true
+}
+
+pub(super) fn render_selection_context_menu(app: &mut EguiApp, response: &egui::Response) {
+    let count = app.controller.ui.map.selected_sample_ids.len();
+    if count == 0 {
+        return;
+    }
+    response.context_menu(|ui| {
+        ui.label(format!("{count} selected"));
+        let actions = [
+            ("Tag Keep", crate::sample_sources::Rating::KEEP_1),
+            ("Tag Neutral", crate::sample_sources::Rating::NEUTRAL),
+            ("Tag Trash", crate::sample_sources::Rating::TRASH_3),
+        ];
+        for (label, tag) in actions {
+            if ui.button(label).clicked() {
+                let _ = app.controller.tag_map_selection(tag);
+                ui.close();
+            }
+        }
+        ui.separator();
+        if ui.button("Find similar to selection").clicked() {
+            let sample_ids = app.controller.ui.map.selected_sample_ids.clone();
+            if let Err(err) = app.controller.find_similar_for_sample_ids(&sample_ids) {
+                app.controller
+                    .set_status(format!("Find similar failed: {err}"), style::StatusTone::Warning);
+            }
+            ui.close();
+        }
+        if ui.button("Clear selection").clicked() {
+            app.controller.ui.map.selected_sample_ids.clear();
+            ui.close();
+        }
+    });
+}
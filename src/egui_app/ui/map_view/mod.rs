@@ -28,16 +28,31 @@ impl EguiApp {
     }
 
     pub(super) fn render_map_window(&mut self, ctx: &egui::Context) {
-        if !self.controller.ui.map.open {
+        if !self.controller.ui.map.open || self.controller.map_disabled() {
             return;
         }
-        egui::Window::new("Sample Map")
+        let mut open = true;
+        let default_size = self.controller.ui.map.window_size.unwrap_or([640.0, 420.0]);
+        let response = egui::Window::new("Sample Map")
+            .open(&mut open)
             .collapsible(false)
             .resizable(true)
-            .default_size([640.0, 420.0])
+            .default_size(default_size)
             .show(ctx, |ui| {
+                let content_size = ui.available_size();
                 self.render_map_panel(ui);
+                content_size
             });
+        if !open {
+            self.controller.close_map();
+            return;
+        }
+        let resizing = ctx.input(|i| i.pointer.any_down());
+        if let Some(size) = response.and_then(|response| response.inner)
+            && !resizing
+        {
+            self.controller.set_map_window_size([size.x, size.y]);
+        }
     }
 
     fn render_map_canvas(&mut self, ui: &mut egui::Ui) {
@@ -62,7 +77,8 @@ impl EguiApp {
                 self.controller.prepare_similarity_for_selected_source();
             }
             let busy = prep_active || self.controller.ui.progress.visible;
-            if map_empty::render_empty_state(ui, rect, &palette, busy) {
+            let progress = self.controller.similarity_prep_embedding_progress();
+            if map_empty::render_empty_state(ui, rect, &palette, busy, progress) {
                 self.controller.prepare_similarity_for_selected_source();
             }
             return;
@@ -209,11 +225,18 @@ impl EguiApp {
             center,
             scale,
             self.controller.ui.map.pan,
-            pointer,
+            pointer.or_else(|| response.hover_pos()),
         );
-        if response.dragged_by(egui::PointerButton::Primary) {
+        let modifiers = ui.input(|i| i.modifiers);
+        if response.drag_started_by(egui::PointerButton::Primary) && modifiers.shift {
+            self.controller.ui.map.lasso_start = pointer;
+        }
+        let lasso_active =
+            map_input::handle_lasso(self, &painter, &response, pointer, rect, center, scale);
+        if !lasso_active && response.dragged_by(egui::PointerButton::Primary) {
             map_input::handle_paint_hover(self, ui, hovered.as_ref());
         }
+        map_input::handle_hover_play(self, ui, hovered.as_ref());
 
         if let Some((point, pos)) = hovered.as_ref() {
             let stroke_color = point_color(point, 200);
@@ -221,8 +244,13 @@ impl EguiApp {
         }
 
         if response.clicked() {
-            map_input::handle_click(self, hovered.as_ref());
+            if modifiers.command {
+                map_input::toggle_multi_selection(self, hovered.as_ref());
+            } else {
+                map_input::handle_click(self, hovered.as_ref());
+            }
         }
+        map_input::render_selection_context_menu(self, &response);
         let (draw_calls, points_rendered, render_mode) = map_render::render_points(
             &painter,
             rect,
@@ -254,6 +282,33 @@ impl EguiApp {
                     )
                 })
         });
+        if !self.controller.ui.map.selected_sample_ids.is_empty() {
+            let selected: std::collections::HashSet<&str> = self
+                .controller
+                .ui
+                .map
+                .selected_sample_ids
+                .iter()
+                .map(String::as_str)
+                .collect();
+            let stroke = egui::Stroke::new(1.5, palette.accent_copper);
+            for point in &self.controller.ui.map.cached_filtered_points {
+                if !selected.contains(point.sample_id.as_str()) {
+                    continue;
+                }
+                let pos = map_render::map_to_screen(
+                    point.x,
+                    point.y,
+                    rect,
+                    center,
+                    scale,
+                    self.controller.ui.map.pan,
+                );
+                if rect.contains(pos) {
+                    painter.circle_stroke(pos, 5.0, stroke);
+                }
+            }
+        }
         if let Some(pos) = focused_pos {
             if rect.contains(pos) {
                 painter.circle_stroke(pos, 6.0, style::focused_row_stroke());
@@ -61,3 +61,25 @@ pub(super) fn cursor_pos_in_client_points(hwnd: HWND, pixels_per_point: f32) ->
         ))
     }
 }
+
+/// Read renderer details from the active OpenGL context, if any.
+pub(super) fn gpu_adapter_info(
+    frame: &eframe::Frame,
+) -> Option<crate::egui_app::state::GpuAdapterInfo> {
+    use eframe::glow::{self, HasContext};
+    let gl = frame.gl()?;
+    // SAFETY: the context is current on the UI thread while `update` runs.
+    let (name, vendor, driver) = unsafe {
+        (
+            gl.get_parameter_string(glow::RENDERER),
+            gl.get_parameter_string(glow::VENDOR),
+            gl.get_parameter_string(glow::VERSION),
+        )
+    };
+    Some(crate::egui_app::state::GpuAdapterInfo {
+        name,
+        vendor,
+        backend: "OpenGL".to_string(),
+        driver,
+    })
+}
@@ -57,6 +57,9 @@ pub(super) fn render_progress_overlay(ctx: &egui::Context, progress: &mut Progre
                             progress.total
                         ));
                     }
+                    if let Some(eta) = progress.eta_label() {
+                        ui.label(RichText::new(eta).color(palette.text_muted));
+                    }
                     ui.add_space(6.0);
                     if progress.cancelable {
                         let canceling = progress.cancel_requested;
@@ -11,7 +11,8 @@ impl EguiApp {
         let tooltip_mode = self.controller.ui.controls.tooltip_mode;
         let visible_count = self.controller.visible_browser_len();
         ui.horizontal(|ui| {
-            let rating_filter_active = !self.controller.ui.browser.rating_filter.is_empty();
+            let rating_filter_active = !self.controller.ui.browser.rating_filter.is_empty()
+                || self.controller.ui.browser.silent_only;
             let clear_color = if rating_filter_active {
                 palette.text_primary
             } else {
@@ -22,7 +23,7 @@ impl EguiApp {
             let clear_response = helpers::tooltip(
                 clear_response,
                 "Clear filters",
-                "Clear rating filters.",
+                "Clear rating and silent filters.",
                 tooltip_mode,
             );
             if clear_response.clicked() {
@@ -30,6 +31,7 @@ impl EguiApp {
                     || self.controller.ui.browser.filter != TriageFlagFilter::All;
                 if needs_clear {
                     self.controller.ui.browser.rating_filter.clear();
+                    self.controller.ui.browser.silent_only = false;
                     self.controller.ui.browser.filter = TriageFlagFilter::All;
                     self.controller.rebuild_browser_lists();
                 }
@@ -87,6 +89,29 @@ impl EguiApp {
                 }
             }
             ui.add_space(ui.spacing().item_spacing.x);
+            let mut silent_only = self.controller.ui.browser.silent_only;
+            if ui
+                .checkbox(&mut silent_only, "Silent")
+                .on_hover_text("Only show samples that analysis found to be silent")
+                .changed()
+            {
+                self.controller.set_browser_silent_only(silent_only);
+            }
+            if silent_only && visible_count > 0 {
+                let trash_response = ui.button(
+                    RichText::new("Trash silent").color(style::destructive_text()),
+                );
+                let trash_response = helpers::tooltip(
+                    trash_response,
+                    "Trash silent",
+                    "Tag every sample flagged as silent in this source as trash.",
+                    tooltip_mode,
+                );
+                if trash_response.clicked() {
+                    self.controller.trash_silent_samples();
+                }
+            }
+            ui.add_space(ui.spacing().item_spacing.x);
             let mut query = self.controller.ui.browser.search_query.clone();
             let search_hint = format!(
                 "Search samples ({})...",
@@ -127,6 +152,8 @@ impl EguiApp {
                         .set_status(format!("Find similar failed: {err}"), style::StatusTone::Error);
                 }
             }
+            ui.checkbox(&mut self.controller.ui.browser.similar_hide_seen, "Hide seen")
+                .on_hover_text("Skip samples already auditioned this session in find-similar results");
             ui.add_space(ui.spacing().item_spacing.x);
             if let Some(similar) = self.controller.ui.browser.similar_query.as_ref() {
                 ui.label(
@@ -168,6 +195,7 @@ impl EguiApp {
                 SampleBrowserSort::Similarity => "Similarity",
                 SampleBrowserSort::PlaybackAgeAsc => "Playback age (oldest)",
                 SampleBrowserSort::PlaybackAgeDesc => "Playback age (recent)",
+                SampleBrowserSort::RatingDesc => "Rating (highest)",
             };
             let mut sort = current_sort;
             egui::ComboBox::from_id_salt("browser_sort")
@@ -184,6 +212,11 @@ impl EguiApp {
                         SampleBrowserSort::PlaybackAgeDesc,
                         "Playback age (recent)",
                     );
+                    ui.selectable_value(
+                        &mut sort,
+                        SampleBrowserSort::RatingDesc,
+                        "Rating (highest)",
+                    );
                 });
             if sort != current_sort {
                 self.controller.set_browser_sort(sort);
@@ -210,6 +243,34 @@ impl EguiApp {
                     self.controller.play_random_visible_sample();
                 }
             }
+            let shuffle_seed = self.controller.browser_shuffle_seed();
+            let shuffle_label = RichText::new("🔀").color(if shuffle_seed.is_some() {
+                palette.accent_ice
+            } else {
+                palette.text_muted
+            });
+            let shuffle_response = helpers::tooltip(
+                ui.add(egui::Button::new(shuffle_label).selected(shuffle_seed.is_some())),
+                "Seeded shuffle",
+                "Shuffle the visible list in a fixed order for the chosen seed. The same seed always gives the same order, and the order survives filtering and rescans.\n\nClick again to return to the sorted list.",
+                tooltip_mode,
+            );
+            if shuffle_response.clicked() {
+                match shuffle_seed {
+                    Some(_) => self.controller.clear_browser_shuffle(),
+                    None => self.controller.shuffle_visible_with_seed(1),
+                }
+            }
+            if let Some(mut seed) = shuffle_seed {
+                let seed_response = ui.add(
+                    egui::DragValue::new(&mut seed)
+                        .speed(1.0)
+                        .prefix("seed "),
+                );
+                if seed_response.changed() {
+                    self.controller.shuffle_visible_with_seed(seed);
+                }
+            }
 
             let count_label = format!(
                 "{} item{}",
@@ -1,4 +1,5 @@
 use super::*;
+use crate::egui_app::controller::LevelMatchMode;
 use crate::egui_app::state::{DragPayload, DragSample, DragSource, DragTarget};
 use crate::egui_app::ui::style::StatusTone;
 use crate::egui_app::view_model;
@@ -139,6 +140,10 @@ impl EguiApp {
                 self.controller.reveal_browser_sample_in_file_explorer(path);
                 close_menu = true;
             }
+            if ui.button("Copy path").clicked() {
+                self.controller.copy_sample_paths_as_text();
+                close_menu = true;
+            }
             if ui.button("Find similar").clicked() {
                 if let Err(err) = self.controller.find_similar_for_visible_row(row) {
                     self.controller
@@ -157,6 +162,19 @@ impl EguiApp {
                     ui.close();
                 }
             }
+            if ui
+                .button("Find acoustically unique")
+                .on_hover_text("List the samples in this source that sound least like any other")
+                .clicked()
+            {
+                if let Err(err) = self.controller.find_outliers_for_selected_source() {
+                    self.controller
+                        .set_status(format!("Find outliers failed: {err}"), StatusTone::Error);
+                } else {
+                    close_menu = true;
+                    ui.close();
+                }
+            }
             if ui.button("Recalculate similarity").clicked() {
                 if let Err(err) = self
                     .controller
@@ -169,6 +187,32 @@ impl EguiApp {
                     ui.close();
                 }
             }
+            if ui
+                .button("Analyze full length")
+                .on_hover_text("Re-analyze ignoring the maximum analysis duration")
+                .clicked()
+            {
+                if let Err(err) = self
+                    .controller
+                    .analyze_browser_rows_full_length(&action_rows)
+                {
+                    self.controller
+                        .set_status(format!("Full analysis failed: {err}"), StatusTone::Error);
+                } else {
+                    close_menu = true;
+                    ui.close();
+                }
+            }
+            if ui
+                .button("Export features to CSV…")
+                .on_hover_text("Write the analysis feature vectors of the selected samples to a CSV file")
+                .clicked()
+            {
+                self.controller
+                    .export_browser_rows_features_csv_via_dialog(&action_rows);
+                close_menu = true;
+                ui.close();
+            }
             ui.separator();
             self.sample_tag_menu(ui, &mut close_menu, |app, tag| {
                 app.controller
@@ -220,6 +264,29 @@ impl EguiApp {
                     close_menu = true;
                 }
             }
+            ui.horizontal(|ui| {
+                ui.label("Rating");
+                let mut choice = None;
+                for stars in 1..=crate::sample_sources::MAX_STARS {
+                    if ui.small_button("★".repeat(stars as usize)).clicked() {
+                        choice = Some(Some(stars));
+                    }
+                }
+                if ui.small_button("Clear").clicked() {
+                    choice = Some(None);
+                }
+                if let Some(stars) = choice {
+                    if let Err(err) =
+                        self.controller
+                            .set_stars_browser_samples(&action_rows, stars, row)
+                    {
+                        self.controller
+                            .set_status(format!("Rating failed: {err}"), StatusTone::Error);
+                    } else {
+                        close_menu = true;
+                    }
+                }
+            });
             ui.separator();
             let bpm_id = ui.make_persistent_id(format!("bpm:triage:{}", path.display()));
             let default_bpm = self.controller.ui.waveform.bpm_value;
@@ -241,6 +308,28 @@ impl EguiApp {
             {
                 close_menu = true;
             }
+            if action_rows.len() > 1 {
+                ui.menu_button("Match levels (overwrite)", |ui| {
+                    let modes = [
+                        (LevelMatchMode::Peak, "Peak", "Scale each wav so its peak hits full range"),
+                        (
+                            LevelMatchMode::Loudness,
+                            "Loudness",
+                            "Turn each wav down to the loudness of the quietest one",
+                        ),
+                    ];
+                    for (mode, label, hint) in modes {
+                        if ui.button(label).on_hover_text(hint).clicked()
+                            && self
+                                .controller
+                                .match_browser_sample_levels(&action_rows, mode)
+                                .is_ok()
+                        {
+                            close_menu = true;
+                        }
+                    }
+                });
+            }
             let crossfade_btn = ui
                 .button("Apply Seamless Loop Crossfade")
                 .on_hover_text("Alt-click to customize the crossfade depth");
@@ -3,7 +3,7 @@
 use super::flat_items_list::FlatItemsListMetrics;
 use super::helpers::{
     NumberColumn, RowBackground, RowMarker, bpm_badge_space, clamp_label_for_width,
-    format_bpm_input, long_badge_space, loop_badge_space, render_list_row,
+    class_chip_space, format_bpm_input, long_badge_space, loop_badge_space, render_list_row,
 };
 use super::status_badges;
 use super::style;
@@ -66,6 +66,12 @@ pub(super) fn render_sample_browser_row(
         .controller
         .bpm_value_for_path(&path)
         .map(|bpm| format!("{} BPM", format_bpm_input(bpm)));
+    let class_label = app
+        .controller
+        .class_prediction_for_path(&path)
+        .map(|prediction| {
+            format!("{} {:.0}%", prediction.label, prediction.confidence * 100.0)
+        });
     let row_width = metrics.row_width;
     let similar_query = app.controller.ui.browser.similar_query.as_ref();
     let is_anchor = similar_query.and_then(|sim| sim.anchor_index) == Some(entry_index);
@@ -125,13 +131,22 @@ pub(super) fn render_sample_browser_row(
             .map(|label| bpm_badge_space(ui, label))
             .unwrap_or(0.0)
     };
+    let class_space = if rename_match {
+        0.0
+    } else {
+        class_label
+            .as_deref()
+            .map(|label| class_chip_space(ui, label))
+            .unwrap_or(0.0)
+    };
     let trailing_space = indicator_space
         + triage_marker_width
             .map(|width| width + metrics.padding * 0.5)
             .unwrap_or(0.0)
         + loop_space
         + long_space
-        + bpm_space;
+        + bpm_space
+        + class_space;
 
     let mut base_label = app
         .controller
@@ -144,12 +159,17 @@ pub(super) fn render_sample_browser_row(
         .controller
         .analysis_failure_for_entry(entry_index)
         .map(str::to_string);
+    let analysis_warning = app
+        .controller
+        .analysis_warning_for_entry(entry_index)
+        .map(str::to_string);
     let base_color = style::playback_age_label_color(last_played_at, context.now_epoch);
     let status_label = status_badges::apply_sample_status(
         base_label,
         base_color,
         missing,
         analysis_failure.as_deref(),
+        analysis_warning.as_deref(),
     );
     let display_label = status_label.label.clone();
 
@@ -217,6 +237,8 @@ pub(super) fn render_sample_browser_row(
                 looped: looped && !rename_match,
                 long_sample: long_sample && !rename_match,
                 bpm_label: if rename_match { None } else { bpm_label.as_deref() },
+                class_label: if rename_match { None } else { class_label.as_deref() },
+                coverage_label: None,
             },
         );
         if let Some(alpha) = context.flash_alpha {
@@ -239,6 +261,20 @@ pub(super) fn render_sample_browser_row(
                 }
             }
         }
+        if response.hovered()
+            && !rename_match
+            && let Some(summary) = app.controller.audio_metadata_summary_for_path(&path)
+        {
+            match hover_text.as_mut() {
+                Some(text) => {
+                    text.push('\n');
+                    text.push_str(&summary);
+                }
+                None => {
+                    hover_text = Some(summary);
+                }
+            }
+        }
         let response = if let Some(hover) = hover_text.as_deref() {
             response.on_hover_text(hover)
         } else {
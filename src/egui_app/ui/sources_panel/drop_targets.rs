@@ -124,6 +124,8 @@ impl EguiApp {
                                 looped: false,
                                 long_sample: false,
                                 bpm_label: None,
+                                class_label: None,
+                                coverage_label: None,
                             },
                         );
                         let response = helpers::tooltip(
@@ -65,6 +65,8 @@ impl EguiApp {
                 looped: false,
                 long_sample: false,
                 bpm_label: None,
+                class_label: None,
+                coverage_label: None,
             },
         );
         let padding = ui.spacing().button_padding.x;
@@ -138,6 +140,18 @@ impl EguiApp {
                 self.controller.start_folder_rename();
                 close_menu = true;
             }
+            if ui.button("Reset tags").clicked() {
+                if let Some(source) = self.controller.current_source() {
+                    match self.controller.reset_tags_under_folder(&source, &row.path) {
+                        Ok(count) => self.controller.set_status(
+                            format!("Reset {count} tag(s) to neutral"),
+                            style::StatusTone::Info,
+                        ),
+                        Err(err) => self.controller.set_status(err, style::StatusTone::Error),
+                    }
+                }
+                close_menu = true;
+            }
             let delete_button = egui::Button::new(
                 RichText::new("Delete")
                     .color(style::destructive_text())
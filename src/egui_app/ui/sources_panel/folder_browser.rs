@@ -167,6 +167,8 @@ impl EguiApp {
                         looped: false,
                         long_sample: false,
                         bpm_label: None,
+                        class_label: None,
+                        coverage_label: None,
                     },
                 );
                 let mut badge_offset = 0.0;
@@ -372,6 +374,8 @@ impl EguiApp {
                             looped: false,
                             long_sample: false,
                             bpm_label: None,
+                            class_label: None,
+                            coverage_label: None,
                         },
                     );
                     let started_drag = if !rename_match
@@ -101,8 +101,11 @@ impl EguiApp {
                 min_folder_total = folder_header_height + header_gap;
                 min_drop_total = drop_header_height + header_gap;
             }
-            let default_sources_total = component_budget * 0.2;
-            let default_drop_total = component_budget * 0.2;
+            let sources_ui = &self.controller.ui.sources;
+            let default_sources_total =
+                component_budget * sources_ui.sources_split_ratio.unwrap_or(0.2);
+            let default_drop_total =
+                component_budget * sources_ui.drop_targets.split_ratio.unwrap_or(0.2);
             let mut sources_height_override = self.controller.ui.sources.sources_height_override;
             let mut sources_resize_origin =
                 self.controller.ui.sources.sources_resize_origin_height;
@@ -336,6 +339,14 @@ impl EguiApp {
             self.controller.ui.sources.sources_resize_origin_height = sources_resize_origin;
             self.controller.ui.sources.drop_targets.height_override = height_override;
             self.controller.ui.sources.drop_targets.resize_origin_height = resize_origin;
+            let resize_finished =
+                sources_handle_response.drag_stopped() || drop_handle_response.drag_stopped();
+            if resize_finished && component_budget > 0.0 {
+                self.controller.set_sources_panel_splits(
+                    Some(sources_list_height / component_budget),
+                    Some(drop_total / component_budget),
+                );
+            }
 
             let focus = self.controller.ui.focus.context;
             let stroke = style::focused_row_stroke();
@@ -33,7 +33,14 @@ impl EguiApp {
                     ui.push_id(&row.id, |ui| {
                         let row_width = ui.available_width();
                         let padding = ui.spacing().button_padding.x * 2.0;
-                        let base_label = clamp_label_for_width(&row.name, row_width - padding);
+                        let coverage_label = row.coverage.map(|coverage| coverage.label());
+                        let badge_space = coverage_label
+                            .as_deref()
+                            .map_or(0.0, |label| helpers::coverage_badge_space(ui, label));
+                        let base_label = clamp_label_for_width(
+                            &row.name,
+                            row_width - padding - badge_space,
+                        );
                         let label = if row.missing {
                             format!("! {base_label}")
                         } else {
@@ -63,11 +70,19 @@ impl EguiApp {
                                 looped: false,
                                 long_sample: false,
                                 bpm_label: None,
+                                class_label: None,
+                                coverage_label: coverage_label.as_deref(),
                             },
                         );
+                        let tooltip_title = match row.last_analyzed_at {
+                            Some(at) => {
+                                format!("{}\nLast analyzed {}", row.path, format_analyzed_at(at))
+                            }
+                            None => row.path.clone(),
+                        };
                         let response = helpers::tooltip(
                             response,
-                            &row.path,
+                            &tooltip_title,
                             "This folder is indexed in your library. Right-click to manage sync settings, re-analyze similarity, or open in File Explorer.",
                             tooltip_mode,
                         );
@@ -155,6 +170,24 @@ impl EguiApp {
                 self.controller.prepare_similarity_for_selected_source();
                 close_menu = true;
             }
+            if helpers::tooltip(
+                ui.button("Analyze embeddings only"),
+                "Analyze embeddings only",
+                "Fast mode: compute only the similarity embeddings for this folder so 'Find similar' and the Map View work. Skips BPM, transients and stored feature vectors.",
+                tooltip_mode,
+            ).clicked() {
+                self.controller.analyze_embeddings_only_for_source(index);
+                close_menu = true;
+            }
+            if helpers::tooltip(
+                ui.button("Reset and reanalyze"),
+                "Reset and reanalyze",
+                "Discard all analysis results for this folder (features, embeddings, map layout and clusters) and analyze every file again. Tags are kept.",
+                tooltip_mode,
+            ).clicked() {
+                self.controller.rebuild_analysis_for_source(index);
+                close_menu = true;
+            }
             ui.separator();
             ui.label(RichText::new("Similarity prep").color(style::palette().text_muted));
             let mut cap_enabled = self.controller.similarity_prep_duration_cap_enabled();
@@ -179,6 +212,27 @@ impl EguiApp {
                     self.controller.set_max_analysis_duration_seconds(seconds);
                 }
             });
+            let mut interaction_pause = self.controller.analysis_interaction_pause_enabled();
+            if ui
+                .checkbox(&mut interaction_pause, "Pause analysis while editing")
+                .on_hover_text(
+                    "Hold analysis jobs during waveform drags and resume shortly after",
+                )
+                .changed()
+            {
+                self.controller
+                    .set_analysis_interaction_pause_enabled(interaction_pause);
+            }
+            let mut quantize = self.controller.quantized_embeddings_enabled();
+            if ui
+                .checkbox(&mut quantize, "Compact embeddings (int8)")
+                .on_hover_text(
+                    "Store new embeddings as 8-bit values for smaller source databases",
+                )
+                .changed()
+            {
+                self.controller.set_quantized_embeddings_enabled(quantize);
+            }
             let mut fast_prep = self.controller.similarity_prep_fast_mode_enabled();
             if ui
                 .checkbox(&mut fast_prep, "Fast similarity prep")
@@ -204,6 +258,24 @@ impl EguiApp {
                         .set_similarity_prep_fast_sample_rate(sample_rate);
                 }
             });
+            ui.horizontal(|ui| {
+                ui.label("Similar results");
+                let mut count = self.controller.similar_result_count() as u32;
+                let response = ui
+                    .add(egui::DragValue::new(&mut count).speed(1.0).range(1..=1_000))
+                    .on_hover_text("Maximum number of samples returned by Find similar");
+                if response.changed() {
+                    self.controller.set_similar_result_count(count);
+                }
+                ui.label("Re-rank depth");
+                let mut depth = self.controller.similar_rerank_candidates() as u32;
+                let response = ui
+                    .add(egui::DragValue::new(&mut depth).speed(10.0).range(1..=5_000))
+                    .on_hover_text("Nearest-neighbour candidates scored before picking results");
+                if response.changed() {
+                    self.controller.set_similar_rerank_candidates(depth);
+                }
+            });
             ui.add_enabled_ui(!self.controller.similarity_prep_in_progress(), |ui| {
                 let mut force_full = self.controller.similarity_prep_force_full_analysis_next();
                 if ui
@@ -243,3 +315,15 @@ impl EguiApp {
         });
     }
 }
+
+fn format_analyzed_at(epoch_seconds: i64) -> String {
+    let Ok(utc) = time::OffsetDateTime::from_unix_timestamp(epoch_seconds) else {
+        return "at an unknown time".to_string();
+    };
+    let offset = time::UtcOffset::current_local_offset().unwrap_or(time::UtcOffset::UTC);
+    utc.to_offset(offset)
+        .format(time::macros::format_description!(
+            "[year]-[month]-[day] [hour]:[minute]"
+        ))
+        .unwrap_or_else(|_| "at an unknown time".to_string())
+}
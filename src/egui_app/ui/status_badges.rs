@@ -3,6 +3,7 @@ use eframe::egui;
 
 const MISSING_PREFIX: &str = "! ";
 const FAILED_SUFFIX: &str = " • FAILED";
+const WARNING_SUFFIX: &str = " • CHECK RATE";
 
 pub(super) struct StatusBadgeLabel {
     pub label: String,
@@ -20,10 +21,16 @@ pub(super) fn apply_sample_status(
     base_color: egui::Color32,
     missing: bool,
     analysis_failure: Option<&str>,
+    analysis_warning: Option<&str>,
 ) -> StatusBadgeLabel {
     let mut label = base_label.into();
     let mut text_color = base_color;
     let mut hover_text = None;
+    if let Some(warning) = analysis_warning.filter(|_| analysis_failure.is_none()) {
+        label.push_str(WARNING_SUFFIX);
+        text_color = style::warning_soft_text();
+        hover_text = Some(warning.to_string());
+    }
     if let Some(reason) = analysis_failure {
         label.push_str(FAILED_SUFFIX);
         text_color = style::destructive_text();
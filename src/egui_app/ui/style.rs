@@ -4,6 +4,7 @@ use eframe::egui::{
     epaint::{CornerRadius, Shadow},
     style::WidgetVisuals,
 };
+use std::cell::Cell;
 
 /// Status tone variants used to pick badge colours.
 #[derive(Clone, Copy, Debug)]
@@ -20,6 +21,30 @@ pub enum StatusTone {
     Error,
 }
 
+/// Colour theme variants selectable from the options panel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Theme {
+    /// Warm low-glare dark theme.
+    #[default]
+    Standard,
+    /// Pure black surfaces with bright text and strokes for accessibility.
+    HighContrast,
+}
+
+thread_local! {
+    static ACTIVE_THEME: Cell<Theme> = const { Cell::new(Theme::Standard) };
+}
+
+/// Select the theme used by [`palette`] on the UI thread.
+pub fn set_theme(theme: Theme) {
+    ACTIVE_THEME.with(|active| active.set(theme));
+}
+
+/// Theme currently used by [`palette`].
+pub fn theme() -> Theme {
+    ACTIVE_THEME.with(Cell::get)
+}
+
 /// Base palette for primary UI surfaces and text.
 #[allow(dead_code)]
 #[derive(Clone, Copy)]
@@ -95,8 +120,15 @@ pub struct SemanticPalette {
     pub missing: Color32,
 }
 
-/// Primary UI palette values.
+/// Primary UI palette values for the active theme.
 pub fn palette() -> Palette {
+    match theme() {
+        Theme::Standard => standard_palette(),
+        Theme::HighContrast => high_contrast_palette(),
+    }
+}
+
+fn standard_palette() -> Palette {
     Palette {
         bg_primary: Color32::from_rgb(12, 11, 10),
         bg_secondary: Color32::from_rgb(20, 18, 16),
@@ -115,6 +147,25 @@ pub fn palette() -> Palette {
     }
 }
 
+fn high_contrast_palette() -> Palette {
+    Palette {
+        bg_primary: Color32::BLACK,
+        bg_secondary: Color32::from_rgb(8, 8, 8),
+        bg_tertiary: Color32::from_rgb(24, 24, 24),
+        panel_outline: Color32::from_rgb(200, 200, 200),
+        grid_strong: Color32::from_rgb(170, 170, 170),
+        grid_soft: Color32::from_rgb(110, 110, 110),
+        text_primary: Color32::WHITE,
+        text_muted: Color32::from_rgb(220, 220, 220),
+        accent_mint: Color32::from_rgb(96, 255, 160),
+        accent_ice: Color32::from_rgb(255, 220, 0),
+        accent_copper: Color32::from_rgb(255, 150, 60),
+        accent_slate: Color32::from_rgb(110, 190, 255),
+        warning: Color32::from_rgb(255, 200, 0),
+        success: Color32::from_rgb(120, 255, 120),
+    }
+}
+
 /// Secondary palette for semantic colours not tied to the base background/foreground set.
 pub fn semantic_palette() -> SemanticPalette {
     SemanticPalette {
@@ -291,6 +342,16 @@ pub fn long_sample_badge_text() -> Color32 {
     high_contrast_text()
 }
 
+/// Fill color for the predicted-class chip shown in the sample browser list.
+pub fn class_chip_fill() -> Color32 {
+    with_alpha(palette().accent_ice, 200)
+}
+
+/// Text color for the predicted-class chip shown in the sample browser list.
+pub fn class_chip_text() -> Color32 {
+    high_contrast_text()
+}
+
 /// Fill color for the BPM badge shown in the sample browser list.
 pub fn bpm_badge_fill() -> Color32 {
     palette().accent_mint
@@ -301,6 +362,16 @@ pub fn bpm_badge_text() -> Color32 {
     high_contrast_text()
 }
 
+/// Fill color for the analysis coverage badge shown in the sources list.
+pub fn coverage_badge_fill() -> Color32 {
+    with_alpha(palette().accent_slate, 180)
+}
+
+/// Text color for the analysis coverage badge shown in the sources list.
+pub fn coverage_badge_text() -> Color32 {
+    high_contrast_text()
+}
+
 /// Text colour representing the playback age bucket for a sample.
 pub fn playback_age_label_color(last_played_at: Option<i64>, now_epoch: i64) -> Color32 {
     const WEEK_SECS: i64 = 60 * 60 * 24 * 7;
@@ -469,6 +540,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn high_contrast_theme_swaps_palette_colors() {
+        set_theme(Theme::HighContrast);
+        let contrast = palette();
+        set_theme(Theme::Standard);
+        let standard = palette();
+
+        assert_eq!(contrast.bg_primary, Color32::BLACK);
+        assert_eq!(contrast.text_primary, Color32::WHITE);
+        assert_eq!(contrast.panel_outline, Color32::from_rgb(200, 200, 200));
+        assert_ne!(contrast.bg_primary, standard.bg_primary);
+        assert_ne!(contrast.text_primary, standard.text_primary);
+        assert_ne!(contrast.panel_outline, standard.panel_outline);
+    }
+
     #[test]
     fn playback_age_label_color_uses_buckets() {
         let semantic = semantic_palette();
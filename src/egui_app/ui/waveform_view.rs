@@ -7,6 +7,7 @@ use std::time::Duration;
 
 mod base_render;
 mod beat_grid;
+mod beat_ruler;
 mod controls;
 mod destructive_prompt;
 mod edit_selection_overlay;
@@ -201,6 +202,14 @@ fn render_waveform_layers(
         layout.display_view,
         layout.view_width,
     );
+    beat_ruler::render_waveform_beat_ruler(
+        app,
+        ui,
+        layout.waveform_rect,
+        &context.palette,
+        layout.display_view,
+        layout.view_width,
+    );
 
     hover_overlay::render_hover_overlay(
         app,
@@ -0,0 +1,185 @@
+use super::style;
+use super::*;
+use crate::egui_app::state::WaveformView;
+use eframe::egui;
+
+const BEATS_PER_BAR: u32 = 4;
+const MIN_BEAT_LABEL_SPACING_PX: f32 = 36.0;
+const MIN_BAR_LABEL_SPACING_PX: f32 = 28.0;
+const MIN_BAR_LINE_SPACING_PX: f32 = 6.0;
+const MAX_RULER_TICKS: usize = 1200;
+const BEAT_TICK_HEIGHT_PX: f32 = 4.0;
+const BAR_TICK_HEIGHT_PX: f32 = 8.0;
+
+/// One beat position on the musical ruler.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct RulerTick {
+    /// Normalized position within the full waveform (0.0-1.0).
+    pub position: f64,
+    /// One-based bar number.
+    pub bar: u32,
+    /// One-based beat number within the bar.
+    pub beat: u32,
+}
+
+impl RulerTick {
+    fn is_bar(&self) -> bool {
+        self.beat == 1
+    }
+
+    fn label(&self) -> String {
+        format!("{}.{}", self.bar, self.beat)
+    }
+}
+
+/// Compute beat ticks for the visible view range of a file at the given tempo.
+///
+/// Returns an empty list when the inputs are invalid or the view would need more
+/// than `max_ticks` ticks.
+pub(super) fn beat_ruler_ticks(
+    bpm: f32,
+    duration_seconds: f32,
+    view: WaveformView,
+    beats_per_bar: u32,
+    max_ticks: usize,
+) -> Vec<RulerTick> {
+    if !bpm.is_finite() || bpm <= 0.0 {
+        return Vec::new();
+    }
+    if !duration_seconds.is_finite() || duration_seconds <= 0.0 {
+        return Vec::new();
+    }
+    let beat_step = 60.0 / bpm as f64 / duration_seconds as f64;
+    if !beat_step.is_finite() || beat_step <= 0.0 {
+        return Vec::new();
+    }
+    let start = view.start.max(0.0);
+    let end = view.end.min(1.0);
+    if end < start {
+        return Vec::new();
+    }
+    let first = (start / beat_step).ceil() as u64;
+    let last = (end / beat_step).floor() as u64;
+    if last < first || (last - first) as usize >= max_ticks {
+        return Vec::new();
+    }
+    let beats_per_bar = beats_per_bar.max(1) as u64;
+    (first..=last)
+        .map(|index| RulerTick {
+            position: index as f64 * beat_step,
+            bar: (index / beats_per_bar + 1) as u32,
+            beat: (index % beats_per_bar + 1) as u32,
+        })
+        .collect()
+}
+
+/// Draw a bars.beats ruler along the top of the waveform when a BPM value is set.
+pub(super) fn render_waveform_beat_ruler(
+    app: &EguiApp,
+    ui: &egui::Ui,
+    rect: egui::Rect,
+    palette: &style::Palette,
+    view: WaveformView,
+    view_width: f64,
+) {
+    let Some(bpm) = app.controller.ui.waveform.bpm_value else {
+        return;
+    };
+    let Some(duration) = app.controller.loaded_audio_duration_seconds() else {
+        return;
+    };
+    if !view_width.is_finite() || view_width <= 0.0 {
+        return;
+    }
+    let ticks = beat_ruler_ticks(bpm, duration, view, BEATS_PER_BAR, MAX_RULER_TICKS);
+    if ticks.is_empty() {
+        return;
+    }
+    let beat_spacing_px = ticks
+        .windows(2)
+        .next()
+        .map(|pair| rect.width() * ((pair[1].position - pair[0].position) / view_width) as f32)
+        .unwrap_or(rect.width());
+    let bar_spacing_px = beat_spacing_px * BEATS_PER_BAR as f32;
+    if bar_spacing_px < MIN_BAR_LINE_SPACING_PX {
+        return;
+    }
+    let label_beats = beat_spacing_px >= MIN_BEAT_LABEL_SPACING_PX;
+    let label_bars = bar_spacing_px >= MIN_BAR_LABEL_SPACING_PX;
+
+    let painter = ui.painter();
+    let base = palette.text_muted;
+    let bar_line = egui::Stroke::new(1.0, style::with_alpha(base, 70));
+    let tick_stroke = egui::Stroke::new(1.0, style::with_alpha(base, 150));
+    let font = egui::FontId::monospace(9.0);
+    let label_color = style::with_alpha(base, 190);
+    for tick in ticks {
+        let x = rect.left() + rect.width() * ((tick.position - view.start) / view_width) as f32;
+        if x < rect.left() || x > rect.right() {
+            continue;
+        }
+        let tick_height = if tick.is_bar() {
+            painter.line_segment(
+                [egui::pos2(x, rect.top()), egui::pos2(x, rect.bottom())],
+                bar_line,
+            );
+            BAR_TICK_HEIGHT_PX
+        } else if beat_spacing_px >= MIN_BAR_LINE_SPACING_PX {
+            BEAT_TICK_HEIGHT_PX
+        } else {
+            continue;
+        };
+        painter.line_segment(
+            [
+                egui::pos2(x, rect.top()),
+                egui::pos2(x, rect.top() + tick_height),
+            ],
+            tick_stroke,
+        );
+        if label_beats || (label_bars && tick.is_bar()) {
+            let text = if label_beats {
+                tick.label()
+            } else {
+                tick.bar.to_string()
+            };
+            painter.text(
+                egui::pos2(x + 2.0, rect.top() + 1.0),
+                egui::Align2::LEFT_TOP,
+                text,
+                font.clone(),
+                label_color,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beat_ruler_ticks_at_120_bpm_land_on_half_second_steps() {
+        // 120 BPM over 4 seconds: one beat every 0.5s, i.e. every 0.125 normalized.
+        let view = WaveformView {
+            start: 0.2,
+            end: 0.8,
+        };
+        let ticks = beat_ruler_ticks(120.0, 4.0, view, 4, 100);
+        let positions: Vec<f64> = ticks.iter().map(|tick| tick.position).collect();
+        let expected = [0.25, 0.375, 0.5, 0.625, 0.75];
+        assert_eq!(positions.len(), expected.len());
+        for (actual, expected) in positions.iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-9, "{actual} vs {expected}");
+        }
+        let labels: Vec<(u32, u32)> = ticks.iter().map(|tick| (tick.bar, tick.beat)).collect();
+        assert_eq!(labels, vec![(1, 3), (1, 4), (2, 1), (2, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn beat_ruler_ticks_rejects_dense_or_invalid_input() {
+        let view = WaveformView::default();
+        assert!(beat_ruler_ticks(120.0, 600.0, view, 4, 100).is_empty());
+        assert!(beat_ruler_ticks(0.0, 4.0, view, 4, 100).is_empty());
+        assert!(beat_ruler_ticks(120.0, 0.0, view, 4, 100).is_empty());
+    }
+}
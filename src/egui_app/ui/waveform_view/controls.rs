@@ -99,6 +99,63 @@ pub(super) fn render_waveform_controls(app: &mut EguiApp, ui: &mut Ui, palette:
                 "When enabled, playback will be normalized to 0dB in real-time. This helps in auditing quiet samples without changing the source file.",
                 tooltip_mode,
             );
+
+            // Solo Band
+            let solo_band = app.controller.ui.waveform.solo_band;
+            let mut selected_band = solo_band;
+            let band_response = egui::ComboBox::from_id_salt("waveform_solo_band")
+                .width(52.0)
+                .selected_text(solo_band.map_or("Full", |band| band.label()))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut selected_band, None, "Full");
+                    for band in crate::audio::SoloBand::ALL {
+                        ui.selectable_value(&mut selected_band, Some(band), band.label());
+                    }
+                })
+                .response;
+            helpers::tooltip(
+                band_response,
+                "Solo Band",
+                "Audition only the low, mid or high band of the sample. Playback only; the audio is not changed.",
+                tooltip_mode,
+            );
+            if selected_band != solo_band {
+                app.controller.set_solo_band(selected_band);
+            }
+
+            // Vertical Zoom
+            let mut vertical_zoom = app.controller.ui.waveform.vertical_zoom;
+            let zoom_response = ui.add(
+                egui::DragValue::new(&mut vertical_zoom)
+                    .range(1.0..=32.0)
+                    .speed(0.05)
+                    .max_decimals(1)
+                    .suffix("×"),
+            );
+            let zoom_changed = zoom_response.changed();
+            helpers::tooltip(
+                zoom_response,
+                "Vertical Zoom",
+                "Magnify the waveform amplitude to inspect quiet content. Display only; the audio is not changed.",
+                tooltip_mode,
+            );
+            if zoom_changed {
+                app.controller.set_waveform_vertical_zoom(vertical_zoom);
+            }
+            let zoomed = (app.controller.ui.waveform.vertical_zoom - 1.0).abs() > f32::EPSILON;
+            let reset_response = ui.add_enabled(
+                zoomed,
+                egui::Button::new(RichText::new("1×").size(11.0)).small(),
+            );
+            if reset_response.clicked() {
+                app.controller.reset_waveform_vertical_zoom();
+            }
+            helpers::tooltip(
+                reset_response,
+                "Reset Vertical Zoom",
+                "Return the waveform to unscaled amplitude.",
+                tooltip_mode,
+            );
         });
 
         ui.add_space(4.0);
@@ -185,10 +242,61 @@ pub(super) fn render_waveform_controls(app: &mut EguiApp, ui: &mut Ui, palette:
                 show_transients = !show_transients;
                 app.controller.set_transient_markers_enabled(show_transients);
             }
+            let transient_density = app.controller.transient_onset_density();
+            transient_response.context_menu(|ui| {
+                if let Some(density) = transient_density {
+                    ui.label(format!("Density: {density:.1} onsets/s"));
+                }
+                let mut max_markers = app.controller.transient_max_markers();
+                let mut capped = max_markers.is_some();
+                if ui.checkbox(&mut capped, "Limit markers").changed() {
+                    max_markers = capped.then_some(16);
+                }
+                if let Some(cap) = max_markers.as_mut() {
+                    ui.add(egui::DragValue::new(cap).range(1..=512).suffix(" max"));
+                }
+                app.controller.set_transient_max_markers(max_markers);
+                ui.separator();
+                let mut tuning = app.controller.transient_tuning();
+                let mut min_gap_ms = tuning.min_gap_seconds * 1000.0;
+                let mut tuning_changed = false;
+                tuning_changed |= ui
+                    .add(egui::Slider::new(&mut tuning.k_high, 1.0..=8.0).text("Threshold"))
+                    .changed();
+                tuning_changed |= ui
+                    .add(egui::Slider::new(&mut tuning.k_low, 0.5..=8.0).text("Re-arm"))
+                    .changed();
+                tuning_changed |= ui
+                    .add(egui::Slider::new(&mut tuning.floor_quantile, 0.0..=0.95).text("Floor"))
+                    .changed();
+                if ui
+                    .add(egui::Slider::new(&mut min_gap_ms, 10.0..=500.0).suffix(" ms").text("Min gap"))
+                    .changed()
+                {
+                    tuning.min_gap_seconds = min_gap_ms / 1000.0;
+                    tuning_changed = true;
+                }
+                if tuning_changed {
+                    app.controller.retune_waveform_transients(tuning);
+                }
+                if ui
+                    .add_enabled(
+                        app.controller.ui.waveform.transient_tuning.is_some(),
+                        egui::Button::new("Automatic tuning"),
+                    )
+                    .clicked()
+                {
+                    app.controller.reset_transient_tuning();
+                }
+            });
+            let transient_title = match transient_density {
+                Some(density) => format!("Show Transients ({density:.1} onsets/s)"),
+                None => "Show Transients".to_string(),
+            };
             helpers::tooltip(
                 transient_response,
-                "Show Transients",
-                "Toggle visibility of detected transient markers. Transients are detected automatically in the background.",
+                &transient_title,
+                "Toggle visibility of detected transient markers. Transients are detected automatically in the background. Right-click to cap the marker count or tune detection.",
                 tooltip_mode,
             );
 
@@ -217,6 +325,28 @@ pub(super) fn render_waveform_controls(app: &mut EguiApp, ui: &mut Ui, palette:
                 "Enable advanced slicing tools to segment the audio into playable regions.",
                 tooltip_mode,
             );
+
+            // A-B Difference Icon
+            let ab_diff_enabled = app.controller.ui.waveform.ab_diff_enabled;
+            let (diff_rect, diff_response) = ui.allocate_exact_size(egui::vec2(28.0, 24.0), egui::Sense::click());
+            let diff_color = if ab_diff_enabled { palette.accent_mint } else { icon_off };
+            let center = diff_rect.center();
+            ui.painter().add(egui::Shape::closed_line(
+                vec![center + egui::vec2(0.0, -5.5), center + egui::vec2(6.0, 5.0), center + egui::vec2(-6.0, 5.0)],
+                egui::Stroke::new(1.5, diff_color),
+            ));
+            if diff_response.hovered() {
+                ui.painter().rect_filled(diff_rect, 2.0, style::row_hover_fill());
+            }
+            if diff_response.clicked() {
+                app.controller.toggle_waveform_ab_diff();
+            }
+            helpers::tooltip(
+                diff_response,
+                "A-B Difference",
+                "Show what the last edit changed by drawing the current audio minus its pre-edit version.",
+                tooltip_mode,
+            );
         });
 
         ui.add_space(4.0);
@@ -295,7 +425,29 @@ pub(super) fn render_waveform_controls(app: &mut EguiApp, ui: &mut Ui, palette:
                 "Enable time-stretching to match the audio to the current BPM. Adjusting BPM will change playback speed without affecting pitch.",
                 tooltip_mode,
             );
-            
+
+            let metronome = app.controller.ui.waveform.metronome_enabled;
+            let (metronome_rect, metronome_response) = ui.allocate_exact_size(egui::vec2(24.0, 24.0), egui::Sense::click());
+            let metronome_color = if metronome { palette.accent_mint } else { icon_off };
+            let center = metronome_rect.center();
+            ui.painter().add(egui::Shape::convex_polygon(
+                vec![center + egui::vec2(0.0, -7.0), center + egui::vec2(5.0, 6.0), center + egui::vec2(-5.0, 6.0)],
+                egui::Color32::TRANSPARENT, egui::Stroke::new(1.3, metronome_color)
+            ));
+            ui.painter().line_segment([center + egui::vec2(0.0, 4.0), center + egui::vec2(3.5, -5.0)], egui::Stroke::new(1.3, metronome_color));
+            if metronome_response.hovered() {
+                ui.painter().rect_filled(metronome_rect, 2.0, style::row_hover_fill());
+            }
+            if metronome_response.clicked() {
+                app.controller.set_metronome_enabled(!metronome);
+            }
+            helpers::tooltip(
+                metronome_response,
+                "Metronome",
+                "Play a click on every beat of the current BPM during playback. Playback only; the audio is not changed.",
+                tooltip_mode,
+            );
+
             app.controller.ui.hotkeys.suppress_for_bpm_input = bpm_edit_response.has_focus();
             if bpm_edit_response.lost_focus() || bpm_edit_response.changed() {
                 if let Some(value) = helpers::parse_bpm_input(&app.controller.ui.waveform.bpm_input) {
@@ -390,10 +542,28 @@ pub(super) fn render_waveform_controls(app: &mut EguiApp, ui: &mut Ui, palette:
                         app.controller.toggle_loop();
                     }
                 }
+                let loop_cycles = app.controller.ui.waveform.playhead.loop_cycles;
+                loop_response.context_menu(|ui| {
+                    ui.label(format!("Cycles played: {loop_cycles}"));
+                    let mut limit = app.controller.ui.waveform.loop_cycle_limit;
+                    let mut capped = limit.is_some();
+                    if ui.checkbox(&mut capped, "Stop after").changed() {
+                        limit = capped.then_some(4);
+                    }
+                    if let Some(cycles) = limit.as_mut() {
+                        ui.add(egui::DragValue::new(cycles).range(1..=999).suffix(" cycles"));
+                    }
+                    app.controller.set_loop_cycle_limit(limit);
+                });
+                let loop_title = match app.controller.ui.waveform.loop_cycle_limit {
+                    Some(limit) if loop_enabled => format!("Toggle Loop ({loop_cycles}/{limit})"),
+                    _ if loop_enabled && loop_cycles > 0 => format!("Toggle Loop ({loop_cycles})"),
+                    _ => "Toggle Loop".to_string(),
+                };
                 helpers::tooltip(
                     loop_response,
-                    "Toggle Loop",
-                    "Continuously loop the current selection. Use 'L' to toggle.\nShift+Click or Shift+L locks the loop state.",
+                    &loop_title,
+                    "Continuously loop the current selection. Use 'L' to toggle.\nShift+Click or Shift+L locks the loop state.\nRight-click to stop after a number of cycles.",
                     tooltip_mode,
                 );
 
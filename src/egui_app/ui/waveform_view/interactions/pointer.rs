@@ -112,6 +112,18 @@ pub(in super::super) fn handle_waveform_pointer_interactions(
             app.controller.finish_selection_drag();
         }
     } else if response.clicked_by(egui::PointerButton::Secondary) {
+        if modifiers.ctrl {
+            if let Some(value) = normalized {
+                match app.controller.remove_nearest_marker(value as f32) {
+                    Ok(Some(_)) => {}
+                    Ok(None) => app
+                        .controller
+                        .set_status("No marker near cursor", StatusTone::Info),
+                    Err(err) => app.controller.set_status(err, StatusTone::Error),
+                }
+            }
+            return;
+        }
         if let Some(selection) = app.controller.ui.waveform.edit_selection {
             let clicked_pos = pointer_pos.or_else(|| response.hover_pos());
             let on_selection = clicked_pos
@@ -133,6 +145,23 @@ pub(in super::super) fn handle_waveform_pointer_interactions(
         if app.controller.ui.waveform.image.is_some() {
             app.controller.focus_waveform_context();
         }
+        if modifiers.alt {
+            if let Some(value) = normalized
+                && !app.controller.snap_waveform_cursor_to_transient(value as f32)
+            {
+                app.controller
+                    .set_status("No transients to snap to", StatusTone::Info);
+            }
+            return;
+        }
+        if modifiers.ctrl {
+            if let Some(value) = normalized
+                && let Err(err) = app.controller.add_marker(value as f32)
+            {
+                app.controller.set_status(err, StatusTone::Error);
+            }
+            return;
+        }
         if app.controller.ui.waveform.selection.is_some() {
             app.controller.clear_selection();
         }
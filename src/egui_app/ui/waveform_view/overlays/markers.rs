@@ -29,6 +29,65 @@ pub(super) fn render_markers(
     }
 
     draw_transient_markers(app, ui, rect, view, to_screen_x);
+    draw_cue_markers(app, ui, rect, view, to_screen_x);
+    draw_user_markers(app, ui, rect, view, to_screen_x);
+}
+
+fn draw_user_markers(
+    app: &EguiApp,
+    ui: &mut egui::Ui,
+    rect: egui::Rect,
+    view: crate::egui_app::state::WaveformView,
+    to_screen_x: &impl Fn(f32, egui::Rect) -> f32,
+) {
+    let palette = style::palette();
+    let stroke = Stroke::new(1.5, style::with_alpha(palette.accent_slate, 220));
+    let handle_fill = style::with_alpha(palette.accent_slate, 230);
+    let top = rect.top() + super::LOOP_BAR_HEIGHT;
+    for &marker in &app.controller.ui.waveform.user_markers {
+        let m = marker as f64;
+        if m < view.start || m > view.end {
+            continue;
+        }
+        let x = to_screen_x(marker, rect);
+        ui.painter()
+            .line_segment([egui::pos2(x, top), egui::pos2(x, rect.bottom())], stroke);
+        let points = vec![
+            egui::pos2(x, top),
+            egui::pos2(x + 4.0, top + 4.0),
+            egui::pos2(x, top + 8.0),
+            egui::pos2(x - 4.0, top + 4.0),
+        ];
+        ui.painter()
+            .add(egui::Shape::convex_polygon(points, handle_fill, Stroke::NONE));
+    }
+}
+
+fn draw_cue_markers(
+    app: &EguiApp,
+    ui: &mut egui::Ui,
+    rect: egui::Rect,
+    view: crate::egui_app::state::WaveformView,
+    to_screen_x: &impl Fn(f32, egui::Rect) -> f32,
+) {
+    let palette = style::palette();
+    let stroke = Stroke::new(1.0, style::with_alpha(palette.accent_copper, 200));
+    let flag_fill = style::with_alpha(palette.accent_copper, 180);
+    let top = rect.top() + super::LOOP_BAR_HEIGHT;
+    for &marker in &app.controller.ui.waveform.cue_markers {
+        let m = marker as f64;
+        if m < view.start || m > view.end {
+            continue;
+        }
+        let x = to_screen_x(marker, rect);
+        ui.painter()
+            .line_segment([egui::pos2(x, top), egui::pos2(x, rect.bottom())], stroke);
+        ui.painter().rect_filled(
+            egui::Rect::from_min_size(egui::pos2(x, top), egui::vec2(5.0, 4.0)),
+            0.0,
+            flag_fill,
+        );
+    }
 }
 
 fn draw_transient_markers(
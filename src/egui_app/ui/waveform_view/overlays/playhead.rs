@@ -12,22 +12,23 @@ pub(super) fn render_playhead(
     highlight: Color32,
     to_screen_x: &impl Fn(f32, egui::Rect) -> f32,
 ) {
+    let trail_duration = app.controller.ui.controls.playhead_trail_duration();
+    let trail_fade = app.controller.ui.controls.playhead_trail_fade();
     let playhead = &mut app.controller.ui.waveform.playhead;
     let now = Instant::now();
-    const TRAIL_DURATION: Duration = Duration::from_millis(1250);
-    const TRAIL_FADE: Duration = Duration::from_millis(450);
+    let trail_enabled = !trail_duration.is_zero();
 
-    for fading in playhead.fading_trails.iter() {
+    for fading in playhead.fading_trails.iter().filter(|_| trail_enabled) {
         let age = now.saturating_duration_since(fading.started_at);
-        if age >= TRAIL_FADE {
+        if age >= trail_fade {
             continue;
         }
-        let fade_t = 1.0 - (age.as_secs_f32() / TRAIL_FADE.as_secs_f32()).clamp(0.0, 1.0);
+        let fade_t = 1.0 - (age.as_secs_f32() / trail_fade.as_secs_f32()).clamp(0.0, 1.0);
         let fade_strength = fade_t * fade_t;
         let Some(last_time) = fading.samples.back().map(|sample| sample.time) else {
             continue;
         };
-        let cutoff = last_time.checked_sub(TRAIL_DURATION).unwrap_or(last_time);
+        let cutoff = last_time.checked_sub(trail_duration).unwrap_or(last_time);
         let window = trail_samples_in_window(&fading.samples, cutoff);
         if window.len() < 2 {
             continue;
@@ -40,15 +41,15 @@ pub(super) fn render_playhead(
             |time| {
                 let base_age = last_time.saturating_duration_since(time);
                 let t = 1.0
-                    - (base_age.as_secs_f32() / TRAIL_DURATION.as_secs_f32()).clamp(0.0, 1.0);
+                    - (base_age.as_secs_f32() / trail_duration.as_secs_f32()).clamp(0.0, 1.0);
                 ((t * t) * 105.0 * fade_strength).round().clamp(0.0, 255.0) as u8
             },
         );
         paint_playhead_trail_mesh(ui, rect, &stops, highlight);
     }
 
-    if playhead.visible && playhead.trail.len() >= 2 {
-        let cutoff = now.checked_sub(TRAIL_DURATION).unwrap_or(now);
+    if trail_enabled && playhead.visible && playhead.trail.len() >= 2 {
+        let cutoff = now.checked_sub(trail_duration).unwrap_or(now);
         let window = trail_samples_in_window(&playhead.trail, cutoff);
         if window.len() >= 2 {
             let stops = gradient_stops_from_trail_window(
@@ -59,7 +60,7 @@ pub(super) fn render_playhead(
                 |time| {
                     let age = now.saturating_duration_since(time);
                     let t =
-                        1.0 - (age.as_secs_f32() / TRAIL_DURATION.as_secs_f32()).clamp(0.0, 1.0);
+                        1.0 - (age.as_secs_f32() / trail_duration.as_secs_f32()).clamp(0.0, 1.0);
                     ((t * t) * 119.0).round().clamp(0.0, 255.0) as u8
                 },
             );
@@ -44,6 +44,15 @@ pub(super) fn render_selection_context_menu(app: &mut EguiApp, ui: &mut egui::Ui
     ).clicked() {
         request_selection_edit(app, &mut close_menu, DestructiveSelectionEdit::ReverseSelection);
     }
+    #[cfg(target_os = "windows")]
+    if helpers::tooltip(
+        ui.button("Paste audio over selection"),
+        "Paste audio over selection",
+        "Replace the selected region with audio from the system clipboard, resampled to the file's sample rate. This is written directly back to the source file.",
+        tooltip_mode,
+    ).clicked() {
+        request_selection_edit(app, &mut close_menu, DestructiveSelectionEdit::PasteClipboard);
+    }
     ui.separator();
     ui.horizontal(|ui| {
         let fade_lr = helpers::tooltip(
@@ -100,6 +109,17 @@ pub(super) fn render_selection_context_menu(app: &mut EguiApp, ui: &mut egui::Ui
         app.controller
             .set_auto_edge_fades_on_selection_exports(auto_edge_fades);
     }
+    let mut export_markers = app.controller.ui.controls.markers_on_selection_exports;
+    let export_markers_response = helpers::tooltip(
+        ui.checkbox(&mut export_markers, "Write markers into new samples"),
+        "Write markers into new samples",
+        "When enabled, samples created from selections carry the waveform's cue and transient markers as a WAV cue chunk that other editors and DAWs can read.",
+        tooltip_mode,
+    );
+    if export_markers_response.changed() {
+        app.controller
+            .set_markers_on_selection_exports(export_markers);
+    }
     if helpers::tooltip(
         ui.button("Normalize selection"),
         "Normalize selection",
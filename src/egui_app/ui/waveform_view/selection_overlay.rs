@@ -105,6 +105,16 @@ pub(super) fn render_selection_overlay(
             bar_rect.top() + (bar_height - galley.size().y) * 0.5,
         );
         painter.galley(text_pos, galley, text_color);
+        let stats_response = ui.interact(
+            bar_rect,
+            ui.id().with("selection_statistics"),
+            egui::Sense::hover(),
+        );
+        if stats_response.hovered()
+            && let Some(stats) = app.controller.selection_statistics()
+        {
+            stats_response.on_hover_text(selection_statistics_text(&stats));
+        }
     }
 
     let top_cut = super::overlays::LOOP_BAR_HEIGHT;
@@ -270,3 +280,16 @@ fn draw_bpm_guides(
         beat_index += 1;
     }
 }
+
+fn selection_statistics_text(
+    stats: &crate::egui_app::controller::SelectionStatistics,
+) -> String {
+    let to_db = |level: f32| 20.0 * level.max(1e-6).log10();
+    format!(
+        "Peak {:.1} dBFS\nRMS {:.1} dBFS\nLoudness {:.1} LUFS\nDuration {:.3} s",
+        to_db(stats.peak),
+        to_db(stats.rms),
+        stats.lufs,
+        stats.duration_seconds,
+    )
+}
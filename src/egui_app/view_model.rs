@@ -19,6 +19,8 @@ pub fn source_row(source: &SampleSource, missing: bool) -> SourceRowView {
         name,
         path: source.root.to_string_lossy().to_string(),
         missing,
+        coverage: None,
+        last_analyzed_at: None,
     }
 }
 
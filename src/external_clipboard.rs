@@ -31,6 +31,11 @@ pub fn read_text() -> Result<String, String> {
     platform::read_text()
 }
 
+/// Read WAV audio bytes from the system clipboard (`None` when no audio is present).
+pub fn read_wave_bytes() -> Result<Option<Vec<u8>>, String> {
+    platform::read_wave_bytes()
+}
+
 #[cfg(not(target_os = "windows"))]
 mod platform {
     use super::*;
@@ -50,6 +55,10 @@ mod platform {
     pub fn read_text() -> Result<String, String> {
         Err("Clipboard text read is only implemented on Windows in this build".into())
     }
+
+    pub fn read_wave_bytes() -> Result<Option<Vec<u8>>, String> {
+        Err("Clipboard audio paste is only implemented on Windows in this build".into())
+    }
 }
 
 #[cfg(target_os = "windows")]
@@ -72,6 +81,7 @@ mod platform {
     use windows::Win32::UI::Shell::{DROPFILES, DragQueryFileW, HDROP};
     use windows::core::w;
 
+    const CF_WAVE: u32 = 12;
     const CF_UNICODETEXT: u32 = 13;
 
     struct Clipboard;
@@ -263,6 +273,26 @@ mod platform {
         Ok(String::from_utf16_lossy(&slice[..len]))
     }
 
+    pub fn read_wave_bytes() -> Result<Option<Vec<u8>>, String> {
+        if unsafe { IsClipboardFormatAvailable(CF_WAVE) }.is_err() {
+            return Ok(None);
+        }
+        let _clipboard = ClipboardReader::new()?;
+        let handle = unsafe { GetClipboardData(CF_WAVE) }
+            .map_err(|err| format!("GetClipboardData(CF_WAVE) failed: {err}"))?;
+        let lock = unsafe { GlobalLockGuard::new(HGLOBAL(handle.0)) }?;
+        let ptr = lock.ptr() as *const u8;
+        if ptr.is_null() {
+            return Ok(None);
+        }
+        let size_bytes = unsafe { GlobalSize(HGLOBAL(handle.0)) };
+        if size_bytes == 0 {
+            return Err("GlobalSize failed for clipboard audio".to_string());
+        }
+        let bytes = unsafe { std::slice::from_raw_parts(ptr, size_bytes) };
+        Ok(Some(bytes.to_vec()))
+    }
+
     fn bounded_utf16_len(slice: &[u16]) -> Result<usize, String> {
         slice
             .iter()
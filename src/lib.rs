@@ -9,6 +9,7 @@ pub mod analysis;
 pub mod app_dirs;
 /// Audio playback utilities.
 pub mod audio;
+mod build_info;
 /// Shared egui UI modules.
 pub mod egui_app;
 /// Platform helpers for copying files to the clipboard.
@@ -32,3 +33,5 @@ pub mod updater;
 pub mod wav_sanitize;
 /// Waveform decoding and rendering helpers.
 pub mod waveform;
+
+pub use build_info::{BuildInfo, build_info};
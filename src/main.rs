@@ -9,6 +9,7 @@
 use eframe::egui;
 use egui::viewport::IconData;
 use sempal::audio::AudioPlayer;
+use sempal::egui_app::launch::LaunchOptions;
 use sempal::egui_app::ui::{EguiApp, MIN_VIEWPORT_SIZE};
 use sempal::logging;
 use sempal::waveform::WaveformRenderer;
@@ -22,6 +23,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         eprintln!("Logging disabled: {err}");
     }
 
+    let launch = LaunchOptions::from_env();
+    if launch.safe_mode {
+        tracing::warn!("Starting in safe mode: map disabled");
+    }
     let renderer = WaveformRenderer::new(680, 260);
     let player = None::<std::rc::Rc<std::cell::RefCell<AudioPlayer>>>;
 
@@ -42,7 +47,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "Sempal",
         native_options,
         Box::new(
-            move |_cc| match EguiApp::new(renderer.clone(), player.clone()) {
+            move |_cc| match EguiApp::new_with_launch_options(
+                renderer.clone(),
+                player.clone(),
+                launch,
+            ) {
                 Ok(app) => Ok(Box::new(app)),
                 Err(err) => Ok(Box::new(LaunchError { message: err })),
             },
@@ -10,7 +10,7 @@ pub use config_io::{
     save_to_path,
 };
 pub use config_types::{
-    AnalysisSettings, AppConfig, AppSettingsCore, ConfigError, DropTargetColor, DropTargetConfig,
-    FeatureFlags, InteractionOptions, TooltipMode, UpdateChannel,
-    UpdateSettings,
+    AnalysisSettings, AppConfig, AppSettingsCore, ConfigError, DestructiveConfirmations,
+    DropTargetColor, DropTargetConfig, FeatureFlags, InteractionOptions, LoadSelectionMode,
+    TooltipMode, TrashStrategy, UpdateChannel, UpdateSettings, WindowLayout, clamp_split_ratio,
 };
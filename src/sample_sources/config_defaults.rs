@@ -1,6 +1,7 @@
 use crate::audio::{AudioInputConfig, AudioOutputConfig};
 
 pub(super) const MAX_ANALYSIS_WORKER_COUNT: u32 = 64;
+pub(super) const MAX_ANALYSIS_CLAIM_BATCH: u32 = 1024;
 pub(super) const MIN_JOB_MESSAGE_QUEUE_CAPACITY: u32 = 32;
 pub(super) const MAX_JOB_MESSAGE_QUEUE_CAPACITY: u32 = 4096;
 
@@ -12,6 +13,10 @@ pub(super) fn clamp_analysis_worker_count(value: u32) -> u32 {
     value.min(MAX_ANALYSIS_WORKER_COUNT)
 }
 
+pub(super) fn clamp_analysis_claim_batch(value: u32) -> u32 {
+    value.min(MAX_ANALYSIS_CLAIM_BATCH)
+}
+
 pub(super) fn clamp_job_message_queue_capacity(value: u32) -> u32 {
     value.clamp(MIN_JOB_MESSAGE_QUEUE_CAPACITY, MAX_JOB_MESSAGE_QUEUE_CAPACITY)
 }
@@ -40,6 +45,10 @@ pub(super) fn default_analysis_worker_count() -> u32 {
     0
 }
 
+pub(super) fn default_analysis_claim_batch() -> u32 {
+    0
+}
+
 pub(super) fn default_job_message_queue_capacity() -> u32 {
     256
 }
@@ -52,6 +61,14 @@ pub(super) fn default_fast_similarity_prep_sample_rate() -> u32 {
     8_000
 }
 
+pub(super) fn default_similar_result_count() -> u32 {
+    40
+}
+
+pub(super) fn default_similar_rerank_candidates() -> u32 {
+    200
+}
+
 pub(super) fn default_volume() -> f32 {
     1.0
 }
@@ -72,6 +89,18 @@ pub(super) fn default_anti_clip_fade_ms() -> f32 {
     2.0
 }
 
+pub(super) fn default_seek_crossfade_ms() -> f32 {
+    30.0
+}
+
+pub(super) fn default_playhead_trail_seconds() -> f32 {
+    1.25
+}
+
+pub(super) fn default_playhead_trail_fade_seconds() -> f32 {
+    0.45
+}
+
 pub(super) fn default_bpm_value() -> f32 {
     142.0
 }
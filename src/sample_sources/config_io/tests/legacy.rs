@@ -1,6 +1,6 @@
 use super::super::super::config_types::{
     AnalysisSettings, AppSettingsCore, DropTargetConfig, FeatureFlags,
-    InteractionOptions, UpdateSettings,
+    InteractionOptions, UpdateSettings, WindowLayout,
 };
 use super::super::LEGACY_CONFIG_FILE_NAME;
 use super::super::load::load_or_default;
@@ -22,6 +22,7 @@ fn migrates_from_legacy_json() {
             job_message_queue_capacity: AppSettingsCore::default().job_message_queue_capacity,
             app_data_dir: None,
             trash_folder: Some(std::path::PathBuf::from("trash_here")),
+            trash_strategy: Default::default(),
             drop_targets: vec![DropTargetConfig::new(std::path::PathBuf::from(
                 "legacy_drop",
             ))],
@@ -30,6 +31,7 @@ fn migrates_from_legacy_json() {
             audio_input: AudioInputConfig::default(),
             volume: 0.9,
             controls: InteractionOptions::default(),
+            layout: WindowLayout::default(),
         },
     };
     let mut data = serde_json::to_value(&legacy).unwrap();
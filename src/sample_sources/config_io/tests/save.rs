@@ -1,13 +1,14 @@
 use super::super::super::config_types::{
-    AnalysisSettings, AppSettingsCore, DropTargetColor, DropTargetConfig, FeatureFlags,
-    InteractionOptions, TooltipMode, UpdateChannel, UpdateSettings,
+    AnalysisSettings, AppSettingsCore, DestructiveConfirmations, DropTargetColor,
+    DropTargetConfig, FeatureFlags, InteractionOptions, LoadSelectionMode, TooltipMode,
+    UpdateChannel, UpdateSettings, WindowLayout,
 };
 use super::super::load::load_settings_from;
 use super::super::save::save_to_path;
 use super::TestConfigEnv;
 #[cfg(unix)]
 use super::super::save::save_settings_to_path;
-use crate::audio::{AudioInputConfig, AudioOutputConfig};
+use crate::audio::{AudioInputConfig, AudioOutputConfig, ResampleQuality};
 use crate::sample_sources::config::AppConfig;
 #[cfg(unix)]
 use super::super::super::config_types::AppSettings;
@@ -59,6 +60,7 @@ fn audio_output_defaults_and_persists() {
                 device: Some("Test Interface".into()),
                 sample_rate: Some(48_000),
                 buffer_size: Some(512),
+                resample_quality: ResampleQuality::Sinc,
             },
             ..AppSettingsCore::default()
         },
@@ -74,6 +76,10 @@ fn audio_output_defaults_and_persists() {
     );
     assert_eq!(loaded.core.audio_output.sample_rate, Some(48_000));
     assert_eq!(loaded.core.audio_output.buffer_size, Some(512));
+    assert_eq!(
+        loaded.core.audio_output.resample_quality,
+        ResampleQuality::Sinc
+    );
 }
 
 #[test]
@@ -139,8 +145,13 @@ fn settings_round_trip_preserves_fields() {
                 limit_similarity_prep_duration: false,
                 long_sample_threshold_seconds: 42.0,
             analysis_worker_count: 2,
+            analysis_claim_batch: 16,
             fast_similarity_prep: true,
             fast_similarity_prep_sample_rate: 8_000,
+            pause_analysis_during_interaction: false,
+            quantize_embeddings: true,
+            similar_result_count: 100,
+            similar_rerank_candidates: 400,
         },
             updates: UpdateSettings {
                 channel: UpdateChannel::Nightly,
@@ -163,6 +174,7 @@ fn settings_round_trip_preserves_fields() {
                 device: Some("Test Interface".into()),
                 sample_rate: Some(96_000),
                 buffer_size: Some(256),
+                resample_quality: ResampleQuality::Linear,
             },
             audio_input: AudioInputConfig {
                 host: Some("asio".into()),
@@ -179,20 +191,42 @@ fn settings_round_trip_preserves_fields() {
                 keyboard_zoom_factor: 1.2,
                 anti_clip_fade_enabled: false,
                 anti_clip_fade_ms: 12.0,
+                seek_crossfade_enabled: true,
+                seek_crossfade_ms: 45.0,
                 auto_edge_fades_on_selection_exports: false,
+                markers_on_selection_exports: true,
                 destructive_yolo_mode: true,
+                destructive_confirmations: DestructiveConfirmations {
+                    crop: true,
+                    fade: false,
+                    mute: false,
+                    ..DestructiveConfirmations::default()
+                },
                 waveform_channel_view: WaveformChannelView::SplitStereo,
                 bpm_snap_enabled: true,
                 bpm_lock_enabled: true,
                 bpm_stretch_enabled: true,
                 bpm_value: 123.0,
+                metronome_enabled: true,
                 transient_snap_enabled: true,
                 transient_markers_enabled: false,
+                transient_max_markers: Some(12),
                 input_monitoring_enabled: false,
                 normalized_audition_enabled: true,
                 advance_after_rating: true,
                 tooltip_mode: TooltipMode::Regular,
                 loop_lock_enabled: true,
+                loop_cycle_limit: Some(4),
+                playhead_trail_seconds: 0.5,
+                playhead_trail_fade_seconds: 0.2,
+                load_selection_mode: LoadSelectionMode::Full,
+                high_contrast_theme: true,
+            },
+            layout: WindowLayout {
+                sources_split: Some(0.3),
+                drop_targets_split: Some(0.15),
+                map_open: true,
+                map_size: Some([800.0, 520.0]),
             },
         },
     };
@@ -224,6 +258,10 @@ fn settings_round_trip_preserves_fields() {
         round_trip.core.analysis.analysis_worker_count,
         cfg.core.analysis.analysis_worker_count
     );
+    assert_eq!(
+        round_trip.core.analysis.analysis_claim_batch,
+        cfg.core.analysis.analysis_claim_batch
+    );
     assert_eq!(
         round_trip.core.analysis.fast_similarity_prep,
         cfg.core.analysis.fast_similarity_prep
@@ -232,6 +270,18 @@ fn settings_round_trip_preserves_fields() {
         round_trip.core.analysis.fast_similarity_prep_sample_rate,
         cfg.core.analysis.fast_similarity_prep_sample_rate
     );
+    assert_eq!(
+        round_trip.core.analysis.quantize_embeddings,
+        cfg.core.analysis.quantize_embeddings
+    );
+    assert_eq!(
+        round_trip.core.analysis.similar_result_count,
+        cfg.core.analysis.similar_result_count
+    );
+    assert_eq!(
+        round_trip.core.analysis.similar_rerank_candidates,
+        cfg.core.analysis.similar_rerank_candidates
+    );
     assert_eq!(
         round_trip.core.job_message_queue_capacity,
         cfg.core.job_message_queue_capacity
@@ -281,10 +331,18 @@ fn settings_round_trip_preserves_fields() {
         round_trip.core.controls.auto_edge_fades_on_selection_exports,
         cfg.core.controls.auto_edge_fades_on_selection_exports
     );
+    assert_eq!(
+        round_trip.core.controls.markers_on_selection_exports,
+        cfg.core.controls.markers_on_selection_exports
+    );
     assert_eq!(
         round_trip.core.controls.destructive_yolo_mode,
         cfg.core.controls.destructive_yolo_mode
     );
+    assert_eq!(
+        round_trip.core.controls.destructive_confirmations,
+        cfg.core.controls.destructive_confirmations
+    );
     assert_eq!(
         round_trip.core.controls.waveform_channel_view,
         cfg.core.controls.waveform_channel_view
@@ -305,6 +363,18 @@ fn settings_round_trip_preserves_fields() {
         round_trip.core.controls.bpm_value,
         cfg.core.controls.bpm_value
     );
+    assert_eq!(
+        round_trip.core.controls.seek_crossfade_enabled,
+        cfg.core.controls.seek_crossfade_enabled
+    );
+    assert_eq!(
+        round_trip.core.controls.seek_crossfade_ms,
+        cfg.core.controls.seek_crossfade_ms
+    );
+    assert_eq!(
+        round_trip.core.controls.metronome_enabled,
+        cfg.core.controls.metronome_enabled
+    );
     assert_eq!(
         round_trip.core.controls.transient_snap_enabled,
         cfg.core.controls.transient_snap_enabled
@@ -313,6 +383,10 @@ fn settings_round_trip_preserves_fields() {
         round_trip.core.controls.transient_markers_enabled,
         cfg.core.controls.transient_markers_enabled
     );
+    assert_eq!(
+        round_trip.core.controls.transient_max_markers,
+        cfg.core.controls.transient_max_markers
+    );
     assert_eq!(
         round_trip.core.controls.input_monitoring_enabled,
         cfg.core.controls.input_monitoring_enabled
@@ -333,6 +407,19 @@ fn settings_round_trip_preserves_fields() {
         round_trip.core.controls.loop_lock_enabled,
         cfg.core.controls.loop_lock_enabled
     );
+    assert_eq!(
+        round_trip.core.controls.loop_cycle_limit,
+        cfg.core.controls.loop_cycle_limit
+    );
+    assert_eq!(
+        round_trip.core.controls.load_selection_mode,
+        cfg.core.controls.load_selection_mode
+    );
+    assert_eq!(
+        round_trip.core.controls.high_contrast_theme,
+        cfg.core.controls.high_contrast_theme
+    );
+    assert_eq!(round_trip.core.layout, cfg.core.layout);
 }
 
 #[test]
@@ -1,14 +1,18 @@
 use serde::{Deserialize, Serialize};
 
 use super::super::config_defaults::{
-    default_analysis_worker_count, default_false, default_fast_similarity_prep_sample_rate,
-    default_long_sample_threshold_seconds, default_max_analysis_duration_seconds, default_true,
+    default_analysis_claim_batch, default_analysis_worker_count, default_false,
+    default_fast_similarity_prep_sample_rate, default_long_sample_threshold_seconds,
+    default_max_analysis_duration_seconds, default_similar_rerank_candidates,
+    default_similar_result_count, default_true,
 };
 
 /// Global preferences for analysis and feature extraction.
 ///
 ///   `limit_similarity_prep_duration`, `long_sample_threshold_seconds`,
-///   `fast_similarity_prep`, `fast_similarity_prep_sample_rate`.
+///   `analysis_claim_batch`, `fast_similarity_prep`, `fast_similarity_prep_sample_rate`,
+///   `pause_analysis_during_interaction`, `quantize_embeddings`, `similar_result_count`,
+///   `similar_rerank_candidates`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisSettings {
     /// Skip analysis for files longer than this many seconds.
@@ -23,12 +27,28 @@ pub struct AnalysisSettings {
     /// Analysis worker count override (0 = auto).
     #[serde(default = "default_analysis_worker_count")]
     pub analysis_worker_count: u32,
+    /// Jobs leased from a source per claim round (0 = auto). Larger batches mean fewer
+    /// database transactions; smaller ones spread work across sources more evenly.
+    #[serde(default = "default_analysis_claim_batch")]
+    pub analysis_claim_batch: u32,
     /// Use a faster, lower-quality analysis pass during similarity prep.
     #[serde(default = "default_false")]
     pub fast_similarity_prep: bool,
     /// Sample rate used during fast similarity prep analysis.
     #[serde(default = "default_fast_similarity_prep_sample_rate")]
     pub fast_similarity_prep_sample_rate: u32,
+    /// Pause analysis job claiming while a waveform drag or edit gesture is in progress.
+    #[serde(default = "default_true")]
+    pub pause_analysis_during_interaction: bool,
+    /// Store new similarity embeddings as int8 to shrink large source databases.
+    #[serde(default = "default_false")]
+    pub quantize_embeddings: bool,
+    /// Maximum number of results returned by similarity searches.
+    #[serde(default = "default_similar_result_count")]
+    pub similar_result_count: u32,
+    /// Number of nearest-neighbour candidates fetched before DSP re-ranking.
+    #[serde(default = "default_similar_rerank_candidates")]
+    pub similar_rerank_candidates: u32,
 }
 
 impl Default for AnalysisSettings {
@@ -38,8 +58,13 @@ impl Default for AnalysisSettings {
             limit_similarity_prep_duration: default_true(),
             long_sample_threshold_seconds: default_long_sample_threshold_seconds(),
             analysis_worker_count: default_analysis_worker_count(),
+            analysis_claim_batch: default_analysis_claim_batch(),
             fast_similarity_prep: default_false(),
             fast_similarity_prep_sample_rate: default_fast_similarity_prep_sample_rate(),
+            pause_analysis_during_interaction: default_true(),
+            quantize_embeddings: default_false(),
+            similar_result_count: default_similar_result_count(),
+            similar_rerank_candidates: default_similar_rerank_candidates(),
         }
     }
 }
@@ -9,17 +9,17 @@ use crate::{
 };
 
 use super::super::config_defaults::{
-    clamp_analysis_worker_count, clamp_job_message_queue_capacity, clamp_volume,
-    default_audio_input, default_audio_output, default_job_message_queue_capacity, default_true,
-    default_volume,
+    clamp_analysis_claim_batch, clamp_analysis_worker_count, clamp_job_message_queue_capacity,
+    clamp_volume, default_audio_input, default_audio_output, default_job_message_queue_capacity,
+    default_true, default_volume,
 };
-use super::{AnalysisSettings, InteractionOptions, UpdateSettings};
+use super::{AnalysisSettings, InteractionOptions, UpdateSettings, WindowLayout};
 
 /// Aggregate application state loaded from disk.
 ///
 /// Config keys (TOML): `feature_flags`, `analysis`, `updates`, `app_data_dir`,
-/// `trash_folder`, `drop_targets`, `last_selected_source`,
-/// `volume`, `audio_output`, `audio_input`, `controls`, `job_message_queue_capacity`.
+/// `trash_folder`, `trash_strategy`, `drop_targets`, `last_selected_source`,
+/// `volume`, `audio_output`, `audio_input`, `controls`, `layout`, `job_message_queue_capacity`.
 ///
 /// `sources` are stored in the library database.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,6 +84,9 @@ pub struct AppSettingsCore {
     #[serde(default)]
     /// Optional trash folder path.
     pub trash_folder: Option<PathBuf>,
+    #[serde(default)]
+    /// How samples tagged as Trash are disposed of.
+    pub trash_strategy: TrashStrategy,
     /// User-defined drop target folders used by the sidebar, with optional colors.
     #[serde(default, deserialize_with = "deserialize_drop_targets")]
     /// Drop target configurations for the sidebar.
@@ -103,6 +106,9 @@ pub struct AppSettingsCore {
     #[serde(default)]
     /// Interaction option defaults.
     pub controls: InteractionOptions,
+    #[serde(default)]
+    /// Window layout and panel sizes restored on startup.
+    pub layout: WindowLayout,
 }
 
 impl AppSettingsCore {
@@ -110,8 +116,11 @@ impl AppSettingsCore {
         self.volume = clamp_volume(self.volume);
         self.analysis.analysis_worker_count =
             clamp_analysis_worker_count(self.analysis.analysis_worker_count);
+        self.analysis.analysis_claim_batch =
+            clamp_analysis_claim_batch(self.analysis.analysis_claim_batch);
         self.job_message_queue_capacity =
             clamp_job_message_queue_capacity(self.job_message_queue_capacity);
+        self.layout = self.layout.normalized();
         self
     }
 }
@@ -138,6 +147,30 @@ pub enum DropTargetColor {
     Clay,
 }
 
+/// Disposal used when clearing out samples tagged as Trash.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TrashStrategy {
+    /// Move files into the configured trash folder.
+    #[default]
+    MoveToFolder,
+    /// Send files to the operating system's recycle bin.
+    OsTrash,
+    /// Delete files permanently.
+    DeletePermanently,
+}
+
+impl TrashStrategy {
+    /// Short label for menus and status text.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::MoveToFolder => "Move to trash folder",
+            Self::OsTrash => "Send to system trash",
+            Self::DeletePermanently => "Delete permanently",
+        }
+    }
+}
+
 /// Config data for a single drop target.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DropTargetConfig {
@@ -199,12 +232,14 @@ impl Default for AppSettingsCore {
             job_message_queue_capacity: default_job_message_queue_capacity(),
             app_data_dir: None,
             trash_folder: None,
+            trash_strategy: TrashStrategy::default(),
             drop_targets: Vec::new(),
             last_selected_source: None,
             audio_output: default_audio_output(),
             audio_input: default_audio_input(),
             volume: default_volume(),
             controls: InteractionOptions::default(),
+            layout: WindowLayout::default(),
         }
     }
 }
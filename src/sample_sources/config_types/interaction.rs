@@ -5,7 +5,8 @@ use crate::waveform::WaveformChannelView;
 
 use super::super::config_defaults::{
     default_anti_clip_fade_ms, default_bpm_value, default_false, default_keyboard_zoom_factor,
-    default_scroll_speed, default_tooltip_mode, default_true, default_wheel_zoom_factor,
+    default_playhead_trail_fade_seconds, default_playhead_trail_seconds, default_scroll_speed,
+    default_seek_crossfade_ms, default_tooltip_mode, default_true, default_wheel_zoom_factor,
 };
 
 /// Tooltip detail level.
@@ -35,14 +36,79 @@ impl Display for TooltipMode {
     }
 }
 
+/// Initial waveform selection applied when a sample loads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadSelectionMode {
+    /// Start with no active selection.
+    #[default]
+    None,
+    /// Select the whole file.
+    Full,
+}
+
+/// Per-operation confirmation toggles for destructive selection edits.
+///
+/// Only consulted when `destructive_yolo_mode` is off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DestructiveConfirmations {
+    /// Confirm before cropping to the selection.
+    #[serde(default = "default_true")]
+    pub crop: bool,
+    /// Confirm before trimming the selection out.
+    #[serde(default = "default_true")]
+    pub trim: bool,
+    /// Confirm before reversing the selection.
+    #[serde(default = "default_true")]
+    pub reverse: bool,
+    /// Confirm before applying directional fades.
+    #[serde(default = "default_true")]
+    pub fade: bool,
+    /// Confirm before adding short edge fades.
+    #[serde(default = "default_true")]
+    pub short_edge_fades: bool,
+    /// Confirm before muting the selection.
+    #[serde(default = "default_true")]
+    pub mute: bool,
+    /// Confirm before normalizing the selection.
+    #[serde(default = "default_true")]
+    pub normalize: bool,
+    /// Confirm before removing clicks.
+    #[serde(default = "default_true")]
+    pub click_removal: bool,
+    /// Confirm before pasting clipboard audio over the selection.
+    #[serde(default = "default_true")]
+    pub paste_clipboard: bool,
+}
+
+impl Default for DestructiveConfirmations {
+    fn default() -> Self {
+        Self {
+            crop: true,
+            trim: true,
+            reverse: true,
+            fade: true,
+            short_edge_fades: true,
+            mute: true,
+            normalize: true,
+            click_removal: true,
+            paste_clipboard: true,
+        }
+    }
+}
+
 /// Interaction tuning for waveform navigation.
 ///
 /// Config keys: `invert_waveform_scroll`, `waveform_scroll_speed`,
 /// `wheel_zoom_factor`, `keyboard_zoom_factor`, `anti_clip_fade_enabled`,
-/// `anti_clip_fade_ms`, `auto_edge_fades_on_selection_exports`, `destructive_yolo_mode`,
-/// `waveform_channel_view`, `bpm_snap_enabled`, `bpm_lock_enabled`, `bpm_stretch_enabled`,
-/// `bpm_value`, `transient_markers_enabled`, `transient_snap_enabled`,
-/// `input_monitoring_enabled`, `normalized_audition_enabled`, `loop_lock_enabled`.
+/// `anti_clip_fade_ms`, `seek_crossfade_enabled`, `seek_crossfade_ms`,
+/// `auto_edge_fades_on_selection_exports`, `markers_on_selection_exports`,
+/// `destructive_yolo_mode`, `destructive_confirmations`, `waveform_channel_view`,
+/// `bpm_snap_enabled`, `bpm_lock_enabled`, `bpm_stretch_enabled`, `bpm_value`,
+/// `metronome_enabled`, `transient_markers_enabled`, `transient_snap_enabled`,
+/// `transient_max_markers`, `input_monitoring_enabled`, `normalized_audition_enabled`,
+/// `loop_lock_enabled`, `loop_cycle_limit`, `playhead_trail_seconds`,
+/// `playhead_trail_fade_seconds`, `load_selection_mode`, `high_contrast_theme`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InteractionOptions {
     /// Invert mouse wheel direction for waveform scrolling.
@@ -63,12 +129,24 @@ pub struct InteractionOptions {
     /// Anti-clip fade duration in milliseconds.
     #[serde(default = "default_anti_clip_fade_ms")]
     pub anti_clip_fade_ms: f32,
+    /// Crossfade into the new position when seeking during playback instead of jumping.
+    #[serde(default = "default_false")]
+    pub seek_crossfade_enabled: bool,
+    /// Seek crossfade window in milliseconds.
+    #[serde(default = "default_seek_crossfade_ms")]
+    pub seek_crossfade_ms: f32,
     /// Auto-apply short edge fades when exporting new samples from selections.
     #[serde(default = "default_true")]
     pub auto_edge_fades_on_selection_exports: bool,
+    /// Write the waveform's markers into a `cue ` chunk when exporting new samples from selections.
+    #[serde(default)]
+    pub markers_on_selection_exports: bool,
     /// Allow destructive edits without confirmation.
     #[serde(default)]
     pub destructive_yolo_mode: bool,
+    /// Which destructive edits ask for confirmation when yolo mode is off.
+    #[serde(default)]
+    pub destructive_confirmations: DestructiveConfirmations,
     /// Default waveform channel visualization mode.
     #[serde(default)]
     pub waveform_channel_view: WaveformChannelView,
@@ -84,12 +162,18 @@ pub struct InteractionOptions {
     /// BPM value used for snapping and stretching.
     #[serde(default = "default_bpm_value")]
     pub bpm_value: f32,
+    /// Mix a metronome click on every beat of the BPM value during playback.
+    #[serde(default = "default_false")]
+    pub metronome_enabled: bool,
     /// Snap selections to detected transient markers.
     #[serde(default = "default_false")]
     pub transient_snap_enabled: bool,
     /// Render transient markers in the waveform UI.
     #[serde(default = "default_true")]
     pub transient_markers_enabled: bool,
+    /// Hard cap on detected transient markers per sample (`None` derives it from duration).
+    #[serde(default)]
+    pub transient_max_markers: Option<u32>,
     /// Enable live input monitoring during recording.
     #[serde(default = "default_true")]
     pub input_monitoring_enabled: bool,
@@ -105,6 +189,21 @@ pub struct InteractionOptions {
     /// Lock loop playback state to prevent auto-updates on sample load/selection.
     #[serde(default = "default_false")]
     pub loop_lock_enabled: bool,
+    /// Stop looped playback after this many cycles (`None` loops forever).
+    #[serde(default)]
+    pub loop_cycle_limit: Option<u32>,
+    /// Length of the playhead trail in seconds (0 disables the trail).
+    #[serde(default = "default_playhead_trail_seconds")]
+    pub playhead_trail_seconds: f32,
+    /// Time in seconds for a detached playhead trail to fade out.
+    #[serde(default = "default_playhead_trail_fade_seconds")]
+    pub playhead_trail_fade_seconds: f32,
+    /// Selection applied to a freshly loaded sample.
+    #[serde(default)]
+    pub load_selection_mode: LoadSelectionMode,
+    /// Use the high-contrast colour theme.
+    #[serde(default = "default_false")]
+    pub high_contrast_theme: bool,
 }
 
 impl Default for InteractionOptions {
@@ -116,20 +215,31 @@ impl Default for InteractionOptions {
             keyboard_zoom_factor: default_keyboard_zoom_factor(),
             anti_clip_fade_enabled: true,
             anti_clip_fade_ms: default_anti_clip_fade_ms(),
+            seek_crossfade_enabled: default_false(),
+            seek_crossfade_ms: default_seek_crossfade_ms(),
             auto_edge_fades_on_selection_exports: default_true(),
+            markers_on_selection_exports: false,
             destructive_yolo_mode: false,
+            destructive_confirmations: DestructiveConfirmations::default(),
             waveform_channel_view: WaveformChannelView::Mono,
             bpm_snap_enabled: default_false(),
             bpm_lock_enabled: default_false(),
             bpm_stretch_enabled: default_false(),
             bpm_value: default_bpm_value(),
+            metronome_enabled: default_false(),
             transient_snap_enabled: default_false(),
             transient_markers_enabled: default_true(),
+            transient_max_markers: None,
             input_monitoring_enabled: default_true(),
             normalized_audition_enabled: default_false(),
             advance_after_rating: true,
             tooltip_mode: default_tooltip_mode(),
             loop_lock_enabled: default_false(),
+            loop_cycle_limit: None,
+            playhead_trail_seconds: default_playhead_trail_seconds(),
+            playhead_trail_fade_seconds: default_playhead_trail_fade_seconds(),
+            load_selection_mode: LoadSelectionMode::None,
+            high_contrast_theme: default_false(),
         }
     }
 }
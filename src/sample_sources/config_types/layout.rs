@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// Smallest persisted map window edge in points.
+const MIN_MAP_WINDOW_EDGE: f32 = 120.0;
+
+/// Persisted window layout and panel sizes.
+///
+/// Config keys: `sources_split`, `drop_targets_split`, `map_open`, `map_size`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WindowLayout {
+    /// Share of the sources panel height given to the sources list (`None` uses the default).
+    #[serde(default)]
+    pub sources_split: Option<f32>,
+    /// Share of the sources panel height given to the drop targets (`None` uses the default).
+    #[serde(default)]
+    pub drop_targets_split: Option<f32>,
+    /// Whether the sample map window was open.
+    #[serde(default)]
+    pub map_open: bool,
+    /// Last map window size as `[width, height]` in points.
+    #[serde(default)]
+    pub map_size: Option<[f32; 2]>,
+}
+
+impl WindowLayout {
+    pub(super) fn normalized(self) -> Self {
+        Self {
+            sources_split: self.sources_split.and_then(clamp_split_ratio),
+            drop_targets_split: self.drop_targets_split.and_then(clamp_split_ratio),
+            map_open: self.map_open,
+            map_size: self.map_size.filter(|size| {
+                size.iter()
+                    .all(|edge| edge.is_finite() && *edge >= MIN_MAP_WINDOW_EDGE)
+            }),
+        }
+    }
+}
+
+/// Clamp a panel split ratio into `0.0..=1.0`, dropping non-finite values.
+pub fn clamp_split_ratio(ratio: f32) -> Option<f32> {
+    ratio.is_finite().then(|| ratio.clamp(0.0, 1.0))
+}
@@ -2,13 +2,17 @@ mod analysis;
 mod app;
 mod errors;
 mod interaction;
+mod layout;
 mod updates;
 
 pub use analysis::AnalysisSettings;
 pub(crate) use app::AppSettings;
 pub use app::{
-    AppConfig, AppSettingsCore, DropTargetColor, DropTargetConfig, FeatureFlags,
+    AppConfig, AppSettingsCore, DropTargetColor, DropTargetConfig, FeatureFlags, TrashStrategy,
 };
 pub use errors::ConfigError;
-pub use interaction::{InteractionOptions, TooltipMode};
+pub use interaction::{
+    DestructiveConfirmations, InteractionOptions, LoadSelectionMode, TooltipMode,
+};
+pub use layout::{WindowLayout, clamp_split_ratio};
 pub use updates::{UpdateChannel, UpdateSettings};
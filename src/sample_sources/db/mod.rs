@@ -24,6 +24,8 @@ pub use util::normalize_relative_path;
 pub const DB_FILE_NAME: &str = ".sempal_samples.db";
 /// Metadata key for the last completed scan timestamp.
 pub const META_LAST_SCAN_COMPLETED_AT: &str = "last_scan_completed_at";
+/// Metadata key for when the source's analysis queue last drained.
+pub const META_LAST_ANALYSIS_COMPLETED_AT: &str = "last_analysis_completed_at";
 /// Metadata key for the last similarity-prep scan timestamp.
 pub const META_LAST_SIMILARITY_PREP_SCAN_AT: &str = "last_similarity_prep_scan_at";
 
@@ -77,6 +79,15 @@ impl Rating {
         self.0 < 0
     }
 
+    /// Short display label matching the tag menu, such as `Keep (+2)` or `Trash (-3)`.
+    pub fn label(self) -> String {
+        match self.0 {
+            0 => "Neutral (0)".to_string(),
+            value if value > 0 => format!("Keep (+{value})"),
+            value => format!("Trash ({value})"),
+        }
+    }
+
     /// Convert the tag to a SQLite-friendly integer.
     pub fn as_i64(self) -> i64 {
         self.0 as i64
@@ -89,6 +100,16 @@ impl Rating {
     }
 }
 
+/// Highest star rating a sample can carry; stars are independent of the keep/trash tag.
+pub const MAX_STARS: u8 = 5;
+
+/// Parse a stored star column, ignoring values outside `1..=MAX_STARS`.
+pub(crate) fn stars_from_i64(value: i64) -> Option<u8> {
+    (1..=MAX_STARS as i64)
+        .contains(&value)
+        .then_some(value as u8)
+}
+
 /// Details about a wav file stored in a source database.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WavEntry {
@@ -110,6 +131,59 @@ pub struct WavEntry {
     /// Epoch seconds of the most recent playback, if any.
     #[serde(default)]
     pub last_played_at: Option<i64>,
+    /// Optional 1-5 star rating, stored alongside the keep/trash tag.
+    #[serde(default)]
+    pub stars: Option<u8>,
+}
+
+/// Audio stream properties probed from a file during scanning.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AudioMetadata {
+    /// Total duration in seconds, when the container reports it.
+    pub duration_seconds: Option<f32>,
+    /// Native sample rate in Hz.
+    pub sample_rate: Option<u32>,
+    /// Channel count.
+    pub channels: Option<u16>,
+}
+
+/// How much of a source has been analyzed and embedded, from cheap row counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnalysisCoverage {
+    /// Supported audio files present on disk.
+    pub total: usize,
+    /// Samples with a stored feature vector.
+    pub analyzed: usize,
+    /// Samples with a stored embedding.
+    pub embedded: usize,
+}
+
+impl AnalysisCoverage {
+    /// Fraction of present files that have features, in `[0, 1]`.
+    pub fn analyzed_fraction(&self) -> f32 {
+        fraction(self.analyzed, self.total)
+    }
+
+    /// Fraction of present files that have embeddings, in `[0, 1]`.
+    pub fn embedded_fraction(&self) -> f32 {
+        fraction(self.embedded, self.total)
+    }
+
+    /// Short badge text such as `85% analyzed / 60% embedded`.
+    pub fn label(&self) -> String {
+        format!(
+            "{:.0}% analyzed / {:.0}% embedded",
+            self.analyzed_fraction() * 100.0,
+            self.embedded_fraction() * 100.0
+        )
+    }
+}
+
+fn fraction(count: usize, total: usize) -> f32 {
+    if total == 0 {
+        return 0.0;
+    }
+    (count as f32 / total as f32).min(1.0)
 }
 
 /// Errors returned when managing a source database.
@@ -135,6 +209,9 @@ pub enum SourceDbError {
     /// Provided path contained disallowed components or was empty.
     #[error("Path contains invalid relative components: {0}")]
     InvalidRelativePath(PathBuf),
+    /// No `wav_files` row exists for the given relative path.
+    #[error("No database entry for {0}")]
+    MissingEntry(PathBuf),
     /// Database is locked or busy.
     #[error("Database is busy, please retry")]
     Busy,
@@ -419,6 +496,25 @@ mod tests {
         assert!(!rows[0].missing);
     }
 
+    #[test]
+    fn user_markers_round_trip() {
+        let dir = tempdir().unwrap();
+        let db = SourceDatabase::open(dir.path()).unwrap();
+        db.upsert_file(Path::new("one.wav"), 10, 5).unwrap();
+        assert!(db.user_markers_for_path(Path::new("one.wav")).unwrap().is_empty());
+        db.set_user_markers(Path::new("one.wav"), &[0.25, 0.5]).unwrap();
+        assert_eq!(
+            db.user_markers_for_path(Path::new("one.wav")).unwrap(),
+            vec![0.25, 0.5]
+        );
+        db.set_user_markers(Path::new("one.wav"), &[]).unwrap();
+        assert!(db.user_markers_for_path(Path::new("one.wav")).unwrap().is_empty());
+        assert!(matches!(
+            db.set_user_markers(Path::new("gone.wav"), &[0.5]),
+            Err(SourceDbError::MissingEntry(_))
+        ));
+    }
+
     #[test]
     fn missing_flag_round_trips() {
         let dir = tempdir().unwrap();
@@ -446,6 +542,50 @@ mod tests {
         assert!(db.index_for_path(Path::new("notes.txt")).unwrap().is_none());
     }
 
+    #[test]
+    fn analysis_coverage_reports_feature_and_embedding_fractions() {
+        let dir = tempdir().unwrap();
+        let db = SourceDatabase::open(dir.path()).unwrap();
+        for name in ["a.wav", "b.wav", "c.wav", "d.wav", "gone.wav"] {
+            db.upsert_file(Path::new(name), 10, 5).unwrap();
+        }
+        db.connection
+            .execute("UPDATE wav_files SET missing = 1 WHERE path = 'gone.wav'", [])
+            .unwrap();
+        for id in ["s::a.wav", "s::b.wav", "s::c.wav", "s::gone.wav", "s::deleted.wav"] {
+            db.connection
+                .execute(
+                    "INSERT INTO features (sample_id, feat_version, vec_blob, computed_at)
+                     VALUES (?1, 1, x'00', 0)",
+                    params![id],
+                )
+                .unwrap();
+        }
+        for (id, model) in [("s::a.wav", "m"), ("s::b.wav", "m"), ("s::c.wav", "old")] {
+            db.connection
+                .execute(
+                    "INSERT INTO embeddings (sample_id, model_id, dim, dtype, l2_normed, vec, created_at)
+                     VALUES (?1, ?2, 1, 'f32', 1, x'00000000', 0)",
+                    params![id, model],
+                )
+                .unwrap();
+        }
+
+        let coverage = db.analysis_coverage("s", "m").unwrap();
+        assert_eq!(
+            coverage,
+            AnalysisCoverage {
+                total: 4,
+                analyzed: 3,
+                embedded: 2,
+            }
+        );
+        assert!((coverage.analyzed_fraction() - 0.75).abs() < 1e-6);
+        assert!((coverage.embedded_fraction() - 0.5).abs() < 1e-6);
+        assert_eq!(coverage.label(), "75% analyzed / 50% embedded");
+        assert_eq!(AnalysisCoverage::default().analyzed_fraction(), 0.0);
+    }
+
     #[test]
     fn applies_workload_pragmas_and_indices() {
         let dir = tempdir().unwrap();
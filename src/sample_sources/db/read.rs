@@ -1,7 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use super::util::{map_sql_error, parse_relative_path_from_db};
-use super::{SourceDatabase, SourceDbError, WavEntry};
+use super::{AnalysisCoverage, AudioMetadata, SourceDatabase, SourceDbError, WavEntry};
 use rusqlite::OptionalExtension;
 
 impl SourceDatabase {
@@ -9,7 +9,8 @@ impl SourceDatabase {
     pub fn list_files(&self) -> Result<Vec<WavEntry>, SourceDbError> {
         let filter = crate::sample_sources::supported_audio_where_clause();
         let sql = format!(
-            "SELECT path, file_size, modified_ns, content_hash, tag, looped, missing, last_played_at
+            "SELECT path, file_size, modified_ns, content_hash, tag, looped, missing, last_played_at,
+                    stars
              FROM wav_files
              WHERE {filter}
              ORDER BY path ASC"
@@ -36,6 +37,7 @@ impl SourceDatabase {
                     looped: row.get::<_, i64>(5)? != 0,
                     missing: row.get::<_, i64>(6)? != 0,
                     last_played_at: row.get(7)?,
+                    stars: row.get::<_, Option<i64>>(8)?.and_then(super::stars_from_i64),
                 }))
             })
             .map_err(map_sql_error)?
@@ -48,7 +50,8 @@ impl SourceDatabase {
     pub fn list_files_by_tag(&self, tag: super::Rating) -> Result<Vec<WavEntry>, SourceDbError> {
         let filter = crate::sample_sources::supported_audio_where_clause();
         let sql = format!(
-            "SELECT path, file_size, modified_ns, content_hash, tag, looped, missing, last_played_at
+            "SELECT path, file_size, modified_ns, content_hash, tag, looped, missing, last_played_at,
+                    stars
              FROM wav_files
              WHERE {filter} AND tag = ?1
              ORDER BY path ASC"
@@ -75,6 +78,7 @@ impl SourceDatabase {
                     looped: row.get::<_, i64>(5)? != 0,
                     missing: row.get::<_, i64>(6)? != 0,
                     last_played_at: row.get(7)?,
+                    stars: row.get::<_, Option<i64>>(8)?.and_then(super::stars_from_i64),
                 }))
             })
             .map_err(map_sql_error)?
@@ -130,6 +134,38 @@ impl SourceDatabase {
         Ok(count.max(0) as usize)
     }
 
+    /// Count present files and how many of them have features and `model_id` embeddings.
+    ///
+    /// Rows for deleted or missing files are ignored, so the fractions never exceed the
+    /// files actually on disk.
+    pub fn analysis_coverage(
+        &self,
+        source_id: &str,
+        model_id: &str,
+    ) -> Result<AnalysisCoverage, SourceDbError> {
+        let filter = crate::sample_sources::supported_audio_where_clause();
+        let sep = crate::sample_sources::sample_id::SAMPLE_ID_SEPARATOR;
+        let sql = format!(
+            "SELECT COUNT(*), COUNT(f.sample_id), COUNT(e.sample_id)
+             FROM wav_files w
+             LEFT JOIN features f ON f.sample_id = ?1 || '{sep}' || w.path
+             LEFT JOIN embeddings e
+                ON e.sample_id = ?1 || '{sep}' || w.path AND e.model_id = ?2
+             WHERE {filter} AND w.missing = 0"
+        );
+        let (total, analyzed, embedded): (i64, i64, i64) = self
+            .connection
+            .query_row(&sql, rusqlite::params![source_id, model_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })
+            .map_err(map_sql_error)?;
+        Ok(AnalysisCoverage {
+            total: total.max(0) as usize,
+            analyzed: analyzed.max(0) as usize,
+            embedded: embedded.max(0) as usize,
+        })
+    }
+
     /// Fetch a page of tracked wav files ordered by path.
     pub fn list_files_page(
         &self,
@@ -138,7 +174,8 @@ impl SourceDatabase {
     ) -> Result<Vec<WavEntry>, SourceDbError> {
         let filter = crate::sample_sources::supported_audio_where_clause();
         let sql = format!(
-            "SELECT path, file_size, modified_ns, content_hash, tag, looped, missing, last_played_at
+            "SELECT path, file_size, modified_ns, content_hash, tag, looped, missing, last_played_at,
+                    stars
              FROM wav_files
              WHERE {filter}
              ORDER BY path ASC
@@ -166,6 +203,7 @@ impl SourceDatabase {
                     looped: row.get::<_, i64>(5)? != 0,
                     missing: row.get::<_, i64>(6)? != 0,
                     last_played_at: row.get(7)?,
+                    stars: row.get::<_, Option<i64>>(8)?.and_then(super::stars_from_i64),
                 }))
             })
             .map_err(map_sql_error)?
@@ -188,6 +226,28 @@ impl SourceDatabase {
         Ok(bpm.map(|value| value as f32))
     }
 
+    /// Fetch the similarity embedding stored for a specific sample id, when available.
+    pub fn embedding_for_sample_id(
+        &self,
+        sample_id: &str,
+    ) -> Result<Option<Vec<f32>>, SourceDbError> {
+        let row: Option<(String, Vec<u8>)> = self
+            .connection
+            .query_row(
+                "SELECT dtype, vec FROM embeddings WHERE sample_id = ?1 AND model_id = ?2",
+                rusqlite::params![sample_id, crate::analysis::similarity::SIMILARITY_MODEL_ID],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(map_sql_error)?;
+        let Some((dtype, blob)) = row else {
+            return Ok(None);
+        };
+        crate::analysis::vector::decode_embedding_blob(&blob, &dtype)
+            .map(Some)
+            .map_err(|_| SourceDbError::Unexpected)
+    }
+
     /// Find the sorted index for a tracked wav path.
     pub fn index_for_path(&self, path: &Path) -> Result<Option<usize>, SourceDbError> {
         if !crate::sample_sources::is_supported_audio(path) {
@@ -265,6 +325,100 @@ impl SourceDatabase {
         Ok(value)
     }
 
+    /// Fetch the 1-5 star rating for a specific wav path, if one is set.
+    pub fn stars_for_path(&self, path: &Path) -> Result<Option<u8>, SourceDbError> {
+        if !crate::sample_sources::is_supported_audio(path) {
+            return Ok(None);
+        }
+        let path_str = super::normalize_relative_path(path)?;
+        let value = self
+            .connection
+            .query_row(
+                "SELECT stars FROM wav_files WHERE path = ?1",
+                rusqlite::params![path_str.as_str()],
+                |row| row.get::<_, Option<i64>>(0),
+            )
+            .optional()
+            .map_err(map_sql_error)?
+            .flatten();
+        Ok(value.and_then(super::stars_from_i64))
+    }
+
+    /// Fetch user-placed waveform markers (normalized positions) for a specific wav path.
+    pub fn user_markers_for_path(&self, path: &Path) -> Result<Vec<f32>, SourceDbError> {
+        if !crate::sample_sources::is_supported_audio(path) {
+            return Ok(Vec::new());
+        }
+        let path_str = super::normalize_relative_path(path)?;
+        let value = self
+            .connection
+            .query_row(
+                "SELECT user_markers FROM wav_files WHERE path = ?1",
+                rusqlite::params![path_str.as_str()],
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .optional()
+            .map_err(map_sql_error)?
+            .flatten();
+        match value {
+            Some(encoded) => {
+                serde_json::from_str(&encoded).map_err(|_| SourceDbError::Unexpected)
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Fetch probed audio stream properties for a specific wav path.
+    pub fn audio_metadata_for_path(
+        &self,
+        path: &Path,
+    ) -> Result<Option<AudioMetadata>, SourceDbError> {
+        if !crate::sample_sources::is_supported_audio(path) {
+            return Ok(None);
+        }
+        let path_str = super::normalize_relative_path(path)?;
+        let value = self
+            .connection
+            .query_row(
+                "SELECT duration_seconds, sample_rate, channels FROM wav_files WHERE path = ?1",
+                rusqlite::params![path_str.as_str()],
+                |row| {
+                    Ok(AudioMetadata {
+                        duration_seconds: row.get::<_, Option<f64>>(0)?.map(|value| value as f32),
+                        sample_rate: row.get::<_, Option<i64>>(1)?.map(|value| value as u32),
+                        channels: row.get::<_, Option<i64>>(2)?.map(|value| value as u16),
+                    })
+                },
+            )
+            .optional()
+            .map_err(map_sql_error)?;
+        Ok(value)
+    }
+
+    /// Fetch paths whose audio stream properties have not been probed yet.
+    ///
+    /// Files whose probe already failed are skipped until their size or mtime changes.
+    pub fn list_unprobed_paths(&self) -> Result<Vec<PathBuf>, SourceDbError> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT path FROM wav_files
+                 WHERE sample_rate IS NULL AND missing = 0
+                   AND (probe_failed_size IS NOT file_size
+                        OR probe_failed_modified_ns IS NOT modified_ns)",
+            )
+            .map_err(map_sql_error)?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(map_sql_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(map_sql_error)?;
+        Ok(rows
+            .into_iter()
+            .filter_map(|path| parse_relative_path_from_db(&path).ok())
+            .collect())
+    }
+
     /// Read a metadata value by key from the database.
     pub fn get_metadata(&self, key: &str) -> Result<Option<String>, SourceDbError> {
         let value: Option<String> = self
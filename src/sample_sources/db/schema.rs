@@ -19,7 +19,14 @@ pub(super) fn apply_schema(connection: &Connection) -> Result<(), SourceDbError>
                 looped INTEGER NOT NULL DEFAULT 0,
                 missing INTEGER NOT NULL DEFAULT 0,
                 extension TEXT NOT NULL DEFAULT '',
-                last_played_at INTEGER
+                last_played_at INTEGER,
+                stars INTEGER,
+                duration_seconds REAL,
+                sample_rate INTEGER,
+                channels INTEGER,
+                user_markers TEXT,
+                probe_failed_size INTEGER,
+                probe_failed_modified_ns INTEGER
              );
              CREATE TABLE IF NOT EXISTS analysis_jobs (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -33,6 +40,10 @@ pub(super) fn apply_schema(connection: &Connection) -> Result<(), SourceDbError>
                 created_at INTEGER NOT NULL,
                 running_at INTEGER,
                 last_error TEXT,
+                decode_outcome TEXT,
+                analysis_warning TEXT,
+                silent INTEGER NOT NULL DEFAULT 0,
+                retry_at INTEGER,
                 UNIQUE(sample_id, job_type)
              );
              CREATE INDEX IF NOT EXISTS idx_analysis_jobs_status_created_id
@@ -257,6 +268,41 @@ fn ensure_wav_files_optional_columns(connection: &Connection) -> Result<(), Sour
             .execute("ALTER TABLE wav_files ADD COLUMN last_played_at INTEGER", [])
             .map_err(map_sql_error)?;
     }
+    if !columns.contains("stars") {
+        connection
+            .execute("ALTER TABLE wav_files ADD COLUMN stars INTEGER", [])
+            .map_err(map_sql_error)?;
+    }
+    if !columns.contains("duration_seconds") {
+        connection
+            .execute("ALTER TABLE wav_files ADD COLUMN duration_seconds REAL", [])
+            .map_err(map_sql_error)?;
+    }
+    if !columns.contains("sample_rate") {
+        connection
+            .execute("ALTER TABLE wav_files ADD COLUMN sample_rate INTEGER", [])
+            .map_err(map_sql_error)?;
+    }
+    if !columns.contains("channels") {
+        connection
+            .execute("ALTER TABLE wav_files ADD COLUMN channels INTEGER", [])
+            .map_err(map_sql_error)?;
+    }
+    if !columns.contains("user_markers") {
+        connection
+            .execute("ALTER TABLE wav_files ADD COLUMN user_markers TEXT", [])
+            .map_err(map_sql_error)?;
+    }
+    if !columns.contains("probe_failed_size") {
+        connection
+            .execute("ALTER TABLE wav_files ADD COLUMN probe_failed_size INTEGER", [])
+            .map_err(map_sql_error)?;
+    }
+    if !columns.contains("probe_failed_modified_ns") {
+        connection
+            .execute("ALTER TABLE wav_files ADD COLUMN probe_failed_modified_ns INTEGER", [])
+            .map_err(map_sql_error)?;
+    }
     Ok(())
 }
 
@@ -324,6 +370,29 @@ fn ensure_analysis_jobs_optional_columns(connection: &Connection) -> Result<(),
             )
             .map_err(map_sql_error)?;
     }
+    if !columns.contains("decode_outcome") {
+        connection
+            .execute("ALTER TABLE analysis_jobs ADD COLUMN decode_outcome TEXT", [])
+            .map_err(map_sql_error)?;
+    }
+    if !columns.contains("analysis_warning") {
+        connection
+            .execute("ALTER TABLE analysis_jobs ADD COLUMN analysis_warning TEXT", [])
+            .map_err(map_sql_error)?;
+    }
+    if !columns.contains("retry_at") {
+        connection
+            .execute("ALTER TABLE analysis_jobs ADD COLUMN retry_at INTEGER", [])
+            .map_err(map_sql_error)?;
+    }
+    if !columns.contains("silent") {
+        connection
+            .execute(
+                "ALTER TABLE analysis_jobs ADD COLUMN silent INTEGER NOT NULL DEFAULT 0",
+                [],
+            )
+            .map_err(map_sql_error)?;
+    }
     Ok(())
 }
 
@@ -1,6 +1,7 @@
 use std::path::{Component, Path, PathBuf};
 
 use super::SourceDbError;
+use crate::sample_sources::path_text::{decode_path_text, encode_path_text};
 
 /// Translate rusqlite errors into friendlier SourceDbError variants.
 pub(super) fn map_sql_error(err: rusqlite::Error) -> SourceDbError {
@@ -19,17 +20,18 @@ pub(super) fn map_sql_error(err: rusqlite::Error) -> SourceDbError {
 
 /// Normalize a relative path for stable database storage.
 ///
-/// Rejects absolute paths, parent traversal, root prefixes, and empty paths.
+/// Rejects absolute paths, parent traversal, root prefixes, and empty paths. Non-UTF-8
+/// names are percent-encoded so they read back to the same bytes.
 pub fn normalize_relative_path(path: &Path) -> Result<String, SourceDbError> {
     let cleaned = sanitize_relative_path(path)?;
-    Ok(cleaned.to_string_lossy().replace('\\', "/"))
+    Ok(encode_path_text(&cleaned))
 }
 
 /// Parse and validate a stored relative path from the database.
 ///
 /// Returns a normalized `PathBuf` without `.` components.
 pub(super) fn parse_relative_path_from_db(path: &str) -> Result<PathBuf, SourceDbError> {
-    sanitize_relative_path(Path::new(path))
+    sanitize_relative_path(&decode_path_text(path))
 }
 
 /// Validate a relative path and normalize away `.` components.
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use rusqlite::params;
 
 use super::util::{map_sql_error, normalize_relative_path};
-use super::{Rating, SourceDatabase, SourceDbError, SourceWriteBatch};
+use super::{AudioMetadata, Rating, SourceDatabase, SourceDbError, SourceWriteBatch};
 
 impl SourceDatabase {
     /// Upsert a wav file row using the path relative to the source root.
@@ -102,6 +102,41 @@ impl SourceDatabase {
         Ok(())
     }
 
+    /// Persist a 1-5 star rating for a wav file; `None` clears it.
+    pub fn set_stars(&self, relative_path: &Path, stars: Option<u8>) -> Result<(), SourceDbError> {
+        let mut batch = self.write_batch()?;
+        batch.set_stars(relative_path, stars)?;
+        batch.commit()
+    }
+
+    /// Persist user-placed waveform markers (normalized positions) for a wav file.
+    ///
+    /// Fails with [`SourceDbError::MissingEntry`] when the file has no database row.
+    pub fn set_user_markers(
+        &self,
+        relative_path: &Path,
+        markers: &[f32],
+    ) -> Result<(), SourceDbError> {
+        let path = normalize_relative_path(relative_path)?;
+        let encoded = if markers.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(markers).map_err(|_| SourceDbError::Unexpected)?)
+        };
+        let updated = self
+            .connection
+            .execute(
+                "UPDATE wav_files SET user_markers = ?1 WHERE path = ?2",
+                params![encoded, path],
+            )
+            .map_err(map_sql_error)?;
+        if updated == 0 {
+            return Err(SourceDbError::MissingEntry(relative_path.to_path_buf()));
+        }
+        Self::bump_revision(&self.connection)?;
+        Ok(())
+    }
+
     /// Remove a wav file row by relative path.
     pub fn remove_file(&self, relative_path: &Path) -> Result<(), SourceDbError> {
         let path = normalize_relative_path(relative_path)?;
@@ -355,6 +390,61 @@ impl<'conn> SourceWriteBatch<'conn> {
         Ok(())
     }
 
+    /// Update the star rating for a wav row within the batch.
+    pub fn set_stars(
+        &mut self,
+        relative_path: &Path,
+        stars: Option<u8>,
+    ) -> Result<(), SourceDbError> {
+        let path = normalize_relative_path(relative_path)?;
+        let value = stars.map(|stars| stars.clamp(1, super::MAX_STARS) as i64);
+        self.tx
+            .prepare_cached("UPDATE wav_files SET stars = ?1 WHERE path = ?2")
+            .map_err(map_sql_error)?
+            .execute(params![value, path])
+            .map_err(map_sql_error)?;
+        Ok(())
+    }
+
+    /// Store probed audio stream properties for a wav row within the batch.
+    pub fn set_audio_metadata(
+        &mut self,
+        relative_path: &Path,
+        metadata: &AudioMetadata,
+    ) -> Result<(), SourceDbError> {
+        let path = normalize_relative_path(relative_path)?;
+        self.tx
+            .prepare_cached(
+                "UPDATE wav_files SET duration_seconds = ?1, sample_rate = ?2, channels = ?3,
+                     probe_failed_size = NULL, probe_failed_modified_ns = NULL
+                 WHERE path = ?4",
+            )
+            .map_err(map_sql_error)?
+            .execute(params![
+                metadata.duration_seconds.map(f64::from),
+                metadata.sample_rate.map(i64::from),
+                metadata.channels.map(i64::from),
+                path
+            ])
+            .map_err(map_sql_error)?;
+        Ok(())
+    }
+
+    /// Remember that probing a wav row failed for its current size and mtime.
+    pub fn mark_probe_failed(&mut self, relative_path: &Path) -> Result<(), SourceDbError> {
+        let path = normalize_relative_path(relative_path)?;
+        self.tx
+            .prepare_cached(
+                "UPDATE wav_files
+                 SET probe_failed_size = file_size, probe_failed_modified_ns = modified_ns
+                 WHERE path = ?1",
+            )
+            .map_err(map_sql_error)?
+            .execute(params![path])
+            .map_err(map_sql_error)?;
+        Ok(())
+    }
+
     /// Remove a wav row within the batch.
     pub fn remove_file(&mut self, relative_path: &Path) -> Result<(), SourceDbError> {
         let path = normalize_relative_path(relative_path)?;
@@ -3,7 +3,7 @@
 use std::path::{Path, PathBuf};
 use std::sync::{LazyLock, Mutex};
 
-use rusqlite::{Connection, Transaction, params};
+use rusqlite::{Connection, OpenFlags, Transaction, params};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::warn;
@@ -12,6 +12,7 @@ mod migrations;
 mod schema_checks;
 mod schema_defs;
 
+use super::path_text::encode_uri_path;
 use super::{SampleSource, SourceId};
 use crate::app_dirs;
 use crate::sample_sources::config::normalize_path;
@@ -50,22 +51,63 @@ pub enum LibraryError {
     /// Failed to deserialize JSON metadata from the DB.
     #[error("Library metadata parse failed: {0}")]
     Json(#[from] serde_json::Error),
+    /// The library database cannot be written, so edits are disabled.
+    #[error("Library database {path} is read-only; edits are disabled")]
+    ReadOnly {
+        /// Path to the read-only database file.
+        path: PathBuf,
+    },
+}
+
+/// Whether the library database can be written on this machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibraryAccess {
+    /// Normal mode: sources can be added, removed, and reordered.
+    ReadWrite,
+    /// Browsing and playback only; the database file or folder is not writable.
+    ReadOnly,
 }
 
 /// Load all sources from the global library database, creating it if missing.
+///
+/// A read-only database is opened without applying schema changes; a read-only location
+/// without a database yields an empty library instead of an error.
 pub fn load() -> Result<LibraryState, LibraryError> {
     let _guard = lock_library();
-    let db = LibraryDatabase::open()?;
+    let db = match LibraryDatabase::open() {
+        Err(LibraryError::ReadOnly { .. }) => return Ok(LibraryState::default()),
+        result => result?,
+    };
     db.load_state()
 }
 
 /// Persist sources to the global library database, replacing existing rows.
+///
+/// When the database is read-only, saving the already-stored sources is a no-op so settings
+/// can still be persisted; any actual change returns [`LibraryError::ReadOnly`].
 pub fn save(state: &LibraryState) -> Result<(), LibraryError> {
     let _guard = lock_library();
+    let db_path = database_path()?;
+    if detect_access(&db_path) == LibraryAccess::ReadOnly {
+        let stored = match LibraryDatabase::open() {
+            Ok(db) => db.load_state()?,
+            Err(LibraryError::ReadOnly { .. }) => LibraryState::default(),
+            Err(err) => return Err(err),
+        };
+        if same_sources(&stored.sources, &state.sources) {
+            return Ok(());
+        }
+        return Err(LibraryError::ReadOnly { path: db_path });
+    }
     let mut db = LibraryDatabase::open()?;
     db.replace_state(state)
 }
 
+/// Report whether the library database can be written.
+pub fn access_mode() -> Result<LibraryAccess, LibraryError> {
+    Ok(detect_access(&database_path()?))
+}
+
 /// Open a connection to the library DB with schema + migrations applied.
 pub fn open_connection() -> Result<Connection, LibraryError> {
     let _guard = lock_library();
@@ -100,6 +142,9 @@ struct LibraryDatabase {
 impl LibraryDatabase {
     fn open() -> Result<Self, LibraryError> {
         let db_path = database_path()?;
+        if detect_access(&db_path) == LibraryAccess::ReadOnly {
+            return Self::open_read_only(&db_path);
+        }
         create_parent_if_needed(&db_path)?;
         let connection = Connection::open(&db_path)?;
         let mut db = Self { connection };
@@ -115,6 +160,28 @@ impl LibraryDatabase {
         Ok(db)
     }
 
+    fn open_read_only(db_path: &Path) -> Result<Self, LibraryError> {
+        if !db_path.is_file() {
+            return Err(LibraryError::ReadOnly {
+                path: db_path.to_path_buf(),
+            });
+        }
+        let connection = match Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        {
+            Ok(connection) => connection,
+            Err(err) => {
+                // WAL databases need a writable `-shm` file; fall back to an immutable snapshot.
+                warn!("Read-only library DB open failed ({err}); retrying as immutable.");
+                let uri = format!("file:{}?immutable=1", encode_uri_path(db_path));
+                Connection::open_with_flags(
+                    uri,
+                    OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_URI,
+                )?
+            }
+        };
+        Ok(Self { connection })
+    }
+
     fn into_connection(self) -> Connection {
         self.connection
     }
@@ -226,6 +293,60 @@ fn database_path() -> Result<PathBuf, LibraryError> {
         .map(|dir| dir.join(LIBRARY_DB_FILE_NAME))
 }
 
+/// Probe writability by actually opening for writing, since permission bits miss ACLs,
+/// read-only mounts and locked-down profiles.
+fn detect_access(db_path: &Path) -> LibraryAccess {
+    if db_path.exists() {
+        return match Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_WRITE) {
+            // SQLite quietly falls back to read-only when the OS refuses write access.
+            Ok(connection) if connection.is_readonly(rusqlite::MAIN_DB).unwrap_or(false) => {
+                LibraryAccess::ReadOnly
+            }
+            Ok(_) => LibraryAccess::ReadWrite,
+            Err(err) if is_read_only_error(&err) => LibraryAccess::ReadOnly,
+            Err(_) => LibraryAccess::ReadWrite,
+        };
+    }
+    let Some(parent) = db_path.parent().filter(|parent| parent.exists()) else {
+        return LibraryAccess::ReadWrite;
+    };
+    let probe = parent.join(format!(".{LIBRARY_DB_FILE_NAME}.write-probe"));
+    match std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+    {
+        Ok(_) => {
+            let _ = std::fs::remove_file(&probe);
+            LibraryAccess::ReadWrite
+        }
+        Err(err)
+            if matches!(
+                err.kind(),
+                std::io::ErrorKind::PermissionDenied | std::io::ErrorKind::ReadOnlyFilesystem
+            ) =>
+        {
+            LibraryAccess::ReadOnly
+        }
+        Err(_) => LibraryAccess::ReadWrite,
+    }
+}
+
+fn is_read_only_error(err: &rusqlite::Error) -> bool {
+    matches!(
+        err.sqlite_error_code(),
+        Some(rusqlite::ErrorCode::ReadOnly | rusqlite::ErrorCode::CannotOpen)
+    )
+}
+
+fn same_sources(left: &[SampleSource], right: &[SampleSource]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .all(|(a, b)| a.id == b.id && a.root == b.root)
+}
+
 fn create_parent_if_needed(path: &Path) -> Result<(), LibraryError> {
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent).map_err(|source| LibraryError::CreateDir {
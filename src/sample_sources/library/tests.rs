@@ -194,3 +194,34 @@ fn reuses_known_source_id_for_same_root() {
         assert_eq!(reused.as_str(), id.as_str());
     });
 }
+
+#[test]
+fn read_only_database_reports_read_only_mode() {
+    let temp = tempdir().unwrap();
+    with_config_home(temp.path(), || {
+        let root = normalize_path(Path::new("some/root"));
+        let state = LibraryState {
+            sources: vec![SampleSource::new_with_id(SourceId::new(), root)],
+        };
+        save(&state).unwrap();
+        assert_eq!(access_mode().unwrap(), LibraryAccess::ReadWrite);
+
+        let db_path = database_path().unwrap();
+        let mut permissions = std::fs::metadata(&db_path).unwrap().permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&db_path, permissions.clone()).unwrap();
+
+        assert_eq!(access_mode().unwrap(), LibraryAccess::ReadOnly);
+        let loaded = load().unwrap();
+        assert_eq!(loaded.sources.len(), 1);
+        save(&loaded).unwrap();
+        assert!(matches!(
+            save(&LibraryState { sources: vec![] }),
+            Err(LibraryError::ReadOnly { .. })
+        ));
+
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        std::fs::set_permissions(&db_path, permissions).unwrap();
+    });
+}
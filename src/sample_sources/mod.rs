@@ -4,20 +4,27 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 mod audio_support;
+pub(crate) mod path_text;
 /// User configuration loading/saving for sample sources.
 pub mod config;
 /// Per-source database helpers.
 pub mod db;
 /// Global library database helpers.
 pub mod library;
+/// Library-wide sample id formatting and parsing.
+pub mod sample_id;
 /// Scan tracking state to avoid duplicate work.
 pub mod scan_state;
 /// Source scanning logic.
 pub mod scanner;
 
 pub(crate) use audio_support::{is_supported_audio, supported_audio_where_clause};
-pub use db::{DB_FILE_NAME, Rating, SourceDatabase, SourceDbError, WavEntry};
+pub use db::{
+    AnalysisCoverage, AudioMetadata, DB_FILE_NAME, MAX_STARS, Rating, SourceDatabase, SourceDbError,
+    WavEntry,
+};
 pub use db::normalize_relative_path;
+pub use sample_id::{make_sample_id, parse_sample_id};
 pub use scan_state::ScanTracker;
 pub use scanner::{ScanError, ScanMode, ScanStats};
 
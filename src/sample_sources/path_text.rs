@@ -0,0 +1,175 @@
+//! Reversible text form of relative paths for database rows and sample ids.
+//!
+//! Valid UTF-8 paths are stored as-is with forward slashes and without a leading `./`.
+//! On Unix, paths containing bytes that aren't valid UTF-8 are stored behind the
+//! [`ENCODED_PATH_MARKER`] prefix, with those bytes and every literal `%` percent-encoded.
+//! Plain text never starts with the marker, so decoding is unambiguous and existing
+//! rows for names that merely contain `%` keep their stored form.
+
+use std::path::{Path, PathBuf};
+
+/// Prefix marking percent-encoded path text; normalized plain paths never start with it.
+const ENCODED_PATH_MARKER: &str = "./";
+
+/// Encode a relative path as storable text with forward slashes.
+pub(crate) fn encode_path_text(path: &Path) -> String {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        let bytes = path.as_os_str().as_bytes();
+        if std::str::from_utf8(bytes).is_err() {
+            let mut out = String::from(ENCODED_PATH_MARKER);
+            percent_encode_into(&mut out, strip_current_dir(bytes), |_| false);
+            return out;
+        }
+    }
+    let text = path.to_string_lossy().replace('\\', "/");
+    let mut plain = text.as_str();
+    while let Some(rest) = plain.strip_prefix(ENCODED_PATH_MARKER) {
+        plain = rest;
+    }
+    plain.to_string()
+}
+
+/// Decode text produced by [`encode_path_text`] back into a path.
+pub(crate) fn decode_path_text(text: &str) -> PathBuf {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+        if let Some(encoded) = text.strip_prefix(ENCODED_PATH_MARKER)
+            && let Some(bytes) = percent_decode(encoded)
+        {
+            return PathBuf::from(std::ffi::OsString::from_vec(bytes));
+        }
+    }
+    PathBuf::from(text)
+}
+
+/// Normalize separators in `path` for lookups without losing non-UTF-8 bytes.
+pub(crate) fn normalize_path_separators(path: &Path) -> PathBuf {
+    decode_path_text(&encode_path_text(path))
+}
+
+/// Render an absolute path for a SQLite `file:` URI, escaping URI delimiters and
+/// non-UTF-8 bytes instead of replacing them.
+pub(crate) fn encode_uri_path(path: &Path) -> String {
+    let mut out = String::new();
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        percent_encode_into(&mut out, path.as_os_str().as_bytes(), is_uri_delimiter);
+    }
+    #[cfg(not(unix))]
+    {
+        let text = path.to_string_lossy().replace('\\', "/");
+        percent_encode_into(&mut out, text.as_bytes(), is_uri_delimiter);
+    }
+    out
+}
+
+fn is_uri_delimiter(ch: char) -> bool {
+    matches!(ch, '?' | '#')
+}
+
+#[cfg(unix)]
+fn strip_current_dir(mut bytes: &[u8]) -> &[u8] {
+    while let Some(rest) = bytes.strip_prefix(ENCODED_PATH_MARKER.as_bytes()) {
+        bytes = rest;
+    }
+    bytes
+}
+
+/// Append `bytes` to `out`, escaping `%`, invalid UTF-8 and chars matching `escape`.
+fn percent_encode_into(out: &mut String, mut bytes: &[u8], escape: impl Fn(char) -> bool) {
+    loop {
+        match std::str::from_utf8(bytes) {
+            Ok(valid) => {
+                push_escaped(out, valid, &escape);
+                return;
+            }
+            Err(err) => {
+                let (valid, rest) = bytes.split_at(err.valid_up_to());
+                if let Ok(valid) = std::str::from_utf8(valid) {
+                    push_escaped(out, valid, &escape);
+                }
+                let invalid_len = err.error_len().unwrap_or(rest.len());
+                for byte in &rest[..invalid_len] {
+                    out.push_str(&format!("%{byte:02X}"));
+                }
+                bytes = &rest[invalid_len..];
+            }
+        }
+    }
+}
+
+fn push_escaped(out: &mut String, valid: &str, escape: &impl Fn(char) -> bool) {
+    for ch in valid.chars() {
+        match ch {
+            '%' => out.push_str("%25"),
+            '\\' => out.push('/'),
+            other if escape(other) => out.push_str(&format!("%{:02X}", other as u32)),
+            other => out.push(other),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn percent_decode(text: &str) -> Option<Vec<u8>> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = text.get(index + 1..index + 3)?;
+            out.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            out.push(bytes[index]);
+            index += 1;
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf8_paths_are_stored_unchanged() {
+        let path = Path::new("drums/100% kick.wav");
+        assert_eq!(encode_path_text(path), "drums/100% kick.wav");
+        assert_eq!(decode_path_text("drums/100% kick.wav"), path);
+        assert_eq!(decode_path_text("drums/%41.wav"), Path::new("drums/%41.wav"));
+    }
+
+    #[test]
+    fn utf8_names_with_percent_escapes_decode_verbatim() {
+        for name in ["pack/caf%E9.wav", "pack/%FF.wav", "./pack/%E9.wav"] {
+            let text = encode_path_text(Path::new(name));
+            assert!(!text.starts_with(ENCODED_PATH_MARKER));
+            assert_eq!(decode_path_text(&text), Path::new(name.trim_start_matches("./")));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn invalid_utf8_paths_round_trip_through_text() {
+        use std::ffi::OsString;
+        use std::os::unix::ffi::OsStringExt;
+
+        let original = PathBuf::from(OsString::from_vec(b"pack/caf\xe9 50%.wav".to_vec()));
+        let text = encode_path_text(&original);
+        assert_eq!(text, "./pack/caf%E9 50%25.wav");
+        assert_eq!(decode_path_text(&text), original);
+        assert_eq!(normalize_path_separators(&original), original);
+    }
+
+    #[test]
+    fn uri_paths_escape_delimiters_and_percent() {
+        assert_eq!(
+            encode_uri_path(Path::new("/data/50% mix?#/library.db")),
+            "/data/50%25 mix%3F%23/library.db"
+        );
+    }
+}
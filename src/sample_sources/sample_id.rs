@@ -0,0 +1,55 @@
+use std::path::{Path, PathBuf};
+
+use super::path_text::{decode_path_text, encode_path_text};
+
+/// Separator between the source id and the relative path in a sample id.
+pub const SAMPLE_ID_SEPARATOR: &str = "::";
+
+/// Build the library-wide sample id `source_id::relative/path`.
+///
+/// Backslashes in `relative_path` are written as `/`, so the same file gets the same id
+/// on every platform and [`parse_sample_id`] returns a forward-slash path. Non-UTF-8
+/// names are percent-encoded and decode back to the original bytes.
+pub fn make_sample_id(source_id: &str, relative_path: &Path) -> String {
+    let rel = encode_path_text(relative_path);
+    format!("{source_id}{SAMPLE_ID_SEPARATOR}{rel}")
+}
+
+/// Split a sample id into its source id and forward-slash relative path.
+///
+/// Inverse of [`make_sample_id`]; fails when either part is missing.
+pub fn parse_sample_id(sample_id: &str) -> Result<(String, PathBuf), String> {
+    let (source, path) = sample_id
+        .split_once(SAMPLE_ID_SEPARATOR)
+        .ok_or_else(|| format!("Invalid sample_id: {sample_id}"))?;
+    if source.is_empty() || path.is_empty() {
+        return Err(format!("Invalid sample_id: {sample_id}"));
+    }
+    Ok((source.to_string(), decode_path_text(&path.replace('\\', "/"))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backslash_paths_round_trip_to_forward_slashes() {
+        let sample_id = make_sample_id("src-1", Path::new(r"Drums\Kicks\kick 01.wav"));
+        assert_eq!(sample_id, "src-1::Drums/Kicks/kick 01.wav");
+
+        let (source, relative_path) = parse_sample_id(&sample_id).unwrap();
+        assert_eq!(source, "src-1");
+        assert_eq!(relative_path, PathBuf::from("Drums/Kicks/kick 01.wav"));
+        assert_eq!(make_sample_id(&source, &relative_path), sample_id);
+
+        let (_, legacy) = parse_sample_id(r"src-1::Drums\kick.wav").unwrap();
+        assert_eq!(legacy, PathBuf::from("Drums/kick.wav"));
+    }
+
+    #[test]
+    fn parse_rejects_missing_parts() {
+        assert!(parse_sample_id("no-separator.wav").is_err());
+        assert!(parse_sample_id("::kick.wav").is_err());
+        assert!(parse_sample_id("src-1::").is_err());
+    }
+}
@@ -4,9 +4,12 @@ mod scan_diff;
 mod scan_diff_phase;
 mod scan_fs;
 mod scan_hash;
+mod scan_paths;
+mod scan_probe;
 mod scan_walk;
 
 pub use scan::{
     ChangedSample, ScanError, ScanMode, ScanStats, hard_rescan, scan_in_background, scan_once,
     scan_with_progress,
 };
+pub use scan_paths::scan_paths;
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 use crate::sample_sources::SourceDatabase;
@@ -11,6 +11,7 @@ pub(crate) struct ScanContext {
     pub(crate) existing: HashMap<PathBuf, WavEntry>,
     pub(crate) existing_by_hash: HashMap<String, Vec<PathBuf>>,
     pub(crate) existing_by_facts: HashMap<(u64, i64), Vec<PathBuf>>,
+    pub(crate) unprobed: HashSet<PathBuf>,
     pub(crate) stats: ScanStats,
     pub(crate) mode: ScanMode,
 }
@@ -24,6 +25,7 @@ impl ScanContext {
             existing,
             existing_by_hash,
             existing_by_facts,
+            unprobed: db.list_unprobed_paths()?.into_iter().collect(),
             stats: ScanStats::default(),
             mode,
         })
@@ -163,6 +163,50 @@ fn scan_detects_missing_paths_without_double_counting() {
     assert_eq!(second.missing, 0);
 }
 
+#[test]
+fn scan_paths_syncs_only_watched_paths() {
+    let dir = tempdir().unwrap();
+    let kept = dir.path().join("a.wav");
+    let removed = dir.path().join("b.wav");
+    let untouched = dir.path().join("c.wav");
+    std::fs::write(&kept, b"alpha").unwrap();
+    std::fs::write(&removed, b"bravo").unwrap();
+    std::fs::write(&untouched, b"charlie").unwrap();
+
+    let db = SourceDatabase::open(dir.path()).unwrap();
+    scan_once(&db).unwrap();
+
+    let created = dir.path().join("d.wav");
+    std::fs::write(&created, b"delta").unwrap();
+    std::fs::write(&kept, b"alpha-modified").unwrap();
+    std::fs::remove_file(&removed).unwrap();
+    // Not part of the event set, so the row must stay as-is.
+    std::fs::remove_file(&untouched).unwrap();
+
+    let stats = crate::sample_sources::scanner::scan_paths(
+        &db,
+        &[created.clone(), kept.clone(), removed.clone()],
+    )
+    .unwrap();
+    assert_eq!(stats.added, 1);
+    assert_eq!(stats.updated, 1);
+    assert_eq!(stats.missing, 1);
+
+    let rows = db.list_files().unwrap();
+    let row = |name: &str| {
+        rows.iter()
+            .find(|row| row.relative_path == PathBuf::from(name))
+            .unwrap()
+    };
+    assert_eq!(rows.len(), 4);
+    assert_eq!(row("a.wav").file_size, b"alpha-modified".len() as u64);
+    assert!(!row("a.wav").missing);
+    assert!(row("b.wav").missing);
+    assert!(!row("c.wav").missing);
+    assert_eq!(row("c.wav").file_size, b"charlie".len() as u64);
+    assert!(!row("d.wav").missing);
+}
+
 #[test]
 fn scan_detects_changed_content_hash() {
     let dir = tempdir().unwrap();
@@ -411,3 +455,108 @@ fn scan_skips_symlink_files() {
     assert_eq!(stats.total_files, 1);
     assert_eq!(stats.added, 1);
 }
+
+#[cfg(unix)]
+#[test]
+fn scan_terminates_on_symlink_cycle() {
+    use std::os::unix::fs as unix_fs;
+
+    let dir = tempdir().unwrap();
+    let outer = dir.path().join("outer");
+    let inner = outer.join("inner");
+    std::fs::create_dir_all(&inner).unwrap();
+    std::fs::write(outer.join("one.wav"), b"one").unwrap();
+    std::fs::write(inner.join("two.wav"), b"two").unwrap();
+    unix_fs::symlink(&outer, inner.join("back")).unwrap();
+    unix_fs::symlink(dir.path(), dir.path().join("root_link")).unwrap();
+
+    let db = SourceDatabase::open(dir.path()).unwrap();
+    let stats = scan_once(&db).unwrap();
+    assert_eq!(stats.total_files, 2);
+    assert_eq!(stats.added, 2);
+}
+
+/// Build a minimal FLAC stream: the marker plus a STREAMINFO block with no audio frames.
+fn write_streaminfo_flac(path: &Path, sample_rate: u32, channels: u8, total_samples: u64) {
+    let mut bytes = b"fLaC".to_vec();
+    bytes.extend_from_slice(&[0x80, 0x00, 0x00, 34]);
+    bytes.extend_from_slice(&4096u16.to_be_bytes());
+    bytes.extend_from_slice(&4096u16.to_be_bytes());
+    bytes.extend_from_slice(&[0; 6]);
+    let packed = (u64::from(sample_rate) << 44)
+        | (u64::from(channels - 1) << 41)
+        | (15u64 << 36)
+        | total_samples;
+    bytes.extend_from_slice(&packed.to_be_bytes());
+    bytes.extend_from_slice(&[0; 16]);
+    std::fs::write(path, bytes).unwrap();
+}
+
+#[test]
+fn scan_probes_flac_metadata() {
+    let dir = tempdir().unwrap();
+    write_streaminfo_flac(&dir.path().join("loop.flac"), 44_100, 2, 88_200);
+
+    let db = SourceDatabase::open(dir.path()).unwrap();
+    let stats = scan_once(&db).unwrap();
+    assert_eq!(stats.added, 1);
+
+    let rows = db.list_files().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].relative_path, PathBuf::from("loop.flac"));
+    let metadata = db
+        .audio_metadata_for_path(Path::new("loop.flac"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(metadata.sample_rate, Some(44_100));
+    assert_eq!(metadata.channels, Some(2));
+    let duration = metadata.duration_seconds.unwrap();
+    assert!((duration - 2.0).abs() < 1e-3);
+}
+
+#[cfg(unix)]
+#[test]
+fn non_utf8_file_name_survives_scan_store_and_resolve() {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+
+    let dir = tempdir().unwrap();
+    let name = OsString::from_vec(b"caf\xe9 %41.wav".to_vec());
+    std::fs::write(dir.path().join(&name), b"one").unwrap();
+
+    let db = SourceDatabase::open(dir.path()).unwrap();
+    let first = scan_once(&db).unwrap();
+    assert_eq!(first.added, 1);
+    let rows = db.list_files().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].relative_path.as_os_str(), name.as_os_str());
+    assert!(dir.path().join(&rows[0].relative_path).is_file());
+    assert_eq!(db.tag_for_path(Path::new(&name)).unwrap(), Some(Rating::NEUTRAL));
+
+    let sample_id = crate::sample_sources::make_sample_id("src", &rows[0].relative_path);
+    let (_, resolved) = crate::sample_sources::parse_sample_id(&sample_id).unwrap();
+    assert_eq!(resolved.as_os_str(), name.as_os_str());
+
+    let second = scan_once(&db).unwrap();
+    assert_eq!(second.added, 0);
+    assert_eq!(second.missing, 0);
+}
+
+#[test]
+fn failed_probe_is_skipped_until_file_changes() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("loop.flac");
+    std::fs::write(&path, b"not audio").unwrap();
+
+    let db = SourceDatabase::open(dir.path()).unwrap();
+    scan_once(&db).unwrap();
+    assert!(db.list_unprobed_paths().unwrap().is_empty());
+
+    write_streaminfo_flac(&path, 48_000, 1, 48_000);
+    scan_once(&db).unwrap();
+    let metadata = db
+        .audio_metadata_for_path(Path::new("loop.flac"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(metadata.sample_rate, Some(48_000));
+}
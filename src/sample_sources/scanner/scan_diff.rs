@@ -188,6 +188,9 @@ fn apply_rename(
     if let Some(last_played_at) = entry.last_played_at {
         batch.set_last_played_at(new_path, last_played_at)?;
     }
+    if entry.stars.is_some() {
+        batch.set_stars(new_path, entry.stars)?;
+    }
     Ok(())
 }
 
@@ -206,6 +209,9 @@ fn apply_rename_without_hash(
     if let Some(last_played_at) = entry.last_played_at {
         batch.set_last_played_at(new_path, last_played_at)?;
     }
+    if entry.stars.is_some() {
+        batch.set_stars(new_path, entry.stars)?;
+    }
     Ok(())
 }
 
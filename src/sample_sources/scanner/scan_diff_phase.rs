@@ -6,6 +6,7 @@ use crate::sample_sources::db::SourceWriteBatch;
 use super::scan::{ScanContext, ScanError};
 use super::scan_diff::apply_diff;
 use super::scan_fs::read_facts;
+use super::scan_probe::probe_audio_metadata;
 
 pub(super) fn diff_phase(
     batch: &mut SourceWriteBatch<'_>,
@@ -15,6 +16,11 @@ pub(super) fn diff_phase(
     cancel: Option<&AtomicBool>,
 ) -> Result<(), ScanError> {
     let facts = read_facts(root, path)?;
+    let relative = facts.relative.clone();
+    let needs_probe = context.unprobed.remove(&relative)
+        || context.existing.get(&relative).is_none_or(|entry| {
+            entry.file_size != facts.size || entry.modified_ns != facts.modified_ns
+        });
     apply_diff(
         batch,
         facts,
@@ -26,6 +32,12 @@ pub(super) fn diff_phase(
         context.mode,
         cancel,
     )?;
+    if needs_probe {
+        match probe_audio_metadata(path) {
+            Some(metadata) => batch.set_audio_metadata(&relative, &metadata)?,
+            None => batch.mark_probe_failed(&relative)?,
+        }
+    }
     context.stats.total_files += 1;
     Ok(())
 }
@@ -82,6 +82,7 @@ pub(super) fn visit_dir(
                     continue;
                 }
             };
+            // Links and junctions are never followed, so a link back into the tree cannot loop.
             if file_type.is_symlink() {
                 continue;
             }
@@ -231,4 +232,31 @@ mod tests {
         );
         assert!(matches!(result, Err(ScanError::Canceled)));
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn visit_dir_does_not_follow_symlink_cycle() {
+        use std::os::unix::fs as unix_fs;
+
+        let dir = tempfile::tempdir().unwrap();
+        let outer = dir.path().join("outer");
+        let inner = outer.join("inner");
+        fs::create_dir_all(&inner).unwrap();
+        fs::write(outer.join("a.wav"), b"a").unwrap();
+        fs::write(inner.join("b.wav"), b"b").unwrap();
+        unix_fs::symlink(&outer, inner.join("back")).unwrap();
+
+        let mut seen = Vec::new();
+        visit_dir(dir.path(), None, &mut |path| {
+            seen.push(path.strip_prefix(dir.path()).unwrap().to_path_buf());
+            Ok(())
+        })
+        .unwrap();
+        seen.sort();
+
+        assert_eq!(
+            seen,
+            vec![PathBuf::from("outer/a.wav"), PathBuf::from("outer/inner/b.wav")]
+        );
+    }
 }
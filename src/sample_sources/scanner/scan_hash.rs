@@ -130,5 +130,8 @@ fn apply_deep_rename(
     if let Some(last_played_at) = missing_entry.last_played_at {
         batch.set_last_played_at(&present_entry.relative_path, last_played_at)?;
     }
+    if missing_entry.stars.is_some() {
+        batch.set_stars(&present_entry.relative_path, missing_entry.stars)?;
+    }
     Ok(())
 }
@@ -0,0 +1,74 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::sample_sources::db::WavEntry;
+use crate::sample_sources::{SourceDatabase, is_supported_audio};
+
+use super::scan::{ScanContext, ScanError, ScanMode, ScanStats};
+use super::scan_diff::{index_by_facts, index_by_hash};
+use super::scan_diff_phase::diff_phase;
+use super::scan_fs::ensure_root_dir;
+
+/// Sync only the given paths into the database instead of walking the whole source.
+///
+/// Paths that exist are diffed like a quick scan; paths that vanished are marked missing.
+/// Rename reconciliation only considers rows for the given paths or rows already marked
+/// missing, so entries outside the set are never touched.
+pub fn scan_paths(db: &SourceDatabase, paths: &[PathBuf]) -> Result<ScanStats, ScanError> {
+    let root = ensure_root_dir(db)?;
+    let changed = relative_audio_paths(&root, paths);
+    let existing: HashMap<PathBuf, WavEntry> = db
+        .list_files()?
+        .into_iter()
+        .filter(|entry| entry.missing || changed.contains(&entry.relative_path))
+        .map(|entry| (entry.relative_path.clone(), entry))
+        .collect();
+    let mut context = ScanContext {
+        existing_by_hash: index_by_hash(&existing),
+        existing_by_facts: index_by_facts(&existing),
+        existing,
+        unprobed: db.list_unprobed_paths()?.into_iter().collect(),
+        stats: ScanStats::default(),
+        mode: ScanMode::Quick,
+    };
+    let mut batch = db.write_batch()?;
+    let mut vanished = Vec::new();
+    for relative in changed {
+        let absolute = root.join(&relative);
+        match std::fs::symlink_metadata(&absolute) {
+            Ok(metadata) if metadata.file_type().is_symlink() => {}
+            Ok(metadata) if metadata.is_file() => {
+                diff_phase(&mut batch, &root, &absolute, &mut context, None)?;
+            }
+            _ => vanished.push(relative),
+        }
+    }
+    for relative in vanished {
+        let Some(entry) = context.existing.remove(&relative) else {
+            continue;
+        };
+        if entry.missing {
+            continue;
+        }
+        batch.set_missing(&relative, true)?;
+        context.stats.missing += 1;
+    }
+    batch.commit()?;
+    Ok(context.stats)
+}
+
+fn relative_audio_paths(root: &Path, paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut seen = HashSet::new();
+    paths
+        .iter()
+        .filter_map(|path| {
+            if path.is_absolute() {
+                path.strip_prefix(root).ok().map(Path::to_path_buf)
+            } else {
+                Some(path.clone())
+            }
+        })
+        .filter(|relative| !relative.as_os_str().is_empty() && is_supported_audio(relative))
+        .filter(|relative| seen.insert(relative.clone()))
+        .collect()
+}
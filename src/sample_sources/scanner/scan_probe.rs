@@ -0,0 +1,27 @@
+use std::path::Path;
+
+use tracing::warn;
+
+use crate::sample_sources::AudioMetadata;
+
+/// Read sample rate, channel count, and duration from the file header.
+/// Probe failures are logged and skipped so a single unreadable file never fails a scan.
+pub(super) fn probe_audio_metadata(path: &Path) -> Option<AudioMetadata> {
+    match crate::analysis::audio::probe_metadata(path) {
+        Ok(probe) => Some(AudioMetadata {
+            duration_seconds: probe
+                .duration_seconds
+                .filter(|duration| duration.is_finite() && *duration >= 0.0),
+            sample_rate: probe.sample_rate,
+            channels: probe.channels,
+        }),
+        Err(err) => {
+            warn!(
+                path = %path.display(),
+                error = %err,
+                "Failed to probe audio metadata during scan"
+            );
+            None
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::app_dirs;
+
+/// File changes an install of a bundle would make, without touching disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstallPlan {
+    /// Actions in the order they would be applied.
+    pub actions: Vec<PlanAction>,
+}
+
+/// Single filesystem step in an [`InstallPlan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanAction {
+    /// Create a directory (and any missing parents).
+    CreateDir {
+        /// Directory to create.
+        path: PathBuf,
+    },
+    /// Copy a bundle file into the install directory.
+    Copy {
+        /// File inside the bundle.
+        source: PathBuf,
+        /// Destination inside the install directory.
+        target: PathBuf,
+    },
+}
+
+/// Build the install plan for copying `bundle_dir` into `install_dir` (dry run).
+pub fn plan_install(bundle_dir: &Path, install_dir: &Path) -> Result<InstallPlan, String> {
+    let entries = collect_bundle_entries(bundle_dir)?;
+    let mut actions = Vec::new();
+    let mut seen_dirs = HashSet::new();
+
+    for (source, relative) in entries {
+        let target = install_dir.join(relative);
+        if let Some(parent) = target.parent() {
+            add_dir_action(&mut actions, &mut seen_dirs, parent.to_path_buf());
+        }
+        actions.push(PlanAction::Copy { source, target });
+    }
+
+    let models_dir = app_dirs::app_root_dir()
+        .map_err(|err| err.to_string())?
+        .join("models");
+    add_dir_action(&mut actions, &mut seen_dirs, models_dir);
+
+    Ok(InstallPlan { actions })
+}
+
+fn add_dir_action(actions: &mut Vec<PlanAction>, seen_dirs: &mut HashSet<PathBuf>, path: PathBuf) {
+    if seen_dirs.insert(path.clone()) {
+        actions.push(PlanAction::CreateDir { path });
+    }
+}
+
+/// List every file in a bundle as `(absolute source, path relative to the bundle)`.
+pub fn collect_bundle_entries(bundle_dir: &Path) -> Result<Vec<(PathBuf, PathBuf)>, String> {
+    if !bundle_dir.exists() {
+        return Err(format!(
+            "Bundle directory not found at {}",
+            bundle_dir.display()
+        ));
+    }
+    let mut files = Vec::new();
+    visit_bundle(bundle_dir, bundle_dir, &mut files)?;
+    Ok(files)
+}
+
+fn visit_bundle(
+    root: &Path,
+    dir: &Path,
+    files: &mut Vec<(PathBuf, PathBuf)>,
+) -> Result<(), String> {
+    for entry in fs::read_dir(dir).map_err(|err| format!("Failed to read bundle: {err}"))? {
+        let entry = entry.map_err(|err| format!("Failed to read bundle entry: {err}"))?;
+        let path = entry.path();
+        if path.is_dir() {
+            visit_bundle(root, &path, files)?;
+        } else {
+            let relative = path
+                .strip_prefix(root)
+                .map_err(|err| format!("Failed to build relative path: {err}"))?
+                .to_path_buf();
+            files.push((path, relative));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_binaries_plan_two_copies_into_install_dir() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let bundle = temp.path().join("bundle");
+        let install = temp.path().join("install");
+        fs::create_dir_all(&bundle).expect("bundle dir");
+        fs::write(bundle.join("sempal.exe"), "app").expect("app exe");
+        fs::write(bundle.join("sempal-updater.exe"), "updater").expect("updater exe");
+
+        let plan = plan_install(&bundle, &install).expect("plan");
+        let mut targets: Vec<PathBuf> = plan
+            .actions
+            .iter()
+            .filter_map(|action| match action {
+                PlanAction::Copy { target, .. } => Some(target.clone()),
+                PlanAction::CreateDir { .. } => None,
+            })
+            .collect();
+        targets.sort();
+        assert_eq!(
+            targets,
+            vec![
+                install.join("sempal-updater.exe"),
+                install.join("sempal.exe")
+            ]
+        );
+        assert!(!install.exists(), "dry run must not create the install dir");
+    }
+}
@@ -8,6 +8,7 @@ mod archive;
 mod check;
 mod fs_ops;
 mod github;
+mod install_plan;
 
 use std::{
     fs,
@@ -23,6 +24,7 @@ use serde::{Deserialize, Serialize};
 pub use apply::{ApplyPlan, StaleRemovalFailure, UpdateManifest};
 pub use check::{UpdateCheckOutcome, UpdateCheckRequest};
 pub use github::ReleaseSummary;
+pub use install_plan::{InstallPlan, PlanAction, collect_bundle_entries, plan_install};
 
 /// Canonical app name used by the release contract.
 pub const APP_NAME: &str = "sempal";
@@ -344,6 +344,74 @@ pub fn sanitize_wav_bytes(mut bytes: Vec<u8>) -> Vec<u8> {
     bytes
 }
 
+/// Read the frame offsets of the cue points stored in a WAV `cue ` chunk.
+///
+/// Returns the offsets sorted and de-duplicated; non-WAV or malformed input yields an empty list.
+pub fn read_cue_points(bytes: &[u8]) -> Vec<u32> {
+    const CUE_POINT_LEN: usize = 24;
+    const SAMPLE_OFFSET_FIELD: usize = 20;
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Vec::new();
+    }
+    let mut offset = 12usize;
+    while offset + 8 <= bytes.len() {
+        let chunk_size =
+            u32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let chunk_data = offset + 8;
+        let Some(chunk_end) = chunk_data.checked_add(chunk_size) else {
+            return Vec::new();
+        };
+        if &bytes[offset..offset + 4] == b"cue " {
+            let data = &bytes[chunk_data..chunk_end.min(bytes.len())];
+            if data.len() < 4 {
+                return Vec::new();
+            }
+            let count = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+            let mut points: Vec<u32> = data[4..]
+                .chunks_exact(CUE_POINT_LEN)
+                .take(count)
+                .map(|point| {
+                    let field = &point[SAMPLE_OFFSET_FIELD..SAMPLE_OFFSET_FIELD + 4];
+                    u32::from_le_bytes(field.try_into().unwrap())
+                })
+                .collect();
+            points.sort_unstable();
+            points.dedup();
+            return points;
+        }
+        offset = chunk_end.saturating_add(chunk_size % 2);
+    }
+    Vec::new()
+}
+
+/// Append a `cue ` chunk marking `frames` to in-memory WAV bytes and update the RIFF size.
+///
+/// Leaves `bytes` untouched when there are no frames or the buffer is not a WAV file.
+pub fn append_cue_chunk(bytes: &mut Vec<u8>, frames: &[u32]) {
+    if frames.is_empty() || bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE"
+    {
+        return;
+    }
+    if bytes.len() % 2 == 1 {
+        bytes.push(0);
+    }
+    let chunk_size = 4 + frames.len() * 24;
+    bytes.extend_from_slice(b"cue ");
+    bytes.extend_from_slice(&(chunk_size as u32).to_le_bytes());
+    bytes.extend_from_slice(&(frames.len() as u32).to_le_bytes());
+    for (index, frame) in frames.iter().enumerate() {
+        let id = index as u32 + 1;
+        bytes.extend_from_slice(&id.to_le_bytes());
+        bytes.extend_from_slice(&frame.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&frame.to_le_bytes());
+    }
+    let riff_size = (bytes.len() as u64).saturating_sub(8).min(u32::MAX as u64) as u32;
+    bytes[4..8].copy_from_slice(&riff_size.to_le_bytes());
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
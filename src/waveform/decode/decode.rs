@@ -23,10 +23,17 @@ impl WaveformRenderer {
         max_frames: usize,
     ) -> Result<DecodedWaveform, WaveformDecodeError> {
         let cache_token = NEXT_CACHE_TOKEN.fetch_add(1, Ordering::Relaxed);
-        if let Some(decoded) = self.load_decoded_wav(bytes, cache_token, max_frames)? {
-            return Ok(decoded);
-        }
-        self.load_decoded_via_symphonia(bytes, cache_token, max_frames)
+        let mut decoded = match self.load_decoded_wav(bytes, cache_token, max_frames)? {
+            Some(decoded) => decoded,
+            None => self.load_decoded_via_symphonia(bytes, cache_token, max_frames)?,
+        };
+        let total_frames = decoded.frame_count();
+        decoded.cue_markers = crate::wav_sanitize::read_cue_points(bytes)
+            .into_iter()
+            .map(|frame| frame as usize)
+            .filter(|&frame| frame < total_frames)
+            .collect();
+        Ok(decoded)
     }
 
     #[cfg(test)]
@@ -74,6 +81,19 @@ mod tests {
 
 
 
+    #[test]
+    fn cue_chunk_points_become_markers() {
+        let mut bytes = wav_bytes_i16(1, &[0_i16; 64]);
+        crate::wav_sanitize::append_cue_chunk(&mut bytes, &[48, 16]);
+
+        let decoded = WaveformRenderer::new(12, 12)
+            .load_decoded(&bytes)
+            .expect("decode wav with cue chunk");
+
+        assert_eq!(decoded.cue_markers, vec![16, 48]);
+        assert_eq!(decoded.cue_marker_positions(), vec![0.25, 0.75]);
+    }
+
     #[test]
     fn peak_only_branch_preserves_duration_and_frames() {
         let renderer = WaveformRenderer::new(12, 12);
@@ -6,6 +6,8 @@ mod resample;
 mod symphonia_reader;
 mod wav_reader;
 
+pub(crate) use resample::resample_linear;
+
 use std::sync::Arc;
 use std::sync::Mutex;
 
@@ -1,5 +1,5 @@
-#[allow(dead_code)]
-pub(super) fn resample_linear(
+/// Linearly resample interleaved audio from `src_rate` to `dst_rate`.
+pub(crate) fn resample_linear(
     samples: &[f32],
     channels: usize,
     src_rate: u32,
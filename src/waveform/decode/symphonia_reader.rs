@@ -57,6 +57,7 @@ impl WaveformRenderer {
             duration_seconds,
             sample_rate,
             channels,
+            cue_markers: Vec::new(),
         })
     }
 
@@ -141,6 +142,7 @@ impl WaveformRenderer {
                         duration_seconds,
                         sample_rate,
                         channels,
+                        cue_markers: Vec::new(),
                     });
                 };
                 let sample = clamp_sample(sample);
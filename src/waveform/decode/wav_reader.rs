@@ -55,6 +55,7 @@ impl WaveformRenderer {
                 duration_seconds,
                 sample_rate: spec_sample_rate,
                 channels: spec_channels,
+                cue_markers: Vec::new(),
             }));
         }
 
@@ -73,6 +74,7 @@ impl WaveformRenderer {
             duration_seconds,
             sample_rate: spec_sample_rate,
             channels: spec_channels,
+            cue_markers: Vec::new(),
         }))
     }
 }
@@ -0,0 +1,64 @@
+//! Sample-wise comparison between two decoded versions of the same sample.
+
+use super::{DecodedWaveform, next_cache_token};
+use std::sync::Arc;
+
+/// Return `current - reference` per interleaved sample, padding the shorter buffer with silence.
+pub(crate) fn difference_samples(reference: &[f32], current: &[f32]) -> Vec<f32> {
+    let len = reference.len().max(current.len());
+    (0..len)
+        .map(|idx| {
+            let before = reference.get(idx).copied().unwrap_or(0.0);
+            let after = current.get(idx).copied().unwrap_or(0.0);
+            after - before
+        })
+        .collect()
+}
+
+/// Build a waveform of `current - reference` so unchanged regions render as silence.
+pub(crate) fn difference_waveform(
+    reference: &DecodedWaveform,
+    current: &DecodedWaveform,
+) -> Result<DecodedWaveform, String> {
+    if reference.samples.is_empty() || current.samples.is_empty() {
+        return Err("Difference view needs fully decoded audio".into());
+    }
+    if reference.channel_count() != current.channel_count() {
+        return Err("Channel count changed since the edit".into());
+    }
+    let samples = difference_samples(&reference.samples, &current.samples);
+    let frames = samples.len() / current.channel_count();
+    Ok(DecodedWaveform {
+        cache_token: next_cache_token(),
+        samples: Arc::from(samples),
+        analysis_samples: Arc::from(Vec::new()),
+        analysis_sample_rate: 0,
+        analysis_stride: 1,
+        peaks: None,
+        duration_seconds: frames as f32 / current.sample_rate.max(1) as f32,
+        sample_rate: current.sample_rate,
+        channels: current.channels,
+        cue_markers: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_buffers_diff_to_silence_and_gain_diffs_proportionally() {
+        let before = [0.5, -0.25, 0.1, 0.0, -0.8];
+        assert!(
+            difference_samples(&before, &before)
+                .iter()
+                .all(|sample| *sample == 0.0)
+        );
+
+        let gained: Vec<f32> = before.iter().map(|sample| sample * 0.5).collect();
+        let diff = difference_samples(&before, &gained);
+        for (delta, original) in diff.iter().zip(before.iter()) {
+            assert!((delta - original * -0.5).abs() < 1e-6);
+        }
+    }
+}
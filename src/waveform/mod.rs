@@ -1,4 +1,5 @@
 mod decode;
+pub(crate) mod diff;
 mod error;
 mod render;
 mod sampling;
@@ -12,9 +13,12 @@ use std::io::Read;
 use std::path::Path;
 use std::sync::Arc;
 
+pub(crate) use decode::resample_linear;
 pub use error::{WaveformDecodeError, WaveformLoadError};
 
 const MAX_WAVEFORM_BYTES: u64 = 512 * 1024 * 1024;
+/// Absolute level treated as full scale; leaves room for the asymmetric integer maximum.
+const CLIP_LEVEL: f32 = 0.9999;
 
 /// Waveform pixels and audio payload loaded from disk.
 pub struct LoadedWaveform {
@@ -60,6 +64,8 @@ pub struct DecodedWaveform {
     pub sample_rate: u32,
     /// Number of audio channels.
     pub channels: u16,
+    /// Frame offsets of markers embedded in the file (WAV `cue ` points), sorted ascending.
+    pub cue_markers: Vec<usize>,
 }
 
 /// Return a monotonic cache token for decoded waveforms.
@@ -103,6 +109,15 @@ impl DecodedWaveform {
         }
     }
 
+    /// Embedded cue markers as normalized `0.0..=1.0` positions.
+    pub fn cue_marker_positions(&self) -> Vec<f32> {
+        let total_frames = self.frame_count().max(1) as f32;
+        self.cue_markers
+            .iter()
+            .map(|&frame| (frame as f32 / total_frames).clamp(0.0, 1.0))
+            .collect()
+    }
+
     pub(crate) fn max_abs_in_span(&self, start: f32, end: f32) -> Option<f32> {
         if !start.is_finite() || !end.is_finite() {
             return None;
@@ -121,6 +136,29 @@ impl DecodedWaveform {
         }
         max_abs_from_samples(&self.samples, self.channel_count(), start, end)
     }
+
+    /// Count samples at or above full scale.
+    ///
+    /// Peak-only waveforms count clipped buckets instead, which is a lower bound.
+    pub(crate) fn clipped_sample_count(&self) -> usize {
+        if self
+            .max_abs_in_span(0.0, 1.0)
+            .is_none_or(|peak| peak < CLIP_LEVEL)
+        {
+            return 0;
+        }
+        if let Some(peaks) = self.peaks.as_deref() {
+            return peaks
+                .mono
+                .iter()
+                .filter(|(min, max)| min.abs().max(max.abs()) >= CLIP_LEVEL)
+                .count();
+        }
+        self.samples
+            .iter()
+            .filter(|sample| sample.abs() >= CLIP_LEVEL)
+            .count()
+    }
 }
 
 fn max_abs_from_samples(samples: &[f32], channels: usize, start: f32, end: f32) -> Option<f32> {
@@ -192,6 +230,7 @@ mod tests {
             duration_seconds: 1.0,
             sample_rate: 4,
             channels: 2,
+            cue_markers: Vec::new(),
         };
         let peak_full = decoded.max_abs_in_span(0.0, 1.0).unwrap();
         let peak_first_half = decoded.max_abs_in_span(0.0, 0.5).unwrap();
@@ -199,6 +238,27 @@ mod tests {
         assert!((peak_first_half - 0.2).abs() < 1e-6);
     }
 
+    #[test]
+    fn clipped_sample_count_counts_full_scale_samples() {
+        let decoded = |samples: Vec<f32>| DecodedWaveform {
+            cache_token: 1,
+            samples: Arc::from(samples),
+            analysis_samples: Arc::from(Vec::new()),
+            analysis_sample_rate: 0,
+            analysis_stride: 1,
+            peaks: None,
+            duration_seconds: 1.0,
+            sample_rate: 8,
+            channels: 1,
+            cue_markers: Vec::new(),
+        };
+        let clipped = decoded(vec![0.2, 1.0, -1.0, 0.5, 1.0, 32767.0 / 32768.0, 0.1, 0.0]);
+        let clean = decoded(vec![0.2, 0.9, -0.95, 0.5, 0.99, 0.3, 0.1, 0.0]);
+
+        assert_eq!(clipped.clipped_sample_count(), 4);
+        assert_eq!(clean.clipped_sample_count(), 0);
+    }
+
     #[test]
     fn max_abs_in_span_uses_peaks_when_samples_empty() {
         let peaks = WaveformPeaks {
@@ -219,6 +279,7 @@ mod tests {
             duration_seconds: 1.0,
             sample_rate: 4,
             channels: 2,
+            cue_markers: Vec::new(),
         };
         let peak_first_bucket = decoded.max_abs_in_span(0.0, 0.5).unwrap();
         let peak_full = decoded.max_abs_in_span(0.0, 1.0).unwrap();
@@ -292,6 +353,15 @@ impl WaveformPeaks {
         }
     }
 
+    /// Downsample the full mono envelope to `width` min/max columns.
+    ///
+    /// Uses the same bucket math as [`Self::sample_columns_for_view`] over the whole file,
+    /// for callers drawing their own visualizations.
+    pub fn resample_to_width(&self, width: u32) -> Vec<(f32, f32)> {
+        let width = width.max(1) as usize;
+        self.sample_peak_columns(&self.mono, 0, self.total_frames.max(1), width)
+    }
+
     fn sample_peak_columns(
         &self,
         peaks: &[(f32, f32)],
@@ -358,6 +428,29 @@ mod peaks_tests {
         assert_eq!(cols.len(), 7);
         assert!(cols.iter().all(|(min, max)| min <= max));
     }
+
+    #[test]
+    fn resample_to_width_covers_full_range() {
+        let peaks = WaveformPeaks {
+            total_frames: 100,
+            channels: 1,
+            bucket_size_frames: 10,
+            mono: (0..10)
+                .map(|i| (-(i as f32) / 10.0, i as f32 / 10.0))
+                .collect(),
+            left: None,
+            right: None,
+        };
+        let columns = peaks.resample_to_width(10);
+        assert_eq!(columns.len(), 10);
+        assert!(columns.iter().all(|(min, max)| min <= max));
+        assert!(
+            columns
+                .iter()
+                .all(|(min, max)| (-1.0..=1.0).contains(min) && (-1.0..=1.0).contains(max))
+        );
+        assert_eq!(columns[9], (-0.9, 0.9));
+    }
 }
 
 /// Renders averaged waveforms from wav samples.
@@ -62,18 +62,15 @@ impl WaveformRenderer {
         height: u32,
     ) -> ColorImage {
         self.render_color_image_for_view_with_size_and_fade(
-            decoded,
-            view_start,
-            view_end,
-            view,
-            width,
-            height,
-            None,
+            decoded, view_start, view_end, view, width, height, None, 1.0,
         )
     }
 
     /// Render an egui color image for a decoded waveform over a normalized view window
     /// with an optional edit-fade preview applied.
+    ///
+    /// `vertical_zoom` magnifies amplitudes for display only (1.0 = unscaled); scaled
+    /// columns are clamped to the image bounds.
     pub fn render_color_image_for_view_with_size_and_fade(
         &self,
         decoded: &DecodedWaveform,
@@ -83,6 +80,7 @@ impl WaveformRenderer {
         width: u32,
         height: u32,
         edit_fade: Option<SelectionRange>,
+        vertical_zoom: f32,
     ) -> ColorImage {
         let width = width.max(1);
         let height = height.max(1);
@@ -115,6 +113,7 @@ impl WaveformRenderer {
                     WaveformColumnView::Mono(cols) => {
                         let mut cols = Self::smooth_columns(&cols, smooth_radius);
                         apply_fade_to_columns(&mut cols, start, end, width, fade);
+                        apply_vertical_zoom_to_columns(&mut cols, vertical_zoom);
                         Self::paint_color_image_for_size_with_density(
                             &cols,
                             width,
@@ -129,6 +128,8 @@ impl WaveformRenderer {
                         let mut right = Self::smooth_columns(&right, smooth_radius);
                         apply_fade_to_columns(&mut left, start, end, width, fade);
                         apply_fade_to_columns(&mut right, start, end, width, fade);
+                        apply_vertical_zoom_to_columns(&mut left, vertical_zoom);
+                        apply_vertical_zoom_to_columns(&mut right, vertical_zoom);
                         Self::paint_split_color_image_with_density(
                             &left,
                             &right,
@@ -153,8 +154,16 @@ impl WaveformRenderer {
             );
         }
 
-        if let Some(image) = self.render_cached_view(decoded, start, end, view, width, height, fade)
-        {
+        if let Some(image) = self.render_cached_view(
+            decoded,
+            start,
+            end,
+            view,
+            width,
+            height,
+            fade,
+            vertical_zoom,
+        ) {
             return image;
         }
 
@@ -170,7 +179,7 @@ impl WaveformRenderer {
         let end_idx = end_frame
             .saturating_mul(channels)
             .min(decoded.samples.len());
-        self.render_color_image_with_size(
+        self.render_color_image_with_size_and_zoom(
             &decoded.samples[start_idx..end_idx],
             channels,
             view,
@@ -179,6 +188,7 @@ impl WaveformRenderer {
             start,
             end,
             fade,
+            vertical_zoom,
         )
     }
 
@@ -196,6 +206,23 @@ impl WaveformRenderer {
         view_start: f32,
         view_end: f32,
         edit_fade: Option<SelectionRange>,
+    ) -> ColorImage {
+        self.render_color_image_with_size_and_zoom(
+            samples, channels, view, width, height, view_start, view_end, edit_fade, 1.0,
+        )
+    }
+
+    fn render_color_image_with_size_and_zoom(
+        &self,
+        samples: &[f32],
+        channels: usize,
+        view: WaveformChannelView,
+        width: u32,
+        height: u32,
+        view_start: f32,
+        view_end: f32,
+        edit_fade: Option<SelectionRange>,
+        vertical_zoom: f32,
     ) -> ColorImage {
         let width = width.max(1);
         let height = height.max(1);
@@ -222,6 +249,7 @@ impl WaveformRenderer {
                         self.foreground,
                         self.background,
                         None,
+                        line_zoom(vertical_zoom),
                     ),
                     WaveformChannelView::SplitStereo => Self::paint_split_line_image(
                         &faded,
@@ -230,6 +258,7 @@ impl WaveformRenderer {
                         height,
                         self.foreground,
                         self.background,
+                        line_zoom(vertical_zoom),
                     ),
                 };
             }
@@ -242,6 +271,7 @@ impl WaveformRenderer {
                     self.foreground,
                     self.background,
                     None,
+                    line_zoom(vertical_zoom),
                 ),
                 WaveformChannelView::SplitStereo => Self::paint_split_line_image(
                     samples,
@@ -250,6 +280,7 @@ impl WaveformRenderer {
                     height,
                     self.foreground,
                     self.background,
+                    line_zoom(vertical_zoom),
                 ),
             };
         }
@@ -259,6 +290,7 @@ impl WaveformRenderer {
             WaveformColumnView::Mono(cols) => {
                 let mut cols = Self::smooth_columns(&cols, smooth_radius);
                 apply_fade_to_columns(&mut cols, view_start, view_end, width, edit_fade);
+                apply_vertical_zoom_to_columns(&mut cols, vertical_zoom);
                 Self::paint_color_image_for_size_with_density(
                     &cols,
                     width,
@@ -273,6 +305,8 @@ impl WaveformRenderer {
                 let mut right = Self::smooth_columns(&right, smooth_radius);
                 apply_fade_to_columns(&mut left, view_start, view_end, width, edit_fade);
                 apply_fade_to_columns(&mut right, view_start, view_end, width, edit_fade);
+                apply_vertical_zoom_to_columns(&mut left, vertical_zoom);
+                apply_vertical_zoom_to_columns(&mut right, vertical_zoom);
                 Self::paint_split_color_image_with_density(
                     &left,
                     &right,
@@ -287,6 +321,29 @@ impl WaveformRenderer {
     }
 }
 
+fn needs_vertical_zoom(vertical_zoom: f32) -> bool {
+    vertical_zoom.is_finite() && vertical_zoom > 0.0 && (vertical_zoom - 1.0).abs() > f32::EPSILON
+}
+
+/// Scale column amplitudes for display, clamping to the drawable range.
+pub(super) fn apply_vertical_zoom_to_columns(columns: &mut [(f32, f32)], vertical_zoom: f32) {
+    if !needs_vertical_zoom(vertical_zoom) {
+        return;
+    }
+    for (min, max) in columns.iter_mut() {
+        *min = (*min * vertical_zoom).clamp(-1.0, 1.0);
+        *max = (*max * vertical_zoom).clamp(-1.0, 1.0);
+    }
+}
+
+fn line_zoom(vertical_zoom: f32) -> f32 {
+    if needs_vertical_zoom(vertical_zoom) {
+        vertical_zoom
+    } else {
+        1.0
+    }
+}
+
 fn fade_intersects_view(
     view_start: f32,
     view_end: f32,
@@ -402,6 +459,7 @@ mod tests {
             duration_seconds: 1.0,
             sample_rate: 48_000,
             channels: 1,
+            cue_markers: Vec::new(),
         };
         let image = renderer.render_color_image_for_view_with_size(
             &decoded,
@@ -414,6 +472,72 @@ mod tests {
         assert_eq!(image.size, [5, 3]);
     }
 
+    #[test]
+    fn vertical_zoom_doubles_column_heights_with_clamping() {
+        let peaks = [(-0.1, 0.2), (-0.25, 0.25), (-0.8, 0.6)];
+        let mut unscaled = peaks.to_vec();
+        let mut zoomed = peaks.to_vec();
+        apply_vertical_zoom_to_columns(&mut unscaled, 1.0);
+        apply_vertical_zoom_to_columns(&mut zoomed, 2.0);
+
+        let height = |(min, max): (f32, f32)| max - min;
+        assert!((height(zoomed[0]) - 2.0 * height(unscaled[0])).abs() < 1e-6);
+        assert!((height(zoomed[1]) - 2.0 * height(unscaled[1])).abs() < 1e-6);
+        assert_eq!(zoomed[2], (-1.0, 1.0));
+    }
+
+    #[test]
+    fn vertical_zoom_magnifies_rendered_columns() {
+        let renderer = WaveformRenderer::new(2, 2);
+        let samples: Vec<f32> = (0..400)
+            .map(|i| if i % 2 == 0 { 0.2 } else { -0.2 })
+            .collect();
+        let lit_rows = |zoom: f32| {
+            let image = renderer.render_color_image_with_size_and_zoom(
+                &samples,
+                1,
+                WaveformChannelView::Mono,
+                4,
+                101,
+                0.0,
+                1.0,
+                None,
+                zoom,
+            );
+            (0..image.size[1])
+                .filter(|y| image.pixels[y * image.size[0]].a() > 0)
+                .count()
+        };
+        let unscaled = lit_rows(1.0);
+        let zoomed = lit_rows(2.0);
+        assert!(zoomed > unscaled * 3 / 2, "{zoomed} vs {unscaled}");
+        assert!(lit_rows(10.0) <= 101);
+    }
+
+    #[test]
+    fn vertical_zoom_scales_line_rendering() {
+        let renderer = WaveformRenderer::new(2, 2);
+        let samples = [0.2_f32; 8];
+        let top_row = |zoom: f32| {
+            let image = renderer.render_color_image_with_size_and_zoom(
+                &samples,
+                1,
+                WaveformChannelView::Mono,
+                16,
+                101,
+                0.0,
+                1.0,
+                None,
+                zoom,
+            );
+            (0..image.size[1])
+                .find(|y| image.pixels[y * image.size[0] + 8].a() > 0)
+                .unwrap()
+        };
+        assert_eq!(top_row(1.0), 40);
+        assert_eq!(top_row(2.0), 30);
+    }
+
     #[test]
     fn columns_window_clamps_to_last_window() {
         let renderer = WaveformRenderer::new(2, 2);
@@ -33,6 +33,7 @@ impl WaveformRenderer {
         width: u32,
         height: u32,
         edit_fade: Option<SelectionRange>,
+        vertical_zoom: f32,
     ) -> Option<ColorImage> {
         let frame_count = decoded.frame_count();
         let fraction = (view_end - view_start).max(0.000_001);
@@ -51,6 +52,7 @@ impl WaveformRenderer {
             CachedColumns::Mono(cols) => {
                 let mut cols = Self::smooth_columns(&cols[start_col..end_col], smooth_radius);
                 super::apply_fade_to_columns(&mut cols, view_start, view_end, width, edit_fade);
+                super::apply_vertical_zoom_to_columns(&mut cols, vertical_zoom);
                 Self::paint_color_image_for_size_with_density(
                     &cols,
                     width,
@@ -65,6 +67,8 @@ impl WaveformRenderer {
                 let mut right = Self::smooth_columns(&right[start_col..end_col], smooth_radius);
                 super::apply_fade_to_columns(&mut left, view_start, view_end, width, edit_fade);
                 super::apply_fade_to_columns(&mut right, view_start, view_end, width, edit_fade);
+                super::apply_vertical_zoom_to_columns(&mut left, vertical_zoom);
+                super::apply_vertical_zoom_to_columns(&mut right, vertical_zoom);
                 Self::paint_split_color_image_with_density(
                     &left,
                     &right,
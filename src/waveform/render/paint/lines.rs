@@ -10,6 +10,7 @@ impl WaveformRenderer {
         foreground: Color32,
         background: Color32,
         channel_index: Option<usize>,
+        vertical_zoom: f32,
     ) -> ColorImage {
         let fill =
             Color32::from_rgba_unmultiplied(background.r(), background.g(), background.b(), 0);
@@ -31,7 +32,9 @@ impl WaveformRenderer {
             foreground.b(),
             foreground.a(),
         );
-        let to_y = |sample: f32| -> f32 { (mid - sample * half_height).clamp(0.0, mid * 2.0) };
+        // Vertical zoom is applied here so zoomed renders never copy the sample slice.
+        let scale = half_height * vertical_zoom;
+        let to_y = |sample: f32| -> f32 { (mid - sample * scale).clamp(0.0, mid * 2.0) };
 
         let mut prev_y = None;
         for x in 0..width as usize {
@@ -71,6 +74,7 @@ impl WaveformRenderer {
         height: u32,
         foreground: Color32,
         background: Color32,
+        vertical_zoom: f32,
     ) -> ColorImage {
         let gap = if height >= 3 { 2 } else { 0 };
         let split_height = height.saturating_sub(gap);
@@ -85,6 +89,7 @@ impl WaveformRenderer {
             foreground,
             background,
             Some(0),
+            vertical_zoom,
         );
         let bottom = Self::paint_line_image(
             samples,
@@ -94,6 +99,7 @@ impl WaveformRenderer {
             foreground,
             background,
             Some(1),
+            vertical_zoom,
         );
         let fill =
             Color32::from_rgba_unmultiplied(background.r(), background.g(), background.b(), 0);
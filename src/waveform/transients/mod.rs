@@ -4,11 +4,11 @@ mod stats;
 
 use super::DecodedWaveform;
 use odf::{analysis_params, mono_samples, spectral_flux_superflux};
-use peaks::{
-    SensitivityParams, compute_baselines, percentile, pick_peaks_hysteresis, smooth_values,
-};
+use peaks::{compute_baselines, percentile, pick_peaks_hysteresis, smooth_values};
 use tracing::info;
 
+pub use peaks::SensitivityParams;
+
 const BASELINE_SECONDS: f32 = 0.15;
 const MAX_THRESHOLD_WINDOW: usize = 64;
 const MIN_THRESHOLD_WINDOW: usize = 8;
@@ -33,10 +33,29 @@ pub struct TransientNovelty {
 
 /// Detect normalized transient positions for a decoded waveform.
 pub fn detect_transients(decoded: &DecodedWaveform, sensitivity: f32) -> Vec<f32> {
+    detect_transients_with_cap(decoded, sensitivity, None)
+}
+
+/// Detect transient positions, keeping at most `max_markers` of the strongest onsets.
+///
+/// `None` keeps the automatic cap derived from the duration and minimum onset gap.
+pub fn detect_transients_with_cap(
+    decoded: &DecodedWaveform,
+    sensitivity: f32,
+    max_markers: Option<usize>,
+) -> Vec<f32> {
     let Some(novelty) = compute_transient_novelty(decoded) else {
         return Vec::new();
     };
-    pick_transients_from_novelty(&novelty, sensitivity, decoded.duration_seconds)
+    pick_transients_with_cap(&novelty, sensitivity, decoded.duration_seconds, max_markers)
+}
+
+/// Detected onsets per second for `transient_count` markers over `duration_seconds`.
+pub fn onset_density(transient_count: usize, duration_seconds: f32) -> f32 {
+    if !duration_seconds.is_finite() || duration_seconds <= 0.0 {
+        return 0.0;
+    }
+    transient_count as f32 / duration_seconds
 }
 
 /// Compute the transient novelty curve for the decoded waveform.
@@ -82,8 +101,30 @@ pub fn pick_transients_from_novelty(
     sensitivity: f32,
     duration_seconds: f32,
 ) -> Vec<f32> {
-    let sensitivity = sensitivity.clamp(0.0, 1.0);
+    pick_transients_with_cap(novelty, sensitivity, duration_seconds, None)
+}
+
+/// Pick transient markers from a novelty curve with an optional hard cap on the count.
+///
+/// The cap only ever lowers the automatic limit; the strongest peaks are kept.
+pub fn pick_transients_with_cap(
+    novelty: &TransientNovelty,
+    sensitivity: f32,
+    duration_seconds: f32,
+    max_markers: Option<usize>,
+) -> Vec<f32> {
     let params = SensitivityParams::from_sensitivity(sensitivity);
+    pick_transients_with_tuning(novelty, params, duration_seconds, max_markers)
+}
+
+/// Pick transient markers from a novelty curve using explicit peak-picking thresholds.
+pub fn pick_transients_with_tuning(
+    novelty: &TransientNovelty,
+    tuning: SensitivityParams,
+    duration_seconds: f32,
+    max_markers: Option<usize>,
+) -> Vec<f32> {
+    let params = tuning.sanitized();
     let novelty_smoothed = smooth_values(&novelty.novelty, SMOOTH_RADIUS);
     let window = ((BASELINE_SECONDS * novelty.sample_rate as f32 / novelty.hop as f32).round()
         as usize)
@@ -94,7 +135,10 @@ pub fn pick_transients_from_novelty(
         / novelty.hop as f32)
         .round()
         .max(1.0) as usize;
-    let max_transients = max_transients(duration_seconds, params.min_gap_seconds);
+    let mut max_transients = max_transients(duration_seconds, params.min_gap_seconds);
+    if let Some(cap) = max_markers {
+        max_transients = max_transients.min(cap.max(1));
+    }
     if std::env::var("SEMPAL_TRANSIENT_DEBUG").is_ok() {
         let min_value = novelty_smoothed
             .iter()
@@ -154,6 +198,33 @@ pub fn pick_transients_from_novelty(
     positions
 }
 
+/// Return the transient marker closest to `position`, if any.
+///
+/// Ties resolve to the earlier marker; non-finite positions yield `None`.
+pub fn nearest_transient(transients: &[f32], position: f32) -> Option<f32> {
+    if !position.is_finite() {
+        return None;
+    }
+    transients
+        .iter()
+        .copied()
+        .filter(|marker| marker.is_finite())
+        .min_by(|a, b| (a - position).abs().total_cmp(&(b - position).abs()))
+}
+
+/// Return the first transient marker strictly after `position`, if any.
+pub fn next_transient_after(transients: &[f32], position: f32) -> Option<f32> {
+    const POSITION_EPS: f32 = 1e-6;
+    if !position.is_finite() {
+        return None;
+    }
+    transients
+        .iter()
+        .copied()
+        .filter(|marker| marker.is_finite() && *marker > position + POSITION_EPS)
+        .min_by(|a, b| a.total_cmp(b))
+}
+
 fn max_transients(duration_seconds: f32, min_gap_seconds: f32) -> usize {
     let duration = duration_seconds.max(0.01);
     let max_by_gap = (duration / min_gap_seconds.max(0.01)).ceil();
@@ -165,6 +236,15 @@ mod tests {
     use super::*;
     use std::sync::Arc;
 
+    #[test]
+    fn nearest_transient_picks_closer_marker() {
+        let markers = [0.2, 0.5, 0.9];
+        assert_eq!(nearest_transient(&markers, 0.3), Some(0.2));
+        assert_eq!(nearest_transient(&markers, 0.4), Some(0.5));
+        assert_eq!(nearest_transient(&markers, 1.0), Some(0.9));
+        assert_eq!(nearest_transient(&[], 0.4), None);
+    }
+
     #[test]
     fn detects_single_spike_transient() {
         let mut samples = vec![0.0f32; 4096];
@@ -179,6 +259,7 @@ mod tests {
             duration_seconds: 1.0,
             sample_rate: 48_000,
             channels: 1,
+            cue_markers: Vec::new(),
         };
         let transients = detect_transients(&decoded, 1.0);
         assert!(!transients.is_empty());
@@ -201,11 +282,36 @@ mod tests {
             duration_seconds: 1.0,
             sample_rate: 48_000,
             channels: 1,
+            cue_markers: Vec::new(),
         };
         let transients = detect_transients(&decoded, 1.0);
         assert!(transients.len() >= 2);
     }
 
+    #[test]
+    fn low_cap_limits_markers_when_many_peaks_exist() {
+        let mut samples = vec![0.0f32; 48_000];
+        for spike in 1..10 {
+            samples[spike * 4_800] = 1.0;
+        }
+        let decoded = DecodedWaveform {
+            cache_token: 4,
+            samples: Arc::from(samples.into_boxed_slice()),
+            analysis_samples: Arc::from(Vec::new()),
+            analysis_sample_rate: 0,
+            analysis_stride: 1,
+            peaks: None,
+            duration_seconds: 1.0,
+            sample_rate: 48_000,
+            channels: 1,
+            cue_markers: Vec::new(),
+        };
+        let uncapped = detect_transients(&decoded, 1.0);
+        assert!(uncapped.len() > 3);
+        let capped = detect_transients_with_cap(&decoded, 1.0, Some(3));
+        assert_eq!(capped.len(), 3);
+    }
+
     #[test]
     fn detects_transients_from_analysis_samples() {
         let mut samples = vec![0.0f32; 4096];
@@ -222,6 +328,7 @@ mod tests {
             duration_seconds: 1.0,
             sample_rate: 48_000,
             channels: 1,
+            cue_markers: Vec::new(),
         };
         let novelty = compute_transient_novelty(&decoded).expect("analysis novelty");
         assert!(novelty.total_frames > 0);
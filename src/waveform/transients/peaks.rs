@@ -3,18 +3,24 @@ use ordered_float::NotNan;
 use std::collections::{BTreeMap, VecDeque};
 use std::ops::Bound::{Excluded, Unbounded};
 
-#[derive(Clone, Copy, Debug)]
-/// Parameters derived from the UI sensitivity slider.
-pub(crate) struct SensitivityParams {
-    pub(crate) k_high: f32,
-    pub(crate) k_low: f32,
-    pub(crate) floor_quantile: f32,
-    pub(crate) min_gap_seconds: f32,
+/// Peak-picking thresholds used to turn a novelty curve into transient markers.
+///
+/// Usually derived from the UI sensitivity slider, but can be tuned field by field.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SensitivityParams {
+    /// Onset threshold in baseline deviations above the rolling median.
+    pub k_high: f32,
+    /// Re-arm threshold in baseline deviations; keeps one onset from firing twice.
+    pub k_low: f32,
+    /// Quantile of the novelty curve below which peaks are ignored.
+    pub floor_quantile: f32,
+    /// Minimum spacing between markers in seconds.
+    pub min_gap_seconds: f32,
 }
 
 impl SensitivityParams {
     /// Map a 0-1 sensitivity value into threshold and gap parameters.
-    pub(crate) fn from_sensitivity(sensitivity: f32) -> Self {
+    pub fn from_sensitivity(sensitivity: f32) -> Self {
         let sensitivity = sensitivity.clamp(0.0, 1.0);
         let k_high = 6.0 - 3.0 * sensitivity;
         let k_low = k_high * 0.5;
@@ -28,6 +34,17 @@ impl SensitivityParams {
         }
     }
 
+    /// Clamp hand-tuned values into ranges the peak picker can use.
+    pub(crate) fn sanitized(self) -> Self {
+        let k_high = self.k_high.max(0.0);
+        Self {
+            k_high,
+            k_low: self.k_low.clamp(0.0, k_high),
+            floor_quantile: self.floor_quantile.clamp(0.0, 1.0),
+            min_gap_seconds: self.min_gap_seconds.max(0.001),
+        }
+    }
+
     /// Return a relaxed pass configuration that keeps the same min-gap.
     pub(crate) fn relaxed(self) -> Self {
         Self {